@@ -1,2 +1,12 @@
 package advisor.authz
-default allow=false
+
+# Input: {"role": "<viewer|analyst|curator|admin>", "action": "<action>"}
+# Role grants live in data.json (loaded as data.advisor.role_permissions)
+# so analyzer-api can evaluate the same table in-process when no OPA
+# sidecar is configured.
+
+default allow = false
+
+allow {
+    data.advisor.role_permissions[input.role][_] == input.action
+}
@@ -1,42 +1,8 @@
-use yew::prelude::*;
-use serde::{Deserialize, Serialize};
+use analyzer_types::sse;
+use analyzer_types::v2::{AnalysisResponse, AnalyzeRequest, Detector, Finding, StreamedFinding, DEFAULT_LANGUAGE};
 use wasm_bindgen_futures::JsFuture;
 use wasm_bindgen::prelude::*;
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
-struct CodeAnalysis {
-    code: String,
-}
-
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
-struct DetectedAlgorithm {
-    name: String,
-    category: String,
-    complexity: String,
-    description: String,
-    wikipedia_link: String,
-}
-
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
-struct DetectedDataStructure {
-    name: String,
-    category: String,
-    complexity: String,
-    description: String,
-    wikipedia_link: String,
-}
-
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
-struct AnalysisResult {
-    patterns: Vec<String>,
-    algorithms: Vec<String>,
-    detailed_algorithms: Vec<DetectedAlgorithm>,
-    data_structures: Vec<String>,
-    detailed_data_structures: Vec<DetectedDataStructure>,
-    complexity: String,
-    recommendations: Vec<String>,
-}
-
 // Add the entry point for the WASM module
 #[wasm_bindgen(start)]
 pub fn main() {
@@ -47,7 +13,6 @@ pub fn main() {
 fn initialize_app() {
     // Check API status
     let window = web_sys::window().unwrap();
-    let document = window.document().unwrap();
     
     // Set a timeout to simulate API connection and update status
     let timeout_callback = Closure::wrap(Box::new(move || {
@@ -102,101 +67,22 @@ fn analyze_code() {
         btn.set_attribute("disabled", "").unwrap();
     }
     
-    // Make API call
+    // Make API call; findings are shown as they stream in, then replaced by the summary
     let future = async move {
-        let result = call_analyze_api(&code).await;
-        
-        // Update UI with results
         let window = web_sys::window().unwrap();
         let document = window.document().unwrap();
         
-        match result {
-            Ok(analysis) => {
-                // Show results container
-                if let Some(container) = document.get_element_by_id("results-container") {
-                    container.set_attribute("style", "display: block;").unwrap();
-                }
-                
-                // Update complexity badge
-                if let Some(badge) = document.get_element_by_id("complexity-badge") {
-                    let class = match analysis.complexity.as_str() {
-                        "High" => "badge badge-danger",
-                        "Medium" => "badge badge-warning",
-                        "Low" => "badge badge-success",
-                        _ => "badge badge-success"
-                    };
-                    badge.set_attribute("class", class).unwrap();
-                    badge.set_inner_html(&format!("Complexity: {}", analysis.complexity));
-                }
-                
-                // Update patterns list
-                if let Some(list) = document.get_element_by_id("patterns-list") {
-                    let mut html = String::new();
-                    for pattern in &analysis.patterns {
-                        html.push_str(&format!("<li>{}</li>", pattern));
-                    }
-                    list.set_inner_html(&html);
-                }
-                
-                // Update algorithms list
-                if let Some(list) = document.get_element_by_id("algorithms-list") {
-                    let mut html = String::new();
-                    for algorithm in &analysis.algorithms {
-                        html.push_str(&format!("<li>{}</li>", algorithm));
-                    }
-                    list.set_inner_html(&html);
-                }
-                
-                // Update detailed algorithms list
-                if let Some(list) = document.get_element_by_id("detailed-algorithms-list") {
-                    let mut html = String::new();
-                    for algorithm in &analysis.detailed_algorithms {
-                        html.push_str(&format!(
-                            "<li><strong>{}</strong> ({})<br><small>{}</small><br><a href=\"{}\" target=\"_blank\">Learn more on Wikipedia</a></li>",
-                            algorithm.name, algorithm.category, algorithm.description, algorithm.wikipedia_link
-                        ));
-                    }
-                    list.set_inner_html(&html);
-                }
-                
-                // Update data structures list
-                if let Some(list) = document.get_element_by_id("data-structures-list") {
-                    let mut html = String::new();
-                    for data_structure in &analysis.data_structures {
-                        html.push_str(&format!("<li>{}</li>", data_structure));
-                    }
-                    list.set_inner_html(&html);
-                }
-                
-                // Update detailed data structures list
-                if let Some(list) = document.get_element_by_id("detailed-data-structures-list") {
-                    let mut html = String::new();
-                    for data_structure in &analysis.detailed_data_structures {
-                        html.push_str(&format!(
-                            "<li><strong>{}</strong> ({})<br><small>{}</small><br><a href=\"{}\" target=\"_blank\">Learn more on Wikipedia</a></li>",
-                            data_structure.name, data_structure.category, data_structure.description, data_structure.wikipedia_link
-                        ));
-                    }
-                    list.set_inner_html(&html);
-                }
-                
-                // Update recommendations list
-                if let Some(list) = document.get_element_by_id("recommendations-list") {
-                    let mut html = String::new();
-                    for recommendation in &analysis.recommendations {
-                        html.push_str(&format!("<li>{}</li>", recommendation));
-                    }
-                    list.set_inner_html(&html);
-                }
-            }
-            Err(e) => {
-                // Show error in recommendations
-                if let Some(list) = document.get_element_by_id("recommendations-list") {
-                    list.set_inner_html(&format!("<li>Error: {}</li>", e));
-                }
-                if let Some(container) = document.get_element_by_id("results-container") {
-                    container.set_attribute("style", "display: block;").unwrap();
-                }
+        start_results(&document);
+        let result = stream_analyze_api(&code, |event| match event {
+            StreamEvent::Finding(finding) => append_finding(&document, &finding),
+            StreamEvent::Summary(analysis) => render_analysis(&document, &analysis),
+        })
+        .await;
+        
+        if let Err(e) = result {
+            // Show error in recommendations
+            if let Some(list) = document.get_element_by_id("recommendations-list") {
+                list.set_inner_html(&format!("<li>Error: {}</li>", e));
             }
         }
         
@@ -206,19 +92,161 @@ fn analyze_code() {
             btn.set_inner_html("<i class=\"fas fa-search\"></i> Analyze Code");
             btn.remove_attribute("disabled").unwrap();
         }
-        
-        // Scroll to results (simple approach)
-        if let Some(container) = document.get_element_by_id("results-container") {
-            // Simple scroll to top of results
-            let _ = container.scroll_into_view();
-        }
     };
     
     wasm_bindgen_futures::spawn_local(future);
 }
 
-// Function to call the backend API
-async fn call_analyze_api(code: &str) -> Result<AnalysisResult, String> {
+const RESULT_LISTS: [&str; 6] = [
+    "patterns-list",
+    "algorithms-list",
+    "detailed-algorithms-list",
+    "data-structures-list",
+    "detailed-data-structures-list",
+    "recommendations-list",
+];
+
+// Show the empty results container before the first event arrives
+fn start_results(document: &web_sys::Document) {
+    for id in RESULT_LISTS {
+        if let Some(list) = document.get_element_by_id(id) {
+            list.set_inner_html("");
+        }
+    }
+    if let Some(badge) = document.get_element_by_id("complexity-badge") {
+        badge.set_attribute("class", "badge").unwrap();
+        badge.set_inner_html("Analyzing...");
+    }
+    if let Some(container) = document.get_element_by_id("results-container") {
+        container.set_attribute("style", "display: block;").unwrap();
+        // Simple scroll to top of results
+        container.scroll_into_view();
+    }
+}
+
+fn finding_details(finding: &Finding) -> String {
+    let relations = if finding.relations.is_empty() {
+        String::new()
+    } else {
+        let related: Vec<String> = finding
+            .relations
+            .iter()
+            .map(|relation| format!("{} {}", relation.kind.as_str().replace('_', " "), relation.target))
+            .collect();
+        format!("<br><small>{}</small>", related.join(" · "))
+    };
+    let definitions = if finding.definitions.is_empty() {
+        String::new()
+    } else {
+        let defined: Vec<String> = finding
+            .definitions
+            .iter()
+            .map(|definition| format!("<code>{}</code>, line {}", definition.name, definition.span.start_line))
+            .collect();
+        format!("<br><small>Defined by {}</small>", defined.join(" · "))
+    };
+    format!(
+        "<strong>{}</strong> ({})<br><small>{}</small>{}{}<br><a href=\"{}\" target=\"_blank\">Learn more on Wikipedia</a>",
+        finding.name, finding.category, finding.description, relations, definitions, finding.wikipedia_link
+    )
+}
+
+// Add one streamed finding to the lists, noting the function it came from
+fn append_finding(document: &web_sys::Document, streamed: &StreamedFinding) {
+    let (list_id, detailed_id) = match streamed.detector {
+        Detector::Algorithms => ("algorithms-list", "detailed-algorithms-list"),
+        Detector::DataStructures => ("data-structures-list", "detailed-data-structures-list"),
+    };
+    let location = match (&streamed.function, streamed.line) {
+        (Some(function), Some(line)) => format!(" <small>in <code>{}</code>, line {}</small>", function, line),
+        (Some(function), None) => format!(" <small>in <code>{}</code></small>", function),
+        _ => String::new(),
+    };
+    
+    if let Some(list) = document.get_element_by_id(list_id) {
+        let _ = list.insert_adjacent_html("beforeend", &format!("<li>{}{}</li>", streamed.finding.name, location));
+    }
+    if let Some(list) = document.get_element_by_id(detailed_id) {
+        let _ = list.insert_adjacent_html("beforeend", &format!("<li>{}{}</li>", finding_details(&streamed.finding), location));
+    }
+}
+
+// Replace the streamed findings with the final result
+fn render_analysis(document: &web_sys::Document, analysis: &AnalysisResponse) {
+    // Update complexity badge
+    if let Some(badge) = document.get_element_by_id("complexity-badge") {
+        let class = match analysis.complexity.as_str() {
+            "High" => "badge badge-danger",
+            "Medium" => "badge badge-warning",
+            "Low" => "badge badge-success",
+            _ => "badge badge-success"
+        };
+        badge.set_attribute("class", class).unwrap();
+        badge.set_inner_html(&format!("Complexity: {}", analysis.complexity));
+    }
+    
+    // Update patterns list
+    if let Some(list) = document.get_element_by_id("patterns-list") {
+        let mut html = String::new();
+        for pattern in &analysis.patterns {
+            html.push_str(&format!("<li>{}</li>", pattern));
+        }
+        list.set_inner_html(&html);
+    }
+    
+    // Update algorithms list
+    if let Some(list) = document.get_element_by_id("algorithms-list") {
+        let mut html = String::new();
+        for algorithm in &analysis.algorithms {
+            html.push_str(&format!("<li>{}</li>", algorithm.name));
+        }
+        list.set_inner_html(&html);
+    }
+    
+    // Update detailed algorithms list
+    if let Some(list) = document.get_element_by_id("detailed-algorithms-list") {
+        let mut html = String::new();
+        for algorithm in &analysis.algorithms {
+            html.push_str(&format!("<li>{}</li>", finding_details(algorithm)));
+        }
+        list.set_inner_html(&html);
+    }
+    
+    // Update data structures list
+    if let Some(list) = document.get_element_by_id("data-structures-list") {
+        let mut html = String::new();
+        for data_structure in &analysis.data_structures {
+            html.push_str(&format!("<li>{}</li>", data_structure.name));
+        }
+        list.set_inner_html(&html);
+    }
+    
+    // Update detailed data structures list
+    if let Some(list) = document.get_element_by_id("detailed-data-structures-list") {
+        let mut html = String::new();
+        for data_structure in &analysis.data_structures {
+            html.push_str(&format!("<li>{}</li>", finding_details(data_structure)));
+        }
+        list.set_inner_html(&html);
+    }
+    
+    // Update recommendations list
+    if let Some(list) = document.get_element_by_id("recommendations-list") {
+        let mut html = String::new();
+        for recommendation in &analysis.recommendations {
+            html.push_str(&format!("<li>{}</li>", recommendation));
+        }
+        list.set_inner_html(&html);
+    }
+}
+
+enum StreamEvent {
+    Finding(StreamedFinding),
+    Summary(AnalysisResponse),
+}
+
+// Function to call the backend API, handing each event to `on_event` as it arrives
+async fn stream_analyze_api(code: &str, mut on_event: impl FnMut(StreamEvent)) -> Result<(), String> {
     let window = web_sys::window().ok_or("Failed to get window object")?;
     let location = window.location();
     
@@ -227,16 +255,20 @@ async fn call_analyze_api(code: &str) -> Result<AnalysisResult, String> {
     
     // Replace the port to point to the API server (assuming it runs on 8081)
     let api_url = if origin.contains(":8080") {
-        origin.replace(":8080", ":8081") + "/analyze"
+        origin.replace(":8080", ":8081") + "/v2/analyze/stream"
     } else {
-        "http://localhost:8081/analyze".to_string()
+        "http://localhost:8081/v2/analyze/stream".to_string()
     };
     
-    let mut opts = web_sys::RequestInit::new();
+    let opts = web_sys::RequestInit::new();
     opts.set_method("POST");
     opts.set_mode(web_sys::RequestMode::Cors);
     
-    let analysis_data = CodeAnalysis { code: code.to_string() };
+    let analysis_data = AnalyzeRequest {
+        code: code.to_string(),
+        language: DEFAULT_LANGUAGE.to_string(),
+        time_budget_ms: None,
+    };
     let json_data = serde_json::to_string(&analysis_data).map_err(|e| e.to_string())?;
     
     let js_value = wasm_bindgen::JsValue::from_str(&json_data);
@@ -247,6 +279,8 @@ async fn call_analyze_api(code: &str) -> Result<AnalysisResult, String> {
     
     request.headers().set("Content-Type", "application/json")
         .map_err(|e| format!("Failed to set headers: {:?}", e))?;
+    request.headers().set("Accept", "text/event-stream")
+        .map_err(|e| format!("Failed to set headers: {:?}", e))?;
     
     let resp_value = JsFuture::from(window.fetch_with_request(&request))
         .await
@@ -254,21 +288,50 @@ async fn call_analyze_api(code: &str) -> Result<AnalysisResult, String> {
     
     let resp: web_sys::Response = resp_value.dyn_into().map_err(|_| "Failed to cast response")?;
     
-    let text_promise = resp.text()
-        .map_err(|e| format!("Failed to get text promise: {:?}", e))?;
-    
-    let text_js_value = JsFuture::from(text_promise)
-        .await
-        .map_err(|e| format!("Failed to get response text: {:?}", e))?;
+    if !resp.ok() {
+        let text_promise = resp.text()
+            .map_err(|e| format!("Failed to get text promise: {:?}", e))?;
+        let text = JsFuture::from(text_promise)
+            .await
+            .map_err(|e| format!("Failed to get response text: {:?}", e))?
+            .as_string()
+            .unwrap_or_default();
+        return Err(format!("API Error ({}): {}", resp.status(), text));
+    }
     
-    let text = text_js_value.as_string()
-        .ok_or("Failed to convert response to string")?;
+    let body = resp.body().ok_or("The response has no body")?;
+    let reader: web_sys::ReadableStreamDefaultReader = body
+        .get_reader()
+        .dyn_into()
+        .map_err(|_| "Failed to get a reader for the response")?;
+    let mut parser = sse::EventParser::new();
     
-    if resp.ok() {
-        let result: AnalysisResult = serde_json::from_str(&text)
-            .map_err(|e| format!("Failed to parse response: {}", e))?;
-        Ok(result)
-    } else {
-        Err(format!("API Error ({}): {}", resp.status(), text))
+    loop {
+        let chunk: web_sys::ReadableStreamReadResult = JsFuture::from(reader.read())
+            .await
+            .map_err(|e| format!("Failed to read the response: {:?}", e))?
+            .unchecked_into();
+        if chunk.get_done().unwrap_or(true) {
+            return Err("The stream ended before the summary".to_string());
+        }
+        let bytes = js_sys::Uint8Array::new(&chunk.get_value()).to_vec();
+        
+        for event in parser.push(&bytes) {
+            match event.event.as_str() {
+                sse::FINDING => {
+                    let finding = serde_json::from_str(&event.data)
+                        .map_err(|e| format!("Failed to parse finding: {}", e))?;
+                    on_event(StreamEvent::Finding(finding));
+                }
+                sse::SUMMARY => {
+                    let analysis = serde_json::from_str(&event.data)
+                        .map_err(|e| format!("Failed to parse response: {}", e))?;
+                    on_event(StreamEvent::Summary(analysis));
+                    return Ok(());
+                }
+                sse::ERROR => return Err(format!("Analysis failed: {}", event.data)),
+                _ => {}
+            }
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,27 @@
+#!/bin/sh
+# Installs the Swagger UI assets served by the analyzer API's /docs page.
+#
+#   scripts/vendor_swagger_ui.sh [DEST]
+#
+# DEST defaults to services/analyzer-api/static/swagger-ui, where the API
+# looks when ANALYZER_SWAGGER_UI_DIR is unset and it runs from its crate
+# directory. The version is the one pinned in src/openapi.rs.
+set -eu
+
+root=$(cd "$(dirname "$0")/.." && pwd)
+dest=${1:-$root/services/analyzer-api/static/swagger-ui}
+version=$(sed -n 's/^pub const SWAGGER_UI_VERSION: &str = "\(.*\)";$/\1/p' "$root/services/analyzer-api/src/openapi.rs")
+if [ -z "$version" ]; then
+    echo "SWAGGER_UI_VERSION not found in src/openapi.rs" >&2
+    exit 1
+fi
+
+tmp=$(mktemp -d)
+trap 'rm -rf "$tmp"' EXIT
+
+curl -fsSL "https://registry.npmjs.org/swagger-ui-dist/-/swagger-ui-dist-$version.tgz" \
+    | tar -xz -C "$tmp" package/swagger-ui.css package/swagger-ui-bundle.js
+
+mkdir -p "$dest"
+cp "$tmp/package/swagger-ui.css" "$tmp/package/swagger-ui-bundle.js" "$dest/"
+echo "Installed swagger-ui-dist $version in $dest"
@@ -0,0 +1,20 @@
+//! Embed the git revision so /health can report which build is running
+
+use std::process::Command;
+
+fn main() {
+    // Docker builds have no .git directory, so allow passing the revision in
+    let revision = std::env::var("GIT_REVISION").ok().or_else(|| {
+        Command::new("git")
+            .args(["rev-parse", "--short", "HEAD"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    });
+
+    println!("cargo:rustc-env=GIT_REVISION={}", revision.unwrap_or_else(|| "unknown".to_string()));
+    println!("cargo:rerun-if-env-changed=GIT_REVISION");
+    println!("cargo:rerun-if-changed=../../.git/HEAD");
+    println!("cargo:rerun-if-changed=../../.git/refs/heads");
+}
@@ -0,0 +1,255 @@
+//! Operations behind the `icalds-admin` command line tool
+//!
+//! The tool itself only parses arguments and prints; what it reports is
+//! built here, so it can be tested without a database or the network.
+
+use analyzer_types::v2::CatalogKind;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+
+use crate::algorithms::{AlgorithmDetector, AlgorithmInfo};
+use crate::catalog::{self, slug, CatalogEntry};
+use crate::data_structures::{DataStructureDetector, DataStructureInfo};
+use crate::health;
+use crate::models::wikipedia_algorithm::{StoredWikipediaAlgorithm, WikipediaAlgorithm, WikipediaSyncPlan};
+
+/// Both catalogs as the server sees them, sorted by name
+#[derive(Debug, Clone, Serialize)]
+pub struct CatalogExport {
+    pub algorithms: Vec<AlgorithmInfo>,
+    pub data_structures: Vec<DataStructureInfo>,
+}
+
+pub fn export_catalog(algorithms: &AlgorithmDetector, data_structures: &DataStructureDetector) -> CatalogExport {
+    let mut export = CatalogExport {
+        algorithms: algorithms.algorithms.values().cloned().collect(),
+        data_structures: data_structures.data_structures.values().cloned().collect(),
+    };
+    export.algorithms.sort_by(|a, b| a.name.cmp(&b.name));
+    export.data_structures.sort_by(|a, b| a.name.cmp(&b.name));
+    export
+}
+
+/// Something wrong with one catalog entry, or with the catalogs as a whole
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ValidationIssue {
+    /// Catalog of the entry; `None` for the detector self-test
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<CatalogKind>,
+    pub name: String,
+    pub problem: String,
+}
+
+/// Check every entry of both catalogs:
+///
+/// - curated and built-in entries must pass [`catalog::validate_entry`];
+///   Wikipedia imports, which have no examples, need a name and an
+///   http(s) link;
+/// - a complexity summary must yield at least one bound;
+/// - relations must point at entries that exist;
+/// - the detector self-test must pass.
+pub fn validate_catalog(algorithms: &AlgorithmDetector, data_structures: &DataStructureDetector) -> Vec<ValidationIssue> {
+    let known: HashSet<(CatalogKind, String)> = algorithms
+        .algorithms
+        .values()
+        .map(|entry| (CatalogKind::Algorithm, slug(&entry.name)))
+        .chain(data_structures.data_structures.values().map(|entry| (CatalogKind::DataStructure, slug(&entry.name))))
+        .collect();
+
+    let mut issues = Vec::new();
+    for entry in sorted(algorithms.algorithms.values()) {
+        check_entry(CatalogKind::Algorithm, entry, !entry.examples.is_empty(), &entry.complexity, &entry.bounds, &known, &mut issues);
+    }
+    for entry in sorted(data_structures.data_structures.values()) {
+        check_entry(CatalogKind::DataStructure, entry, !entry.examples.is_empty(), &entry.complexity, &entry.bounds, &known, &mut issues);
+    }
+
+    for check in health::run_detector_self_test(algorithms, data_structures) {
+        if !check.passed() {
+            issues.push(ValidationIssue {
+                kind: None,
+                name: check.name,
+                problem: check.detail.unwrap_or_default(),
+            });
+        }
+    }
+    issues
+}
+
+fn sorted<'a, T: CatalogEntry>(entries: impl Iterator<Item = &'a T>) -> Vec<&'a T> {
+    let mut entries: Vec<&T> = entries.collect();
+    entries.sort_by(|a, b| a.name().cmp(b.name()));
+    entries
+}
+
+fn check_entry<T: CatalogEntry + Serialize>(
+    kind: CatalogKind,
+    entry: &T,
+    curated: bool,
+    complexity: &str,
+    bounds: &crate::complexity::Complexity,
+    known: &HashSet<(CatalogKind, String)>,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    let mut issue = |problem: String| {
+        issues.push(ValidationIssue {
+            kind: Some(kind),
+            name: entry.name().to_string(),
+            problem,
+        })
+    };
+
+    if curated {
+        if let Err(e) = catalog::validate_entry(entry) {
+            issue(e.to_string());
+        }
+    } else {
+        let link = serde_json::to_value(entry)
+            .ok()
+            .and_then(|value| value.get("wikipedia_link").and_then(Value::as_str).map(str::to_string))
+            .unwrap_or_default();
+        if entry.name().trim().is_empty() {
+            issue("name must not be empty".to_string());
+        }
+        if !(link.starts_with("https://") || link.starts_with("http://")) {
+            issue("imported entries must link their article".to_string());
+        }
+    }
+
+    if !complexity.trim().is_empty() && complexity != "Not specified" && bounds.is_empty() {
+        issue(format!("complexity '{}' has no bound in O-notation", complexity));
+    }
+    for relation in entry.relations() {
+        if !known.contains(&(relation.target_kind, slug(&relation.target))) {
+            issue(format!("relation {} points at unknown {} '{}'", relation.kind.as_str(), relation.target_kind.as_str(), relation.target));
+        }
+    }
+}
+
+/// How an import would change one stored article
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Added,
+    Changed,
+    Removed,
+}
+
+/// One article an import would add, change or remove
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EntryChange {
+    pub change: ChangeKind,
+    pub title: String,
+    pub url: String,
+    /// Fields that differ, each with its `before` and `after` value
+    pub fields: Value,
+}
+
+/// The articles a planned import touches, with the fields it changes;
+/// unchanged articles are left out
+pub fn describe_plan(stored: &[StoredWikipediaAlgorithm], plan: &WikipediaSyncPlan) -> Vec<EntryChange> {
+    let by_url: BTreeMap<&str, &WikipediaAlgorithm> = stored.iter().map(|row| (row.algorithm.url.as_str(), &row.algorithm)).collect();
+    // The revision moves with every edit and is not a change of its own
+    let fields = |entry: &WikipediaAlgorithm| {
+        let mut value = serde_json::to_value(entry).unwrap_or(Value::Null);
+        if let Some(fields) = value.as_object_mut() {
+            fields.remove("revision_id");
+        }
+        value
+    };
+
+    let mut changes = Vec::new();
+    for (change, entries) in [
+        (ChangeKind::Added, &plan.added),
+        (ChangeKind::Changed, &plan.changed),
+        (ChangeKind::Removed, &plan.removed),
+    ] {
+        for entry in entries {
+            let before = match change {
+                // A removed row listed again counts as added, from nothing
+                ChangeKind::Added => None,
+                _ => by_url.get(entry.url.as_str()).map(|stored| fields(stored)),
+            };
+            let after = (change != ChangeKind::Removed).then(|| fields(entry));
+            changes.push(EntryChange {
+                change,
+                title: entry.title.clone(),
+                url: entry.url.clone(),
+                fields: catalog::diff(before.as_ref(), after.as_ref()),
+            });
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wikipedia::sync::plan_sync;
+    use analyzer_types::v2::{Relation, RelationKind};
+
+    #[test]
+    fn test_builtin_catalogs_validate() {
+        let issues = validate_catalog(&AlgorithmDetector::new(), &DataStructureDetector::new());
+        assert!(issues.is_empty(), "{:?}", issues);
+    }
+
+    #[test]
+    fn test_validate_reports_broken_entries() {
+        let algorithms = AlgorithmDetector::new();
+        let mut data_structures = DataStructureDetector::new();
+        let stack = data_structures.data_structures.get_mut("Stack").unwrap();
+        stack.complexity = "constant".to_string();
+        stack.bounds = Default::default();
+        stack.relations.push(Relation::data_structure(RelationKind::VariantOf, "Spaghetti Stack"));
+        data_structures.data_structures.insert(
+            "Rope".to_string(),
+            crate::wikipedia::data_structure_info(WikipediaAlgorithm {
+                title: "Rope".to_string(),
+                url: "Rope_(data_structure)".to_string(),
+                category: "Linear".to_string(),
+                description: None,
+                complexity: None,
+                revision_id: None,
+            }),
+        );
+
+        let problems: Vec<(String, String)> = validate_catalog(&algorithms, &data_structures)
+            .into_iter()
+            .map(|issue| (issue.name, issue.problem))
+            .collect();
+        assert_eq!(
+            problems,
+            [
+                ("Rope".to_string(), "imported entries must link their article".to_string()),
+                ("Stack".to_string(), "complexity 'constant' has no bound in O-notation".to_string()),
+                ("Stack".to_string(), "relation variant_of points at unknown data_structure 'Spaghetti Stack'".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_describe_plan() {
+        let entry = |title: &str, description: &str| WikipediaAlgorithm {
+            title: title.to_string(),
+            url: format!("https://en.wikipedia.org/wiki/{title}"),
+            category: "Sorting".to_string(),
+            description: Some(description.to_string()),
+            complexity: None,
+            revision_id: Some(1),
+        };
+        let stored = vec![
+            StoredWikipediaAlgorithm { algorithm: entry("Quicksort", "Old."), last_seen_at: None, removed_at: None },
+            StoredWikipediaAlgorithm { algorithm: entry("Bogosort", "Shuffles."), last_seen_at: None, removed_at: None },
+        ];
+        let plan = plan_sync(&stored, vec![entry("Quicksort", "New."), entry("Timsort", "Runs.")]);
+
+        let changes = describe_plan(&stored, &plan);
+        let summary: Vec<(ChangeKind, &str)> = changes.iter().map(|c| (c.change, c.title.as_str())).collect();
+        assert_eq!(summary, [(ChangeKind::Added, "Timsort"), (ChangeKind::Changed, "Quicksort"), (ChangeKind::Removed, "Bogosort")]);
+        assert_eq!(changes[1].fields, serde_json::json!({ "description": { "before": "Old.", "after": "New." } }));
+        assert_eq!(changes[2].fields["title"], serde_json::json!({ "before": "Bogosort", "after": null }));
+        assert!(changes[0].fields.get("revision_id").is_none());
+    }
+}
@@ -1,20 +1,44 @@
 //! Algorithm detection and classification system
 
+use analyzer_types::v2::Relation;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::OnceLock;
+use utoipa::ToSchema;
+use crate::complexity::Complexity;
+use crate::limits::AnalysisBudget;
+use fingerprint::{CodeShape, Fingerprint};
 pub mod data;
+pub mod fingerprint;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
 pub struct AlgorithmInfo {
     pub name: String,
     pub category: AlgorithmCategory,
+    /// Human-readable summary, e.g. `O(n log n)`
     pub complexity: String,
+    /// Best, average and worst-case time and auxiliary space
+    #[serde(default)]
+    pub bounds: Complexity,
+    /// Whether equal elements keep their relative order; sorting algorithms only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stable: Option<bool>,
+    /// Whether it sorts within the input using at most O(log n) extra space; sorting algorithms only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_place: Option<bool>,
     pub description: String,
     pub wikipedia_link: String,
     pub examples: Vec<String>,
+    /// Structural patterns; matching any one of them detects the algorithm
+    #[serde(default)]
+    pub fingerprints: Vec<Fingerprint>,
+    /// Typed edges to other catalog entries
+    #[serde(default)]
+    pub relations: Vec<Relation>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
 pub enum AlgorithmCategory {
     Sorting,
     Searching,
@@ -55,12 +79,20 @@ impl std::fmt::Display for AlgorithmCategory {
 
 pub struct AlgorithmDetector {
     pub algorithms: HashMap<String, AlgorithmInfo>,
+    names_lower: LowercaseNames,
+}
+
+impl Default for AlgorithmDetector {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl AlgorithmDetector {
     pub fn new() -> Self {
         let mut detector = Self {
             algorithms: HashMap::new(),
+            names_lower: LowercaseNames::default(),
         };
         detector.initialize_algorithms();
         detector
@@ -76,17 +108,32 @@ impl AlgorithmDetector {
     }
 
     pub fn detect_algorithms(&self, code: &str) -> Vec<DetectedAlgorithm> {
+        self.detect_algorithms_within(code, &AnalysisBudget::unlimited())
+    }
+
+    /// Detect algorithms, stopping early once the budget runs out
+    pub fn detect_algorithms_within(&self, code: &str, budget: &AnalysisBudget) -> Vec<DetectedAlgorithm> {
         let mut detected = Vec::new();
         
+        // Normalize the input once instead of once per catalog entry
+        let code_lower = code.to_lowercase();
+        let code_normalized = normalize_whitespace(code);
+        let shape = CodeShape::of(code);
+        
         for (name, info) in &self.algorithms {
+            if !budget.check() {
+                break;
+            }
+            
             // Check for algorithm patterns in the code
-            if self.pattern_matches(code, info) {
+            if self.pattern_matches(code, &code_lower, &code_normalized, &shape, info) {
                 detected.push(DetectedAlgorithm {
                     name: name.clone(),
                     category: info.category.clone(),
                     complexity: info.complexity.clone(),
                     description: info.description.clone(),
                     wikipedia_link: info.wikipedia_link.clone(),
+                    relations: info.relations.clone(),
                 });
             }
         }
@@ -94,52 +141,34 @@ impl AlgorithmDetector {
         detected
     }
 
-    fn pattern_matches(&self, code: &str, algorithm_info: &AlgorithmInfo) -> bool {
+    fn pattern_matches(
+        &self,
+        code: &str,
+        code_lower: &str,
+        code_normalized: &str,
+        shape: &CodeShape,
+        algorithm_info: &AlgorithmInfo,
+    ) -> bool {
         // Check if any of the examples are found in the code
         for example in &algorithm_info.examples {
-            if code.contains(example) || self.fuzzy_match(code, example) {
+            if code.contains(example) || self.fuzzy_match(code_normalized, example) {
                 return true;
             }
         }
-        
-        // Check for algorithm name in the code
-        if code.to_lowercase().contains(&algorithm_info.name.to_lowercase()) {
+
+        if algorithm_info.fingerprints.iter().any(|fingerprint| shape.matches(fingerprint)) {
             return true;
         }
-        
-        // Check for category-related keywords
-        let category_keywords = match algorithm_info.category {
-            AlgorithmCategory::Sorting => vec!["sort", "sorted", "ordering"],
-            AlgorithmCategory::Searching => vec!["search", "find", "lookup"],
-            AlgorithmCategory::Graph => vec!["graph", "node", "edge", "vertex"],
-            AlgorithmCategory::DynamicProgramming => vec!["dp", "memo", "subproblem"],
-            AlgorithmCategory::Greedy => vec!["greedy", "optimal", "choice"],
-            AlgorithmCategory::Backtracking => vec!["backtrack", "recurse", "prune"],
-            AlgorithmCategory::DivideConquer => vec!["divide", "conquer", "merge"],
-            AlgorithmCategory::Mathematical => vec!["math", "prime", "gcd", "lcm"],
-            AlgorithmCategory::Cryptographic => vec!["encrypt", "decrypt", "hash", "cipher"],
-            AlgorithmCategory::MachineLearning => vec!["train", "predict", "model", "neural"],
-            AlgorithmCategory::String => vec!["string", "substring", "pattern"],
-            AlgorithmCategory::Tree => vec!["tree", "bst", "binary", "traversal"],
-            AlgorithmCategory::Hashing => vec!["hash", "map", "dict", "table"],
-            AlgorithmCategory::Other => vec![],
-        };
-        
-        for keyword in category_keywords {
-            if code.to_lowercase().contains(keyword) {
-                return true;
-            }
-        }
-        
-        false
+
+        // Check for algorithm name in the code; this is all Wikipedia
+        // imports, which have no examples or fingerprints, are matched on
+        let names = self.algorithms.values().map(|info| info.name.as_str());
+        mentions(code_lower, &self.names_lower.get(names, &algorithm_info.name))
     }
 
-    fn fuzzy_match(&self, code: &str, pattern: &str) -> bool {
+    fn fuzzy_match(&self, code_normalized: &str, pattern: &str) -> bool {
         // Simple fuzzy matching - could be enhanced with more sophisticated algorithms
-        let code_normalized = code.replace(" ", "").replace("\n", "").replace("\t", "");
-        let pattern_normalized = pattern.replace(" ", "").replace("\n", "").replace("\t", "");
-        
-        code_normalized.contains(&pattern_normalized)
+        code_normalized.contains(&normalize_whitespace(pattern))
     }
     
     pub fn get_algorithms_by_category(&self, category: AlgorithmCategory) -> Vec<&AlgorithmInfo> {
@@ -164,13 +193,44 @@ impl AlgorithmDetector {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Lowercased catalog names, so detection does not lowercase every name on
+/// every analysis. Filled from the whole catalog on the first detection;
+/// names added to the catalog after that are lowercased when they are met.
+#[derive(Debug, Default)]
+pub(crate) struct LowercaseNames(OnceLock<HashMap<String, String>>);
+
+impl LowercaseNames {
+    /// `name` lowercased; the first call lowercases all of `names`
+    pub(crate) fn get<'a>(&'a self, names: impl Iterator<Item = &'a str>, name: &str) -> Cow<'a, str> {
+        let lowercased = self.0.get_or_init(|| names.map(|name| (name.to_string(), name.to_lowercase())).collect());
+        match lowercased.get(name) {
+            Some(lower) => Cow::Borrowed(lower),
+            None => Cow::Owned(name.to_lowercase()),
+        }
+    }
+}
+
+/// Whether `name` appears in `text` as whole words, so `rsa` is not found in `traversal`
+pub(crate) fn mentions(text: &str, name: &str) -> bool {
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric());
+    text.match_indices(name).any(|(start, _)| {
+        !is_word(text[..start].chars().next_back()) && !is_word(text[start + name.len()..].chars().next())
+    })
+}
+
+/// Strip spaces, tabs and newlines so examples match regardless of formatting
+fn normalize_whitespace(text: &str) -> String {
+    text.chars().filter(|c| !matches!(c, ' ' | '\n' | '\t')).collect()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DetectedAlgorithm {
     pub name: String,
     pub category: AlgorithmCategory,
     pub complexity: String,
     pub description: String,
     pub wikipedia_link: String,
+    pub relations: Vec<Relation>,
 }
 
 #[cfg(test)]
@@ -201,10 +261,72 @@ mod tests {
         assert!(bubble_sort_detected);
     }
     
+    #[test]
+    fn test_lowercase_names_cover_entries_added_later() {
+        let names = LowercaseNames::default();
+        assert_eq!(names.get(["Quick Sort", "A*"].into_iter(), "A*"), "a*");
+        assert!(matches!(names.get(std::iter::empty(), "Quick Sort"), Cow::Borrowed("quick sort")));
+        assert!(matches!(names.get(std::iter::empty(), "Timsort"), Cow::Owned(name) if name == "timsort"));
+    }
+
     #[test]
     fn test_category_filtering() {
         let detector = AlgorithmDetector::new();
         let sorting_algorithms = detector.get_algorithms_by_category(AlgorithmCategory::Sorting);
         assert!(!sorting_algorithms.is_empty());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_built_in_bounds_are_consistent() {
+        let detector = AlgorithmDetector::new();
+        for info in detector.algorithms.values() {
+            let bounds = &info.bounds;
+            let (best, average, worst) = (bounds.best.as_ref(), bounds.average.as_ref(), bounds.worst.as_ref());
+            assert!(best.is_some() && average.is_some() && worst.is_some(), "{} lacks time bounds", info.name);
+            assert!(best.unwrap().growth() <= average.unwrap().growth(), "{}: best above average", info.name);
+            assert!(average.unwrap().growth() <= worst.unwrap().growth(), "{}: average above worst", info.name);
+
+            let is_sort = info.category == AlgorithmCategory::Sorting;
+            assert_eq!(info.stable.is_some(), is_sort, "{}: stable flag", info.name);
+            assert_eq!(info.in_place.is_some(), is_sort, "{}: in-place flag", info.name);
+        }
+
+        let merge_sort = &detector.algorithms["Merge Sort"];
+        assert_eq!((merge_sort.stable, merge_sort.in_place), (Some(true), Some(false)));
+        assert!(detector.algorithms["Quick Sort"].bounds.worst > detector.algorithms["Merge Sort"].bounds.worst);
+    }
+
+    #[test]
+    fn test_catalog_covers_every_category() {
+        let detector = AlgorithmDetector::new();
+        assert!(detector.algorithms.len() >= 150);
+        assert_eq!(detector.get_all_categories().len(), 14);
+        assert!(detector.algorithms.values().all(|info| !info.fingerprints.is_empty()));
+    }
+
+    #[test]
+    fn test_labeled_samples_are_detected() {
+        let detector = AlgorithmDetector::new();
+        let samples = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/algorithms");
+        let mut missed = Vec::new();
+        let mut detections = 0;
+
+        for name in detector.algorithms.keys() {
+            let path = samples.join(format!("{}.rs", crate::catalog::slug(name)));
+            let code = std::fs::read_to_string(&path)
+                .unwrap_or_else(|_| panic!("{name} has no sample at {}", path.display()));
+            let detected = detector.detect_algorithms(&code);
+            if !detected.iter().any(|algorithm| &algorithm.name == name) {
+                missed.push(name.as_str());
+            }
+            detections += detected.len();
+        }
+
+        missed.sort();
+        assert!(missed.is_empty(), "samples not detected: {missed:?}");
+        // Related algorithms overlap (Tarjan's contains a DFS), but a sample
+        // should mostly be recognized as what it is
+        let per_sample = detections as f64 / detector.algorithms.len() as f64;
+        assert!(per_sample < 1.25, "{per_sample:.2} detections per sample");
+    }
+}
@@ -1,6 +1,28 @@
 //! Comprehensive algorithm database
+//!
+//! Every entry carries structural fingerprints, and a labeled sample in
+//! `tests/fixtures/algorithms/<slug>.rs` that it must be detected in.
 
+use super::fingerprint::Feature::{Loop, NestedLoops, Recursion, TripleNestedLoops};
+use super::fingerprint::Fingerprint;
 use super::{AlgorithmInfo, AlgorithmCategory};
+use crate::complexity::{BigO, Complexity};
+use analyzer_types::v2::{Relation, RelationKind};
+
+fn big_o(notation: &str) -> BigO {
+    notation.parse().unwrap_or_else(|e| panic!("built-in catalog: {}", e))
+}
+
+/// Best, average and worst-case time and auxiliary space
+fn time(best: &str, average: &str, worst: &str, space: &str) -> Complexity {
+    Complexity {
+        best: Some(big_o(best)),
+        average: Some(big_o(average)),
+        worst: Some(big_o(worst)),
+        space: Some(big_o(space)),
+        operations: Default::default(),
+    }
+}
 
 /// Returns a comprehensive list of algorithms with detailed information
 pub fn get_algorithms_database() -> Vec<AlgorithmInfo> {
@@ -10,52 +32,370 @@ pub fn get_algorithms_database() -> Vec<AlgorithmInfo> {
             name: "Bubble Sort".to_string(),
             category: AlgorithmCategory::Sorting,
             complexity: "O(n²)".to_string(),
+            bounds: time("O(n)", "O(n²)", "O(n²)", "O(1)"),
+            stable: Some(true),
+            in_place: Some(true),
             description: "A simple sorting algorithm that repeatedly steps through the list, compares adjacent elements and swaps them if they are in the wrong order.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Bubble_sort".to_string(),
             examples: vec![
                 "for i in 0..arr.len() { for j in 0..arr.len() - 1 - i { if arr[j] > arr[j + 1] { /* swap */ } } }".to_string(),
                 "while swapped { swapped = false; for i in 1..n { if arr[i-1] > arr[i] { /* swap */ } } }".to_string(),
             ],
+            fingerprints: vec![
+                Fingerprint::new(&["bubble"], &[NestedLoops]),
+                Fingerprint::new(&["swapped", ".swap("], &[NestedLoops]),
+                Fingerprint::new(&["[j+1]", ".swap(j,j+1)"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Insertion Sort"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
         },
         AlgorithmInfo {
             name: "Quick Sort".to_string(),
             category: AlgorithmCategory::Sorting,
             complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n²)", "O(log n)"),
+            stable: Some(false),
+            in_place: Some(true),
             description: "An efficient sorting algorithm that uses a divide-and-conquer approach to sort elements by selecting a 'pivot' element and partitioning the array around it.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Quicksort".to_string(),
             examples: vec![
                 "fn quicksort(arr: &mut [i32]) { if arr.len() <= 1 { return; } let pivot = partition(arr); /* recursive calls */ }".to_string(),
             ],
+            fingerprints: vec![
+                Fingerprint::new(&["quicksort"], &[]),
+                Fingerprint::new(&["quick", "sort"], &[]),
+                Fingerprint::new(&["partition", "pivot"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Merge Sort"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Heap Sort"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
         },
         AlgorithmInfo {
             name: "Merge Sort".to_string(),
             category: AlgorithmCategory::Sorting,
             complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n log n)", "O(n)"),
+            stable: Some(true),
+            in_place: Some(false),
             description: "A divide-and-conquer algorithm that divides the array into halves, sorts them recursively, and then merges the sorted halves.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Merge_sort".to_string(),
             examples: vec![
                 "fn merge_sort(arr: &mut [i32]) { if arr.len() <= 1 { return; } let mid = arr.len() / 2; /* recursive calls and merge */ }".to_string(),
             ],
+            fingerprints: vec![
+                Fingerprint::new(&["mergesort"], &[]),
+                Fingerprint::new(&["merge", "sort"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Quick Sort"),
+                Relation::data_structure(RelationKind::Uses, "Dynamic Array"),
+            ],
         },
         AlgorithmInfo {
             name: "Heap Sort".to_string(),
             category: AlgorithmCategory::Sorting,
             complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n log n)", "O(1)"),
+            stable: Some(false),
+            in_place: Some(true),
             description: "A comparison-based sorting algorithm that uses a binary heap data structure to create a sorted array.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Heapsort".to_string(),
             examples: vec![
                 "fn heap_sort(arr: &mut [i32]) { let len = arr.len(); /* build heap */ for i in (0..len/2).rev() { heapify(arr, len, i); } /* extract elements */ }".to_string(),
             ],
+            fingerprints: vec![
+                Fingerprint::new(&["heapsort"], &[]),
+                Fingerprint::new(&["heap", "sort"], &[Loop]),
+                Fingerprint::new(&["heapify", ".swap(0,"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Quick Sort"),
+                Relation::data_structure(RelationKind::Uses, "Binary Heap"),
+            ],
         },
         AlgorithmInfo {
             name: "Insertion Sort".to_string(),
             category: AlgorithmCategory::Sorting,
             complexity: "O(n²)".to_string(),
+            bounds: time("O(n)", "O(n²)", "O(n²)", "O(1)"),
+            stable: Some(true),
+            in_place: Some(true),
             description: "A simple sorting algorithm that builds the final sorted array one item at a time by comparing each element with the previous elements and inserting it into its correct position.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Insertion_sort".to_string(),
             examples: vec![
                 "fn insertion_sort(arr: &mut [i32]) { for i in 1..arr.len() { let key = arr[i]; let mut j = i; while j > 0 && arr[j-1] > key { arr[j] = arr[j-1]; j -= 1; } arr[j] = key; } }".to_string(),
             ],
+            fingerprints: vec![
+                Fingerprint::new(&["insertion"], &[NestedLoops]),
+                Fingerprint::new(&["key", "whilej>0&&"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Bubble Sort"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Selection Sort".to_string(),
+            category: AlgorithmCategory::Sorting,
+            complexity: "O(n²)".to_string(),
+            bounds: time("O(n²)", "O(n²)", "O(n²)", "O(1)"),
+            stable: Some(false),
+            in_place: Some(true),
+            description: "Repeatedly selects the smallest remaining element and swaps it to the front of the unsorted part.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Selection_sort".to_string(),
+            examples: vec![
+                "for i in 0..n { let mut min_idx = i; for j in i + 1..n { if arr[j] < arr[min_idx] { min_idx = j; } } arr.swap(i, min_idx); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["selection", "sort"], &[NestedLoops]),
+                Fingerprint::new(&["min_idx", ".swap("], &[NestedLoops]),
+                Fingerprint::new(&["min_index", ".swap("], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Insertion Sort"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Shell Sort".to_string(),
+            category: AlgorithmCategory::Sorting,
+            complexity: "O(n^1.5)".to_string(),
+            bounds: time("O(n log n)", "O(n^1.25)", "O(n^1.5)", "O(1)"),
+            stable: Some(false),
+            in_place: Some(true),
+            description: "An insertion sort over elements a shrinking gap apart, so that far-off elements move into place early.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Shellsort".to_string(),
+            examples: vec![
+                "let mut gap = n / 2; while gap > 0 { for i in gap..n { /* gapped insertion */ } gap /= 2; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["shell", "sort"], &[NestedLoops]),
+                Fingerprint::new(&["shellsort"], &[]),
+                Fingerprint::new(&["gap", "gap/=2"], &[TripleNestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Insertion Sort"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Counting Sort".to_string(),
+            category: AlgorithmCategory::Sorting,
+            complexity: "O(n + k)".to_string(),
+            bounds: time("O(n + k)", "O(n + k)", "O(n + k)", "O(n + k)"),
+            stable: Some(true),
+            in_place: Some(false),
+            description: "Sorts small integer keys by counting how often each key occurs and computing each key's position from the running totals.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Counting_sort".to_string(),
+            examples: vec![
+                "let mut count = vec![0; max + 1]; for &x in arr { count[x] += 1; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["counting", "sort"], &[Loop]),
+                Fingerprint::new(&["count", "[count["], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Radix Sort"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Radix Sort".to_string(),
+            category: AlgorithmCategory::Sorting,
+            complexity: "O(d·(n + b))".to_string(),
+            bounds: time("O(d*(n + b))", "O(d*(n + b))", "O(d*(n + b))", "O(n + b)"),
+            stable: Some(true),
+            in_place: Some(false),
+            description: "Sorts integers digit by digit, least significant first, with a stable counting sort on each digit.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Radix_sort".to_string(),
+            examples: vec![
+                "let mut exp = 1; while max / exp > 0 { counting_sort_by_digit(arr, exp); exp *= 10; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["radix"], &[Loop]),
+                Fingerprint::new(&["exp", "/exp%10"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Counting Sort"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Bucket Sort".to_string(),
+            category: AlgorithmCategory::Sorting,
+            complexity: "O(n + k)".to_string(),
+            bounds: time("O(n + k)", "O(n + k)", "O(n²)", "O(n + k)"),
+            stable: Some(true),
+            in_place: Some(false),
+            description: "Scatters values into buckets by range, sorts each bucket and concatenates them; fast when values are spread evenly.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Bucket_sort".to_string(),
+            examples: vec![
+                "let mut buckets = vec![Vec::new(); n]; for x in arr { buckets[(x * n as f64) as usize].push(x); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["bucket", "sort"], &[Loop]),
+                Fingerprint::new(&["buckets", "sort_by", "concat"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Insertion Sort"),
+                Relation::data_structure(RelationKind::Uses, "Dynamic Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Cocktail Shaker Sort".to_string(),
+            category: AlgorithmCategory::Sorting,
+            complexity: "O(n²)".to_string(),
+            bounds: time("O(n)", "O(n²)", "O(n²)", "O(1)"),
+            stable: Some(true),
+            in_place: Some(true),
+            description: "A bubble sort that alternates forward and backward passes, moving both large and small elements into place quickly.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Cocktail_shaker_sort".to_string(),
+            examples: vec![
+                "while swapped { for i in start..end { /* forward pass */ } end -= 1; for i in (start..end).rev() { /* backward pass */ } start += 1; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["cocktail"], &[NestedLoops]),
+                Fingerprint::new(&["shaker"], &[NestedLoops]),
+                Fingerprint::new(&["swapped", ".rev()", ".swap("], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Bubble Sort"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Gnome Sort".to_string(),
+            category: AlgorithmCategory::Sorting,
+            complexity: "O(n²)".to_string(),
+            bounds: time("O(n)", "O(n²)", "O(n²)", "O(1)"),
+            stable: Some(true),
+            in_place: Some(true),
+            description: "Walks forward while elements are in order and steps back swapping when they are not, with a single loop.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Gnome_sort".to_string(),
+            examples: vec![
+                "while pos < n { if pos == 0 || arr[pos] >= arr[pos - 1] { pos += 1; } else { arr.swap(pos, pos - 1); pos -= 1; } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["gnome"], &[Loop]),
+                Fingerprint::new(&["pos-=1", ".swap(pos,pos-1)"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Insertion Sort"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Comb Sort".to_string(),
+            category: AlgorithmCategory::Sorting,
+            complexity: "O(n²)".to_string(),
+            bounds: time("O(n log n)", "O(n²)", "O(n²)", "O(1)"),
+            stable: Some(false),
+            in_place: Some(true),
+            description: "A bubble sort that compares elements a gap apart, shrinking the gap by a factor of about 1.3 on every pass.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Comb_sort".to_string(),
+            examples: vec![
+                "gap = (gap * 10 / 13).max(1);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["comb", "sort"], &[NestedLoops]),
+                Fingerprint::new(&["gap", "shrink"], &[NestedLoops]),
+                Fingerprint::new(&["gap", "*10/13"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Bubble Sort"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Timsort".to_string(),
+            category: AlgorithmCategory::Sorting,
+            complexity: "O(n log n)".to_string(),
+            bounds: time("O(n)", "O(n log n)", "O(n log n)", "O(n)"),
+            stable: Some(true),
+            in_place: Some(false),
+            description: "A hybrid of insertion sort on short runs and merge sort across runs; the basis of the standard library's stable sort.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Timsort".to_string(),
+            examples: vec![
+                "for start in (0..n).step_by(MIN_RUN) { insertion_sort(&mut arr[start..(start + MIN_RUN).min(n)]); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["timsort"], &[]),
+                Fingerprint::new(&["tim", "sort"], &[]),
+                Fingerprint::new(&["min_run", "merge"], &[Loop]),
+                Fingerprint::new(&["run", "merge", "insertion"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Insertion Sort"),
+                Relation::algorithm(RelationKind::Uses, "Merge Sort"),
+                Relation::data_structure(RelationKind::Uses, "Dynamic Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Cycle Sort".to_string(),
+            category: AlgorithmCategory::Sorting,
+            complexity: "O(n²)".to_string(),
+            bounds: time("O(n²)", "O(n²)", "O(n²)", "O(1)"),
+            stable: Some(false),
+            in_place: Some(true),
+            description: "Rotates each cycle of the permutation into place, writing every element at most once; useful when writes are expensive.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Cycle_sort".to_string(),
+            examples: vec![
+                "for cycle_start in 0..n - 1 { let mut item = arr[cycle_start]; /* find position and rotate the cycle */ }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["cycle", "sort"], &[NestedLoops]),
+                Fingerprint::new(&["cycle_start", "item"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Selection Sort"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Pancake Sort".to_string(),
+            category: AlgorithmCategory::Sorting,
+            complexity: "O(n²)".to_string(),
+            bounds: time("O(n)", "O(n²)", "O(n²)", "O(1)"),
+            stable: Some(false),
+            in_place: Some(true),
+            description: "Sorts using only prefix reversals: flip the largest unsorted element to the front, then flip it to the end of the unsorted part.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Pancake_sorting".to_string(),
+            examples: vec![
+                "for size in (2..=n).rev() { let max = index_of_max(&arr[..size]); arr[..=max].reverse(); arr[..size].reverse(); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["pancake"], &[Loop]),
+                Fingerprint::new(&["flip", ".reverse()"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Selection Sort"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Bitonic Sort".to_string(),
+            category: AlgorithmCategory::Sorting,
+            complexity: "O(n log² n)".to_string(),
+            bounds: time("O(n log² n)", "O(n log² n)", "O(n log² n)", "O(log n)"),
+            stable: Some(false),
+            in_place: Some(true),
+            description: "A sorting network that builds bitonic sequences and merges them; every comparison is fixed in advance, which suits parallel hardware.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Bitonic_sorter".to_string(),
+            examples: vec![
+                "fn bitonic_merge(arr: &mut [i32], ascending: bool) { let half = arr.len() / 2; /* compare and swap, then recurse on both halves */ }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["bitonic"], &[]),
+                Fingerprint::new(&["ascending", "half"], &[Recursion, Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Merge Sort"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
         },
 
         // Searching Algorithms
@@ -63,21 +403,214 @@ pub fn get_algorithms_database() -> Vec<AlgorithmInfo> {
             name: "Binary Search".to_string(),
             category: AlgorithmCategory::Searching,
             complexity: "O(log n)".to_string(),
+            bounds: time("O(1)", "O(log n)", "O(log n)", "O(1)"),
+            stable: None,
+            in_place: None,
             description: "A search algorithm that finds the position of a target value within a sorted array by repeatedly dividing the search interval in half.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Binary_search_algorithm".to_string(),
             examples: vec![
                 "fn binary_search(arr: &[i32], target: i32) -> Option<usize> { let mut left = 0; let mut right = arr.len(); while left < right { /* midpoint calculation */ } }".to_string(),
             ],
+            fingerprints: vec![
+                Fingerprint::new(&["binary", "search"], &[Loop]),
+                Fingerprint::new(&["mid", "left", "right", "target"], &[Loop]),
+                Fingerprint::new(&["mid", "lo", "hi", "target"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Linear Search"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
         },
         AlgorithmInfo {
             name: "Linear Search".to_string(),
             category: AlgorithmCategory::Searching,
             complexity: "O(n)".to_string(),
+            bounds: time("O(1)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
             description: "A simple search algorithm that checks every element in the list until it finds the target value or reaches the end of the list.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Linear_search".to_string(),
             examples: vec![
                 "fn linear_search(arr: &[i32], target: i32) -> Option<usize> { for (i, &item) in arr.iter().enumerate() { if item == target { return Some(i); } } None }".to_string(),
             ],
+            fingerprints: vec![
+                Fingerprint::new(&["linear", "search"], &[Loop]),
+                Fingerprint::new(&["enumerate", "==target", "returnSome("], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Binary Search"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Jump Search".to_string(),
+            category: AlgorithmCategory::Searching,
+            complexity: "O(√n)".to_string(),
+            bounds: time("O(1)", "O(√n)", "O(√n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Searches a sorted array by jumping ahead in blocks of √n and scanning linearly inside the block that may hold the target.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Jump_search".to_string(),
+            examples: vec![
+                "let step = (n as f64).sqrt() as usize; while arr[prev.min(n) - 1] < target { prev += step; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["jump", "search"], &[Loop]),
+                Fingerprint::new(&["step", "sqrt", "prev"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Binary Search"),
+                Relation::algorithm(RelationKind::Uses, "Linear Search"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Interpolation Search".to_string(),
+            category: AlgorithmCategory::Searching,
+            complexity: "O(log log n)".to_string(),
+            bounds: time("O(1)", "O(log log n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Guesses the target's position from its value relative to the ends of the range; very fast on uniformly distributed keys.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Interpolation_search".to_string(),
+            examples: vec![
+                "let pos = lo + ((target - arr[lo]) * (hi - lo) as i64 / (arr[hi] - arr[lo])) as usize;".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["interpolation"], &[Loop]),
+                Fingerprint::new(&["pos", "*(hi-lo)"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Binary Search"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Exponential Search".to_string(),
+            category: AlgorithmCategory::Searching,
+            complexity: "O(log i)".to_string(),
+            bounds: time("O(1)", "O(log i)", "O(log i)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Doubles a bound until it passes the target, then binary searches the last range; suits unbounded or very long sorted inputs.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Exponential_search".to_string(),
+            examples: vec![
+                "let mut bound = 1; while bound < n && arr[bound] < target { bound *= 2; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["exponential", "search"], &[]),
+                Fingerprint::new(&["bound", "bound*=2"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Binary Search"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Ternary Search".to_string(),
+            category: AlgorithmCategory::Searching,
+            complexity: "O(log n)".to_string(),
+            bounds: time("O(log n)", "O(log n)", "O(log n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the extremum of a unimodal function by comparing two interior points and discarding a third of the range each step.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Ternary_search".to_string(),
+            examples: vec![
+                "let m1 = lo + (hi - lo) / 3; let m2 = hi - (hi - lo) / 3; if f(m1) < f(m2) { lo = m1; } else { hi = m2; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["ternary"], &[Loop]),
+                Fingerprint::new(&["mid1", "mid2"], &[Loop]),
+                Fingerprint::new(&["m1", "m2", "/3"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Golden-Section Search"),
+                Relation::algorithm(RelationKind::VariantOf, "Binary Search"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Fibonacci Search".to_string(),
+            category: AlgorithmCategory::Searching,
+            complexity: "O(log n)".to_string(),
+            bounds: time("O(1)", "O(log n)", "O(log n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Narrows a sorted range using Fibonacci numbers instead of halves, needing only addition and subtraction to compute probes.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Fibonacci_search_technique".to_string(),
+            examples: vec![
+                "while fib > 1 { let i = (offset + fib2).min(n - 1); /* shift the Fibonacci triple down */ }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["fibonacci", "search"], &[Loop]),
+                Fingerprint::new(&["fib1", "fib2", "offset"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Binary Search"),
+                Relation::algorithm(RelationKind::Uses, "Fibonacci Sequence"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Quickselect".to_string(),
+            category: AlgorithmCategory::Searching,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n²)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the k-th smallest element by partitioning around a pivot and continuing only into the side that holds it.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Quickselect".to_string(),
+            examples: vec![
+                "loop { let p = partition(&mut arr[lo..=hi]) + lo; if p == k { return arr[k]; } /* narrow to one side */ }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["quickselect"], &[]),
+                Fingerprint::new(&["quick", "select"], &[]),
+                Fingerprint::new(&["kth", "partition", "pivot"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Quick Sort"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Median of Medians"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Median of Medians".to_string(),
+            category: AlgorithmCategory::Searching,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Picks a pivot guaranteed to be near the middle by taking the median of the medians of groups of five, giving worst-case linear selection.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Median_of_medians".to_string(),
+            examples: vec![
+                "let medians: Vec<i32> = arr.chunks(5).map(median_of_five).collect();".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["median", "of", "medians"], &[]),
+                Fingerprint::new(&["medians", ".chunks(5)"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Quickselect"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Golden-Section Search".to_string(),
+            category: AlgorithmCategory::Searching,
+            complexity: "O(log(1/ε))".to_string(),
+            bounds: time("O(log n)", "O(log n)", "O(log n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the extremum of a unimodal function by shrinking the bracket by the golden ratio, reusing one function evaluation per step.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Golden-section_search".to_string(),
+            examples: vec![
+                "let c = b - (b - a) * INV_PHI; let d = a + (b - a) * INV_PHI;".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["golden"], &[Loop]),
+                Fingerprint::new(&["inv_phi"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Ternary Search"),
+            ],
         },
 
         // Graph Algorithms
@@ -85,140 +618,2722 @@ pub fn get_algorithms_database() -> Vec<AlgorithmInfo> {
             name: "Dijkstra's Algorithm".to_string(),
             category: AlgorithmCategory::Graph,
             complexity: "O((V + E) log V)".to_string(),
+            bounds: time("O((V + E) log V)", "O((V + E) log V)", "O((V + E) log V)", "O(V)"),
+            stable: None,
+            in_place: None,
             description: "An algorithm for finding the shortest paths between nodes in a graph with non-negative edge weights.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm".to_string(),
             examples: vec![
                 "fn dijkstra(graph: &Graph, start: Node) -> HashMap<Node, Distance> { let mut distances = HashMap::new(); /* priority queue operations */ }".to_string(),
             ],
+            fingerprints: vec![
+                Fingerprint::new(&["dijkstra"], &[]),
+                Fingerprint::new(&["dist", "BinaryHeap", "Reverse"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Prerequisite, "Breadth-First Search"),
+                Relation::data_structure(RelationKind::Uses, "Binary Heap"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
+            ],
         },
         AlgorithmInfo {
             name: "Depth-First Search".to_string(),
             category: AlgorithmCategory::Graph,
             complexity: "O(V + E)".to_string(),
+            bounds: time("O(V + E)", "O(V + E)", "O(V + E)", "O(V)"),
+            stable: None,
+            in_place: None,
             description: "An algorithm for traversing or searching tree or graph data structures by exploring as far as possible along each branch before backtracking.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Depth-first_search".to_string(),
             examples: vec![
                 "fn dfs(graph: &Graph, node: Node, visited: &mut HashSet<Node>) { visited.insert(node); for neighbor in graph.neighbors(node) { if !visited.contains(&neighbor) { dfs(graph, neighbor, visited); } } }".to_string(),
             ],
+            fingerprints: vec![
+                Fingerprint::new(&["dfs"], &[]),
+                Fingerprint::new(&["depth", "first"], &[]),
+                Fingerprint::new(&["visited", "neighbors"], &[Recursion]),
+                Fingerprint::new(&["visited", "stack", "pop"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Breadth-First Search"),
+                Relation::data_structure(RelationKind::Uses, "Stack"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
+            ],
         },
         AlgorithmInfo {
             name: "Breadth-First Search".to_string(),
             category: AlgorithmCategory::Graph,
             complexity: "O(V + E)".to_string(),
+            bounds: time("O(V + E)", "O(V + E)", "O(V + E)", "O(V)"),
+            stable: None,
+            in_place: None,
             description: "An algorithm for traversing or searching tree or graph data structures that explores all the vertices at the present depth level before moving on to vertices at the next depth level.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Breadth-first_search".to_string(),
             examples: vec![
                 "fn bfs(graph: &Graph, start: Node) -> Vec<Node> { let mut visited = HashSet::new(); let mut queue = VecDeque::new(); /* queue operations */ }".to_string(),
             ],
+            fingerprints: vec![
+                Fingerprint::new(&["bfs"], &[]),
+                Fingerprint::new(&["breadth", "first"], &[]),
+                Fingerprint::new(&["VecDeque", "visited", "pop_front"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Depth-First Search"),
+                Relation::data_structure(RelationKind::Uses, "Queue"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
+            ],
         },
         AlgorithmInfo {
             name: "A* Search Algorithm".to_string(),
             category: AlgorithmCategory::Graph,
             complexity: "O(b^d)".to_string(),
+            bounds: time("O(d)", "O(b^d)", "O(b^d)", "O(b^d)"),
+            stable: None,
+            in_place: None,
             description: "A graph traversal and path search algorithm that uses a heuristic to estimate the cost of the cheapest path from a given node to the goal.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/A*_search_algorithm".to_string(),
             examples: vec![
                 "fn a_star(graph: &Graph, start: Node, goal: Node) -> Option<Path> { let mut open_set = BinaryHeap::new(); /* heuristic function */ }".to_string(),
             ],
+            fingerprints: vec![
+                Fingerprint::new(&["astar"], &[]),
+                Fingerprint::new(&["a_star"], &[]),
+                Fingerprint::new(&["heuristic", "BinaryHeap", "goal"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Dijkstra's Algorithm"),
+                Relation::data_structure(RelationKind::Uses, "Binary Heap"),
+                Relation::data_structure(RelationKind::Uses, "Hash Table"),
+            ],
         },
-
-        // Dynamic Programming
         AlgorithmInfo {
-            name: "Fibonacci Sequence".to_string(),
-            category: AlgorithmCategory::DynamicProgramming,
-            complexity: "O(n)".to_string(),
-            description: "A sequence where each number is the sum of the two preceding ones, often implemented with dynamic programming to avoid redundant calculations.".to_string(),
-            wikipedia_link: "https://en.wikipedia.org/wiki/Fibonacci_number".to_string(),
+            name: "Bellman-Ford Algorithm".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(V·E)".to_string(),
+            bounds: time("O(E)", "O(V*E)", "O(V*E)", "O(V)"),
+            stable: None,
+            in_place: None,
+            description: "Finds single-source shortest paths with negative edge weights by relaxing every edge V-1 times, and detects negative cycles.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Bellman%E2%80%93Ford_algorithm".to_string(),
             examples: vec![
-                "fn fibonacci(n: usize) -> u64 { let mut memo = vec![0; n+1]; memo[0] = 0; memo[1] = 1; for i in 2..=n { memo[i] = memo[i-1] + memo[i-2]; } memo[n] }".to_string(),
+                "for _ in 0..n - 1 { for &(u, v, w) in &edges { if dist[u] + w < dist[v] { dist[v] = dist[u] + w; } } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["bellman"], &[]),
+                Fingerprint::new(&["dist", "edges", "negative"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Dijkstra's Algorithm"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
             ],
         },
         AlgorithmInfo {
-            name: "Longest Common Subsequence".to_string(),
-            category: AlgorithmCategory::DynamicProgramming,
-            complexity: "O(m*n)".to_string(),
-            description: "A dynamic programming algorithm that finds the longest subsequence common to two sequences.".to_string(),
-            wikipedia_link: "https://en.wikipedia.org/wiki/Longest_common_subsequence_problem".to_string(),
+            name: "Floyd-Warshall Algorithm".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(V³)".to_string(),
+            bounds: time("O(V^3)", "O(V^3)", "O(V^3)", "O(V^2)"),
+            stable: None,
+            in_place: None,
+            description: "Computes shortest paths between all pairs of vertices by allowing each vertex in turn as an intermediate stop.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm".to_string(),
             examples: vec![
-                "fn lcs(str1: &str, str2: &str) -> usize { let m = str1.len(); let n = str2.len(); let mut dp = vec![vec![0; n+1]; m+1]; /* fill dp table */ }".to_string(),
+                "for k in 0..n { for i in 0..n { for j in 0..n { dist[i][j] = dist[i][j].min(dist[i][k] + dist[k][j]); } } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["floyd", "warshall"], &[]),
+                Fingerprint::new(&["[i][k]+", "[k][j]"], &[TripleNestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Johnson's Algorithm"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
             ],
         },
-
-        // Greedy Algorithms
         AlgorithmInfo {
-            name: "Dijkstra's Algorithm".to_string(),
-            category: AlgorithmCategory::Greedy,
-            complexity: "O((V + E) log V)".to_string(),
-            description: "A greedy algorithm for finding the shortest paths between nodes in a graph with non-negative edge weights.".to_string(),
-            wikipedia_link: "https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm".to_string(),
+            name: "Topological Sort".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(V + E)".to_string(),
+            bounds: time("O(V + E)", "O(V + E)", "O(V + E)", "O(V)"),
+            stable: None,
+            in_place: None,
+            description: "Orders the vertices of a directed acyclic graph so every edge points forward, by repeatedly removing vertices with no incoming edges (Kahn) or by DFS finish order.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Topological_sorting".to_string(),
             examples: vec![
-                "fn dijkstra(graph: &Graph, start: Node) -> HashMap<Node, Distance> { let mut distances = HashMap::new(); /* priority queue operations */ }".to_string(),
+                "let mut queue: VecDeque<usize> = (0..n).filter(|&v| in_degree[v] == 0).collect();".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["topological"], &[]),
+                Fingerprint::new(&["topo"], &[Loop]),
+                Fingerprint::new(&["in_degree", "VecDeque"], &[Loop]),
+                Fingerprint::new(&["indegree", "VecDeque"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Depth-First Search"),
+                Relation::data_structure(RelationKind::Uses, "Queue"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
             ],
         },
         AlgorithmInfo {
-            name: "Kruskal's Algorithm".to_string(),
-            category: AlgorithmCategory::Greedy,
-            complexity: "O(E log E)".to_string(),
-            description: "A minimum spanning tree algorithm that finds an edge of the least possible weight that connects any two trees in the forest.".to_string(),
-            wikipedia_link: "https://en.wikipedia.org/wiki/Kruskal%27s_algorithm".to_string(),
+            name: "Tarjan's Strongly Connected Components".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(V + E)".to_string(),
+            bounds: time("O(V + E)", "O(V + E)", "O(V + E)", "O(V)"),
+            stable: None,
+            in_place: None,
+            description: "Finds strongly connected components in one depth-first pass, tracking each vertex's discovery index and lowest reachable index on a stack.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Tarjan%27s_strongly_connected_components_algorithm".to_string(),
             examples: vec![
-                "fn kruskal(edges: &mut [Edge]) -> Vec<Edge> { edges.sort_by(|a, b| a.weight.cmp(&b.weight)); /* union-find operations */ }".to_string(),
+                "if low[v] == index[v] { let mut component = Vec::new(); loop { let w = stack.pop().unwrap(); on_stack[w] = false; component.push(w); if w == v { break; } } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["tarjan"], &[]),
+                Fingerprint::new(&["low", "on_stack"], &[Recursion]),
+                Fingerprint::new(&["lowlink", "stack"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Depth-First Search"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Kosaraju's Algorithm"),
+                Relation::data_structure(RelationKind::Uses, "Stack"),
             ],
         },
-
-        // Mathematical Algorithms
         AlgorithmInfo {
-            name: "Euclidean Algorithm".to_string(),
-            category: AlgorithmCategory::Mathematical,
-            complexity: "O(log min(a,b))".to_string(),
-            description: "An efficient method for computing the greatest common divisor (GCD) of two numbers.".to_string(),
-            wikipedia_link: "https://en.wikipedia.org/wiki/Euclidean_algorithm".to_string(),
+            name: "Kosaraju's Algorithm".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(V + E)".to_string(),
+            bounds: time("O(V + E)", "O(V + E)", "O(V + E)", "O(V + E)"),
+            stable: None,
+            in_place: None,
+            description: "Finds strongly connected components with two depth-first passes: one to record finish order, one over the transposed graph in reverse finish order.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Kosaraju%27s_algorithm".to_string(),
             examples: vec![
-                "fn gcd(a: u32, b: u32) -> u32 { if b == 0 { a } else { gcd(b, a % b) } }".to_string(),
+                "for &v in order.iter().rev() { if !visited[v] { collect(&transpose, v, &mut visited, &mut component); } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["kosaraju"], &[]),
+                Fingerprint::new(&["transpose", "visited", "order"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Depth-First Search"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Tarjan's Strongly Connected Components"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
             ],
         },
         AlgorithmInfo {
-            name: "Sieve of Eratosthenes".to_string(),
-            category: AlgorithmCategory::Mathematical,
-            complexity: "O(n log log n)".to_string(),
-            description: "An ancient algorithm for finding all prime numbers up to any given limit.".to_string(),
-            wikipedia_link: "https://en.wikipedia.org/wiki/Sieve_of_Eratosthenes".to_string(),
+            name: "Ford-Fulkerson Algorithm".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(E·f)".to_string(),
+            bounds: time("O(E*f)", "O(E*f)", "O(E*f)", "O(V)"),
+            stable: None,
+            in_place: None,
+            description: "Computes maximum flow by repeatedly pushing flow along any augmenting path in the residual graph, found here by depth-first search.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Ford%E2%80%93Fulkerson_algorithm".to_string(),
             examples: vec![
-                "fn sieve_of_eratosthenes(limit: usize) -> Vec<bool> { let mut is_prime = vec![true; limit + 1]; is_prime[0] = false; is_prime[1] = false; /* marking multiples */ }".to_string(),
+                "while let Some(pushed) = augment(&mut residual, source, sink, u64::MAX, &mut visited) { flow += pushed; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["ford", "fulkerson"], &[]),
+                Fingerprint::new(&["residual", "augment"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Depth-First Search"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
             ],
         },
-
-        // String Algorithms
         AlgorithmInfo {
-            name: "KMP Algorithm".to_string(),
-            category: AlgorithmCategory::String,
-            complexity: "O(n + m)".to_string(),
-            description: "A string searching algorithm that searches for occurrences of a pattern within a main text string by employing the observation that when a mismatch occurs, the word itself embodies sufficient information to determine where the next match could begin.".to_string(),
-            wikipedia_link: "https://en.wikipedia.org/wiki/Knuth%E2%80%93Morris%E2%80%93Pratt_algorithm".to_string(),
+            name: "Edmonds-Karp Algorithm".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(V·E²)".to_string(),
+            bounds: time("O(V*E^2)", "O(V*E^2)", "O(V*E^2)", "O(V^2)"),
+            stable: None,
+            in_place: None,
+            description: "Ford-Fulkerson with breadth-first search for augmenting paths, which bounds the number of augmentations by O(V·E).".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Edmonds%E2%80%93Karp_algorithm".to_string(),
             examples: vec![
-                "fn kmp_search(text: &str, pattern: &str) -> Vec<usize> { let lps = compute_lps(pattern); /* matching logic */ }".to_string(),
+                "while let Some(parent) = bfs_path(&residual, source, sink) { /* find bottleneck and update residual capacities */ }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["edmonds", "karp"], &[]),
+                Fingerprint::new(&["residual", "parent", "VecDeque", "sink"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Ford-Fulkerson Algorithm"),
+                Relation::algorithm(RelationKind::Uses, "Breadth-First Search"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
             ],
         },
         AlgorithmInfo {
-            name: "Rabin-Karp Algorithm".to_string(),
-            category: AlgorithmCategory::String,
-            complexity: "O(n+m)".to_string(),
-            description: "A string searching algorithm that uses hashing to find any one of a set of pattern strings in a text.".to_string(),
-            wikipedia_link: "https://en.wikipedia.org/wiki/Rabin%E2%80%93Karp_algorithm".to_string(),
+            name: "Dinic's Algorithm".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(V²·E)".to_string(),
+            bounds: time("O(V^2*E)", "O(V^2*E)", "O(V^2*E)", "O(V + E)"),
+            stable: None,
+            in_place: None,
+            description: "Computes maximum flow in phases: a BFS builds a level graph and DFS pushes blocking flows along it.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Dinic%27s_algorithm".to_string(),
             examples: vec![
-                "fn rabin_karp(text: &str, pattern: &str) -> Vec<usize> { let pattern_hash = hash(pattern); /* rolling hash */ }".to_string(),
+                "while self.build_levels(source, sink) { self.next_edge.fill(0); while let Some(f) = self.push(source, sink, u64::MAX) { flow += f; } }".to_string(),
             ],
-        },
+            fingerprints: vec![
+                Fingerprint::new(&["dinic"], &[]),
+                Fingerprint::new(&["level", "capacity", "sink"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Edmonds-Karp Algorithm"),
+                Relation::algorithm(RelationKind::Uses, "Breadth-First Search"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Hopcroft-Karp Algorithm".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(E√V)".to_string(),
+            bounds: time("O(E*sqrt(V))", "O(E*sqrt(V))", "O(E*sqrt(V))", "O(V)"),
+            stable: None,
+            in_place: None,
+            description: "Finds a maximum matching in a bipartite graph by augmenting along many shortest vertex-disjoint paths per phase.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Hopcroft%E2%80%93Karp_algorithm".to_string(),
+            examples: vec![
+                "while self.bfs() { for u in 0..self.left { if self.pair_u[u].is_none() && self.dfs(u) { matching += 1; } } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["hopcroft"], &[]),
+                Fingerprint::new(&["pair_u", "pair_v"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Breadth-First Search"),
+                Relation::algorithm(RelationKind::Uses, "Depth-First Search"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Johnson's Algorithm".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(V² log V + V·E)".to_string(),
+            bounds: time("O(V^2 log V + V*E)", "O(V^2 log V + V*E)", "O(V^2 log V + V*E)", "O(V^2)"),
+            stable: None,
+            in_place: None,
+            description: "All-pairs shortest paths on sparse graphs: Bellman-Ford computes vertex potentials that reweight edges to be non-negative, then Dijkstra runs from every vertex.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Johnson%27s_algorithm".to_string(),
+            examples: vec![
+                "let weight = w + potential[u] - potential[v];".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["johnson"], &[]),
+                Fingerprint::new(&["potential", "dijkstra"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Bellman-Ford Algorithm"),
+                Relation::algorithm(RelationKind::Uses, "Dijkstra's Algorithm"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Floyd-Warshall Algorithm"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Bidirectional Search".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(b^(d/2))".to_string(),
+            bounds: time("O(1)", "O(b^(d/2))", "O(b^(d/2))", "O(b^(d/2))"),
+            stable: None,
+            in_place: None,
+            description: "Runs two breadth-first searches, from the start and from the goal, and stops when their frontiers meet.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Bidirectional_search".to_string(),
+            examples: vec![
+                "while !forward.is_empty() && !backward.is_empty() { if expand(&mut forward, &mut seen_forward, &seen_backward) { return true; } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["bidirectional"], &[]),
+                Fingerprint::new(&["forward", "backward", "VecDeque", "visited"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Breadth-First Search"),
+                Relation::data_structure(RelationKind::Uses, "Queue"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Bipartite Graph Check".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(V + E)".to_string(),
+            bounds: time("O(V + E)", "O(V + E)", "O(V + E)", "O(V)"),
+            stable: None,
+            in_place: None,
+            description: "Two-colors the graph with a breadth-first search; it is bipartite exactly when no edge joins two vertices of the same color.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Bipartite_graph".to_string(),
+            examples: vec![
+                "if color[w] == color[v] { return false; } if color[w] == UNSET { color[w] = 1 - color[v]; queue.push_back(w); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["bipartite"], &[Loop]),
+                Fingerprint::new(&["side", "1-side["], &[Loop]),
+                Fingerprint::new(&["color", "1-color["], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Breadth-First Search"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Hierholzer's Algorithm".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(E)".to_string(),
+            bounds: time("O(E)", "O(E)", "O(E)", "O(E)"),
+            stable: None,
+            in_place: None,
+            description: "Builds an Eulerian circuit by following unused edges until stuck and splicing in sub-circuits while backtracking.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Eulerian_path#Hierholzer's_algorithm".to_string(),
+            examples: vec![
+                "while let Some(&v) = stack.last() { match adjacency[v].pop() { Some(w) => stack.push(w), None => circuit.push(stack.pop().unwrap()) } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["hierholzer"], &[]),
+                Fingerprint::new(&["eulerian"], &[Loop]),
+                Fingerprint::new(&["euler", "circuit"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Depth-First Search"),
+                Relation::data_structure(RelationKind::Uses, "Stack"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Bridge Finding".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(V + E)".to_string(),
+            bounds: time("O(V + E)", "O(V + E)", "O(V + E)", "O(V)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the edges and articulation points whose removal disconnects the graph, comparing discovery times with the lowest time reachable from each subtree.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Bridge_(graph_theory)".to_string(),
+            examples: vec![
+                "if low[w] > disc[v] { bridges.push((v, w)); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["bridges", "low", "disc"], &[Recursion]),
+                Fingerprint::new(&["articulation"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Depth-First Search"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Tarjan's Strongly Connected Components"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Graph Cycle Detection".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(V + E)".to_string(),
+            bounds: time("O(V + E)", "O(V + E)", "O(V + E)", "O(V)"),
+            stable: None,
+            in_place: None,
+            description: "Detects a cycle in a directed graph with a depth-first search that marks vertices on the current path; reaching one again closes a cycle.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Cycle_(graph_theory)#Cycle_detection".to_string(),
+            examples: vec![
+                "if rec_stack[w] { return true; } if !visited[w] && has_cycle(w, graph, visited, rec_stack) { return true; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["rec_stack"], &[Recursion]),
+                Fingerprint::new(&["cycle", "visiting"], &[Recursion]),
+                Fingerprint::new(&["gray", "black", "cycle"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Depth-First Search"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Topological Sort"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Flood Fill".to_string(),
+            category: AlgorithmCategory::Graph,
+            complexity: "O(rows·cols)".to_string(),
+            bounds: time("O(1)", "O(r*c)", "O(r*c)", "O(r*c)"),
+            stable: None,
+            in_place: None,
+            description: "Recolors the connected region of a grid around a seed cell, visiting the four neighbors of every matching cell.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Flood_fill".to_string(),
+            examples: vec![
+                "if grid[r][c] != target { return; } grid[r][c] = replacement; fill(grid, r + 1, c, target, replacement);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["flood", "fill"], &[]),
+                Fingerprint::new(&["fill", "replacement"], &[Recursion]),
+                Fingerprint::new(&["fill", "new_color"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Depth-First Search"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+
+        // Dynamic Programming
+        AlgorithmInfo {
+            name: "Fibonacci Sequence".to_string(),
+            category: AlgorithmCategory::DynamicProgramming,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A sequence where each number is the sum of the two preceding ones, often implemented with dynamic programming to avoid redundant calculations.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Fibonacci_number".to_string(),
+            examples: vec![
+                "fn fibonacci(n: usize) -> u64 { let mut memo = vec![0; n+1]; memo[0] = 0; memo[1] = 1; for i in 2..=n { memo[i] = memo[i-1] + memo[i-2]; } memo[n] }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["fib"], &[Recursion]),
+                Fingerprint::new(&["fibonacci"], &[Recursion]),
+                Fingerprint::new(&["fibonacci", "memo"], &[]),
+                Fingerprint::new(&["[i-1]+", "[i-2]"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::Uses, "Dynamic Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Longest Common Subsequence".to_string(),
+            category: AlgorithmCategory::DynamicProgramming,
+            complexity: "O(m*n)".to_string(),
+            bounds: time("O(m*n)", "O(m*n)", "O(m*n)", "O(m*n)"),
+            stable: None,
+            in_place: None,
+            description: "A dynamic programming algorithm that finds the longest subsequence common to two sequences.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Longest_common_subsequence_problem".to_string(),
+            examples: vec![
+                "fn lcs(str1: &str, str2: &str) -> usize { let m = str1.len(); let n = str2.len(); let mut dp = vec![vec![0; n+1]; m+1]; /* fill dp table */ }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["lcs"], &[NestedLoops]),
+                Fingerprint::new(&["longest", "common", "subsequence"], &[]),
+                Fingerprint::new(&["[i-1][j-1]+1", "[i-1][j].max("], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Prerequisite, "Fibonacci Sequence"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Edit Distance"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "0/1 Knapsack".to_string(),
+            category: AlgorithmCategory::DynamicProgramming,
+            complexity: "O(n·W)".to_string(),
+            bounds: time("O(n*W)", "O(n*W)", "O(n*W)", "O(W)"),
+            stable: None,
+            in_place: None,
+            description: "Chooses items to maximize total value within a weight capacity, each item taken at most once, with a table over items and capacities.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Knapsack_problem".to_string(),
+            examples: vec![
+                "for i in 0..n { for w in (weights[i]..=capacity).rev() { dp[w] = dp[w].max(dp[w - weights[i]] + values[i]); } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["knapsack", ".rev()"], &[NestedLoops]),
+                Fingerprint::new(&["knapsack", "[i-1]"], &[NestedLoops]),
+                Fingerprint::new(&["weights", "values", "capacity", ".rev()"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Fractional Knapsack"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Unbounded Knapsack"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Unbounded Knapsack".to_string(),
+            category: AlgorithmCategory::DynamicProgramming,
+            complexity: "O(n·W)".to_string(),
+            bounds: time("O(n*W)", "O(n*W)", "O(n*W)", "O(W)"),
+            stable: None,
+            in_place: None,
+            description: "The knapsack problem with unlimited copies of every item; capacities are filled in increasing order so an item can be reused.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Knapsack_problem#Unbounded_knapsack_problem".to_string(),
+            examples: vec![
+                "for w in 0..=capacity { for (&wt, &val) in weights.iter().zip(values) { if wt <= w { best[w] = best[w].max(best[w - wt] + val); } } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["unbounded", "knapsack"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "0/1 Knapsack"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Coin Change"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Longest Increasing Subsequence".to_string(),
+            category: AlgorithmCategory::DynamicProgramming,
+            complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the longest strictly increasing subsequence by keeping the smallest tail of every increasing run length and binary searching it.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Longest_increasing_subsequence".to_string(),
+            examples: vec![
+                "let pos = tails.partition_point(|&t| t < x); if pos == tails.len() { tails.push(x); } else { tails[pos] = x; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["lis"], &[Loop]),
+                Fingerprint::new(&["longest", "increasing"], &[]),
+                Fingerprint::new(&["tails", "partition_point"], &[Loop]),
+                Fingerprint::new(&["tails", "binary_search"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Binary Search"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Longest Common Subsequence"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Edit Distance".to_string(),
+            category: AlgorithmCategory::DynamicProgramming,
+            complexity: "O(m·n)".to_string(),
+            bounds: time("O(m*n)", "O(m*n)", "O(m*n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "The Levenshtein distance: the fewest insertions, deletions and substitutions turning one string into another, computed row by row.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Levenshtein_distance".to_string(),
+            examples: vec![
+                "curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["levenshtein"], &[]),
+                Fingerprint::new(&["edit", "distance"], &[NestedLoops]),
+                Fingerprint::new(&["substitution", "insertion", "deletion"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Longest Common Subsequence"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Coin Change".to_string(),
+            category: AlgorithmCategory::DynamicProgramming,
+            complexity: "O(n·amount)".to_string(),
+            bounds: time("O(n*a)", "O(n*a)", "O(n*a)", "O(a)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the fewest coins, or the number of ways, to make an amount from given denominations, building up from smaller amounts.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Change-making_problem".to_string(),
+            examples: vec![
+                "for coin in coins { for a in coin..=amount { dp[a] = dp[a].min(dp[a - coin] + 1); } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["coins", "amount", "[a-coin]"], &[NestedLoops]),
+                Fingerprint::new(&["coins", "amount", "[i-coin]"], &[NestedLoops]),
+                Fingerprint::new(&["coin", "change", "dp"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Greedy Coin Change"),
+                Relation::algorithm(RelationKind::VariantOf, "Unbounded Knapsack"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Matrix Chain Multiplication".to_string(),
+            category: AlgorithmCategory::DynamicProgramming,
+            complexity: "O(n³)".to_string(),
+            bounds: time("O(n^3)", "O(n^3)", "O(n^3)", "O(n^2)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the cheapest order to multiply a chain of matrices by trying every split point of every sub-chain.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Matrix_chain_multiplication".to_string(),
+            examples: vec![
+                "cost[i][j] = (i..j).map(|k| cost[i][k] + cost[k + 1][j] + dims[i] * dims[k + 1] * dims[j + 1]).min().unwrap();".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["matrix", "chain"], &[]),
+                Fingerprint::new(&["dims", "[i][k]+", "[k+1][j]"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Strassen's Algorithm"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Rod Cutting".to_string(),
+            category: AlgorithmCategory::DynamicProgramming,
+            complexity: "O(n²)".to_string(),
+            bounds: time("O(n^2)", "O(n^2)", "O(n^2)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Finds how to cut a rod into pieces to maximize the total price, from the best revenue of every shorter length.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Cutting_stock_problem".to_string(),
+            examples: vec![
+                "for len in 1..=n { for cut in 1..=len { revenue[len] = revenue[len].max(prices[cut - 1] + revenue[len - cut]); } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["rod"], &[NestedLoops]),
+                Fingerprint::new(&["prices", "cut", "revenue"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Unbounded Knapsack"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Longest Palindromic Subsequence".to_string(),
+            category: AlgorithmCategory::DynamicProgramming,
+            complexity: "O(n²)".to_string(),
+            bounds: time("O(n^2)", "O(n^2)", "O(n^2)", "O(n^2)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the longest subsequence that reads the same both ways, over every substring from shortest to longest.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Longest_palindromic_subsequence".to_string(),
+            examples: vec![
+                "dp[i][j] = if s[i] == s[j] { dp[i + 1][j - 1] + 2 } else { dp[i + 1][j].max(dp[i][j - 1]) };".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["palindromic", "subsequence"], &[]),
+                Fingerprint::new(&["[i+1][j-1]+2"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Longest Common Subsequence"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Manacher's Algorithm"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Subset Sum".to_string(),
+            category: AlgorithmCategory::DynamicProgramming,
+            complexity: "O(n·target)".to_string(),
+            bounds: time("O(n*t)", "O(n*t)", "O(n*t)", "O(t)"),
+            stable: None,
+            in_place: None,
+            description: "Decides whether some subset of the numbers adds up to the target, tracking every reachable sum.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Subset_sum_problem".to_string(),
+            examples: vec![
+                "for &x in nums { for s in (x..=target).rev() { reachable[s] |= reachable[s - x]; } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["subset", "sum"], &[NestedLoops]),
+                Fingerprint::new(&["reachable", "target", ".rev()"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "0/1 Knapsack"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Kadane's Algorithm".to_string(),
+            category: AlgorithmCategory::DynamicProgramming,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the maximum-sum contiguous subarray in one pass by extending the best sum ending at each position or starting afresh.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Maximum_subarray_problem#Kadane's_algorithm".to_string(),
+            examples: vec![
+                "max_ending_here = x.max(max_ending_here + x); max_so_far = max_so_far.max(max_ending_here);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["kadane"], &[]),
+                Fingerprint::new(&["max_ending_here"], &[Loop]),
+                Fingerprint::new(&["max_so_far", "current"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Held-Karp Algorithm".to_string(),
+            category: AlgorithmCategory::DynamicProgramming,
+            complexity: "O(2^n·n²)".to_string(),
+            bounds: time("O(2^n * n^2)", "O(2^n * n^2)", "O(2^n * n^2)", "O(2^n * n)"),
+            stable: None,
+            in_place: None,
+            description: "Solves the travelling salesman problem exactly with a table over subsets of visited cities and the city last visited.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Held%E2%80%93Karp_algorithm".to_string(),
+            examples: vec![
+                "for mask in 1..1 << n { for last in 0..n { if mask & (1 << last) == 0 { continue; } /* extend to unvisited cities */ } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["held", "karp"], &[]),
+                Fingerprint::new(&["tsp"], &[NestedLoops]),
+                Fingerprint::new(&["mask", "1<<", "dist"], &[TripleNestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Hamiltonian Path"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Longest Common Substring".to_string(),
+            category: AlgorithmCategory::DynamicProgramming,
+            complexity: "O(m·n)".to_string(),
+            bounds: time("O(m*n)", "O(m*n)", "O(m*n)", "O(m*n)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the longest string that is a contiguous substring of both inputs from the length of the common suffix at every pair of positions.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Longest_common_substring".to_string(),
+            examples: vec![
+                "if a[i - 1] == b[j - 1] { dp[i][j] = dp[i - 1][j - 1] + 1; best = best.max(dp[i][j]); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["longest", "common", "substring"], &[]),
+                Fingerprint::new(&["substring", "[i-1][j-1]+1"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Longest Common Subsequence"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Suffix Array"),
+            ],
+        },
+
+        // Greedy Algorithms
+        AlgorithmInfo {
+            name: "Kruskal's Algorithm".to_string(),
+            category: AlgorithmCategory::Greedy,
+            complexity: "O(E log E)".to_string(),
+            bounds: time("O(E log E)", "O(E log E)", "O(E log E)", "O(V + E)"),
+            stable: None,
+            in_place: None,
+            description: "A minimum spanning tree algorithm that finds an edge of the least possible weight that connects any two trees in the forest.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Kruskal%27s_algorithm".to_string(),
+            examples: vec![
+                "fn kruskal(edges: &mut [Edge]) -> Vec<Edge> { edges.sort_by(|a, b| a.weight.cmp(&b.weight)); /* union-find operations */ }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["kruskal"], &[]),
+                Fingerprint::new(&["edges", "sort", "find", "union"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Merge Sort"),
+                Relation::algorithm(RelationKind::Uses, "Union-Find"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Prim's Algorithm"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Prim's Algorithm".to_string(),
+            category: AlgorithmCategory::Greedy,
+            complexity: "O(E log V)".to_string(),
+            bounds: time("O(E log V)", "O(E log V)", "O(E log V)", "O(V + E)"),
+            stable: None,
+            in_place: None,
+            description: "Grows a minimum spanning tree from one vertex, always adding the cheapest edge that leaves the tree.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Prim%27s_algorithm".to_string(),
+            examples: vec![
+                "while let Some(Reverse((w, v))) = heap.pop() { if in_mst[v] { continue; } in_mst[v] = true; total += w; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["prim"], &[]),
+                Fingerprint::new(&["in_mst", "BinaryHeap"], &[Loop]),
+                Fingerprint::new(&["in_tree", "BinaryHeap"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Kruskal's Algorithm"),
+                Relation::data_structure(RelationKind::Uses, "Binary Heap"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Boruvka's Algorithm".to_string(),
+            category: AlgorithmCategory::Greedy,
+            complexity: "O(E log V)".to_string(),
+            bounds: time("O(E log V)", "O(E log V)", "O(E log V)", "O(V)"),
+            stable: None,
+            in_place: None,
+            description: "Builds a minimum spanning tree in rounds: every component picks its cheapest outgoing edge and all of them are added at once.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Bor%C5%AFvka%27s_algorithm".to_string(),
+            examples: vec![
+                "let mut cheapest: Vec<Option<usize>> = vec![None; n]; for (i, &(u, v, w)) in edges.iter().enumerate() { /* keep the lightest edge per component */ }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["boruvka"], &[]),
+                Fingerprint::new(&["cheapest", "components", "find"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Kruskal's Algorithm"),
+                Relation::algorithm(RelationKind::Uses, "Union-Find"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Activity Selection".to_string(),
+            category: AlgorithmCategory::Greedy,
+            complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Picks the largest set of non-overlapping intervals by always taking the one that finishes first.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Activity_selection_problem".to_string(),
+            examples: vec![
+                "activities.sort_by_key(|a| a.1); for (start, finish) in activities { if start >= last_finish { chosen.push((start, finish)); last_finish = finish; } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["activity", "selection"], &[]),
+                Fingerprint::new(&["activities", "finish"], &[Loop]),
+                Fingerprint::new(&["last_finish", "sort"], &[Loop]),
+                Fingerprint::new(&["last_end", "sort"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Interval Partitioning"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Huffman Coding".to_string(),
+            category: AlgorithmCategory::Greedy,
+            complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Builds an optimal prefix code by repeatedly merging the two least frequent symbols into a new tree node.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Huffman_coding".to_string(),
+            examples: vec![
+                "while heap.len() > 1 { let a = heap.pop().unwrap(); let b = heap.pop().unwrap(); heap.push(Node::merge(a, b)); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["huffman"], &[]),
+                Fingerprint::new(&["freq", "BinaryHeap", "left", "right"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::Uses, "Binary Heap"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Fractional Knapsack".to_string(),
+            category: AlgorithmCategory::Greedy,
+            complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Fills the knapsack with items in order of value per unit weight, taking a fraction of the last item that fits.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Continuous_knapsack_problem".to_string(),
+            examples: vec![
+                "items.sort_by(|a, b| (b.value / b.weight).total_cmp(&(a.value / a.weight)));".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["fractional"], &[Loop]),
+                Fingerprint::new(&["ratio", "capacity", "weight"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "0/1 Knapsack"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Job Sequencing".to_string(),
+            category: AlgorithmCategory::Greedy,
+            complexity: "O(n²)".to_string(),
+            bounds: time("O(n log n)", "O(n^2)", "O(n^2)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Schedules unit-time jobs with deadlines for the greatest profit, placing each job, most profitable first, in the latest free slot before its deadline.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Job-shop_scheduling".to_string(),
+            examples: vec![
+                "for slot in (0..job.deadline.min(n)).rev() { if slots[slot].is_none() { slots[slot] = Some(job.id); break; } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["job", "sequencing"], &[]),
+                Fingerprint::new(&["deadline", "profit", "slots"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Activity Selection"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Greedy Coin Change".to_string(),
+            category: AlgorithmCategory::Greedy,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Makes change by taking as many of the largest coin as fit, then the next; optimal only for canonical coin systems.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Change-making_problem#Greedy_method".to_string(),
+            examples: vec![
+                "for &coin in coins.iter().rev() { count += amount / coin; amount %= coin; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["coins", "amount/coin"], &[Loop]),
+                Fingerprint::new(&["coins", "whileamount>=coin"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Coin Change"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Greedy Coloring".to_string(),
+            category: AlgorithmCategory::Greedy,
+            complexity: "O(V + E)".to_string(),
+            bounds: time("O(V + E)", "O(V + E)", "O(V + E)", "O(V)"),
+            stable: None,
+            in_place: None,
+            description: "Colors vertices one at a time with the smallest color not used by an already colored neighbor; uses at most one more color than the maximum degree.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Greedy_coloring".to_string(),
+            examples: vec![
+                "let color = (0..).find(|c| !taken.contains(c)).unwrap();".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["greedy", "coloring"], &[]),
+                Fingerprint::new(&["available", "color"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Backtracking Graph Coloring"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Interval Partitioning".to_string(),
+            category: AlgorithmCategory::Greedy,
+            complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Assigns intervals to the fewest rooms by processing them by start time and reusing the room that frees up earliest.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Interval_scheduling#Interval_partitioning".to_string(),
+            examples: vec![
+                "if let Some(&Reverse(end)) = rooms.peek() { if end <= start { rooms.pop(); } } rooms.push(Reverse(finish));".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["interval", "partitioning"], &[]),
+                Fingerprint::new(&["rooms", "Reverse", "start"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Activity Selection"),
+                Relation::data_structure(RelationKind::Uses, "Binary Heap"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Egyptian Fraction".to_string(),
+            category: AlgorithmCategory::Greedy,
+            complexity: "O(k)".to_string(),
+            bounds: time("O(1)", "O(k)", "O(k)", "O(k)"),
+            stable: None,
+            in_place: None,
+            description: "Writes a fraction as a sum of distinct unit fractions by always subtracting the largest unit fraction that fits (Fibonacci–Sylvester).".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Greedy_algorithm_for_Egyptian_fractions".to_string(),
+            examples: vec![
+                "let unit = den.div_ceil(num); parts.push(unit); (num, den) = (num * unit - den, den * unit);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["egyptian"], &[]),
+                Fingerprint::new(&["unit", "denominator", "div_ceil"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Euclidean Algorithm"),
+            ],
+        },
+
+        // Backtracking Algorithms
+        AlgorithmInfo {
+            name: "N-Queens".to_string(),
+            category: AlgorithmCategory::Backtracking,
+            complexity: "O(n!)".to_string(),
+            bounds: time("O(n!)", "O(n!)", "O(n!)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Places n queens on an n×n board so none attack each other, one row at a time, undoing a placement when a row has no safe column.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Eight_queens_puzzle".to_string(),
+            examples: vec![
+                "for col in 0..n { if safe(row, col) { place(row, col); solve(row + 1); remove(row, col); } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["queens"], &[Recursion]),
+                Fingerprint::new(&["queen"], &[Recursion]),
+                Fingerprint::new(&["cols", "diag1", "diag2"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Sudoku Solver"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Sudoku Solver".to_string(),
+            category: AlgorithmCategory::Backtracking,
+            complexity: "O(9^m)".to_string(),
+            bounds: time("O(m)", "O(9^m)", "O(9^m)", "O(m)"),
+            stable: None,
+            in_place: None,
+            description: "Fills empty cells one at a time with digits that break no row, column or box rule, backtracking when a cell has no valid digit.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Sudoku_solving_algorithms".to_string(),
+            examples: vec![
+                "let (br, bc) = (row / 3 * 3, col / 3 * 3);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["sudoku"], &[]),
+                Fingerprint::new(&["board", "/3*3"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "N-Queens"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Permutation Generation".to_string(),
+            category: AlgorithmCategory::Backtracking,
+            complexity: "O(n·n!)".to_string(),
+            bounds: time("O(n*n!)", "O(n*n!)", "O(n*n!)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Generates every ordering of the input by choosing each unused element for the next position and backtracking.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Permutation#Algorithms_to_generate_permutations".to_string(),
+            examples: vec![
+                "for i in 0..items.len() { if used[i] { continue; } used[i] = true; current.push(items[i]); permute(items, used, current, out); current.pop(); used[i] = false; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["permute"], &[Recursion]),
+                Fingerprint::new(&["permutations", "used"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Heap's Algorithm"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Heap's Algorithm".to_string(),
+            category: AlgorithmCategory::Backtracking,
+            complexity: "O(n!)".to_string(),
+            bounds: time("O(n!)", "O(n!)", "O(n!)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Generates all permutations with a single swap between consecutive ones, choosing the swap by the parity of the current size.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Heap%27s_algorithm".to_string(),
+            examples: vec![
+                "if k % 2 == 0 { items.swap(i, k - 1); } else { items.swap(0, k - 1); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["heap", "permutations"], &[Recursion]),
+                Fingerprint::new(&[".swap(0,k-1)", "%2==0"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Permutation Generation"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Subset Generation".to_string(),
+            category: AlgorithmCategory::Backtracking,
+            complexity: "O(n·2^n)".to_string(),
+            bounds: time("O(n*2^n)", "O(n*2^n)", "O(n*2^n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Generates the power set by deciding for each element whether to include it, or by counting through bitmasks.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Power_set".to_string(),
+            examples: vec![
+                "for mask in 0..1u32 << n { subsets.push((0..n).filter(|i| mask >> i & 1 == 1).map(|i| items[i]).collect()); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["subsets"], &[Recursion]),
+                Fingerprint::new(&["power", "set"], &[]),
+                Fingerprint::new(&["powerset"], &[]),
+                Fingerprint::new(&["subsets", "mask", "<<"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Permutation Generation"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Combination Sum".to_string(),
+            category: AlgorithmCategory::Backtracking,
+            complexity: "O(2^t)".to_string(),
+            bounds: time("O(2^t)", "O(2^t)", "O(2^t)", "O(t)"),
+            stable: None,
+            in_place: None,
+            description: "Finds every combination of candidates, reused as often as needed, that adds up to a target, pruning once the remainder goes negative.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Backtracking".to_string(),
+            examples: vec![
+                "for i in start..candidates.len() { if candidates[i] > remaining { break; } current.push(candidates[i]); search(i, remaining - candidates[i]); current.pop(); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["combination", "sum"], &[Recursion]),
+                Fingerprint::new(&["candidates", "remaining"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Subset Sum"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Knight's Tour".to_string(),
+            category: AlgorithmCategory::Backtracking,
+            complexity: "O(8^(n²))".to_string(),
+            bounds: time("O(n^2)", "O(8^(n^2))", "O(8^(n^2))", "O(n^2)"),
+            stable: None,
+            in_place: None,
+            description: "Finds a sequence of knight moves that visits every square of the board once, trying each of the eight moves and backtracking.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Knight%27s_tour".to_string(),
+            examples: vec![
+                "const MOVES: [(i32, i32); 8] = [(2, 1), (1, 2), (-1, 2), (-2, 1), (-2, -1), (-1, -2), (1, -2), (2, -1)];".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["knight"], &[Recursion]),
+                Fingerprint::new(&["tour", "moves"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Hamiltonian Path"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Rat in a Maze".to_string(),
+            category: AlgorithmCategory::Backtracking,
+            complexity: "O(2^(n²))".to_string(),
+            bounds: time("O(n)", "O(2^(n^2))", "O(2^(n^2))", "O(n^2)"),
+            stable: None,
+            in_place: None,
+            description: "Finds a path through a grid maze from the top-left to the bottom-right cell, marking the path and unmarking dead ends.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Maze-solving_algorithm".to_string(),
+            examples: vec![
+                "path[r][c] = true; if solve(maze, r + 1, c, path) || solve(maze, r, c + 1, path) { return true; } path[r][c] = false;".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["maze"], &[Recursion]),
+                Fingerprint::new(&["rat"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Breadth-First Search"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Hamiltonian Path".to_string(),
+            category: AlgorithmCategory::Backtracking,
+            complexity: "O(n!)".to_string(),
+            bounds: time("O(n)", "O(n!)", "O(n!)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Searches for a path that visits every vertex exactly once, extending the path one unvisited neighbor at a time.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Hamiltonian_path_problem".to_string(),
+            examples: vec![
+                "if path.len() == n { return true; } for &w in &graph[v] { if !visited[w] { /* extend the path */ } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["hamiltonian"], &[Recursion]),
+                Fingerprint::new(&["path", "visited", "path.len()==n"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Held-Karp Algorithm"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Backtracking Graph Coloring".to_string(),
+            category: AlgorithmCategory::Backtracking,
+            complexity: "O(m^V)".to_string(),
+            bounds: time("O(V)", "O(m^V)", "O(m^V)", "O(V)"),
+            stable: None,
+            in_place: None,
+            description: "Decides whether a graph can be colored with m colors, assigning each vertex a color no neighbor has and backtracking on conflicts.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Graph_coloring#Algorithms".to_string(),
+            examples: vec![
+                "for c in 0..m { if is_safe(graph, &colors, v, c) { colors[v] = Some(c); if color(graph, m, colors, v + 1) { return true; } colors[v] = None; } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["is_safe", "color"], &[Recursion]),
+                Fingerprint::new(&["is_safe", "colors"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Greedy Coloring"),
+                Relation::data_structure(RelationKind::Uses, "Graph"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Word Search".to_string(),
+            category: AlgorithmCategory::Backtracking,
+            complexity: "O(r·c·4^L)".to_string(),
+            bounds: time("O(r*c)", "O(r*c*4^L)", "O(r*c*4^L)", "O(L)"),
+            stable: None,
+            in_place: None,
+            description: "Finds a word in a letter grid along adjacent cells, marking cells on the current path so none is used twice.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Backtracking".to_string(),
+            examples: vec![
+                "board[r][c] = '#'; let found = DIRS.iter().any(|&(dr, dc)| search(board, word, i + 1, r + dr, c + dc)); board[r][c] = letter;".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["word", "search", "board"], &[]),
+                Fingerprint::new(&["board", "word"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Depth-First Search"),
+            ],
+        },
+
+        // Divide and Conquer
+        AlgorithmInfo {
+            name: "Strassen's Algorithm".to_string(),
+            category: AlgorithmCategory::DivideConquer,
+            complexity: "O(n^2.81)".to_string(),
+            bounds: time("O(n^2.81)", "O(n^2.81)", "O(n^2.81)", "O(n^2)"),
+            stable: None,
+            in_place: None,
+            description: "Multiplies matrices by splitting them into quadrants and combining seven recursive products instead of eight.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Strassen_algorithm".to_string(),
+            examples: vec![
+                "let m1 = strassen(&add(&a11, &a22), &add(&b11, &b22));".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["strassen"], &[]),
+                Fingerprint::new(&["m1", "m7", "a11", "b22"], &[]),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Karatsuba Multiplication".to_string(),
+            category: AlgorithmCategory::DivideConquer,
+            complexity: "O(n^1.585)".to_string(),
+            bounds: time("O(n^1.585)", "O(n^1.585)", "O(n^1.585)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Multiplies large numbers with three half-size multiplications instead of four, recovering the middle term from (a+b)(c+d).".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Karatsuba_algorithm".to_string(),
+            examples: vec![
+                "let z1 = karatsuba(low1 + high1, low2 + high2) - z2 - z0;".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["karatsuba"], &[]),
+                Fingerprint::new(&["z0", "z1", "z2"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Fast Fourier Transform"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Closest Pair of Points".to_string(),
+            category: AlgorithmCategory::DivideConquer,
+            complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the two closest points by solving each half and checking only the narrow strip around the dividing line.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Closest_pair_of_points_problem".to_string(),
+            examples: vec![
+                "let strip: Vec<Point> = points.iter().filter(|p| (p.x - mid_x).abs() < best).copied().collect();".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["closest", "pair"], &[]),
+                Fingerprint::new(&["strip", "dist"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Merge Sort"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Fast Fourier Transform".to_string(),
+            category: AlgorithmCategory::DivideConquer,
+            complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "The Cooley-Tukey FFT: evaluates a polynomial at the roots of unity by splitting it into even and odd coefficients, e.g. to multiply polynomials quickly.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Fast_Fourier_transform".to_string(),
+            examples: vec![
+                "let t = twiddle * odd[k]; out[k] = even[k] + t; out[k + n / 2] = even[k] - t;".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["fft"], &[Loop]),
+                Fingerprint::new(&["fourier"], &[]),
+                Fingerprint::new(&["twiddle", "even", "odd"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Karatsuba Multiplication"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Counting Inversions".to_string(),
+            category: AlgorithmCategory::DivideConquer,
+            complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Counts the pairs that are out of order with a merge sort: whenever an element of the right half is merged first, it is inverted with every remaining left element.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Inversion_(discrete_mathematics)".to_string(),
+            examples: vec![
+                "if left[i] <= right[j] { merged.push(left[i]); i += 1; } else { inversions += left.len() - i; merged.push(right[j]); j += 1; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["inversions"], &[Recursion]),
+                Fingerprint::new(&["inversion", "count"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Merge Sort"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Quickhull".to_string(),
+            category: AlgorithmCategory::DivideConquer,
+            complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n²)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Builds a convex hull by taking the point farthest from a dividing line and recursing on the points outside the two new edges.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Quickhull".to_string(),
+            examples: vec![
+                "let farthest = points.iter().max_by(|p, q| distance(a, b, p).total_cmp(&distance(a, b, q)));".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["quickhull"], &[]),
+                Fingerprint::new(&["hull", "farthest"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Graham Scan"),
+                Relation::algorithm(RelationKind::VariantOf, "Quick Sort"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Tower of Hanoi".to_string(),
+            category: AlgorithmCategory::DivideConquer,
+            complexity: "O(2^n)".to_string(),
+            bounds: time("O(2^n)", "O(2^n)", "O(2^n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Moves a stack of disks between pegs by moving all but the largest out of the way, moving the largest, and moving the rest back on top.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Tower_of_Hanoi".to_string(),
+            examples: vec![
+                "hanoi(n - 1, from, via, to, moves); moves.push((from, to)); hanoi(n - 1, via, to, from, moves);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["hanoi"], &[Recursion]),
+                Fingerprint::new(&["disks", "from", "to", "via"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::Uses, "Stack"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Skyline Problem".to_string(),
+            category: AlgorithmCategory::DivideConquer,
+            complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Computes the outline of overlapping rectangular buildings by merging the skylines of two halves, like the merge step of merge sort.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Divide-and-conquer_algorithm".to_string(),
+            examples: vec![
+                "let height = left_height.max(right_height); if skyline.last().map(|&(_, h)| h) != Some(height) { skyline.push((x, height)); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["skyline"], &[]),
+                Fingerprint::new(&["buildings", "height"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Merge Sort"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Fast Walsh-Hadamard Transform".to_string(),
+            category: AlgorithmCategory::DivideConquer,
+            complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n log n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Transforms a vector of length 2^k with butterflies of sums and differences; used for XOR convolutions.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Fast_Walsh%E2%80%93Hadamard_transform".to_string(),
+            examples: vec![
+                "let (u, v) = (a[j], a[j + len]); a[j] = u + v; a[j + len] = u - v;".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["walsh"], &[]),
+                Fingerprint::new(&["hadamard"], &[]),
+                Fingerprint::new(&["fwht"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Fast Fourier Transform"),
+            ],
+        },
+
+        // Mathematical Algorithms
+        AlgorithmInfo {
+            name: "Euclidean Algorithm".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(log min(a,b))".to_string(),
+            bounds: time("O(1)", "O(log min(a,b))", "O(log min(a,b))", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "An efficient method for computing the greatest common divisor (GCD) of two numbers.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Euclidean_algorithm".to_string(),
+            examples: vec![
+                "fn gcd(a: u32, b: u32) -> u32 { if b == 0 { a } else { gcd(b, a % b) } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["gcd", "%"], &[Recursion]),
+                Fingerprint::new(&["gcd", "%"], &[Loop]),
+            ],
+            relations: Vec::new(),
+        },
+        AlgorithmInfo {
+            name: "Extended Euclidean Algorithm".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(log min(a,b))".to_string(),
+            bounds: time("O(1)", "O(log min(a,b))", "O(log min(a,b))", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Computes the GCD together with Bézout coefficients x and y such that ax + by = gcd(a, b); the standard way to find modular inverses.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm".to_string(),
+            examples: vec![
+                "let (g, x1, y1) = extended_gcd(b, a % b); (g, y1, x1 - (a / b) * y1)".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["extended", "gcd"], &[]),
+                Fingerprint::new(&["egcd"], &[]),
+                Fingerprint::new(&["mod_inverse", "x1", "y1"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Euclidean Algorithm"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Sieve of Eratosthenes".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(n log log n)".to_string(),
+            bounds: time("O(n log log n)", "O(n log log n)", "O(n log log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "An ancient algorithm for finding all prime numbers up to any given limit.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Sieve_of_Eratosthenes".to_string(),
+            examples: vec![
+                "fn sieve_of_eratosthenes(limit: usize) -> Vec<bool> { let mut is_prime = vec![true; limit + 1]; is_prime[0] = false; is_prime[1] = false; /* marking multiples */ }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["sieve"], &[NestedLoops]),
+                Fingerprint::new(&["is_prime", "step_by"], &[NestedLoops]),
+                Fingerprint::new(&["composite", "step_by"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Trial Division"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Modular Exponentiation".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(log e)".to_string(),
+            bounds: time("O(log e)", "O(log e)", "O(log e)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Computes b^e mod m by squaring: square the base for every bit of the exponent and multiply it in where the bit is set.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Modular_exponentiation".to_string(),
+            examples: vec![
+                "while exp > 0 { if exp & 1 == 1 { result = result * base % modulus; } base = base * base % modulus; exp >>= 1; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["mod_pow"], &[Loop]),
+                Fingerprint::new(&["pow_mod"], &[Loop]),
+                Fingerprint::new(&["modpow"], &[Loop]),
+                Fingerprint::new(&["base", "exp", ">>=1", "%"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Prerequisite, "Euclidean Algorithm"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Miller-Rabin Primality Test".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(k log³ n)".to_string(),
+            bounds: time("O(k log^3 n)", "O(k log^3 n)", "O(k log^3 n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A probabilistic primality test: writes n-1 as 2^s·d and checks that each witness a behaves as it must for a prime; deterministic for 64-bit n with fixed witnesses.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test".to_string(),
+            examples: vec![
+                "for &a in &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] { if !passes(n, a, d, s) { return false; } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["miller", "rabin"], &[]),
+                Fingerprint::new(&["witness"], &[NestedLoops]),
+                Fingerprint::new(&["witnesses"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Modular Exponentiation"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Trial Division"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Trial Division".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(√n)".to_string(),
+            bounds: time("O(1)", "O(√n)", "O(√n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Tests primality or factors a number by dividing it by every candidate up to its square root.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Trial_division".to_string(),
+            examples: vec![
+                "let mut i = 2; while i * i <= n { if n % i == 0 { return false; } i += 1; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["trial", "division"], &[]),
+                Fingerprint::new(&["i*i<=n", "%i==0"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Miller-Rabin Primality Test"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Newton's Method".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(log n·F(n))".to_string(),
+            bounds: time("O(1)", "O(log n)", "O(log n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Finds a root of a differentiable function by following the tangent line: x ← x - f(x)/f'(x), converging quadratically near a simple root.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Newton%27s_method".to_string(),
+            examples: vec![
+                "for _ in 0..MAX_ITER { let step = f(x) / derivative(x); x -= step; if step.abs() < tolerance { break; } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["newton"], &[Loop]),
+                Fingerprint::new(&["derivative", "tolerance"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Bisection Method"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Bisection Method".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(log((b-a)/ε))".to_string(),
+            bounds: time("O(log n)", "O(log n)", "O(log n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Finds a root of a continuous function by halving an interval whose ends have opposite signs.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Bisection_method".to_string(),
+            examples: vec![
+                "let mid = (a + b) / 2.0; if f(a) * f(mid) <= 0.0 { b = mid; } else { a = mid; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["bisection"], &[Loop]),
+                Fingerprint::new(&["mid", "*f(mid)"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Binary Search"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Newton's Method"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Pollard's Rho Algorithm".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(n^(1/4))".to_string(),
+            bounds: time("O(1)", "O(n^(1/4))", "O(n^(1/4))", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Finds a non-trivial factor by iterating x ← x² + c mod n and taking the GCD of differences, detecting the cycle with Floyd's method.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Pollard%27s_rho_algorithm".to_string(),
+            examples: vec![
+                "x = f(x); y = f(f(y)); d = gcd(x.abs_diff(y), n);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["pollard"], &[]),
+                Fingerprint::new(&["rho", "gcd"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Euclidean Algorithm"),
+                Relation::algorithm(RelationKind::Uses, "Floyd's Cycle Detection"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Chinese Remainder Theorem".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(k log M)".to_string(),
+            bounds: time("O(k log M)", "O(k log M)", "O(k log M)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Combines congruences x ≡ aᵢ mod nᵢ with pairwise coprime moduli into the unique solution modulo their product.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Chinese_remainder_theorem".to_string(),
+            examples: vec![
+                "for (&a, &n) in remainders.iter().zip(moduli) { let p = product / n; x += a * mod_inverse(p, n) * p; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["crt"], &[Loop]),
+                Fingerprint::new(&["chinese", "remainder"], &[]),
+                Fingerprint::new(&["remainders", "moduli"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Extended Euclidean Algorithm"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Matrix Exponentiation".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(k³ log n)".to_string(),
+            bounds: time("O(k^3 log n)", "O(k^3 log n)", "O(k^3 log n)", "O(k^2)"),
+            stable: None,
+            in_place: None,
+            description: "Raises a square matrix to the n-th power by repeated squaring; computes linear recurrences such as Fibonacci numbers in logarithmic time.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Exponentiation_by_squaring#Matrices".to_string(),
+            examples: vec![
+                "while n > 0 { if n & 1 == 1 { result = mat_mul(&result, &base); } base = mat_mul(&base, &base); n >>= 1; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["mat_pow"], &[]),
+                Fingerprint::new(&["matrix", "power"], &[Loop]),
+                Fingerprint::new(&["mat_mul", ">>=1"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Modular Exponentiation"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Fibonacci Sequence"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Gaussian Elimination".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(n³)".to_string(),
+            bounds: time("O(n^3)", "O(n^3)", "O(n^3)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Solves a linear system by reducing the augmented matrix to row echelon form with partial pivoting, then back-substituting.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Gaussian_elimination".to_string(),
+            examples: vec![
+                "let factor = m[row][col] / m[col][col]; for k in col..=n { m[row][k] -= factor * m[col][k]; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["gaussian"], &[]),
+                Fingerprint::new(&["elimination"], &[NestedLoops]),
+                Fingerprint::new(&["pivot", "factor"], &[TripleNestedLoops]),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Binomial Coefficient".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(n·k)".to_string(),
+            bounds: time("O(n*k)", "O(n*k)", "O(n*k)", "O(k)"),
+            stable: None,
+            in_place: None,
+            description: "Computes n choose k from Pascal's triangle, where every entry is the sum of the two above it.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Binomial_coefficient".to_string(),
+            examples: vec![
+                "for i in 1..=n { for j in (1..=i.min(k)).rev() { row[j] += row[j - 1]; } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["binomial"], &[]),
+                Fingerprint::new(&["pascal"], &[Loop]),
+                Fingerprint::new(&["choose"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Fibonacci Sequence"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Horner's Method".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Evaluates a polynomial with one multiplication and one addition per coefficient by nesting: ((aₙx + aₙ₋₁)x + …)x + a₀.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Horner%27s_method".to_string(),
+            examples: vec![
+                "coefficients.iter().rev().fold(0.0, |acc, &c| acc * x + c)".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["horner"], &[]),
+                Fingerprint::new(&["coefficients", "*x+"], &[]),
+                Fingerprint::new(&["coeffs", "*x+"], &[]),
+            ],
+            relations: Vec::new(),
+        },
+        AlgorithmInfo {
+            name: "Euler's Totient Function".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(√n)".to_string(),
+            bounds: time("O(1)", "O(√n)", "O(√n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Counts the integers up to n that are coprime to n by factoring n and applying φ(n) = n·∏(1 - 1/p).".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Euler%27s_totient_function".to_string(),
+            examples: vec![
+                "if n % p == 0 { while n % p == 0 { n /= p; } result -= result / p; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["totient"], &[]),
+                Fingerprint::new(&["phi", "result-=result/"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Trial Division"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Simpson's Rule".to_string(),
+            category: AlgorithmCategory::Mathematical,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Approximates a definite integral by fitting parabolas through pairs of subintervals, weighting the points 1, 4, 2, 4, …, 1.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Simpson%27s_rule".to_string(),
+            examples: vec![
+                "let weight = if i % 2 == 1 { 4.0 } else { 2.0 }; sum += weight * f(a + i as f64 * h);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["simpson"], &[]),
+                Fingerprint::new(&["integrate", "4.0", "2.0"], &[Loop]),
+            ],
+            relations: Vec::new(),
+        },
+
+        // Cryptographic Algorithms
+        AlgorithmInfo {
+            name: "Caesar Cipher".to_string(),
+            category: AlgorithmCategory::Cryptographic,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Shifts every letter a fixed number of places down the alphabet; trivially broken, but the base of many classical ciphers.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Caesar_cipher".to_string(),
+            examples: vec![
+                "(((c as u8 - base + shift) % 26) + base) as char".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["caesar"], &[]),
+                Fingerprint::new(&["shift", "%26"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Vigenere Cipher"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Vigenere Cipher".to_string(),
+            category: AlgorithmCategory::Cryptographic,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "A polyalphabetic cipher that shifts each letter by the matching letter of a repeating keyword.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Vigen%C3%A8re_cipher".to_string(),
+            examples: vec![
+                "text.bytes().zip(key.bytes().cycle()).map(|(c, k)| (c - b'A' + k - b'A') % 26 + b'A')".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["vigenere"], &[]),
+                Fingerprint::new(&["key", "cycle", "%26"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Caesar Cipher"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "XOR Cipher".to_string(),
+            category: AlgorithmCategory::Cryptographic,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "XORs every byte with a repeating key; the same operation encrypts and decrypts, and it is secure only as a one-time pad.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/XOR_cipher".to_string(),
+            examples: vec![
+                "data.iter().zip(key.iter().cycle()).map(|(b, k)| b ^ k).collect()".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["xor", "cipher"], &[]),
+                Fingerprint::new(&["key", "cycle", "^"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Vigenere Cipher"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "RSA".to_string(),
+            category: AlgorithmCategory::Cryptographic,
+            complexity: "O(k³)".to_string(),
+            bounds: time("O(k^3)", "O(k^3)", "O(k^3)", "O(k)"),
+            stable: None,
+            in_place: None,
+            description: "Public-key encryption: c = mᵉ mod n with n = pq, and decryption with the private exponent d ≡ e⁻¹ mod φ(n).".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/RSA_(cryptosystem)".to_string(),
+            examples: vec![
+                "let d = mod_inverse(e, (p - 1) * (q - 1)); let cipher = mod_pow(message, e, n);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["rsa"], &[]),
+                Fingerprint::new(&["mod_inverse", "phi", "mod_pow"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Modular Exponentiation"),
+                Relation::algorithm(RelationKind::Uses, "Extended Euclidean Algorithm"),
+                Relation::algorithm(RelationKind::Uses, "Miller-Rabin Primality Test"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Diffie-Hellman Key Exchange".to_string(),
+            category: AlgorithmCategory::Cryptographic,
+            complexity: "O(k³)".to_string(),
+            bounds: time("O(k^3)", "O(k^3)", "O(k^3)", "O(k)"),
+            stable: None,
+            in_place: None,
+            description: "Two parties agree on a shared secret over a public channel: each publishes gˣ mod p and raises the other's value to its own secret exponent.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Diffie%E2%80%93Hellman_key_exchange".to_string(),
+            examples: vec![
+                "let public = mod_pow(g, secret, p); let shared = mod_pow(their_public, secret, p);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["diffie"], &[]),
+                Fingerprint::new(&["hellman"], &[]),
+                Fingerprint::new(&["shared", "secret", "public", "mod_pow"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Modular Exponentiation"),
+                Relation::algorithm(RelationKind::AlternativeTo, "RSA"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "SHA-256".to_string(),
+            category: AlgorithmCategory::Cryptographic,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A cryptographic hash from the SHA-2 family: pads the message and runs 64 rounds of rotations, choice and majority functions per 512-bit block.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/SHA-2".to_string(),
+            examples: vec![
+                "let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["sha256"], &[Loop]),
+                Fingerprint::new(&["0x428a2f98"], &[]),
+                Fingerprint::new(&["rotate_right", "maj", "ch"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "MD5"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "MD5".to_string(),
+            category: AlgorithmCategory::Cryptographic,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A 128-bit message digest with four rounds of sixteen operations per block; broken for collision resistance and kept only for checksums.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/MD5".to_string(),
+            examples: vec![
+                "let f = (b & c) | (!b & d); let g = i;".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["md5"], &[Loop]),
+                Fingerprint::new(&["0xd76aa478"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "SHA-256"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "AES".to_string(),
+            category: AlgorithmCategory::Cryptographic,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "The Advanced Encryption Standard (Rijndael): a 128-bit block cipher of rounds of byte substitution, row shifts, column mixing and round-key addition.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Advanced_Encryption_Standard".to_string(),
+            examples: vec![
+                "sub_bytes(&mut state); shift_rows(&mut state); mix_columns(&mut state); add_round_key(&mut state, &keys[round]);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["aes"], &[Loop]),
+                Fingerprint::new(&["shift_rows"], &[]),
+                Fingerprint::new(&["mix_columns"], &[]),
+                Fingerprint::new(&["sub_bytes", "sbox"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "ChaCha20"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "RC4".to_string(),
+            category: AlgorithmCategory::Cryptographic,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A stream cipher that permutes a 256-byte state with the key, then swaps state bytes to produce a keystream; no longer considered secure.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/RC4".to_string(),
+            examples: vec![
+                "j = j.wrapping_add(s[i]).wrapping_add(key[i % key.len()]); s.swap(i, j as usize);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["rc4"], &[]),
+                Fingerprint::new(&["ksa"], &[Loop]),
+                Fingerprint::new(&["prga"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "ChaCha20"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "HMAC".to_string(),
+            category: AlgorithmCategory::Cryptographic,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A message authentication code built from a hash: H((K ⊕ opad) ‖ H((K ⊕ ipad) ‖ m)).".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/HMAC".to_string(),
+            examples: vec![
+                "let inner: Vec<u8> = key.iter().map(|b| b ^ 0x36).collect();".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["hmac"], &[]),
+                Fingerprint::new(&["ipad", "opad"], &[]),
+                Fingerprint::new(&["0x36", "0x5c"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "SHA-256"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Tiny Encryption Algorithm".to_string(),
+            category: AlgorithmCategory::Cryptographic,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A compact Feistel block cipher of 32 cycles of shifts, additions and XORs with the key and the constant 0x9E3779B9.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Tiny_Encryption_Algorithm".to_string(),
+            examples: vec![
+                "sum = sum.wrapping_add(DELTA); v0 = v0.wrapping_add(((v1 << 4).wrapping_add(k[0])) ^ (v1.wrapping_add(sum)) ^ ((v1 >> 5).wrapping_add(k[1])));".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["0x9e3779b9"], &[Loop]),
+                Fingerprint::new(&["tea", "delta"], &[Loop]),
+                Fingerprint::new(&["xtea"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "AES"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "ChaCha20".to_string(),
+            category: AlgorithmCategory::Cryptographic,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A stream cipher built from add-rotate-XOR quarter rounds over a 4×4 state of 32-bit words, run for 20 rounds per 64-byte block.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Salsa20#ChaCha_variant".to_string(),
+            examples: vec![
+                "s[a] = s[a].wrapping_add(s[b]); s[d] ^= s[a]; s[d] = s[d].rotate_left(16);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["chacha"], &[]),
+                Fingerprint::new(&["chacha20"], &[]),
+                Fingerprint::new(&["quarter_round"], &[]),
+                Fingerprint::new(&["0x61707865"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "AES"),
+            ],
+        },
+
+        // Machine Learning Algorithms
+        AlgorithmInfo {
+            name: "k-Means Clustering".to_string(),
+            category: AlgorithmCategory::MachineLearning,
+            complexity: "O(n·k·i)".to_string(),
+            bounds: time("O(n*k*i)", "O(n*k*i)", "O(n*k*i)", "O(n + k)"),
+            stable: None,
+            in_place: None,
+            description: "Partitions points into k clusters by alternately assigning each point to its nearest centroid and moving each centroid to the mean of its points.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/K-means_clustering".to_string(),
+            examples: vec![
+                "let nearest = (0..k).min_by(|&a, &b| distance(p, &centroids[a]).total_cmp(&distance(p, &centroids[b]))).unwrap();".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["kmeans"], &[]),
+                Fingerprint::new(&["k_means"], &[]),
+                Fingerprint::new(&["centroids", "assignments"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "DBSCAN"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "k-Nearest Neighbors".to_string(),
+            category: AlgorithmCategory::MachineLearning,
+            complexity: "O(n·d)".to_string(),
+            bounds: time("O(n*d)", "O(n*d + n log n)", "O(n*d + n log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Classifies a point by majority vote among the k training points closest to it.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/K-nearest_neighbors_algorithm".to_string(),
+            examples: vec![
+                "distances.sort_by(|a, b| a.0.total_cmp(&b.0)); let votes = distances.iter().take(k).map(|(_, label)| label);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["knn"], &[]),
+                Fingerprint::new(&["nearest", "neighbors", ".take(k)"], &[]),
+                Fingerprint::new(&["distances", "votes", ".take(k)"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "k-Means Clustering"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Linear Regression".to_string(),
+            category: AlgorithmCategory::MachineLearning,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Fits a line y = slope·x + intercept by ordinary least squares, minimizing the sum of squared residuals.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Linear_regression".to_string(),
+            examples: vec![
+                "let slope = covariance / variance; let intercept = mean_y - slope * mean_x;".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["linear", "regression"], &[]),
+                Fingerprint::new(&["slope", "intercept", "mean_x"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Gradient Descent"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Logistic Regression".to_string(),
+            category: AlgorithmCategory::MachineLearning,
+            complexity: "O(n·d·i)".to_string(),
+            bounds: time("O(n*d*i)", "O(n*d*i)", "O(n*d*i)", "O(d)"),
+            stable: None,
+            in_place: None,
+            description: "A binary classifier that passes a weighted sum through the sigmoid and fits the weights by gradient descent on the log loss.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Logistic_regression".to_string(),
+            examples: vec![
+                "let prediction = sigmoid(dot(&weights, x) + bias); let error = prediction - y;".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["logistic"], &[]),
+                Fingerprint::new(&["sigmoid", "log_loss"], &[]),
+                Fingerprint::new(&["sigmoid", "weights", "bias", "learning_rate"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Gradient Descent"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Perceptron"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Perceptron".to_string(),
+            category: AlgorithmCategory::MachineLearning,
+            complexity: "O(n·d·i)".to_string(),
+            bounds: time("O(n*d*i)", "O(n*d*i)", "O(n*d*i)", "O(d)"),
+            stable: None,
+            in_place: None,
+            description: "A linear classifier trained online: whenever a sample is misclassified, its features are added to or subtracted from the weights.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Perceptron".to_string(),
+            examples: vec![
+                "let predicted = if activation >= 0.0 { 1 } else { -1 }; if predicted != label { /* update weights */ }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["perceptron"], &[]),
+                Fingerprint::new(&["weights", "bias", "activation", ">=0.0"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Logistic Regression"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Naive Bayes Classifier".to_string(),
+            category: AlgorithmCategory::MachineLearning,
+            complexity: "O(n·d)".to_string(),
+            bounds: time("O(n*d)", "O(n*d)", "O(n*d)", "O(c*d)"),
+            stable: None,
+            in_place: None,
+            description: "Classifies by Bayes' theorem under the assumption that features are independent given the class, summing log priors and log likelihoods.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Naive_Bayes_classifier".to_string(),
+            examples: vec![
+                "let score = prior.ln() + features.iter().map(|f| likelihood(class, f).ln()).sum::<f64>();".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["naive", "bayes"], &[]),
+                Fingerprint::new(&["prior", "likelihood"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Logistic Regression"),
+                Relation::data_structure(RelationKind::Uses, "Hash Table"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "ID3 Decision Tree".to_string(),
+            category: AlgorithmCategory::MachineLearning,
+            complexity: "O(n·d²)".to_string(),
+            bounds: time("O(n*d)", "O(n*d^2)", "O(n*d^2)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Grows a decision tree by splitting on the attribute with the highest information gain, recursing until the labels are pure.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/ID3_algorithm".to_string(),
+            examples: vec![
+                "let best = attributes.iter().max_by(|a, b| information_gain(rows, a).total_cmp(&information_gain(rows, b)));".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["id3"], &[]),
+                Fingerprint::new(&["entropy", "information_gain"], &[]),
+                Fingerprint::new(&["entropy", "split"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::Uses, "Hash Table"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Gradient Descent".to_string(),
+            category: AlgorithmCategory::MachineLearning,
+            complexity: "O(i·d)".to_string(),
+            bounds: time("O(i*d)", "O(i*d)", "O(i*d)", "O(d)"),
+            stable: None,
+            in_place: None,
+            description: "Minimizes a differentiable function by stepping against its gradient, scaled by a learning rate, until it stops improving.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Gradient_descent".to_string(),
+            examples: vec![
+                "for (p, g) in params.iter_mut().zip(gradient(&params)) { *p -= learning_rate * g; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["gradient", "descent"], &[]),
+                Fingerprint::new(&["learning_rate", "gradient"], &[Loop]),
+                Fingerprint::new(&["learning_rate", "grad"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Newton's Method"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Backpropagation".to_string(),
+            category: AlgorithmCategory::MachineLearning,
+            complexity: "O(W)".to_string(),
+            bounds: time("O(W)", "O(W)", "O(W)", "O(W)"),
+            stable: None,
+            in_place: None,
+            description: "Trains a neural network by propagating the output error backwards through the layers with the chain rule to get every weight's gradient.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Backpropagation".to_string(),
+            examples: vec![
+                "let hidden_delta = (0..hidden).map(|h| output_delta * w2[h] * hidden_out[h] * (1.0 - hidden_out[h]));".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["backprop"], &[]),
+                Fingerprint::new(&["backpropagation"], &[]),
+                Fingerprint::new(&["hidden", "delta", "weights"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Gradient Descent"),
+                Relation::algorithm(RelationKind::VariantOf, "Perceptron"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "DBSCAN".to_string(),
+            category: AlgorithmCategory::MachineLearning,
+            complexity: "O(n²)".to_string(),
+            bounds: time("O(n log n)", "O(n^2)", "O(n^2)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Density-based clustering: grows clusters from core points with at least min_points neighbors within eps, and marks the rest as noise.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/DBSCAN".to_string(),
+            examples: vec![
+                "let neighbors = region_query(points, p, eps); if neighbors.len() < min_points { labels[p] = Label::Noise; continue; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["dbscan"], &[]),
+                Fingerprint::new(&["eps", "noise"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "k-Means Clustering"),
+                Relation::data_structure(RelationKind::Uses, "Queue"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Principal Component Analysis".to_string(),
+            category: AlgorithmCategory::MachineLearning,
+            complexity: "O(n·d² + d³)".to_string(),
+            bounds: time("O(n*d^2 + d^3)", "O(n*d^2 + d^3)", "O(n*d^2 + d^3)", "O(d^2)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the directions of greatest variance as eigenvectors of the covariance matrix, here by power iteration.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Principal_component_analysis".to_string(),
+            examples: vec![
+                "for _ in 0..iterations { v = normalize(&multiply(&covariance, &v)); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["pca"], &[]),
+                Fingerprint::new(&["covariance", "eigenvector"], &[Loop]),
+                Fingerprint::new(&["covariance", "power", "iteration"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Gaussian Elimination"),
+            ],
+        },
+
+        // String Algorithms
+        AlgorithmInfo {
+            name: "KMP Algorithm".to_string(),
+            category: AlgorithmCategory::String,
+            complexity: "O(n + m)".to_string(),
+            bounds: time("O(n + m)", "O(n + m)", "O(n + m)", "O(m)"),
+            stable: None,
+            in_place: None,
+            description: "A string searching algorithm that searches for occurrences of a pattern within a main text string by employing the observation that when a mismatch occurs, the word itself embodies sufficient information to determine where the next match could begin.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Knuth%E2%80%93Morris%E2%80%93Pratt_algorithm".to_string(),
+            examples: vec![
+                "fn kmp_search(text: &str, pattern: &str) -> Vec<usize> { let lps = compute_lps(pattern); /* matching logic */ }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["kmp"], &[]),
+                Fingerprint::new(&["lps"], &[Loop]),
+                Fingerprint::new(&["failure", "pattern"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Rabin-Karp Algorithm"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Z-Algorithm"),
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Rabin-Karp Algorithm".to_string(),
+            category: AlgorithmCategory::String,
+            complexity: "O(n+m)".to_string(),
+            bounds: time("O(n + m)", "O(n + m)", "O(n*m)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A string searching algorithm that uses hashing to find any one of a set of pattern strings in a text.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Rabin%E2%80%93Karp_algorithm".to_string(),
+            examples: vec![
+                "fn rabin_karp(text: &str, pattern: &str) -> Vec<usize> { let pattern_hash = hash(pattern); /* rolling hash */ }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["rabin", "karp"], &[]),
+                Fingerprint::new(&["pattern_hash", "window_hash"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "KMP Algorithm"),
+                Relation::algorithm(RelationKind::Uses, "Hash Table"),
+                Relation::algorithm(RelationKind::Uses, "Polynomial Rolling Hash"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Z-Algorithm".to_string(),
+            category: AlgorithmCategory::String,
+            complexity: "O(n + m)".to_string(),
+            bounds: time("O(n + m)", "O(n + m)", "O(n + m)", "O(n + m)"),
+            stable: None,
+            in_place: None,
+            description: "Computes for every position the length of the longest substring starting there that is also a prefix, reusing the rightmost match box; pattern search runs it over pattern + separator + text.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/String-searching_algorithm".to_string(),
+            examples: vec![
+                "if i < r { z[i] = (r - i).min(z[i - l]); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["z_function"], &[]),
+                Fingerprint::new(&["z_array"], &[]),
+                Fingerprint::new(&["z[i-l]"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "KMP Algorithm"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Aho-Corasick Algorithm".to_string(),
+            category: AlgorithmCategory::String,
+            complexity: "O(n + m + z)".to_string(),
+            bounds: time("O(n + m + z)", "O(n + m + z)", "O(n + m + z)", "O(m)"),
+            stable: None,
+            in_place: None,
+            description: "Matches many patterns at once by running the text through a trie of the patterns whose failure links point to the longest proper suffix that is also in the trie.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Aho%E2%80%93Corasick_algorithm".to_string(),
+            examples: vec![
+                "while state != 0 && !nodes[state].next.contains_key(&c) { state = nodes[state].fail; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["aho"], &[]),
+                Fingerprint::new(&["corasick"], &[]),
+                Fingerprint::new(&["fail", "output", "VecDeque"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Trie Search"),
+                Relation::algorithm(RelationKind::VariantOf, "KMP Algorithm"),
+                Relation::algorithm(RelationKind::Uses, "Breadth-First Search"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Boyer-Moore String Search".to_string(),
+            category: AlgorithmCategory::String,
+            complexity: "O(n·m)".to_string(),
+            bounds: time("O(n/m)", "O(n)", "O(n*m)", "O(k)"),
+            stable: None,
+            in_place: None,
+            description: "Compares the pattern right to left and, on a mismatch, skips ahead using the last position of the mismatched character in the pattern; sublinear on typical text.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Boyer%E2%80%93Moore_string-search_algorithm".to_string(),
+            examples: vec![
+                "let shift = bad_char[text[s + j] as usize]; s += (j as isize - shift).max(1) as usize;".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["bad_char"], &[Loop]),
+                Fingerprint::new(&["horspool"], &[]),
+                Fingerprint::new(&["boyer", "moore", "pattern"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "KMP Algorithm"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Naive String Search"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Manacher's Algorithm".to_string(),
+            category: AlgorithmCategory::String,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the longest palindromic substring in linear time by mirroring radii around the center of the rightmost palindrome found so far.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Longest_palindromic_substring#Manacher's_algorithm".to_string(),
+            examples: vec![
+                "let mirror = 2 * center - i; if i < right { p[i] = (right - i).min(p[mirror]); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["manacher"], &[]),
+                Fingerprint::new(&["mirror", "center", "right"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Longest Palindromic Subsequence"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Suffix Array".to_string(),
+            category: AlgorithmCategory::String,
+            complexity: "O(n log² n)".to_string(),
+            bounds: time("O(n log n)", "O(n log^2 n)", "O(n log^2 n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "The sorted order of all suffixes of a string, built here by prefix doubling on rank pairs; enables substring search by binary search.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Suffix_array".to_string(),
+            examples: vec![
+                "sa.sort_by_key(|&i| (rank[i], rank.get(i + k).copied().unwrap_or(-1)));".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["suffix_array"], &[]),
+                Fingerprint::new(&["suffix", "array", "rank"], &[Loop]),
+                Fingerprint::new(&["suffixes", "sort"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Merge Sort"),
+                Relation::algorithm(RelationKind::Uses, "Binary Search"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Longest Common Substring"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Naive String Search".to_string(),
+            category: AlgorithmCategory::String,
+            complexity: "O(n·m)".to_string(),
+            bounds: time("O(n)", "O(n*m)", "O(n*m)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Compares the pattern against every alignment of the text; simple, and fine for short patterns.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/String-searching_algorithm#Na%C3%AFve_string_search".to_string(),
+            examples: vec![
+                "text.windows(pattern.len()).position(|window| window == pattern)".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&[".windows(pattern.len())"], &[]),
+                Fingerprint::new(&["text[i+j]!=pattern[j]"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "KMP Algorithm"),
+                Relation::algorithm(RelationKind::VariantOf, "Linear Search"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Run-Length Encoding".to_string(),
+            category: AlgorithmCategory::String,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Compresses runs of repeated symbols into (count, symbol) pairs.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Run-length_encoding".to_string(),
+            examples: vec![
+                "if Some(c) == current { run += 1; } else { flush(&mut out, current, run); current = Some(c); run = 1; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["rle"], &[]),
+                Fingerprint::new(&["run", "length", "encode"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "LZ77"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Burrows-Wheeler Transform".to_string(),
+            category: AlgorithmCategory::String,
+            complexity: "O(n² log n)".to_string(),
+            bounds: time("O(n^2 log n)", "O(n^2 log n)", "O(n^2 log n)", "O(n^2)"),
+            stable: None,
+            in_place: None,
+            description: "Sorts all rotations of the input and keeps the last column, which groups equal characters for better compression and is reversible.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Burrows%E2%80%93Wheeler_transform".to_string(),
+            examples: vec![
+                "rotations.sort(); let last: String = rotations.iter().map(|r| r.chars().last().unwrap()).collect();".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["bwt"], &[]),
+                Fingerprint::new(&["burrows"], &[]),
+                Fingerprint::new(&["rotations", "sort", "last"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Suffix Array"),
+                Relation::algorithm(RelationKind::Uses, "Run-Length Encoding"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "LZ77".to_string(),
+            category: AlgorithmCategory::String,
+            complexity: "O(n·w)".to_string(),
+            bounds: time("O(n)", "O(n*w)", "O(n*w)", "O(w)"),
+            stable: None,
+            in_place: None,
+            description: "Dictionary compression that replaces repeated data with (distance, length) references to its earlier occurrence within a sliding window.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/LZ77_and_LZ78".to_string(),
+            examples: vec![
+                "tokens.push(Token { offset: best_offset, length: best_length, next: input.get(i + best_length).copied() });".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["lz77"], &[]),
+                Fingerprint::new(&["search_buffer", "lookahead"], &[Loop]),
+                Fingerprint::new(&["best_offset", "best_length", "window"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Run-Length Encoding"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Huffman Coding"),
+            ],
+        },
 
         // Tree Algorithms
         AlgorithmInfo {
-            name: "Binary Search Tree".to_string(),
+            name: "Binary Search Tree".to_string(),
+            category: AlgorithmCategory::Tree,
+            complexity: "O(log n)".to_string(),
+            bounds: time("O(1)", "O(log n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A node-based binary tree data structure that has the following properties: the left subtree of a node contains only nodes with keys lesser than the node's key, and the right subtree contains only nodes with keys greater than the node's key.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Binary_search_tree".to_string(),
+            examples: vec![
+                "struct BST { root: Option<Box<Node>> } impl BST { fn insert(&mut self, value: i32) { /* recursive insertion */ } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["bst"], &[]),
+                Fingerprint::new(&["insert", "left", "right", "Option<Box<"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Prerequisite, "Binary Search"),
+                Relation::data_structure(RelationKind::Uses, "Binary Search Tree"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "In-Order Traversal".to_string(),
+            category: AlgorithmCategory::Tree,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(h)"),
+            stable: None,
+            in_place: None,
+            description: "Visits the left subtree, then the node, then the right subtree; yields a binary search tree's keys in sorted order.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Tree_traversal#In-order,_LNR".to_string(),
+            examples: vec![
+                "inorder(&node.left, out); out.push(node.value); inorder(&node.right, out);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["inorder"], &[Recursion]),
+                Fingerprint::new(&["in_order"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Depth-First Search"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Morris Traversal"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Pre-Order Traversal".to_string(),
+            category: AlgorithmCategory::Tree,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(h)"),
+            stable: None,
+            in_place: None,
+            description: "Visits the node before its subtrees; used to copy or serialize a tree.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Tree_traversal#Pre-order,_NLR".to_string(),
+            examples: vec![
+                "out.push(node.value); preorder(&node.left, out); preorder(&node.right, out);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["preorder"], &[]),
+                Fingerprint::new(&["pre_order"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Depth-First Search"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Post-Order Traversal".to_string(),
+            category: AlgorithmCategory::Tree,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(h)"),
+            stable: None,
+            in_place: None,
+            description: "Visits both subtrees before the node; used to free or evaluate a tree bottom-up.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Tree_traversal#Post-order,_LRN".to_string(),
+            examples: vec![
+                "postorder(&node.left, out); postorder(&node.right, out); out.push(node.value);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["postorder"], &[]),
+                Fingerprint::new(&["post_order"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Depth-First Search"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Level-Order Traversal".to_string(),
+            category: AlgorithmCategory::Tree,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(w)"),
+            stable: None,
+            in_place: None,
+            description: "Visits a tree level by level with a queue, left to right.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Tree_traversal#Breadth-first_search".to_string(),
+            examples: vec![
+                "if let Some(left) = &node.left { queue.push_back(left); } if let Some(right) = &node.right { queue.push_back(right); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["level_order"], &[]),
+                Fingerprint::new(&["level", "VecDeque", "left", "right"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Breadth-First Search"),
+                Relation::data_structure(RelationKind::Uses, "Queue"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Lowest Common Ancestor".to_string(),
             category: AlgorithmCategory::Tree,
             complexity: "O(log n)".to_string(),
-            description: "A node-based binary tree data structure that has the following properties: the left subtree of a node contains only nodes with keys lesser than the node's key, and the right subtree contains only nodes with keys greater than the node's key.".to_string(),
-            wikipedia_link: "https://en.wikipedia.org/wiki/Binary_search_tree".to_string(),
+            bounds: time("O(1)", "O(log n)", "O(log n)", "O(n log n)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the deepest node that has both given nodes as descendants; binary lifting answers each query in O(log n) after O(n log n) preprocessing.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Lowest_common_ancestor".to_string(),
             examples: vec![
-                "struct BST { root: Option<Box<Node>> } impl BST { fn insert(&mut self, value: i32) { /* recursive insertion */ } }".to_string(),
+                "for k in (0..LOG).rev() { if up[k][a] != up[k][b] { a = up[k][a]; b = up[k][b]; } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["lca"], &[]),
+                Fingerprint::new(&["lowest", "common", "ancestor"], &[]),
+                Fingerprint::new(&["up[k][a]", "depth"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Depth-First Search"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "AVL Tree Rotation".to_string(),
+            category: AlgorithmCategory::Tree,
+            complexity: "O(log n)".to_string(),
+            bounds: time("O(log n)", "O(log n)", "O(log n)", "O(log n)"),
+            stable: None,
+            in_place: None,
+            description: "Keeps a binary search tree balanced after insertion by rotating any node whose subtree heights differ by more than one.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/AVL_tree".to_string(),
+            examples: vec![
+                "if balance > 1 && key < node.left.as_ref().unwrap().key { return rotate_right(node); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["avl"], &[]),
+                Fingerprint::new(&["rotate", "height", "balance"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Binary Search Tree"),
+                Relation::data_structure(RelationKind::Uses, "AVL Tree"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Red-Black Tree Insertion"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Red-Black Tree Insertion".to_string(),
+            category: AlgorithmCategory::Tree,
+            complexity: "O(log n)".to_string(),
+            bounds: time("O(log n)", "O(log n)", "O(log n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Inserts as into a binary search tree, then restores the red-black invariants by recoloring and at most two rotations.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Red%E2%80%93black_tree".to_string(),
+            examples: vec![
+                "if uncle_color == Color::Red { parent.color = Color::Black; uncle.color = Color::Black; grandparent.color = Color::Red; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["red", "black", "rotate"], &[]),
+                Fingerprint::new(&["red", "black", "uncle"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Binary Search Tree"),
+                Relation::algorithm(RelationKind::AlternativeTo, "AVL Tree Rotation"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Tree Diameter".to_string(),
+            category: AlgorithmCategory::Tree,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(h)"),
+            stable: None,
+            in_place: None,
+            description: "The longest path between any two nodes, from one post-order pass that combines the two deepest child heights at each node.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Distance_(graph_theory)".to_string(),
+            examples: vec![
+                "*diameter = (*diameter).max(left + right); 1 + left.max(right)".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["diameter"], &[Recursion]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Post-Order Traversal"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Segment Tree".to_string(),
+            category: AlgorithmCategory::Tree,
+            complexity: "O(log n)".to_string(),
+            bounds: time("O(1)", "O(log n)", "O(log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "A binary tree over array ranges that answers range queries such as sums or minimums and applies point updates in O(log n).".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Segment_tree".to_string(),
+            examples: vec![
+                "self.tree[node] = self.tree[2 * node] + self.tree[2 * node + 1];".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["segment", "tree"], &[]),
+                Fingerprint::new(&["segtree"], &[]),
+                Fingerprint::new(&["tree[2*node]", "tree[2*node+1]"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Fenwick Tree"),
+                Relation::algorithm(RelationKind::Uses, "Binary Search"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Fenwick Tree".to_string(),
+            category: AlgorithmCategory::Tree,
+            complexity: "O(log n)".to_string(),
+            bounds: time("O(1)", "O(log n)", "O(log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "A binary indexed tree: prefix sums and point updates in O(log n) by stepping through indices with their lowest set bit.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Fenwick_tree".to_string(),
+            examples: vec![
+                "while i <= n { tree[i] += delta; i += i & i.wrapping_neg(); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["fenwick"], &[]),
+                Fingerprint::new(&["&i.wrapping_neg()"], &[Loop]),
+                Fingerprint::new(&["&(!i+1)"], &[Loop]),
+                Fingerprint::new(&["&(-i)"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Segment Tree"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Morris Traversal".to_string(),
+            category: AlgorithmCategory::Tree,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "In-order traversal in constant extra space: temporarily threads each predecessor's right pointer back to the current node.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Tree_traversal#Morris_in-order_traversal_using_threading".to_string(),
+            examples: vec![
+                "while nodes[pre].right.is_some() && nodes[pre].right != Some(current) { pre = nodes[pre].right.unwrap(); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["morris"], &[]),
+                Fingerprint::new(&["predecessor", "current", "right"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "In-Order Traversal"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Trie Search".to_string(),
+            category: AlgorithmCategory::Tree,
+            complexity: "O(m)".to_string(),
+            bounds: time("O(1)", "O(m)", "O(m)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Walks a prefix tree one character at a time; lookups and inserts cost the key length, independent of how many keys are stored.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Trie".to_string(),
+            examples: vec![
+                "node = node.children.entry(c).or_default(); } node.is_end = true;".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["trie"], &[Loop]),
+                Fingerprint::new(&["children", "is_end"], &[Loop]),
+                Fingerprint::new(&["children", "is_word"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::Uses, "Hash Table"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Binary Search Tree"),
             ],
         },
 
@@ -227,11 +3342,488 @@ pub fn get_algorithms_database() -> Vec<AlgorithmInfo> {
             name: "Hash Table".to_string(),
             category: AlgorithmCategory::Hashing,
             complexity: "O(1)".to_string(),
+            bounds: time("O(1)", "O(1)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
             description: "A data structure that implements an associative array, a structure that can map keys to values using a hash function to compute an index into an array of buckets or slots.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Hash_table".to_string(),
             examples: vec![
                 "struct HashTable { buckets: Vec<Vec<(K, V)>> } impl HashTable { fn insert(&mut self, key: K, value: V) { /* hash function */ } }".to_string(),
             ],
+            fingerprints: vec![
+                Fingerprint::new(&["buckets", "hash"], &[Loop]),
+                Fingerprint::new(&["hash", "table"], &[]),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::Uses, "Hash Table"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "FNV-1a Hash".to_string(),
+            category: AlgorithmCategory::Hashing,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A fast non-cryptographic hash: for every byte, XOR it into the state and multiply by the FNV prime.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function".to_string(),
+            examples: vec![
+                "hash ^= byte as u64; hash = hash.wrapping_mul(0x100000001b3);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["fnv"], &[]),
+                Fingerprint::new(&["0xcbf29ce484222325"], &[]),
+                Fingerprint::new(&["0x811c9dc5"], &[]),
+                Fingerprint::new(&["0x100000001b3"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "djb2 Hash"),
+                Relation::algorithm(RelationKind::AlternativeTo, "MurmurHash3"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "djb2 Hash".to_string(),
+            category: AlgorithmCategory::Hashing,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Bernstein's string hash: start at 5381 and, for every byte, multiply by 33 and add the byte.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/List_of_hash_functions".to_string(),
+            examples: vec![
+                "hash = ((hash << 5).wrapping_add(hash)).wrapping_add(c as u64);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["djb2"], &[]),
+                Fingerprint::new(&["5381", "<<5"], &[]),
+                Fingerprint::new(&["5381", "33"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "FNV-1a Hash"),
+                Relation::algorithm(RelationKind::VariantOf, "Polynomial Rolling Hash"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "MurmurHash3".to_string(),
+            category: AlgorithmCategory::Hashing,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A non-cryptographic hash that mixes 4-byte blocks with multiply-rotate-multiply rounds and finishes with an avalanche step.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/MurmurHash".to_string(),
+            examples: vec![
+                "k = k.wrapping_mul(C1); k = k.rotate_left(15); k = k.wrapping_mul(C2);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["murmur"], &[]),
+                Fingerprint::new(&["murmur3"], &[]),
+                Fingerprint::new(&["0xcc9e2d51"], &[]),
+                Fingerprint::new(&["fmix32"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "FNV-1a Hash"),
+                Relation::algorithm(RelationKind::Uses, "Bloom Filter"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "CRC-32".to_string(),
+            category: AlgorithmCategory::Hashing,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A cyclic redundancy check: polynomial division over GF(2) that detects accidental changes to data, computed bitwise or from a 256-entry table.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Cyclic_redundancy_check".to_string(),
+            examples: vec![
+                "crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB88320 } else { crc >> 1 };".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["crc32"], &[]),
+                Fingerprint::new(&["0xedb88320"], &[]),
+                Fingerprint::new(&["crc", "polynomial"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Adler-32"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Adler-32".to_string(),
+            category: AlgorithmCategory::Hashing,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A checksum of two running sums modulo 65521, faster but weaker than CRC-32; used by zlib.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Adler-32".to_string(),
+            examples: vec![
+                "a = (a + byte as u32) % MOD_ADLER; b = (b + a) % MOD_ADLER;".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["adler"], &[]),
+                Fingerprint::new(&["adler32"], &[]),
+                Fingerprint::new(&["65521"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "CRC-32"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Polynomial Rolling Hash".to_string(),
+            category: AlgorithmCategory::Hashing,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Hashes a string as a polynomial in a fixed base modulo a prime; prefix hashes give any substring's hash in O(1).".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Rolling_hash".to_string(),
+            examples: vec![
+                "prefix[i + 1] = (prefix[i] * BASE + s[i] as u64) % MODULUS;".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["rolling", "hash"], &[]),
+                Fingerprint::new(&["prefix_hash"], &[Loop]),
+                Fingerprint::new(&["prefix", "base", "modulus", "pow"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Modular Exponentiation"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Consistent Hashing".to_string(),
+            category: AlgorithmCategory::Hashing,
+            complexity: "O(log n)".to_string(),
+            bounds: time("O(log n)", "O(log n)", "O(log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Places nodes and keys on a hash ring and assigns each key to the next node clockwise, so adding or removing a node moves only its neighbors' keys.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Consistent_hashing".to_string(),
+            examples: vec![
+                "self.ring.range(hash..).next().or_else(|| self.ring.iter().next()).map(|(_, node)| node)".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["consistent", "hashing"], &[]),
+                Fingerprint::new(&["ring", "BTreeMap", ".range("], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Binary Search"),
+                Relation::algorithm(RelationKind::Uses, "FNV-1a Hash"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Cuckoo Hashing".to_string(),
+            category: AlgorithmCategory::Hashing,
+            complexity: "O(1)".to_string(),
+            bounds: time("O(1)", "O(1)", "O(n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Open addressing with two hash functions and two tables: an insert evicts whatever occupies its slot, which moves to its other table; lookups probe at most two slots.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Cuckoo_hashing".to_string(),
+            examples: vec![
+                "let evicted = std::mem::replace(&mut self.tables[t][slot], Some(key));".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["cuckoo"], &[]),
+                Fingerprint::new(&["evicted", "tables"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Linear Probing"),
+                Relation::algorithm(RelationKind::VariantOf, "Hash Table"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Linear Probing".to_string(),
+            category: AlgorithmCategory::Hashing,
+            complexity: "O(1)".to_string(),
+            bounds: time("O(1)", "O(1)", "O(n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Open addressing that resolves a collision by trying the following slots in order until a free one is found.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Linear_probing".to_string(),
+            examples: vec![
+                "while let Some((k, _)) = &self.slots[index] { if *k == key { break; } index = (index + 1) % self.slots.len(); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["linear", "probing"], &[]),
+                Fingerprint::new(&["slots", "+1)%"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::VariantOf, "Hash Table"),
+                Relation::algorithm(RelationKind::AlternativeTo, "Cuckoo Hashing"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Bloom Filter".to_string(),
+            category: AlgorithmCategory::Hashing,
+            complexity: "O(k)".to_string(),
+            bounds: time("O(k)", "O(k)", "O(k)", "O(m)"),
+            stable: None,
+            in_place: None,
+            description: "A bit array set by k hash functions that answers membership with no false negatives and a tunable false-positive rate.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Bloom_filter".to_string(),
+            examples: vec![
+                "(0..self.hashes).all(|i| self.bits[self.index(item, i)])".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["bloom"], &[]),
+                Fingerprint::new(&["might_contain"], &[]),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::AlternativeTo, "HashSet"),
+                Relation::algorithm(RelationKind::Uses, "FNV-1a Hash"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Zobrist Hashing".to_string(),
+            category: AlgorithmCategory::Hashing,
+            complexity: "O(1)".to_string(),
+            bounds: time("O(1)", "O(1)", "O(1)", "O(p*s)"),
+            stable: None,
+            in_place: None,
+            description: "Hashes a board position as the XOR of a random key per (piece, square); a move updates the hash with two XORs.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Zobrist_hashing".to_string(),
+            examples: vec![
+                "hash ^= keys[piece][from]; hash ^= keys[piece][to];".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["zobrist"], &[]),
+                Fingerprint::new(&["piece", "square", "^="], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Linear Congruential Generator"),
+                Relation::algorithm(RelationKind::Uses, "Hash Table"),
+            ],
+        },
+
+        // Other Algorithms
+        AlgorithmInfo {
+            name: "Union-Find".to_string(),
+            category: AlgorithmCategory::Other,
+            complexity: "O(α(n))".to_string(),
+            bounds: time("O(1)", "O(1)", "O(log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "A disjoint-set forest with path compression and union by rank; near-constant amortized time per operation.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Disjoint-set_data_structure".to_string(),
+            examples: vec![
+                "if self.parent[x] != x { self.parent[x] = self.find(self.parent[x]); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["union", "find", "parent"], &[]),
+                Fingerprint::new(&["dsu"], &[]),
+                Fingerprint::new(&["disjoint", "set"], &[]),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::Uses, "Array"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Floyd's Cycle Detection".to_string(),
+            category: AlgorithmCategory::Other,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(1)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "The tortoise and hare: one pointer moves one step and another two; they meet inside a cycle if there is one.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Cycle_detection#Floyd's_tortoise_and_hare".to_string(),
+            examples: vec![
+                "slow = next(slow); fast = next(next(fast)); if slow == fast { return true; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["tortoise", "hare"], &[]),
+                Fingerprint::new(&["slow", "fast"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Graph Cycle Detection"),
+                Relation::data_structure(RelationKind::Uses, "Linked List"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Reservoir Sampling".to_string(),
+            category: AlgorithmCategory::Other,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(k)"),
+            stable: None,
+            in_place: None,
+            description: "Keeps a uniform random sample of k items from a stream of unknown length: the i-th item replaces a random slot with probability k/i.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Reservoir_sampling".to_string(),
+            examples: vec![
+                "let j = rng.gen_range(0..=i); if j < k { reservoir[j] = item; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["reservoir"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Fisher-Yates Shuffle"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Fisher-Yates Shuffle".to_string(),
+            category: AlgorithmCategory::Other,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Shuffles in place uniformly: walk from the end and swap each element with a random one at or before it.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle".to_string(),
+            examples: vec![
+                "for i in (1..v.len()).rev() { let j = rng.gen_range(0..=i); v.swap(i, j); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["fisher", "yates"], &[]),
+                Fingerprint::new(&["gen_range(0..=i)", ".swap(i,j)"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Linear Congruential Generator"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Boyer-Moore Majority Vote".to_string(),
+            category: AlgorithmCategory::Other,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Finds the majority element, if any, in one pass and constant space by pairing off each candidate vote against a different value.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Boyer%E2%80%93Moore_majority_vote_algorithm".to_string(),
+            examples: vec![
+                "if count == 0 { candidate = x; } count = if x == candidate { count + 1 } else { count - 1 };".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["majority"], &[Loop]),
+                Fingerprint::new(&["candidate", "count", "count==0"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::AlternativeTo, "Hash Table"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Gale-Shapley Algorithm".to_string(),
+            category: AlgorithmCategory::Other,
+            complexity: "O(n²)".to_string(),
+            bounds: time("O(n)", "O(n^2)", "O(n^2)", "O(n^2)"),
+            stable: None,
+            in_place: None,
+            description: "Finds a stable matching: free proposers propose in preference order and each receiver keeps the best offer so far.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Gale%E2%80%93Shapley_algorithm".to_string(),
+            examples: vec![
+                "if rank[w][m] < rank[w][current] { engaged[w] = Some(m); free.push(current); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["gale"], &[]),
+                Fingerprint::new(&["shapley"], &[]),
+                Fingerprint::new(&["engaged", "free", "preferences"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Hopcroft-Karp Algorithm"),
+                Relation::data_structure(RelationKind::Uses, "Queue"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "LRU Cache".to_string(),
+            category: AlgorithmCategory::Other,
+            complexity: "O(1)".to_string(),
+            bounds: time("O(1)", "O(1)", "O(1)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "A bounded cache that evicts the least recently used entry, pairing a hash map with a recency list.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Cache_replacement_policies#Least_recently_used_(LRU)".to_string(),
+            examples: vec![
+                "if self.map.len() > self.capacity { if let Some(oldest) = self.order.pop_front() { self.map.remove(&oldest); } }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["lru"], &[]),
+                Fingerprint::new(&["least", "recently", "used"], &[]),
+                Fingerprint::new(&["capacity", "evict", "recent"], &[]),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::Uses, "Hash Table"),
+                Relation::data_structure(RelationKind::Uses, "Linked List"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Sliding Window Maximum".to_string(),
+            category: AlgorithmCategory::Other,
+            complexity: "O(n)".to_string(),
+            bounds: time("O(n)", "O(n)", "O(n)", "O(k)"),
+            stable: None,
+            in_place: None,
+            description: "The maximum of every window of size k in one pass, keeping a deque of indices whose values decrease.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Sliding_window_protocol".to_string(),
+            examples: vec![
+                "while window.back().is_some_and(|&j| nums[j] <= nums[i]) { window.pop_back(); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["sliding", "window", "max"], &[Loop]),
+                Fingerprint::new(&["window", "VecDeque", "pop_back", "pop_front"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::Uses, "Queue"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Kernighan's Bit Counting".to_string(),
+            category: AlgorithmCategory::Other,
+            complexity: "O(k)".to_string(),
+            bounds: time("O(1)", "O(log n)", "O(log n)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "Counts set bits by repeatedly clearing the lowest one with n & (n - 1); takes as many steps as there are set bits.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Hamming_weight".to_string(),
+            examples: vec![
+                "while n != 0 { n &= n - 1; count += 1; }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["n&=n-1"], &[Loop]),
+                Fingerprint::new(&["n&(n-1)"], &[Loop]),
+                Fingerprint::new(&["x&=x-1"], &[Loop]),
+                Fingerprint::new(&["x&(x-1)"], &[Loop]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Fenwick Tree"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Linear Congruential Generator".to_string(),
+            category: AlgorithmCategory::Other,
+            complexity: "O(1)".to_string(),
+            bounds: time("O(1)", "O(1)", "O(1)", "O(1)"),
+            stable: None,
+            in_place: None,
+            description: "A pseudorandom generator with the recurrence x ← (a·x + c) mod m; fast, but not for cryptography.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Linear_congruential_generator".to_string(),
+            examples: vec![
+                "self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["lcg"], &[]),
+                Fingerprint::new(&["6364136223846793005"], &[]),
+                Fingerprint::new(&["1103515245"], &[]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Fisher-Yates Shuffle"),
+            ],
+        },
+        AlgorithmInfo {
+            name: "Graham Scan".to_string(),
+            category: AlgorithmCategory::Other,
+            complexity: "O(n log n)".to_string(),
+            bounds: time("O(n log n)", "O(n log n)", "O(n log n)", "O(n)"),
+            stable: None,
+            in_place: None,
+            description: "Builds the convex hull by sorting points and keeping a stack that pops any point making a clockwise turn; the monotone chain form sorts by x.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Graham_scan".to_string(),
+            examples: vec![
+                "while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0 { hull.pop(); }".to_string(),
+            ],
+            fingerprints: vec![
+                Fingerprint::new(&["graham"], &[]),
+                Fingerprint::new(&["hull", "cross", "sort"], &[NestedLoops]),
+            ],
+            relations: vec![
+                Relation::algorithm(RelationKind::AlternativeTo, "Quickhull"),
+                Relation::data_structure(RelationKind::Uses, "Stack"),
+            ],
         },
     ]
-}
\ No newline at end of file
+}
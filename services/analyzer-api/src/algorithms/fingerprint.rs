@@ -0,0 +1,236 @@
+//! Structural fingerprints of catalog algorithms
+//!
+//! A fingerprint lists what an implementation can hardly do without: the
+//! identifiers it names (`pivot`, `indegree`), code fragments it contains
+//! (`[i][k]+`) and the shape of its control flow (nested loops,
+//! recursion). Code is reduced to a [`CodeShape`] once per analysis, with
+//! comments and literals blanked out, and every fingerprint is checked
+//! against that shape.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use utoipa::ToSchema;
+
+use crate::functions::{split_functions, strip_non_code};
+
+/// Control-flow shapes a fingerprint can require
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Feature {
+    /// A `for`, `while` or `loop`
+    Loop,
+    /// A loop inside a loop
+    NestedLoops,
+    /// Three loops deep
+    TripleNestedLoops,
+    /// A function that calls itself
+    Recursion,
+}
+
+/// Tokens and features that must all be present
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub struct Fingerprint {
+    /// Identifier words, matched case-insensitively against whole identifiers
+    /// and their snake_case or camelCase parts; anything else is a code
+    /// fragment, matched with whitespace removed
+    #[serde(default)]
+    pub tokens: Vec<String>,
+    #[serde(default)]
+    pub features: Vec<Feature>,
+}
+
+impl Fingerprint {
+    pub fn new(tokens: &[&str], features: &[Feature]) -> Self {
+        Fingerprint {
+            tokens: tokens.iter().map(|token| token.to_string()).collect(),
+            features: features.to_vec(),
+        }
+    }
+}
+
+/// What the fingerprints of every catalog entry are checked against
+#[derive(Debug, Clone, Default)]
+pub struct CodeShape {
+    words: HashSet<String>,
+    /// Code without comments, literals or whitespace
+    compact: String,
+    loop_depth: usize,
+    recursive: bool,
+}
+
+impl CodeShape {
+    pub fn of(code: &str) -> Self {
+        let stripped = strip_non_code(code);
+        CodeShape {
+            words: words(&stripped),
+            compact: stripped.chars().filter(|c| !c.is_whitespace()).collect(),
+            loop_depth: loop_depth(&stripped),
+            recursive: is_recursive(&stripped),
+        }
+    }
+
+    pub fn has(&self, feature: Feature) -> bool {
+        match feature {
+            Feature::Loop => self.loop_depth >= 1,
+            Feature::NestedLoops => self.loop_depth >= 2,
+            Feature::TripleNestedLoops => self.loop_depth >= 3,
+            Feature::Recursion => self.recursive,
+        }
+    }
+
+    pub fn matches(&self, fingerprint: &Fingerprint) -> bool {
+        if fingerprint.tokens.is_empty() && fingerprint.features.is_empty() {
+            return false;
+        }
+        fingerprint.features.iter().all(|&feature| self.has(feature))
+            && fingerprint.tokens.iter().all(|token| self.contains(token))
+    }
+
+    fn contains(&self, token: &str) -> bool {
+        if !token.is_empty() && token.bytes().all(is_ident_byte) {
+            self.words.contains(&token.to_lowercase())
+        } else {
+            let fragment: String = token.chars().filter(|c| !c.is_whitespace()).collect();
+            self.compact.contains(&fragment)
+        }
+    }
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+fn identifiers(code: &str) -> impl Iterator<Item = (usize, &str)> {
+    let bytes = code.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() && !is_ident_byte(bytes[i]) {
+            i += 1;
+        }
+        if i == bytes.len() {
+            return None;
+        }
+        let start = i;
+        while i < bytes.len() && is_ident_byte(bytes[i]) {
+            i += 1;
+        }
+        Some((start, &code[start..i]))
+    })
+}
+
+/// Every identifier in lowercase, plus its snake_case and camelCase parts
+fn words(code: &str) -> HashSet<String> {
+    let mut words = HashSet::new();
+    for (_, ident) in identifiers(code) {
+        words.insert(ident.to_lowercase());
+        for part in ident.split('_').filter(|part| !part.is_empty()) {
+            let mut current = String::new();
+            let mut previous_lower = false;
+            for c in part.chars() {
+                if c.is_ascii_uppercase() && previous_lower {
+                    words.insert(std::mem::take(&mut current));
+                }
+                previous_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+                current.push(c.to_ascii_lowercase());
+            }
+            words.insert(current);
+        }
+    }
+    words
+}
+
+/// How deep loops nest, judged by which braces open a loop body
+fn loop_depth(code: &str) -> usize {
+    let bytes = code.as_bytes();
+    let mut open: Vec<bool> = Vec::new();
+    let (mut loop_pending, mut impl_pending) = (false, false);
+    let mut deepest = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'{' => {
+                open.push(loop_pending);
+                loop_pending = false;
+                impl_pending = false;
+                deepest = deepest.max(open.iter().filter(|&&is_loop| is_loop).count());
+            }
+            b'}' => {
+                open.pop();
+            }
+            b';' => loop_pending = false,
+            b if is_ident_byte(b) => {
+                let start = i;
+                while i < bytes.len() && is_ident_byte(bytes[i]) {
+                    i += 1;
+                }
+                match &code[start..i] {
+                    // `impl Trait for Type` is not a loop
+                    "impl" => impl_pending = true,
+                    "for" if impl_pending => {}
+                    "for" | "while" | "loop" => loop_pending = true,
+                    _ => {}
+                }
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    deepest
+}
+
+/// Whether a function calls itself directly, by name, `self.name(` or `Self::name(`
+fn is_recursive(code: &str) -> bool {
+    split_functions(code).iter().any(|function| {
+        let body = &code[function.span.clone()];
+        let Some(definition) = body.find(function.name.as_str()) else {
+            return false;
+        };
+        let after_name = definition + function.name.len();
+        identifiers(body).any(|(start, ident)| {
+            if start < after_name || ident != function.name {
+                return false;
+            }
+            let before = body[..start].trim_end();
+            let receiver_ok = if let Some(rest) = before.strip_suffix('.') {
+                rest.trim_end().ends_with("self") && !rest.trim_end().ends_with(".self")
+            } else if let Some(rest) = before.strip_suffix("::") {
+                rest.trim_end().ends_with("Self")
+            } else {
+                !before.ends_with("fn")
+            };
+            let rest = body[start + ident.len()..].trim_start();
+            receiver_ok && (rest.starts_with('(') || rest.starts_with("::<"))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_shape() {
+        let code = r#"
+        // quickSort would go here
+        impl Ord for Item { fn cmp(&self) -> Ordering { todo!() } }
+        fn floyd(dist: &mut Vec<Vec<u64>>) {
+            for k in 0..n { for i in 0..n { for j in 0..n {
+                dist[i][j] = dist[i][j].min(dist[i][k] + dist[k][j]);
+            } } }
+        }
+        fn gcdOf(a: u64, b: u64) -> u64 { if b == 0 { a } else { gcdOf(b, a % b) } }
+        "#;
+        let shape = CodeShape::of(code);
+        assert!(shape.has(Feature::TripleNestedLoops) && shape.has(Feature::Recursion));
+        assert!(shape.matches(&Fingerprint::new(&["GCD", "of", "[i][k] + "], &[Feature::Loop])));
+        assert!(!shape.matches(&Fingerprint::new(&["quick"], &[])));
+        assert!(!shape.matches(&Fingerprint::new(&[], &[])));
+
+        let not_recursive = CodeShape::of("impl Set for Tree { fn insert(&mut self) { self.map.insert(1); } }");
+        assert!(!not_recursive.has(Feature::Recursion) && !not_recursive.has(Feature::Loop));
+        assert!(CodeShape::of("fn walk(&self) { self.walk(); }").has(Feature::Recursion));
+    }
+}
@@ -0,0 +1,567 @@
+//! Performance smells: constructs that make code slower than it needs to be
+//!
+//! | Kind | Severity | Flagged |
+//! |------|----------|---------|
+//! | `nested_loop_comparison` | high | a `for` loop inside another over the same collection, comparing with `==` or `!=` |
+//! | `clone_in_loop` | high | `clone()` of a collection inside a loop |
+//! | `string_concat_in_loop` | medium | `+` with a string operand inside a loop |
+//! | `case_conversion_in_loop` | medium | `to_lowercase()` and the like, inside a loop, of a value the loop does not change |
+//! | `collect_then_iterate` | low | a `collect()` iterated right away and not used again |
+//!
+//! As in [`collections`](crate::data_structures::collections), loops are
+//! `for`, `while` and `loop` bodies and closures passed to per-element
+//! methods such as `filter`. A value counts as unchanged by a loop when the
+//! loop neither binds, assigns, mutably borrows nor calls a method that may
+//! mutate it.
+
+use analyzer_types::v2::{AntiPattern, AntiPatternKind, CollectionUsage, Severity, SourceSpan};
+use proc_macro2::{TokenStream, TokenTree};
+use std::collections::HashSet;
+use std::ops::Range;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{BinOp, Expr, ExprMethodCall, Lit, Pat, Stmt};
+
+use crate::data_structures::collections::{binding_name, PER_ELEMENT};
+use crate::data_structures::shapes::source_span;
+use crate::syntax::Syntax;
+
+/// Methods that walk the collection they are called on
+const WALKS: [&str; 12] = [
+    "iter", "iter_mut", "into_iter", "enumerate", "skip", "take", "chars", "bytes", "keys", "values", "copied", "cloned",
+];
+
+/// Methods that only read their receiver
+const READ_ONLY: [&str; 24] = [
+    "len", "is_empty", "iter", "chars", "bytes", "as_str", "as_ref", "get", "contains", "contains_key", "starts_with",
+    "ends_with", "first", "last", "trim", "clone", "to_string", "to_owned", "to_lowercase", "to_uppercase",
+    "to_ascii_lowercase", "to_ascii_uppercase", "eq", "cmp",
+];
+
+const CASE_CONVERSIONS: [&str; 4] = ["to_lowercase", "to_uppercase", "to_ascii_lowercase", "to_ascii_uppercase"];
+
+/// The anti-patterns of the code, in source order. `syntax` is the code
+/// parsed and `collections` its
+/// [`collection_usage`](crate::data_structures::collections::collection_usage),
+/// which tells collections apart from other cloned values.
+pub fn anti_patterns(code: &str, syntax: &Syntax, collections: &[CollectionUsage]) -> Vec<AntiPattern> {
+    let mut smells = Smells {
+        code,
+        base: 0,
+        prefix: 0,
+        loops: Vec::new(),
+        walking: Vec::new(),
+        found: Vec::new(),
+    };
+
+    match syntax {
+        Syntax::File(file) => smells.visit_file(file),
+        Syntax::Block(block) => {
+            smells.prefix = 1;
+            smells.visit_block(block);
+        }
+        Syntax::Functions(functions) => {
+            for (start, item) in functions {
+                smells.base = *start;
+                smells.visit_item_fn(item);
+            }
+        }
+    }
+
+    let mut found = smells.found;
+
+    for usage in collections {
+        let target = usage.binding.as_deref().unwrap_or(&usage.collection);
+        for operation in usage.operations.iter().filter(|op| op.in_loop && op.operation == "clone") {
+            found.push(AntiPattern {
+                kind: AntiPatternKind::CloneInLoop,
+                severity: Severity::High,
+                span: operation.span,
+                message: format!(
+                    "`{}.clone()` copies the whole {} on every iteration, {} each time; borrow it, or clone it once before the loop",
+                    target, usage.collection, operation.cost
+                ),
+            });
+        }
+    }
+
+    found.sort_by_key(|anti_pattern| (anti_pattern.span.start_line, anti_pattern.span.start_column));
+    found
+}
+
+struct Smells<'a> {
+    code: &'a str,
+    /// Offset in `code` of the text being visited
+    base: usize,
+    /// Bytes added in front of that text so it parses
+    prefix: usize,
+    /// Per enclosing loop, innermost last, the names it may change
+    loops: Vec<HashSet<String>>,
+    /// Per enclosing `for` loop, the collection it walks
+    walking: Vec<Option<String>>,
+    found: Vec<AntiPattern>,
+}
+
+impl Smells<'_> {
+    fn span(&self, range: Range<usize>) -> SourceSpan {
+        let at = |offset: usize| (self.base + offset).saturating_sub(self.prefix).min(self.code.len());
+        let start = at(range.start);
+        source_span(self.code, start..at(range.end).max(start + 1).min(self.code.len()))
+    }
+
+    fn report(&mut self, kind: AntiPatternKind, severity: Severity, range: Range<usize>, message: String) {
+        let span = self.span(range);
+        self.found.push(AntiPattern { kind, severity, span, message });
+    }
+
+    /// Run `visit` as the body of a loop that may change `changed`
+    fn in_loop(&mut self, changed: HashSet<String>, visit: impl FnOnce(&mut Self)) {
+        self.loops.push(changed);
+        visit(self);
+        self.loops.pop();
+    }
+
+    /// Run `visit` outside of any loop, for a nested function
+    fn in_function(&mut self, visit: impl FnOnce(&mut Self)) {
+        let loops = std::mem::take(&mut self.loops);
+        let walking = std::mem::take(&mut self.walking);
+        visit(self);
+        self.loops = loops;
+        self.walking = walking;
+    }
+
+    /// `for pat in <collect()>`, or `<collect()>.iter()`
+    fn check_collect(&mut self, iterated: &Expr, end: usize) {
+        if let Expr::MethodCall(call) = strip(iterated) {
+            if call.method == "collect" {
+                self.report(
+                    AntiPatternKind::CollectThenIterate,
+                    Severity::Low,
+                    call.method.span().byte_range().start..end,
+                    "`collect()` builds a collection only to iterate it; keep iterating the iterator instead".to_string(),
+                );
+            }
+        }
+    }
+
+    /// `let name = <collect()>;` directly followed by a `for` loop over
+    /// `name` that is the only use of it
+    fn check_collect_binding(&mut self, stmts: &[Stmt]) {
+        for (index, pair) in stmts.windows(2).enumerate() {
+            let (Stmt::Local(local), Stmt::Expr(Expr::ForLoop(for_loop), _)) = (&pair[0], &pair[1]) else {
+                continue;
+            };
+            let Some(init) = &local.init else {
+                continue;
+            };
+            let (Expr::MethodCall(collect), Some(name)) = (&*init.expr, pattern_name(&local.pat)) else {
+                continue;
+            };
+            if collect.method != "collect" || walked(&for_loop.expr).as_deref() != Some(name.as_str()) {
+                continue;
+            }
+            let mut mentions = Mentions { name: &name, count: 0 };
+            mentions.visit_block(&for_loop.body);
+            for stmt in &stmts[index + 2..] {
+                mentions.visit_stmt(stmt);
+            }
+            if mentions.count == 0 {
+                self.report(
+                    AntiPatternKind::CollectThenIterate,
+                    Severity::Low,
+                    collect.method.span().byte_range().start..collect.span().byte_range().end,
+                    format!("`{}` is collected only to be iterated once; loop over the iterator instead", name),
+                );
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Smells<'_> {
+    fn visit_item_fn(&mut self, item: &'ast syn::ItemFn) {
+        self.in_function(|smells| visit::visit_item_fn(smells, item));
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast syn::ImplItemFn) {
+        self.in_function(|smells| visit::visit_impl_item_fn(smells, item));
+    }
+
+    fn visit_block(&mut self, block: &'ast syn::Block) {
+        self.check_collect_binding(&block.stmts);
+        visit::visit_block(self, block);
+    }
+
+    fn visit_expr_for_loop(&mut self, expr: &'ast syn::ExprForLoop) {
+        // The iterated expression is evaluated once
+        self.visit_expr(&expr.expr);
+        self.check_collect(&expr.expr, expr.expr.span().byte_range().end);
+
+        let collection = walked(&expr.expr);
+        if let Some(name) = &collection {
+            let mut compares = Compares(false);
+            compares.visit_block(&expr.body);
+            if compares.0 && self.walking.iter().any(|outer| outer.as_ref() == Some(name)) {
+                self.report(
+                    AntiPatternKind::NestedLoopComparison,
+                    Severity::High,
+                    expr.for_token.span.byte_range().start..expr.expr.span().byte_range().end,
+                    format!(
+                        "nested loops both walk `{}` and compare its elements, O(n²); a HashSet or HashMap built in one pass does it in O(n)",
+                        name
+                    ),
+                );
+            }
+        }
+
+        let mut changed = Changed::default();
+        changed.visit_pat(&expr.pat);
+        changed.visit_block(&expr.body);
+        self.walking.push(collection);
+        self.in_loop(changed.0, |smells| {
+            smells.visit_pat(&expr.pat);
+            smells.visit_block(&expr.body);
+        });
+        self.walking.pop();
+    }
+
+    fn visit_expr_while(&mut self, expr: &'ast syn::ExprWhile) {
+        let mut changed = Changed::default();
+        changed.visit_expr_while(expr);
+        self.in_loop(changed.0, |smells| visit::visit_expr_while(smells, expr));
+    }
+
+    fn visit_expr_loop(&mut self, expr: &'ast syn::ExprLoop) {
+        let mut changed = Changed::default();
+        changed.visit_expr_loop(expr);
+        self.in_loop(changed.0, |smells| visit::visit_expr_loop(smells, expr));
+    }
+
+    fn visit_expr_binary(&mut self, expr: &'ast syn::ExprBinary) {
+        let operands = concatenated(expr);
+        let concatenates = matches!(expr.op, BinOp::Add(_)) && !self.loops.is_empty();
+        if concatenates && operands.iter().enumerate().any(|(index, operand)| is_string(operand, index)) {
+            self.report(
+                AntiPatternKind::StringConcatInLoop,
+                Severity::Medium,
+                expr.span().byte_range(),
+                "string `+` in a loop allocates and copies on every iteration; `push_str` into one String made before the loop, or `concat`/`join` the parts once".to_string(),
+            );
+            // Only the outermost `+` of a chain is reported
+            for operand in operands {
+                self.visit_expr(operand);
+            }
+        } else {
+            visit::visit_expr_binary(self, expr);
+        }
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        if ["iter", "iter_mut", "into_iter", "for_each"].iter().any(|name| call.method == name) {
+            self.check_collect(&call.receiver, call.span().byte_range().end);
+        }
+
+        let converts_case = CASE_CONVERSIONS.iter().any(|name| call.method == name);
+        if let (true, Some(changed)) = (converts_case, self.loops.last()) {
+            let invariant = read_names(&call.receiver).is_some_and(|names| names.iter().all(|name| !changed.contains(name)));
+            if invariant {
+                self.report(
+                    AntiPatternKind::CaseConversionInLoop,
+                    Severity::Medium,
+                    call.receiver.span().byte_range().start..call.span().byte_range().end,
+                    format!("`{}()` converts the same value on every iteration; convert it once before the loop", call.method),
+                );
+            }
+        }
+
+        self.visit_expr(&call.receiver);
+        let per_element = PER_ELEMENT.iter().any(|name| call.method == name);
+        for arg in &call.args {
+            match arg {
+                Expr::Closure(closure) if per_element => {
+                    let mut changed = Changed::default();
+                    changed.visit_expr_closure(closure);
+                    self.in_loop(changed.0, |smells| smells.visit_expr(arg));
+                }
+                _ => self.visit_expr(arg),
+            }
+        }
+    }
+}
+
+/// The collection a `for` loop walks: `v` for `&v`, `v.iter().enumerate()`
+/// or `0..v.len()`
+fn walked(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Reference(reference) => walked(&reference.expr),
+        Expr::Paren(paren) => walked(&paren.expr),
+        Expr::MethodCall(call) if WALKS.iter().any(|name| call.method == name) => walked(&call.receiver),
+        Expr::Range(range) => match range.end.as_deref()? {
+            Expr::MethodCall(call) if call.method == "len" => walked(&call.receiver),
+            _ => None,
+        },
+        _ => binding_name(expr),
+    }
+}
+
+fn strip(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Reference(reference) => strip(&reference.expr),
+        Expr::Paren(paren) => strip(&paren.expr),
+        _ => expr,
+    }
+}
+
+/// The operands of a chain of `+`, left to right
+fn concatenated(binary: &syn::ExprBinary) -> Vec<&Expr> {
+    let mut operands = match &*binary.left {
+        Expr::Binary(left) if matches!(left.op, BinOp::Add(_)) => concatenated(left),
+        left => vec![left],
+    };
+    operands.push(&binary.right);
+    operands
+}
+
+/// Whether an operand of `+` is visibly a string: a literal, a conversion
+/// to `String`, or a borrow on the right-hand side as in `s + &t`
+fn is_string(operand: &Expr, index: usize) -> bool {
+    match operand {
+        Expr::Lit(literal) => matches!(literal.lit, Lit::Str(_)),
+        Expr::MethodCall(call) => ["to_string", "to_owned", "to_lowercase", "to_uppercase"].iter().any(|name| call.method == name),
+        Expr::Macro(macro_call) => macro_call.mac.path.is_ident("format"),
+        Expr::Call(call) => matches!(&*call.func, Expr::Path(path) if path.path.segments.len() == 2 && path.path.segments[0].ident == "String"),
+        Expr::Reference(_) => index > 0,
+        Expr::Paren(paren) => is_string(&paren.expr, index),
+        _ => false,
+    }
+}
+
+/// Names an expression reads, or `None` when it calls anything and so may
+/// differ between evaluations
+fn read_names(expr: &Expr) -> Option<Vec<String>> {
+    match expr {
+        Expr::Lit(_) => Some(Vec::new()),
+        Expr::Path(path) => Some(path.path.get_ident().map(|ident| ident.to_string()).into_iter().collect()),
+        Expr::Field(field) => match binding_name(expr) {
+            Some(name) => Some(vec![name]),
+            None => read_names(&field.base),
+        },
+        Expr::Reference(reference) => read_names(&reference.expr),
+        Expr::Paren(paren) => read_names(&paren.expr),
+        Expr::Unary(unary) => read_names(&unary.expr),
+        Expr::Index(index) => {
+            let mut names = read_names(&index.expr)?;
+            names.extend(read_names(&index.index)?);
+            Some(names)
+        }
+        _ => None,
+    }
+}
+
+/// The variable, or `self.field`, that an expression writes through
+fn root(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Field(field) => binding_name(expr).or_else(|| root(&field.base)),
+        Expr::Index(index) => root(&index.expr),
+        Expr::MethodCall(call) => root(&call.receiver),
+        Expr::Paren(paren) => root(&paren.expr),
+        Expr::Reference(reference) => root(&reference.expr),
+        Expr::Unary(unary) => root(&unary.expr),
+        _ => binding_name(expr),
+    }
+}
+
+fn pattern_name(pat: &Pat) -> Option<String> {
+    match pat {
+        Pat::Ident(ident) => Some(ident.ident.to_string()),
+        Pat::Type(typed) => pattern_name(&typed.pat),
+        _ => None,
+    }
+}
+
+/// Names a loop binds, assigns, mutably borrows or calls a possibly
+/// mutating method on
+#[derive(Default)]
+struct Changed(HashSet<String>);
+
+impl<'ast> Visit<'ast> for Changed {
+    fn visit_pat_ident(&mut self, pat: &'ast syn::PatIdent) {
+        self.0.insert(pat.ident.to_string());
+        visit::visit_pat_ident(self, pat);
+    }
+
+    fn visit_expr_assign(&mut self, assign: &'ast syn::ExprAssign) {
+        self.0.extend(root(&assign.left));
+        visit::visit_expr_assign(self, assign);
+    }
+
+    fn visit_expr_binary(&mut self, binary: &'ast syn::ExprBinary) {
+        let assigns = matches!(
+            binary.op,
+            BinOp::AddAssign(_)
+                | BinOp::SubAssign(_)
+                | BinOp::MulAssign(_)
+                | BinOp::DivAssign(_)
+                | BinOp::RemAssign(_)
+                | BinOp::BitXorAssign(_)
+                | BinOp::BitAndAssign(_)
+                | BinOp::BitOrAssign(_)
+                | BinOp::ShlAssign(_)
+                | BinOp::ShrAssign(_)
+        );
+        if assigns {
+            self.0.extend(root(&binary.left));
+        }
+        visit::visit_expr_binary(self, binary);
+    }
+
+    fn visit_expr_reference(&mut self, reference: &'ast syn::ExprReference) {
+        if reference.mutability.is_some() {
+            self.0.extend(root(&reference.expr));
+        }
+        visit::visit_expr_reference(self, reference);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        if !READ_ONLY.iter().any(|name| call.method == name) {
+            self.0.extend(root(&call.receiver));
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+}
+
+/// Whether a loop body compares with `==`, `!=` or `eq`
+struct Compares(bool);
+
+impl<'ast> Visit<'ast> for Compares {
+    fn visit_expr_binary(&mut self, binary: &'ast syn::ExprBinary) {
+        self.0 |= matches!(binary.op, BinOp::Eq(_) | BinOp::Ne(_));
+        visit::visit_expr_binary(self, binary);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        self.0 |= call.method == "eq" || call.method == "ne";
+        visit::visit_expr_method_call(self, call);
+    }
+}
+
+/// Uses of a name, including inside macro calls
+struct Mentions<'n> {
+    name: &'n str,
+    count: usize,
+}
+
+impl<'ast> Visit<'ast> for Mentions<'_> {
+    fn visit_path(&mut self, path: &'ast syn::Path) {
+        if path.is_ident(self.name) {
+            self.count += 1;
+        }
+        visit::visit_path(self, path);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        self.count += tokens_mention(mac.tokens.clone(), self.name);
+    }
+}
+
+fn tokens_mention(tokens: TokenStream, name: &str) -> usize {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(ident) => usize::from(ident == name),
+            TokenTree::Group(group) => tokens_mention(group.stream(), name),
+            _ => 0,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_structures::collections::collection_usage;
+
+    /// `line kind severity` per anti-pattern
+    fn summary(code: &str) -> Vec<String> {
+        let syntax = Syntax::parse(code);
+        anti_patterns(code, &syntax, &collection_usage(code, &syntax))
+            .into_iter()
+            .map(|found| format!("{} {} {}", found.span.start_line, found.kind.as_str(), found.severity.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_flags_each_kind() {
+        let code = r#"
+fn duplicates(items: &Vec<String>, stop_words: &[String], query: &str) -> String {
+    let mut report = String::new();
+    for (i, a) in items.iter().enumerate() {
+        for b in items.iter().skip(i + 1) {
+            if a == b {
+                report = report + "duplicate: " + b;
+            }
+        }
+        let snapshot = items.clone();
+        if stop_words.iter().any(|word| query.to_lowercase() == *word) {
+            continue;
+        }
+        if snapshot.is_empty() {
+            break;
+        }
+    }
+    let lengths: Vec<usize> = items.iter().map(|item| item.len()).collect();
+    for length in lengths {
+        report.push_str(&length.to_string());
+    }
+    for item in items.iter().cloned().collect::<Vec<_>>().iter() {
+        report.push_str(item);
+    }
+    report
+}
+"#;
+        assert_eq!(
+            summary(code),
+            [
+                "5 nested_loop_comparison high",
+                "7 string_concat_in_loop medium",
+                "10 clone_in_loop high",
+                "11 case_conversion_in_loop medium",
+                "18 collect_then_iterate low",
+                "22 collect_then_iterate low",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_values_changed_by_the_loop_are_left_alone() {
+        let code = r#"
+fn matches(entries: &[Entry], code_lower: &str) -> usize {
+    let mut found = Vec::new();
+    let mut line = String::new();
+    for entry in entries {
+        if code_lower.contains(&entry.name.to_lowercase()) {
+            found.push(entry.name.clone());
+        }
+        line.push('x');
+        let upper = line.to_uppercase();
+        let total = found.len() + 1;
+        report(upper, total);
+    }
+    let names: Vec<&str> = entries.iter().map(|entry| entry.name.as_str()).collect();
+    for name in &names {
+        report(name, 0);
+    }
+    println!("{:?}", names);
+    found.len()
+}
+"#;
+        assert!(summary(code).is_empty());
+    }
+
+    #[test]
+    fn test_deeply_nested_functions_are_skipped() {
+        let code = format!(
+            "fn deep() {{ let x = {}1; }}\nfn shallow(v: Vec<u8>) {{ for _ in 0..3 {{ let w = v.clone(); }} }}\n",
+            "-".repeat(5_000)
+        );
+        assert_eq!(summary(&code), ["2 clone_in_loop high"]);
+    }
+}
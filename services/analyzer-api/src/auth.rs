@@ -0,0 +1,434 @@
+//! API-key authentication and policy-based authorization
+//!
+//! Clients present a key either as `X-API-Key: <key>` or as
+//! `Authorization: Bearer <key>`. Keys are stored hashed in the `api_keys`
+//! table together with a role. Whether a role may perform an action is
+//! decided by the `advisor.authz` policy in `analysis/policies/opa`, either
+//! by a local OPA sidecar (`OPA_URL`) or in-process from the same role
+//! table the policy reads.
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{Payload, ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderMap, AUTHORIZATION};
+use actix_web::http::StatusCode;
+use actix_web::middleware::Next;
+use actix_web::{web, Error, FromRequest, HttpMessage, HttpRequest, HttpResponse, ResponseError};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::future::{ready, Ready};
+use std::sync::Arc;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::db::Database;
+use crate::models::error_response::ErrorResponse;
+
+/// Role table shared with the rego policy
+const POLICY_DATA: &str = include_str!("../../../analysis/policies/opa/data.json");
+
+/// Header carrying a raw API key
+pub const API_KEY_HEADER: &str = "X-API-Key";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Viewer,
+    Analyst,
+    /// Analyst who may also propose catalog changes
+    Curator,
+    Admin,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Analyst => "analyst",
+            Role::Curator => "curator",
+            Role::Admin => "admin",
+        }
+    }
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for Role {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "viewer" => Ok(Role::Viewer),
+            "analyst" => Ok(Role::Analyst),
+            "curator" => Ok(Role::Curator),
+            "admin" => Ok(Role::Admin),
+            other => Err(format!("Unknown role: {}", other)),
+        }
+    }
+}
+
+/// Operations guarded by the policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Analyze,
+    ReadHistory,
+    ManageKeys,
+    ReadUsage,
+    ReadCatalog,
+    /// Propose catalog changes and read their history
+    WriteCatalog,
+    /// Approve or reject proposed catalog changes
+    ReviewCatalog,
+}
+
+impl Action {
+    /// Name used for this action in the policy input and role table
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Action::Analyze => "analyze",
+            Action::ReadHistory => "history:read",
+            Action::ManageKeys => "keys:manage",
+            Action::ReadUsage => "usage:read",
+            Action::ReadCatalog => "catalog:read",
+            Action::WriteCatalog => "catalog:write",
+            Action::ReviewCatalog => "catalog:review",
+        }
+    }
+}
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The authenticated caller, stored in the request extensions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal {
+    /// `None` for anonymous callers
+    pub key_id: Option<Uuid>,
+    pub name: String,
+    pub role: Role,
+    /// Rate-limit plan, see [`crate::rate_limit::Plan`]
+    pub plan: String,
+}
+
+impl FromRequest for Principal {
+    type Error = AuthError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        ready(
+            req.extensions()
+                .get::<Principal>()
+                .cloned()
+                .ok_or(AuthError::MissingCredentials),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthError {
+    MissingCredentials,
+    InvalidCredentials,
+    Forbidden(Action),
+    PolicyUnavailable(String),
+}
+
+impl std::fmt::Display for AuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthError::MissingCredentials => write!(f, "Provide an API key via the X-API-Key header or a bearer token"),
+            AuthError::InvalidCredentials => write!(f, "The API key is unknown or has been revoked"),
+            AuthError::Forbidden(action) => write!(f, "Your role is not allowed to perform '{}'", action),
+            AuthError::PolicyUnavailable(e) => write!(f, "Authorization policy could not be evaluated: {}", e),
+        }
+    }
+}
+
+impl ResponseError for AuthError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            AuthError::MissingCredentials | AuthError::InvalidCredentials => StatusCode::UNAUTHORIZED,
+            AuthError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AuthError::PolicyUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let error = match self {
+            AuthError::MissingCredentials => "missing_credentials",
+            AuthError::InvalidCredentials => "invalid_credentials",
+            AuthError::Forbidden(_) => "forbidden",
+            AuthError::PolicyUnavailable(_) => "policy_unavailable",
+        };
+        let mut response = HttpResponse::build(self.status_code());
+        if self.status_code() == StatusCode::UNAUTHORIZED {
+            response.insert_header(("WWW-Authenticate", "Bearer"));
+        }
+        response.json(ErrorResponse::new(error, self.to_string()))
+    }
+}
+
+/// Hex-encoded SHA-256 of a raw key; only the hash is stored
+pub fn hash_api_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+/// Generate a new random API key
+pub fn generate_api_key() -> String {
+    format!("icalds_{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+/// Pull the raw key out of `X-API-Key` or `Authorization: Bearer`; the
+/// scheme is case-insensitive and may be followed by any amount of whitespace
+fn extract_credential(headers: &HeaderMap) -> Option<String> {
+    if let Some(key) = headers.get(API_KEY_HEADER).and_then(|v| v.to_str().ok()) {
+        return Some(key.trim().to_string());
+    }
+
+    let value = headers.get(AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, token) = value.trim_start().split_once(|c: char| c.is_ascii_whitespace())?;
+    scheme
+        .eq_ignore_ascii_case("Bearer")
+        .then(|| token.trim().to_string())
+}
+
+#[derive(Deserialize)]
+struct PolicyData {
+    advisor: AdvisorData,
+}
+
+#[derive(Deserialize)]
+struct AdvisorData {
+    role_permissions: HashMap<String, HashSet<String>>,
+}
+
+/// Evaluates the `advisor.authz` policy
+pub enum PolicyEngine {
+    /// Evaluate the policy's rule against the embedded role table
+    InProcess(HashMap<String, HashSet<String>>),
+    /// Ask an OPA sidecar, e.g. `http://localhost:8181`
+    Sidecar { client: reqwest::Client, url: String },
+}
+
+impl PolicyEngine {
+    pub fn embedded() -> Self {
+        let data: PolicyData = serde_json::from_str(POLICY_DATA).expect("Invalid policy data.json");
+        PolicyEngine::InProcess(data.advisor.role_permissions)
+    }
+
+    /// Use the sidecar at `OPA_URL` if set, otherwise evaluate in-process
+    pub fn from_env() -> Self {
+        match env::var("OPA_URL") {
+            Ok(url) if !url.is_empty() => PolicyEngine::Sidecar {
+                client: reqwest::Client::new(),
+                url: format!("{}/v1/data/advisor/authz/allow", url.trim_end_matches('/')),
+            },
+            _ => Self::embedded(),
+        }
+    }
+
+    pub async fn allows(&self, role: Role, action: Action) -> Result<bool, AuthError> {
+        match self {
+            PolicyEngine::InProcess(permissions) => Ok(permissions
+                .get(role.as_str())
+                .map(|actions| actions.contains(action.as_str()))
+                .unwrap_or(false)),
+            PolicyEngine::Sidecar { client, url } => {
+                let input = serde_json::json!({
+                    "input": { "role": role.as_str(), "action": action.as_str() }
+                });
+                let response: serde_json::Value = client
+                    .post(url)
+                    .json(&input)
+                    .send()
+                    .await
+                    .and_then(|r| r.error_for_status())
+                    .map_err(|e| AuthError::PolicyUnavailable(e.to_string()))?
+                    .json()
+                    .await
+                    .map_err(|e| AuthError::PolicyUnavailable(e.to_string()))?;
+
+                // An undefined decision means deny, matching `default allow = false`
+                Ok(response.get("result").and_then(|r| r.as_bool()).unwrap_or(false))
+            }
+        }
+    }
+}
+
+/// Resolves credentials to a [`Principal`] and checks it against the policy
+pub struct Authenticator {
+    db: Arc<Database>,
+    policy: PolicyEngine,
+    anonymous_role: Option<Role>,
+}
+
+impl Authenticator {
+    pub fn new(db: Arc<Database>, policy: PolicyEngine, anonymous_role: Option<Role>) -> Self {
+        Self {
+            db,
+            policy,
+            anonymous_role,
+        }
+    }
+
+    /// Build from `OPA_URL` and `ANALYZER_ANONYMOUS_ROLE`.
+    ///
+    /// When `ANALYZER_ANONYMOUS_ROLE` is set, requests without credentials
+    /// are treated as that role instead of being rejected.
+    pub fn from_env(db: Arc<Database>) -> Self {
+        let anonymous_role = env::var("ANALYZER_ANONYMOUS_ROLE")
+            .ok()
+            .and_then(|role| role.parse().ok());
+        Self::new(db, PolicyEngine::from_env(), anonymous_role)
+    }
+
+    pub async fn authenticate(&self, headers: &HeaderMap) -> Result<Principal, AuthError> {
+        let key = match extract_credential(headers) {
+            Some(key) if !key.is_empty() => key,
+            _ => {
+                return self
+                    .anonymous_role
+                    .map(|role| Principal {
+                        key_id: None,
+                        name: "anonymous".to_string(),
+                        role,
+                        plan: crate::rate_limit::DEFAULT_PLAN.to_string(),
+                    })
+                    .ok_or(AuthError::MissingCredentials)
+            }
+        };
+
+        let record = self
+            .db
+            .find_active_api_key(&hash_api_key(&key))
+            .await
+            .map_err(|e| AuthError::PolicyUnavailable(e.to_string()))?
+            .ok_or(AuthError::InvalidCredentials)?;
+
+        let role = record.role.parse().map_err(|_| AuthError::InvalidCredentials)?;
+
+        Ok(Principal {
+            key_id: Some(record.id),
+            name: record.name,
+            role,
+            plan: record.plan,
+        })
+    }
+
+    pub async fn authorize(&self, principal: &Principal, action: Action) -> Result<(), AuthError> {
+        if self.policy.allows(principal.role, action).await? {
+            Ok(())
+        } else {
+            Err(AuthError::Forbidden(action))
+        }
+    }
+}
+
+/// Middleware body: authenticate the caller and require `action`.
+///
+/// ```ignore
+/// web::resource("/analyze")
+///     .wrap(from_fn(|req, next| auth::require(Action::Analyze, req, next)))
+/// ```
+pub async fn require(
+    action: Action,
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let authenticator = req
+        .app_data::<web::Data<Authenticator>>()
+        .cloned()
+        .expect("Authenticator is not registered as app data");
+
+    let principal = authenticator.authenticate(req.headers()).await?;
+    authenticator.authorize(&principal, action).await?;
+    req.extensions_mut().insert(principal);
+
+    next.call(req).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{middleware::from_fn, test as actix_test, App};
+
+    fn lazy_db() -> Arc<Database> {
+        Arc::new(Database::connect_lazy("mysql://localhost:3306/icalds").unwrap())
+    }
+
+    #[actix_web::test]
+    async fn test_embedded_policy_roles() {
+        let policy = PolicyEngine::embedded();
+        assert!(policy.allows(Role::Admin, Action::ManageKeys).await.unwrap());
+        assert!(policy.allows(Role::Analyst, Action::Analyze).await.unwrap());
+        assert!(!policy.allows(Role::Analyst, Action::ManageKeys).await.unwrap());
+        assert!(policy.allows(Role::Viewer, Action::ReadHistory).await.unwrap());
+        assert!(!policy.allows(Role::Viewer, Action::Analyze).await.unwrap());
+        assert!(policy.allows(Role::Viewer, Action::ReadCatalog).await.unwrap());
+        assert!(policy.allows(Role::Curator, Action::WriteCatalog).await.unwrap());
+        assert!(!policy.allows(Role::Curator, Action::ReviewCatalog).await.unwrap());
+        assert!(policy.allows(Role::Admin, Action::ReviewCatalog).await.unwrap());
+    }
+
+    #[test]
+    fn test_extract_credential() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(extract_credential(&headers), None);
+
+        headers.insert(AUTHORIZATION, "Bearer abc".parse().unwrap());
+        assert_eq!(extract_credential(&headers), Some("abc".to_string()));
+
+        for value in ["bearer abc", "BEARER abc", "Bearer   abc", "Bearer\tabc"] {
+            headers.insert(AUTHORIZATION, value.parse().unwrap());
+            assert_eq!(extract_credential(&headers), Some("abc".to_string()), "{value}");
+        }
+        for value in ["Basic abc", "Bearerabc", "Bearer"] {
+            headers.insert(AUTHORIZATION, value.parse().unwrap());
+            assert_eq!(extract_credential(&headers), None, "{value}");
+        }
+
+        headers.insert(API_KEY_HEADER.parse().unwrap(), "xyz".parse().unwrap());
+        assert_eq!(extract_credential(&headers), Some("xyz".to_string()));
+
+        assert_eq!(hash_api_key("xyz").len(), 64);
+    }
+
+    #[actix_web::test]
+    async fn test_require_middleware() {
+        let authenticator = web::Data::new(Authenticator::new(
+            lazy_db(),
+            PolicyEngine::embedded(),
+            Some(Role::Analyst),
+        ));
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(authenticator)
+                .service(
+                    web::resource("/analyze")
+                        .wrap(from_fn(|req, next| require(Action::Analyze, req, next)))
+                        .to(HttpResponse::Ok),
+                )
+                .service(
+                    web::resource("/admin")
+                        .wrap(from_fn(|req, next| require(Action::ManageKeys, req, next)))
+                        .to(HttpResponse::Ok),
+                ),
+        )
+        .await;
+
+        let resp = actix_test::call_service(&app, actix_test::TestRequest::get().uri("/analyze").to_request()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let err = actix_test::try_call_service(&app, actix_test::TestRequest::get().uri("/admin").to_request())
+            .await
+            .unwrap_err();
+        assert_eq!(err.as_response_error().status_code(), StatusCode::FORBIDDEN);
+    }
+}
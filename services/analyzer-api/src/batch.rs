@@ -0,0 +1,357 @@
+//! Batch analysis: unpacking uploaded archives and summarizing results
+//!
+//! A batch arrives either as a JSON list of named files or as a tar,
+//! tar.gz or zip archive. Archive entries that are not source files of
+//! the requested language are skipped and reported, not analyzed.
+
+use analyzer_types::v2::{BatchFile, BatchFileResult, Inventory, InventoryEntry};
+use flate2::read::GzDecoder;
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read};
+
+use crate::limits::{self, AnalysisLimits, InputError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveKind {
+    Tar,
+    TarGz,
+    Zip,
+}
+
+impl ArchiveKind {
+    /// Archive format for a request `Content-Type`, ignoring parameters
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type.split(';').next().unwrap_or("").trim();
+        match mime {
+            "application/x-tar" => Some(ArchiveKind::Tar),
+            "application/gzip" | "application/x-gzip" | "application/x-compressed-tar" => Some(ArchiveKind::TarGz),
+            "application/zip" | "application/x-zip-compressed" => Some(ArchiveKind::Zip),
+            _ => None,
+        }
+    }
+}
+
+/// Reasons a batch is rejected as a whole
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchError {
+    NoFiles,
+    TooManyFiles(usize),
+    /// Unpacked contents exceed this many bytes
+    TooLarge(usize),
+    InvalidArchive(String),
+    /// A file exceeds the per-file limit of `max_payload_bytes`
+    FileTooLarge { path: String, limit: usize },
+    /// A file is rejected the way a single submission would be
+    InvalidFile { path: String, error: InputError },
+}
+
+impl BatchError {
+    /// Machine-readable error code returned to clients
+    pub fn code(&self) -> &'static str {
+        match self {
+            BatchError::NoFiles => "empty_batch",
+            BatchError::TooManyFiles(_) => "too_many_files",
+            BatchError::TooLarge(_) => "batch_too_large",
+            BatchError::InvalidArchive(_) => "invalid_archive",
+            BatchError::FileTooLarge { .. } => "file_too_large",
+            BatchError::InvalidFile { error, .. } => error.code(),
+        }
+    }
+}
+
+impl std::fmt::Display for BatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BatchError::NoFiles => write!(f, "The batch contains no source files"),
+            BatchError::TooManyFiles(limit) => write!(f, "A batch may contain at most {} files", limit),
+            BatchError::TooLarge(limit) => write!(f, "The unpacked batch exceeds {} bytes", limit),
+            BatchError::InvalidArchive(reason) => write!(f, "The archive could not be read: {}", reason),
+            BatchError::FileTooLarge { path, limit } => write!(f, "{} exceeds the per-file limit of {} bytes", path, limit),
+            BatchError::InvalidFile { path, error } => write!(f, "{}: {}", path, error),
+        }
+    }
+}
+
+impl std::error::Error for BatchError {}
+
+/// File extensions analyzed for a language
+pub fn source_extensions(language: &str) -> &'static [&'static str] {
+    match language {
+        "rust" => &["rs"],
+        _ => &[],
+    }
+}
+
+fn is_source_path(path: &str, language: &str) -> bool {
+    let extension = path.rsplit_once('.').map(|(_, ext)| ext).unwrap_or("");
+    source_extensions(language).contains(&extension)
+}
+
+/// Check a batch before any file is analyzed: the number of files, and
+/// each file against the limits a single submission must meet
+pub fn check_batch(files: &[BatchFile], limits: &AnalysisLimits) -> Result<(), BatchError> {
+    if files.is_empty() {
+        return Err(BatchError::NoFiles);
+    }
+    if files.len() > limits.max_batch_files {
+        return Err(BatchError::TooManyFiles(limits.max_batch_files));
+    }
+    for file in files {
+        if file.code.len() > limits.max_payload_bytes {
+            return Err(BatchError::FileTooLarge {
+                path: file.path.clone(),
+                limit: limits.max_payload_bytes,
+            });
+        }
+        limits::validate_code(&file.code).map_err(|error| BatchError::InvalidFile {
+            path: file.path.clone(),
+            error,
+        })?;
+    }
+    Ok(())
+}
+
+/// Files unpacked from an archive
+#[derive(Debug, Default)]
+pub struct Extracted {
+    pub files: Vec<BatchFile>,
+    /// Entries that are not source files of the language, or not UTF-8
+    /// text, and empty or binary ones
+    pub skipped: Vec<String>,
+}
+
+impl Extracted {
+    /// Keep one entry, stopping once the unpacked size reaches `max_bytes`
+    fn push(&mut self, path: String, reader: impl Read, language: &str, unpacked: &mut usize, max_bytes: usize) -> Result<(), BatchError> {
+        let path = path.trim_start_matches("./").to_string();
+        if !is_source_path(&path, language) {
+            self.skipped.push(path);
+            return Ok(());
+        }
+
+        // Read one byte past the remaining allowance to detect oversized archives
+        let remaining = max_bytes.saturating_sub(*unpacked);
+        let mut bytes = Vec::new();
+        reader
+            .take(remaining as u64 + 1)
+            .read_to_end(&mut bytes)
+            .map_err(|e| BatchError::InvalidArchive(e.to_string()))?;
+        if bytes.len() > remaining {
+            return Err(BatchError::TooLarge(max_bytes));
+        }
+        *unpacked += bytes.len();
+
+        match String::from_utf8(bytes) {
+            Ok(code) if limits::validate_code(&code).is_ok() => self.files.push(BatchFile { path, code }),
+            _ => self.skipped.push(path),
+        }
+        Ok(())
+    }
+}
+
+/// Unpack the source files of `language` from an uploaded archive
+pub fn extract_archive(kind: ArchiveKind, bytes: &[u8], language: &str, max_bytes: usize) -> Result<Extracted, BatchError> {
+    let invalid = |e: std::io::Error| BatchError::InvalidArchive(e.to_string());
+    let mut extracted = Extracted::default();
+    let mut unpacked = 0;
+
+    match kind {
+        ArchiveKind::Tar | ArchiveKind::TarGz => {
+            let reader: Box<dyn Read> = if kind == ArchiveKind::TarGz {
+                Box::new(GzDecoder::new(bytes))
+            } else {
+                Box::new(bytes)
+            };
+            let mut archive = tar::Archive::new(reader);
+            for entry in archive.entries().map_err(invalid)? {
+                let entry = entry.map_err(invalid)?;
+                if !entry.header().entry_type().is_file() {
+                    continue;
+                }
+                let path = entry.path().map_err(invalid)?.to_string_lossy().into_owned();
+                extracted.push(path, entry, language, &mut unpacked, max_bytes)?;
+            }
+        }
+        ArchiveKind::Zip => {
+            let mut archive = zip::ZipArchive::new(Cursor::new(bytes))
+                .map_err(|e| BatchError::InvalidArchive(e.to_string()))?;
+            for index in 0..archive.len() {
+                let file = archive
+                    .by_index(index)
+                    .map_err(|e| BatchError::InvalidArchive(e.to_string()))?;
+                if !file.is_file() {
+                    continue;
+                }
+                let path = file.name().to_string();
+                extracted.push(path, file, language, &mut unpacked, max_bytes)?;
+            }
+        }
+    }
+
+    Ok(extracted)
+}
+
+/// Summarize per-file results into the algorithms and data structures of the whole batch
+pub fn build_inventory(results: &[BatchFileResult], skipped: Vec<String>) -> Inventory {
+    let mut algorithms: BTreeMap<&str, InventoryEntry> = BTreeMap::new();
+    let mut data_structures: BTreeMap<&str, InventoryEntry> = BTreeMap::new();
+    let mut inventory = Inventory {
+        skipped,
+        ..Inventory::default()
+    };
+
+    for file in results {
+        let Some(result) = &file.result else {
+            inventory.failed += 1;
+            continue;
+        };
+        inventory.analyzed += 1;
+        inventory.timed_out |= result.timed_out;
+
+        for (findings, entries) in [
+            (&result.algorithms, &mut algorithms),
+            (&result.data_structures, &mut data_structures),
+        ] {
+            for finding in findings {
+                let entry = entries.entry(&finding.name).or_insert_with(|| InventoryEntry {
+                    name: finding.name.clone(),
+                    category: finding.category.clone(),
+                    files: Vec::new(),
+                });
+                if !entry.files.contains(&file.path) {
+                    entry.files.push(file.path.clone());
+                }
+            }
+        }
+    }
+
+    inventory.algorithms = by_spread(algorithms);
+    inventory.data_structures = by_spread(data_structures);
+    inventory
+}
+
+/// Entries found in the most files first, then by name
+fn by_spread(entries: BTreeMap<&str, InventoryEntry>) -> Vec<InventoryEntry> {
+    let mut entries: Vec<InventoryEntry> = entries.into_values().collect();
+    entries.sort_by(|a, b| b.files.len().cmp(&a.files.len()).then_with(|| a.name.cmp(&b.name)));
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use analyzer_types::v2::{AnalysisResponse, Finding};
+    use analyzer_types::ErrorResponse;
+    use std::io::Write;
+
+    fn tar_with(entries: &[(&str, &str)]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for (path, content) in entries {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, content.as_bytes()).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    #[test]
+    fn test_extract_tar_skips_other_files() {
+        let bytes = tar_with(&[("src/main.rs", "fn main() {}"), ("Cargo.toml", "[package]")]);
+        let extracted = extract_archive(ArchiveKind::Tar, &bytes, "rust", 1024).unwrap();
+
+        assert_eq!(extracted.files.len(), 1);
+        assert_eq!(extracted.files[0].path, "src/main.rs");
+        assert_eq!(extracted.skipped, vec!["Cargo.toml".to_string()]);
+    }
+
+    #[test]
+    fn test_extract_zip() {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        writer.start_file("lib.rs", zip::write::SimpleFileOptions::default()).unwrap();
+        writer.write_all(b"pub fn f() {}").unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+
+        let extracted = extract_archive(ArchiveKind::Zip, &bytes, "rust", 1024).unwrap();
+        assert_eq!(extracted.files[0].code, "pub fn f() {}");
+    }
+
+    #[test]
+    fn test_extract_enforces_unpacked_size() {
+        let bytes = tar_with(&[("a.rs", "fn a() {}"), ("b.rs", "fn b() {}")]);
+        let result = extract_archive(ArchiveKind::Tar, &bytes, "rust", 12);
+        assert_eq!(result.unwrap_err(), BatchError::TooLarge(12));
+
+        let result = extract_archive(ArchiveKind::Zip, b"not a zip", "rust", 12);
+        assert!(matches!(result, Err(BatchError::InvalidArchive(_))));
+    }
+
+    #[test]
+    fn test_check_batch() {
+        let limits = AnalysisLimits {
+            max_batch_files: 2,
+            max_payload_bytes: 16,
+            ..AnalysisLimits::default()
+        };
+        let file = |path: &str, code: &str| BatchFile { path: path.to_string(), code: code.to_string() };
+        let valid = file("a.rs", "fn a() {}");
+        assert_eq!(check_batch(&[], &limits), Err(BatchError::NoFiles));
+        assert_eq!(check_batch(&vec![valid.clone(); 3], &limits), Err(BatchError::TooManyFiles(2)));
+        assert_eq!(check_batch(std::slice::from_ref(&valid), &limits), Ok(()));
+
+        let large = file("b.rs", "fn b() { let _ = 1; }");
+        assert_eq!(
+            check_batch(&[valid.clone(), large], &limits),
+            Err(BatchError::FileTooLarge { path: "b.rs".to_string(), limit: 16 })
+        );
+        let error = check_batch(&[valid, file("c.rs", " \n")], &limits).unwrap_err();
+        assert_eq!(error, BatchError::InvalidFile { path: "c.rs".to_string(), error: InputError::Empty });
+        assert_eq!(error.code(), "empty_input");
+    }
+
+    #[test]
+    fn test_inventory_counts_files_per_finding() {
+        let finding = |name: &str| Finding {
+            name: name.to_string(),
+            category: "Sorting".to_string(),
+            complexity: String::new(),
+            description: String::new(),
+            wikipedia_link: String::new(),
+            relations: Vec::new(),
+            definitions: Vec::new(),
+        };
+        let analyzed = |path: &str, algorithms: Vec<Finding>| BatchFileResult {
+            path: path.to_string(),
+            result: Some(AnalysisResponse {
+                language: "rust".to_string(),
+                complexity: "Low".to_string(),
+                timed_out: false,
+                algorithms,
+                data_structures: vec![],
+                collections: vec![],
+                suggestions: vec![],
+                anti_patterns: vec![],
+                patterns: vec![],
+                recommendations: vec![],
+            }),
+            error: None,
+        };
+        let results = vec![
+            analyzed("a.rs", vec![finding("Quick Sort"), finding("Bubble Sort")]),
+            analyzed("b.rs", vec![finding("Quick Sort")]),
+            BatchFileResult {
+                path: "c.rs".to_string(),
+                result: None,
+                error: Some(ErrorResponse::new("empty_input", "empty")),
+            },
+        ];
+
+        let inventory = build_inventory(&results, vec!["README.md".to_string()]);
+        assert_eq!((inventory.analyzed, inventory.failed), (2, 1));
+        assert_eq!(inventory.algorithms[0].name, "Quick Sort");
+        assert_eq!(inventory.algorithms[0].files, vec!["a.rs", "b.rs"]);
+        assert_eq!(inventory.algorithms[1].name, "Bubble Sort");
+        assert_eq!(inventory.skipped, vec!["README.md"]);
+    }
+}
@@ -0,0 +1,349 @@
+//! Administration tool for the analyzer's database and catalogs
+//!
+//! Every command prints text by default and JSON with `--json`; commands
+//! that write take `--dry-run` to report what they would do instead.
+
+use analyzer_api::admin::{self, ChangeKind, EntryChange};
+use analyzer_api::algorithms::AlgorithmDetector;
+use analyzer_api::catalog;
+use analyzer_api::data_structures::DataStructureDetector;
+use analyzer_api::db::Database;
+use analyzer_api::models::code_sample::SamplePurge;
+use analyzer_api::models::wikipedia_algorithm::{WikipediaList, WikipediaPurge, WikipediaSyncReport};
+use analyzer_api::wikipedia::WikipediaScraper;
+use chrono::{Duration, Utc};
+use clap::{Parser, Subcommand, ValueEnum};
+use dotenv::dotenv;
+use serde::Serialize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "icalds-admin", version, about = "Administer the analyzer's database and catalogs")]
+struct Cli {
+    /// Print JSON instead of text
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Fetch the Wikipedia lists and apply them to the database
+    Import {
+        #[arg(long, value_enum, default_value_t = ListArg::All)]
+        list: ListArg,
+        /// Report what would change without writing it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Show, field by field, what an import would change in the database
+    Diff {
+        #[arg(long, value_enum, default_value_t = ListArg::All)]
+        list: ListArg,
+    },
+    /// Write both catalogs, as the server builds them, as JSON
+    Export {
+        /// File to write instead of standard output
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Export the built-in catalogs without connecting to the database
+        #[arg(long)]
+        built_in: bool,
+    },
+    /// Check every catalog entry; exits with status 1 if any has problems
+    Validate {
+        /// Validate the built-in catalogs without connecting to the database
+        #[arg(long)]
+        built_in: bool,
+    },
+    /// Create missing tables and bring existing ones up to date
+    Migrate {
+        /// List the pending changes without making them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Delete stub and soft-deleted Wikipedia rows
+    Purge {
+        #[arg(long, value_enum, default_value_t = ListArg::All)]
+        list: ListArg,
+        /// Also delete submitted code samples, and their analysis results,
+        /// created more than this many days ago
+        #[arg(long, value_name = "DAYS")]
+        analysis_history_older_than_days: Option<u32>,
+        /// Count what would be deleted without deleting it
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ListArg {
+    Algorithms,
+    DataStructures,
+    All,
+}
+
+impl ListArg {
+    fn lists(self) -> Vec<WikipediaList> {
+        match self {
+            ListArg::Algorithms => vec![WikipediaList::Algorithms],
+            ListArg::DataStructures => vec![WikipediaList::DataStructures],
+            ListArg::All => WikipediaList::ALL.to_vec(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ImportOutput {
+    list: &'static str,
+    dry_run: bool,
+    #[serde(flatten)]
+    report: WikipediaSyncReport,
+}
+
+#[derive(Serialize)]
+struct DiffOutput {
+    list: &'static str,
+    changes: Vec<EntryChange>,
+}
+
+#[derive(Serialize)]
+struct ExportOutput {
+    output: PathBuf,
+    algorithms: usize,
+    data_structures: usize,
+}
+
+#[derive(Serialize)]
+struct PurgeOutput {
+    dry_run: bool,
+    lists: Vec<ListPurge>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analysis_history: Option<SamplePurge>,
+}
+
+#[derive(Serialize)]
+struct ListPurge {
+    list: &'static str,
+    #[serde(flatten)]
+    purge: WikipediaPurge,
+}
+
+#[derive(Serialize)]
+struct MigrateOutput {
+    dry_run: bool,
+    steps: Vec<String>,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    dotenv().ok();
+    let cli = Cli::parse();
+
+    match run(cli).await {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let json = cli.json;
+
+    match cli.command {
+        Command::Import { list, dry_run } => {
+            let scraper = WikipediaScraper::new(connect().await?);
+            let mut outputs = Vec::new();
+            for list in list.lists() {
+                let plan = scraper.plan_import(list).await?;
+                if !dry_run {
+                    scraper.db.apply_wikipedia_sync(list, &plan).await?;
+                }
+                outputs.push(ImportOutput { list: list.as_str(), dry_run, report: plan.report() });
+            }
+            print(json, &outputs, || {
+                for output in &outputs {
+                    let verb = if dry_run { "Would sync" } else { "Synced" };
+                    println!("{} {}: {}", verb, output.list.replace('_', " "), output.report);
+                    for (mark, titles) in [("+", &output.report.added), ("~", &output.report.changed), ("-", &output.report.removed)] {
+                        for title in titles {
+                            println!("  {} {}", mark, title);
+                        }
+                    }
+                }
+            })?;
+        }
+        Command::Diff { list } => {
+            let scraper = WikipediaScraper::new(connect().await?);
+            let mut outputs = Vec::new();
+            for list in list.lists() {
+                let plan = scraper.plan_import(list).await?;
+                let stored = scraper.db.get_stored_wikipedia_entries(list).await?;
+                outputs.push(DiffOutput { list: list.as_str(), changes: admin::describe_plan(&stored, &plan) });
+            }
+            print(json, &outputs, || {
+                for output in &outputs {
+                    println!("{}: {} changes", output.list.replace('_', " "), output.changes.len());
+                    for change in &output.changes {
+                        print_change(change);
+                    }
+                }
+            })?;
+        }
+        Command::Export { output, built_in } => {
+            let (algorithms, data_structures) = load_catalogs(built_in).await?;
+            let export = admin::export_catalog(&algorithms, &data_structures);
+            let body = serde_json::to_string_pretty(&export)?;
+            match output {
+                None => println!("{}", body),
+                Some(path) => {
+                    std::fs::write(&path, body + "\n")?;
+                    let summary = ExportOutput {
+                        output: path,
+                        algorithms: export.algorithms.len(),
+                        data_structures: export.data_structures.len(),
+                    };
+                    print(json, &summary, || {
+                        println!(
+                            "Wrote {} algorithms and {} data structures to {}",
+                            summary.algorithms,
+                            summary.data_structures,
+                            summary.output.display()
+                        );
+                    })?;
+                }
+            }
+        }
+        Command::Validate { built_in } => {
+            let (algorithms, data_structures) = load_catalogs(built_in).await?;
+            let issues = admin::validate_catalog(&algorithms, &data_structures);
+            print(json, &issues, || {
+                for issue in &issues {
+                    match issue.kind {
+                        Some(kind) => println!("{} '{}': {}", kind.as_str(), issue.name, issue.problem),
+                        None => println!("{}: {}", issue.name, issue.problem),
+                    }
+                }
+                println!(
+                    "{} algorithms and {} data structures checked, {} problems",
+                    algorithms.algorithms.len(),
+                    data_structures.data_structures.len(),
+                    issues.len()
+                );
+            })?;
+            if !issues.is_empty() {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Command::Migrate { dry_run } => {
+            let db = Database::new().await?;
+            let steps = db.pending_migrations().await?;
+            if !dry_run {
+                db.init().await?;
+            }
+            let output = MigrateOutput { dry_run, steps };
+            print(json, &output, || {
+                if output.steps.is_empty() {
+                    println!("Schema is up to date");
+                }
+                for step in &output.steps {
+                    println!("{} {}", if dry_run { "Would" } else { "Did" }, step);
+                }
+            })?;
+        }
+        Command::Purge { list, analysis_history_older_than_days, dry_run } => {
+            let db = connect().await?;
+            let mut lists = Vec::new();
+            for list in list.lists() {
+                lists.push(ListPurge { list: list.as_str(), purge: db.purge_wikipedia_entries(list, dry_run).await? });
+            }
+            let analysis_history = match analysis_history_older_than_days {
+                Some(days) => Some(db.purge_code_samples(Utc::now() - Duration::days(days.into()), dry_run).await?),
+                None => None,
+            };
+            let output = PurgeOutput { dry_run, lists, analysis_history };
+            let verb = if dry_run { "Would delete" } else { "Deleted" };
+            print(json, &output, || {
+                for list in &output.lists {
+                    println!(
+                        "{} {} stub and {} removed {}",
+                        verb,
+                        list.purge.stubs,
+                        list.purge.removed,
+                        list.list.replace('_', " ")
+                    );
+                }
+                if let Some(history) = &output.analysis_history {
+                    println!(
+                        "{} {} code samples and {} analysis results created before {}",
+                        verb,
+                        history.samples,
+                        history.results,
+                        history.cutoff.format("%Y-%m-%d %H:%M")
+                    );
+                }
+            })?;
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Connect to a database whose schema is current; only `migrate` changes
+/// the schema, so no other command, dry run or not, can alter it
+async fn connect() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = Database::new().await?;
+    let pending = db.pending_migrations().await?;
+    if !pending.is_empty() {
+        return Err(format!(
+            "the database schema is out of date ({} pending changes); run `icalds-admin migrate` first",
+            pending.len()
+        )
+        .into());
+    }
+    Ok(db)
+}
+
+/// The catalogs the server would build: built-ins, Wikipedia imports and
+/// curated overrides, or only the built-ins
+async fn load_catalogs(built_in: bool) -> Result<(AlgorithmDetector, DataStructureDetector), Box<dyn std::error::Error>> {
+    if built_in {
+        return Ok((AlgorithmDetector::new(), DataStructureDetector::new()));
+    }
+    let db = connect().await?;
+    let overrides = db.get_catalog_overrides().await?;
+    let algorithms = db.get_all_wikipedia_algorithms().await?;
+    let data_structures = db.get_all_wikipedia_data_structures().await?;
+    Ok(catalog::build_detectors(&overrides, &algorithms, &data_structures))
+}
+
+fn print<T: Serialize>(json: bool, value: &T, text: impl FnOnce()) -> Result<(), serde_json::Error> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(value)?);
+    } else {
+        text();
+    }
+    Ok(())
+}
+
+fn print_change(change: &EntryChange) {
+    let mark = match change.change {
+        ChangeKind::Added => "+",
+        ChangeKind::Changed => "~",
+        ChangeKind::Removed => "-",
+    };
+    println!("  {} {} ({})", mark, change.title, change.url);
+    if change.change != ChangeKind::Changed {
+        return;
+    }
+    if let Some(fields) = change.fields.as_object() {
+        for (field, values) in fields {
+            println!("      {}: {} -> {}", field, values["before"], values["after"]);
+        }
+    }
+}
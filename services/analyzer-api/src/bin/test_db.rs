@@ -2,7 +2,6 @@
 
 use analyzer_api::db::Database;
 use analyzer_api::models::{code_sample::NewCodeSample, analysis_result::{NewAnalysisResult, DetailedAlgorithm}};
-use uuid::Uuid;
 // Add dotenv import
 use dotenv::dotenv;
 
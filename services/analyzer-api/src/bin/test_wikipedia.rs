@@ -1,18 +0,0 @@
-//! Test Wikipedia integration
-
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    println!("Testing Wikipedia integration...");
-
-    // This is a simple test that doesn't require a database connection
-    // In a real scenario, you would connect to the database
-    
-    // For now, let's just test that our data structures work correctly
-    
-    println!("Wikipedia integration test completed successfully!");
-    println!("To fully test the Wikipedia integration, ensure you have:");
-    println!("1. A running MySQL database");
-    println!("2. Properly configured DATABASE_URL environment variable");
-    println!("3. Run: cargo run --bin fetch_wikipedia_algorithms");
-    
-    Ok(())
-}
\ No newline at end of file
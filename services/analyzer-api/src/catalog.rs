@@ -0,0 +1,732 @@
+//! Browsing the algorithm and data structure catalogs
+//!
+//! Both catalogs are searched the same way: an optional category filter,
+//! which accepts either the enum name (`DynamicProgramming`) or its label
+//! (`Dynamic Programming`), and a free-text query whose words must all
+//! appear somewhere in the entry. Single entries are looked up by name or
+//! by slug (`quick-sort`).
+//!
+//! Curators change the catalogs through reviewed changes. Approved entries
+//! are stored as overrides and laid over the built-in catalogs whenever the
+//! detectors are built; [`Live`] lets the running server swap in the result.
+//! Algorithms and data structures imported from Wikipedia are merged in
+//! before the overrides, so curated entries always win.
+
+use analyzer_types::v2::Relation;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::algorithms::{AlgorithmDetector, AlgorithmInfo};
+use crate::complexity::Complexity;
+use crate::data_structures::{DataStructureDetector, DataStructureInfo};
+use crate::models::catalog_change::{CatalogKind, CatalogOverride};
+use crate::models::wikipedia_algorithm::{WikipediaAlgorithm, WikipediaDataStructure};
+
+/// An entry of either catalog
+pub trait CatalogEntry {
+    fn name(&self) -> &str;
+    /// Enum variant name of the category, e.g. `DynamicProgramming`
+    fn category_name(&self) -> String;
+    /// Human-readable category, e.g. `Dynamic Programming`
+    fn category_label(&self) -> String;
+    /// Everything a free-text query is matched against
+    fn search_text(&self) -> String;
+    fn relations(&self) -> &[Relation];
+}
+
+impl CatalogEntry for AlgorithmInfo {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category_name(&self) -> String {
+        format!("{:?}", self.category)
+    }
+
+    fn category_label(&self) -> String {
+        self.category.to_string()
+    }
+
+    fn search_text(&self) -> String {
+        [
+            self.name.as_str(),
+            &self.category.to_string(),
+            &self.complexity,
+            &self.description,
+            &self.examples.join("\n"),
+        ]
+        .join("\n")
+    }
+
+    fn relations(&self) -> &[Relation] {
+        &self.relations
+    }
+}
+
+impl CatalogEntry for DataStructureInfo {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category_name(&self) -> String {
+        format!("{:?}", self.category)
+    }
+
+    fn category_label(&self) -> String {
+        self.category.to_string()
+    }
+
+    fn search_text(&self) -> String {
+        [
+            self.name.as_str(),
+            &self.category.to_string(),
+            &self.complexity,
+            &self.description,
+            &self.examples.join("\n"),
+        ]
+        .join("\n")
+    }
+
+    fn relations(&self) -> &[Relation] {
+        &self.relations
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+pub struct CatalogQuery {
+    /// Only entries of this category, by name or label
+    pub category: Option<String>,
+    /// Words that must all appear in the name, category, complexity, description or examples
+    pub q: Option<String>,
+}
+
+/// A category and how many catalog entries it has
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct CategorySummary {
+    /// Enum name, as used in catalog entries
+    pub name: String,
+    pub label: String,
+    pub entries: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct CatalogCategories {
+    pub algorithms: Vec<CategorySummary>,
+    pub data_structures: Vec<CategorySummary>,
+}
+
+/// Reasons a catalog query or change is rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CatalogError {
+    /// The filter matches no category; holds the known category names
+    UnknownCategory(String, Vec<String>),
+    /// A proposed entry is incomplete or malformed
+    InvalidEntry(String),
+}
+
+impl CatalogError {
+    /// Machine-readable error code returned to clients
+    pub fn code(&self) -> &'static str {
+        match self {
+            CatalogError::UnknownCategory(..) => "unknown_category",
+            CatalogError::InvalidEntry(_) => "invalid_entry",
+        }
+    }
+}
+
+impl std::fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CatalogError::UnknownCategory(category, known) => {
+                write!(f, "Unknown category '{}'; expected one of: {}", category, known.join(", "))
+            }
+            CatalogError::InvalidEntry(reason) => write!(f, "Invalid catalog entry: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+/// Fields a proposed entry must fill in, whichever catalog it is for
+pub fn validate_entry<T: CatalogEntry + Serialize>(entry: &T) -> Result<(), CatalogError> {
+    let value = serde_json::to_value(entry).unwrap_or(Value::Null);
+    let text = |field: &str| value.get(field).and_then(Value::as_str).unwrap_or("").trim().to_string();
+
+    if entry.name().trim().is_empty() {
+        return Err(CatalogError::InvalidEntry("name must not be empty".to_string()));
+    }
+    for field in ["complexity", "description"] {
+        if text(field).is_empty() {
+            return Err(CatalogError::InvalidEntry(format!("{} must not be empty", field)));
+        }
+    }
+    let link = text("wikipedia_link");
+    let is_url = link.starts_with("https://") || link.starts_with("http://");
+    if !link.is_empty() && !is_url {
+        return Err(CatalogError::InvalidEntry("wikipedia_link must be an http(s) URL".to_string()));
+    }
+    let has_example = value
+        .get("examples")
+        .and_then(Value::as_array)
+        .is_some_and(|examples| examples.iter().any(|e| e.as_str().is_some_and(|e| !e.trim().is_empty())));
+    if !has_example {
+        return Err(CatalogError::InvalidEntry("examples must hold at least one snippet to match".to_string()));
+    }
+    let is_empty = |list: Option<&Value>| list.and_then(Value::as_array).is_none_or(|items| items.is_empty());
+    let blank_fingerprint = value
+        .get("fingerprints")
+        .and_then(Value::as_array)
+        .is_some_and(|fingerprints| fingerprints.iter().any(|f| is_empty(f.get("tokens")) && is_empty(f.get("features"))));
+    if blank_fingerprint {
+        return Err(CatalogError::InvalidEntry("fingerprints must each list tokens or features".to_string()));
+    }
+    Ok(())
+}
+
+/// Fields that differ between two versions of an entry, each with its
+/// `before` and `after` value; a missing version counts as all-null
+pub fn diff(before: Option<&Value>, after: Option<&Value>) -> Value {
+    let fields = |value: Option<&Value>| value.and_then(Value::as_object).cloned().unwrap_or_default();
+    let before = fields(before);
+    let after = fields(after);
+
+    let mut names: Vec<&String> = before.keys().chain(after.keys()).collect();
+    names.sort();
+    names.dedup();
+
+    let mut changed = serde_json::Map::new();
+    for name in names {
+        let old = before.get(name).unwrap_or(&Value::Null);
+        let new = after.get(name).unwrap_or(&Value::Null);
+        if old != new {
+            changed.insert(name.clone(), serde_json::json!({ "before": old, "after": new }));
+        }
+    }
+    Value::Object(changed)
+}
+
+/// Lay the overrides for `kind` over `entries`, keyed by name. Overrides
+/// that no longer deserialize are skipped rather than failing the load.
+pub fn apply_overrides<T: DeserializeOwned>(entries: &mut HashMap<String, T>, kind: CatalogKind, overrides: &[CatalogOverride]) {
+    for item in overrides.iter().filter(|o| o.kind == kind) {
+        match &item.entry {
+            None => {
+                entries.remove(&item.name);
+            }
+            Some(entry) => match serde_json::from_value(entry.clone()) {
+                Ok(entry) => {
+                    entries.insert(item.name.clone(), entry);
+                }
+                Err(e) => eprintln!("Skipping catalog override '{}': {}", item.name, e),
+            },
+        }
+    }
+}
+
+/// What merging the Wikipedia imports did to a catalog
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportMerge {
+    /// Imports that became entries of their own
+    pub added: usize,
+    /// Imports that filled gaps in an entry of the same name or article
+    pub enriched: usize,
+    /// Imports left out because curators own an entry of that name or article
+    pub curated: usize,
+}
+
+/// A catalog entry type Wikipedia imports are merged into
+trait Imported: CatalogEntry + Clone + PartialEq {
+    const KIND: CatalogKind;
+
+    fn from_import(import: &WikipediaAlgorithm) -> Self;
+
+    fn wikipedia_link(&self) -> &str;
+
+    /// Link, description, complexity summary and bounds, for [`fill_gaps`]
+    fn fields_mut(&mut self) -> (&mut String, &mut String, &mut String, &mut Complexity);
+}
+
+impl Imported for AlgorithmInfo {
+    const KIND: CatalogKind = CatalogKind::Algorithm;
+
+    fn from_import(import: &WikipediaAlgorithm) -> Self {
+        crate::wikipedia::algorithm_info(import.clone())
+    }
+
+    fn wikipedia_link(&self) -> &str {
+        &self.wikipedia_link
+    }
+
+    fn fields_mut(&mut self) -> (&mut String, &mut String, &mut String, &mut Complexity) {
+        (&mut self.wikipedia_link, &mut self.description, &mut self.complexity, &mut self.bounds)
+    }
+}
+
+impl Imported for DataStructureInfo {
+    const KIND: CatalogKind = CatalogKind::DataStructure;
+
+    fn from_import(import: &WikipediaDataStructure) -> Self {
+        crate::wikipedia::data_structure_info(import.clone())
+    }
+
+    fn wikipedia_link(&self) -> &str {
+        &self.wikipedia_link
+    }
+
+    fn fields_mut(&mut self) -> (&mut String, &mut String, &mut String, &mut Complexity) {
+        (&mut self.wikipedia_link, &mut self.description, &mut self.complexity, &mut self.bounds)
+    }
+}
+
+/// Merge algorithms imported from Wikipedia into `entries`.
+///
+/// An import is the same algorithm as an entry when their names fold to
+/// the same text (`Quicksort`, `Quick Sort`) or they link the same article.
+/// Matching built-in entries keep what they have and only take the
+/// import's link, description and bounds where they have none; other
+/// imports are added, once each. Names and articles with a curated
+/// override are left to the override.
+pub fn merge_wikipedia_algorithms(
+    entries: &mut HashMap<String, AlgorithmInfo>,
+    imported: &[WikipediaAlgorithm],
+    overrides: &[CatalogOverride],
+) -> ImportMerge {
+    merge_imports(entries, imported, overrides)
+}
+
+/// Merge data structures imported from Wikipedia into `entries`, the same
+/// way as [`merge_wikipedia_algorithms`]; matching entries also take the
+/// costs of operations they have none for
+pub fn merge_wikipedia_data_structures(
+    entries: &mut HashMap<String, DataStructureInfo>,
+    imported: &[WikipediaDataStructure],
+    overrides: &[CatalogOverride],
+) -> ImportMerge {
+    merge_imports(entries, imported, overrides)
+}
+
+fn merge_imports<T: Imported>(
+    entries: &mut HashMap<String, T>,
+    imported: &[WikipediaAlgorithm],
+    overrides: &[CatalogOverride],
+) -> ImportMerge {
+    let link_key = |link: &str| link.trim().trim_end_matches('/').to_lowercase();
+
+    let mut curated: HashSet<String> = HashSet::new();
+    for item in overrides.iter().filter(|o| o.kind == T::KIND) {
+        curated.insert(fold(&item.name));
+        if let Some(link) = item.entry.as_ref().and_then(|entry| entry.get("wikipedia_link")).and_then(Value::as_str) {
+            if !link.trim().is_empty() {
+                curated.insert(link_key(link));
+            }
+        }
+    }
+
+    let mut known: HashMap<String, String> = HashMap::new();
+    for (key, entry) in entries.iter() {
+        known.insert(fold(entry.name()), key.clone());
+        if !entry.wikipedia_link().trim().is_empty() {
+            known.insert(link_key(entry.wikipedia_link()), key.clone());
+        }
+    }
+
+    let mut merge = ImportMerge::default();
+    let mut seen: HashSet<String> = HashSet::new();
+    for import in imported {
+        let keys = [fold(&import.title), link_key(&import.url)];
+        if keys.iter().any(|key| curated.contains(key)) {
+            merge.curated += 1;
+            continue;
+        }
+        // Rows repeat across fetches; each article counts once
+        if !seen.insert(keys[1].clone()) {
+            continue;
+        }
+
+        match keys.iter().find_map(|key| known.get(key)).and_then(|name| entries.get_mut(name)) {
+            Some(entry) => {
+                if fill_gaps(entry, import) {
+                    merge.enriched += 1;
+                }
+            }
+            None => {
+                let entry = T::from_import(import);
+                for key in keys {
+                    known.insert(key, entry.name().to_string());
+                }
+                entries.insert(entry.name().to_string(), entry);
+                merge.added += 1;
+            }
+        }
+    }
+    merge
+}
+
+/// Take the import's link, description and bounds where the entry has
+/// none; returns whether anything was taken
+fn fill_gaps<T: Imported>(entry: &mut T, import: &WikipediaAlgorithm) -> bool {
+    let before = entry.clone();
+    let (link, description, summary, current) = entry.fields_mut();
+
+    if link.trim().is_empty() {
+        *link = import.url.clone();
+    }
+    if let Some(imported) = import.description.as_ref().filter(|d| !d.trim().is_empty()) {
+        if description.trim().is_empty() {
+            *description = imported.clone();
+        }
+    }
+    if let Some(complexity) = import.complexity.as_ref().filter(|c| !c.trim().is_empty()) {
+        if summary.trim().is_empty() {
+            *summary = complexity.clone();
+        }
+        let bounds = Complexity::from_summary(complexity);
+        for (slot, bound) in [
+            (&mut current.best, bounds.best),
+            (&mut current.average, bounds.average),
+            (&mut current.worst, bounds.worst),
+            (&mut current.space, bounds.space),
+        ] {
+            if slot.is_none() {
+                *slot = bound;
+            }
+        }
+        for (operation, bound) in bounds.operations {
+            current.operations.entry(operation).or_insert(bound);
+        }
+    }
+
+    *entry != before
+}
+
+/// The built-in detectors, merged with the Wikipedia imports, with the
+/// approved overrides applied
+pub fn build_detectors(
+    overrides: &[CatalogOverride],
+    imported_algorithms: &[WikipediaAlgorithm],
+    imported_data_structures: &[WikipediaDataStructure],
+) -> (AlgorithmDetector, DataStructureDetector) {
+    let mut algorithm_detector = AlgorithmDetector::new();
+    let mut data_structure_detector = DataStructureDetector::new();
+    merge_wikipedia_algorithms(&mut algorithm_detector.algorithms, imported_algorithms, overrides);
+    merge_wikipedia_data_structures(&mut data_structure_detector.data_structures, imported_data_structures, overrides);
+    apply_overrides(&mut algorithm_detector.algorithms, CatalogKind::Algorithm, overrides);
+    apply_overrides(&mut data_structure_detector.data_structures, CatalogKind::DataStructure, overrides);
+    (algorithm_detector, data_structure_detector)
+}
+
+/// A value the server reads on every request and replaces as a whole when
+/// the catalog changes; readers keep the snapshot they loaded
+pub struct Live<T> {
+    current: RwLock<Arc<T>>,
+}
+
+impl<T> Live<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            current: RwLock::new(Arc::new(value)),
+        }
+    }
+
+    pub fn load(&self) -> Arc<T> {
+        self.current.read().unwrap().clone()
+    }
+
+    pub fn store(&self, value: T) {
+        *self.current.write().unwrap() = Arc::new(value);
+    }
+}
+
+/// Lowercase letters and digits only, so names, labels and slugs compare equal
+fn fold(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// URL-friendly form of an entry name, e.g. `dijkstra-s-algorithm`
+pub fn slug(name: &str) -> String {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Entries matching `query`, by name
+pub fn search<'a, T: CatalogEntry>(
+    entries: impl IntoIterator<Item = &'a T>,
+    query: &CatalogQuery,
+) -> Result<Vec<&'a T>, CatalogError> {
+    let entries: Vec<&T> = entries.into_iter().collect();
+
+    let category = match query.category.as_deref().map(str::trim).filter(|c| !c.is_empty()) {
+        Some(wanted) => {
+            let folded = fold(wanted);
+            let known = categories(entries.iter().copied());
+            let found = known
+                .iter()
+                .find(|c| fold(&c.name) == folded || fold(&c.label) == folded)
+                .map(|c| c.name.clone());
+            match found {
+                Some(name) => Some(name),
+                None => {
+                    return Err(CatalogError::UnknownCategory(
+                        wanted.to_string(),
+                        known.into_iter().map(|c| c.name).collect(),
+                    ))
+                }
+            }
+        }
+        None => None,
+    };
+
+    let terms: Vec<String> = query
+        .q
+        .as_deref()
+        .unwrap_or("")
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect();
+
+    let mut found: Vec<&T> = entries
+        .into_iter()
+        .filter(|entry| category.as_ref().is_none_or(|c| entry.category_name() == *c))
+        .filter(|entry| {
+            if terms.is_empty() {
+                return true;
+            }
+            let text = entry.search_text().to_lowercase();
+            terms.iter().all(|term| text.contains(term.as_str()))
+        })
+        .collect();
+
+    // Entries whose name contains every word come first
+    let in_name = |entry: &T| {
+        let name = entry.name().to_lowercase();
+        terms.iter().all(|term| name.contains(term.as_str()))
+    };
+    found.sort_by(|a, b| in_name(b).cmp(&in_name(a)).then_with(|| a.name().cmp(b.name())));
+    Ok(found)
+}
+
+/// The entry with this name or slug, ignoring case
+pub fn find<'a, T: CatalogEntry>(entries: impl IntoIterator<Item = &'a T>, name: &str) -> Option<&'a T> {
+    let folded = fold(name);
+    entries.into_iter().find(|entry| fold(entry.name()) == folded)
+}
+
+/// Categories that have entries, by label
+pub fn categories<'a, T: CatalogEntry + 'a>(entries: impl IntoIterator<Item = &'a T>) -> Vec<CategorySummary> {
+    let mut counts: BTreeMap<String, CategorySummary> = BTreeMap::new();
+    for entry in entries {
+        counts
+            .entry(entry.category_label())
+            .or_insert_with(|| CategorySummary {
+                name: entry.category_name(),
+                label: entry.category_label(),
+                entries: 0,
+            })
+            .entries += 1;
+    }
+    counts.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{AlgorithmCategory, AlgorithmDetector};
+    use crate::data_structures::{DataStructureCategory, DataStructureDetector};
+
+    fn query(category: Option<&str>, q: Option<&str>) -> CatalogQuery {
+        CatalogQuery {
+            category: category.map(str::to_string),
+            q: q.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_filter_by_name_or_label() {
+        let detector = AlgorithmDetector::new();
+        let by_name = search(detector.algorithms.values(), &query(Some("DynamicProgramming"), None)).unwrap();
+        let by_label = search(detector.algorithms.values(), &query(Some("dynamic programming"), None)).unwrap();
+
+        assert!(!by_name.is_empty());
+        assert_eq!(by_name, by_label);
+        assert!(by_name.iter().all(|a| a.category == AlgorithmCategory::DynamicProgramming));
+
+        let err = search(detector.algorithms.values(), &query(Some("Astrology"), None)).unwrap_err();
+        assert_eq!(err.code(), "unknown_category");
+    }
+
+    #[test]
+    fn test_search_ranks_name_matches_first() {
+        let detector = AlgorithmDetector::new();
+        let found = search(detector.algorithms.values(), &query(None, Some("sort"))).unwrap();
+        assert!(found.len() > 1);
+        assert!(found[0].name.to_lowercase().contains("sort"));
+
+        let found = search(detector.algorithms.values(), &query(None, Some("no-such-word-anywhere"))).unwrap();
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn test_find_by_name_or_slug() {
+        let detector = DataStructureDetector::new();
+        let entry = detector.data_structures.values().next().unwrap();
+
+        assert_eq!(find(detector.data_structures.values(), &entry.name.to_uppercase()), Some(entry));
+        assert_eq!(find(detector.data_structures.values(), &slug(&entry.name)), Some(entry));
+        assert_eq!(find(detector.data_structures.values(), "no such structure"), None);
+        assert_eq!(slug("Dijkstra's Algorithm"), "dijkstra-s-algorithm");
+    }
+
+    #[test]
+    fn test_diff_lists_changed_fields_only() {
+        let before = serde_json::json!({"name": "X", "complexity": "O(n)", "examples": ["a"]});
+        let after = serde_json::json!({"name": "X", "complexity": "O(log n)", "examples": ["a"]});
+
+        assert_eq!(
+            diff(Some(&before), Some(&after)),
+            serde_json::json!({"complexity": {"before": "O(n)", "after": "O(log n)"}})
+        );
+        assert_eq!(diff(Some(&before), None)["name"], serde_json::json!({"before": "X", "after": null}));
+        assert_eq!(diff(Some(&before), Some(&before)), serde_json::json!({}));
+    }
+
+    #[test]
+    fn test_overrides_replace_add_and_remove_entries() {
+        let built_in = AlgorithmDetector::new();
+        let mut replaced = built_in.algorithms["Bubble Sort"].clone();
+        replaced.complexity = "O(n)".to_string();
+        let mut added = replaced.clone();
+        added.name = "Cocktail Sort".to_string();
+
+        let remove = built_in.algorithms.keys().find(|name| *name != "Bubble Sort").unwrap().clone();
+        let overrides = vec![
+            CatalogOverride { kind: CatalogKind::Algorithm, name: "Bubble Sort".to_string(), entry: Some(serde_json::to_value(&replaced).unwrap()) },
+            CatalogOverride { kind: CatalogKind::Algorithm, name: added.name.clone(), entry: Some(serde_json::to_value(&added).unwrap()) },
+            CatalogOverride { kind: CatalogKind::Algorithm, name: remove.clone(), entry: None },
+            // Meant for the other catalog, so left alone here
+            CatalogOverride { kind: CatalogKind::DataStructure, name: "Bubble Sort".to_string(), entry: None },
+        ];
+
+        let (algorithms, data_structures) = build_detectors(&overrides, &[], &[]);
+        assert_eq!(algorithms.algorithms["Bubble Sort"].complexity, "O(n)");
+        assert!(algorithms.algorithms.contains_key("Cocktail Sort"));
+        assert!(!algorithms.algorithms.contains_key(&remove));
+        assert_eq!(algorithms.algorithms.len(), built_in.algorithms.len());
+        assert_eq!(data_structures.data_structures.len(), DataStructureDetector::new().data_structures.len());
+    }
+
+    #[test]
+    fn test_wikipedia_imports_merge_under_overrides() {
+        let import = |title: &str, url: &str, description: Option<&str>, complexity: Option<&str>| WikipediaAlgorithm {
+            title: title.to_string(),
+            url: format!("https://en.wikipedia.org/wiki/{url}"),
+            category: "Sorting".to_string(),
+            description: description.map(str::to_string),
+            complexity: complexity.map(str::to_string),
+            revision_id: None,
+        };
+        let imported = vec![
+            // The built-in Quick Sort, found by its article
+            import("Quicksort", "Quicksort", Some("Quicksort is a divide-and-conquer sorting algorithm."), Some("O(n^2) worst, O(log n) space")),
+            import("Bogosort", "Bogosort", Some("Shuffles until sorted."), None),
+            import("Bogosort", "Bogosort", Some("Shuffles until sorted."), None),
+            import("Stooge sort", "Stooge_sort", None, None),
+        ];
+        let overrides = vec![CatalogOverride { kind: CatalogKind::Algorithm, name: "Stooge Sort".to_string(), entry: None }];
+
+        let mut entries = AlgorithmDetector::new().algorithms;
+        let built_in = entries["Quick Sort"].clone();
+        entries.get_mut("Quick Sort").unwrap().description.clear();
+        let merge = merge_wikipedia_algorithms(&mut entries, &imported, &overrides);
+        assert_eq!(merge, ImportMerge { added: 1, enriched: 1, curated: 1 });
+        assert_eq!(entries["Quick Sort"].description, "Quicksort is a divide-and-conquer sorting algorithm.");
+        assert_eq!(entries["Quick Sort"].complexity, built_in.complexity);
+        assert!(!entries.contains_key("Quicksort"));
+
+        let (detector, _) = build_detectors(&overrides, &imported, &[]);
+        assert_eq!(detector.algorithms["Quick Sort"], built_in);
+        assert!(!detector.algorithms.contains_key("Stooge sort"));
+
+        // Imports are found by name, not by the words of their category
+        let found = |code: &str| detector.detect_algorithms(code).into_iter().any(|a| a.name == "Bogosort");
+        assert!(found("// Bogosort: shuffle until sorted\nfn shuffle_sort(v: &mut Vec<i32>) {}"));
+        assert!(!found("fn sorted(v: &[i32]) -> bool { v.windows(2).all(|w| w[0] <= w[1]) }"));
+    }
+
+    #[test]
+    fn test_wikipedia_data_structures_merge() {
+        let import = |title: &str, url: &str, category: &str, complexity: Option<&str>| WikipediaDataStructure {
+            title: title.to_string(),
+            url: format!("https://en.wikipedia.org/wiki/{url}"),
+            category: category.to_string(),
+            description: Some(format!("{title} from Wikipedia.")),
+            complexity: complexity.map(str::to_string),
+            revision_id: None,
+        };
+        let imported = vec![
+            // The built-in Hash Table, which only lacks a cost for `find_min`
+            import("Hash table", "Hash_table", "Hash-Based", Some("O(1) search, O(n) find min, O(n) worst")),
+            import("Skip list", "Skip_list", "Linear", Some("O(log n) search, O(log n) insert, O(n) worst, O(n log n) space")),
+            import("Treap", "Treap", "Tree", None),
+        ];
+        let overrides = vec![CatalogOverride { kind: CatalogKind::DataStructure, name: "Treap".to_string(), entry: None }];
+
+        let mut entries = DataStructureDetector::new().data_structures;
+        let built_in = entries["Hash Table"].clone();
+        let merge = merge_wikipedia_data_structures(&mut entries, &imported, &overrides);
+        assert_eq!(merge, ImportMerge { added: 1, enriched: 1, curated: 1 });
+        assert_eq!(entries["Hash Table"].description, built_in.description);
+        assert_eq!(entries["Hash Table"].bounds.operation("search"), built_in.bounds.operation("search"));
+        assert_eq!(entries["Hash Table"].bounds.operation("find_min").map(ToString::to_string).as_deref(), Some("O(n)"));
+
+        let skip_list = &entries["Skip list"];
+        assert_eq!(skip_list.category, DataStructureCategory::Linear);
+        assert_eq!(skip_list.bounds.operation("insert").map(ToString::to_string).as_deref(), Some("O(log n)"));
+        assert_eq!(skip_list.bounds.space.as_ref().map(ToString::to_string).as_deref(), Some("O(n log n)"));
+
+        let (_, detector) = build_detectors(&overrides, &[], &imported);
+        assert!(detector.data_structures.contains_key("Skip list"));
+        assert!(!detector.data_structures.contains_key("Treap"));
+
+        // Imports are found by name, not by the keywords of their category
+        let found = |code: &str| detector.detect_data_structures(code).into_iter().any(|d| d.name == "Skip list");
+        assert!(found("/// A skip list of scores\nstruct Scores { levels: Vec<Vec<usize>> }"));
+        assert!(!found("let list = vec![1, 2, 3];"));
+    }
+
+    #[test]
+    fn test_validate_entry() {
+        let detector = AlgorithmDetector::new();
+        let mut entry = detector.algorithms["Bubble Sort"].clone();
+        assert_eq!(validate_entry(&entry), Ok(()));
+
+        entry.wikipedia_link = "javascript:alert(1)".to_string();
+        assert_eq!(validate_entry(&entry).unwrap_err().code(), "invalid_entry");
+
+        entry.wikipedia_link.clear();
+        entry.examples = vec![" ".to_string()];
+        assert!(validate_entry(&entry).is_err());
+
+        entry.examples = vec!["swapped = true".to_string()];
+        entry.fingerprints.push(Default::default());
+        assert!(validate_entry(&entry).is_err());
+    }
+
+    #[test]
+    fn test_categories_count_entries() {
+        let detector = AlgorithmDetector::new();
+        let summary = categories(detector.algorithms.values());
+        let total: usize = summary.iter().map(|c| c.entries).sum();
+        assert_eq!(total, detector.algorithms.len());
+        assert!(summary.iter().any(|c| c.name == "DynamicProgramming" && c.label == "Dynamic Programming"));
+    }
+}
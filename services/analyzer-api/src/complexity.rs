@@ -0,0 +1,812 @@
+//! Structured asymptotic complexity
+//!
+//! Catalog entries describe their cost with [`Complexity`]: best, average
+//! and worst-case time, auxiliary space, and for data structures the cost
+//! of individual operations. Each bound is a [`BigO`] parsed from the usual
+//! notation (`O(n log n)`, `O((V + E) log V)`, `O(n²)`, `O(2^n)`).
+//!
+//! Bounds are ordered by growth rate. Every size variable is taken to grow
+//! at the same rate, so `O(V + E)` ranks with `O(n)` and `O(m*n)` with
+//! `O(n²)`; constant factors and log bases are ignored. Bounds that grow
+//! equally fast are ordered by their notation so the order stays total.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+/// One asymptotic bound, e.g. `O(n log n)`
+#[derive(Debug, Clone)]
+pub struct BigO {
+    /// The expression inside `O(...)`, with whitespace collapsed
+    expression: String,
+    growth: Growth,
+}
+
+impl BigO {
+    /// The expression inside `O(...)`, e.g. `n log n`
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    pub fn growth(&self) -> Growth {
+        self.growth
+    }
+
+    /// Whether both bounds grow equally fast, e.g. `O(n + m)` and `O(2n)`
+    pub fn same_growth(&self, other: &BigO) -> bool {
+        self.growth == other.growth
+    }
+}
+
+impl std::fmt::Display for BigO {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "O({})", self.expression)
+    }
+}
+
+impl PartialEq for BigO {
+    fn eq(&self, other: &Self) -> bool {
+        self.expression == other.expression
+    }
+}
+
+impl Eq for BigO {}
+
+impl std::hash::Hash for BigO {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.expression.hash(state);
+    }
+}
+
+impl Ord for BigO {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.growth
+            .cmp(&other.growth)
+            .then_with(|| self.expression.cmp(&other.expression))
+    }
+}
+
+impl PartialOrd for BigO {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::str::FromStr for BigO {
+    type Err = ComplexityError;
+
+    /// Accepts `O(...)`, `Θ(...)`, `Ω(...)` or a bare expression such as `n log n`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |reason: &str| ComplexityError {
+            input: s.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let trimmed = s.trim();
+        let inner = ["O(", "Θ(", "Ω(", "θ(", "Ο("]
+            .iter()
+            .find_map(|prefix| trimmed.strip_prefix(prefix))
+            .map(|rest| rest.strip_suffix(')').ok_or_else(|| error("missing closing parenthesis")))
+            .transpose()?
+            .unwrap_or(trimmed);
+
+        let tokens = tokenize(inner).map_err(|reason| error(&reason))?;
+        if tokens.is_empty() {
+            return Err(error("empty expression"));
+        }
+        let mut parser = Parser { tokens: &tokens, pos: 0, depth: 0 };
+        let expr = parser.sum().map_err(|reason| error(&reason))?;
+        if parser.pos != tokens.len() {
+            return Err(error("unexpected trailing input"));
+        }
+
+        Ok(BigO {
+            expression: inner.split_whitespace().collect::<Vec<_>>().join(" "),
+            growth: expr.growth(),
+        })
+    }
+}
+
+impl Serialize for BigO {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BigO {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Notation that could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplexityError {
+    pub input: String,
+    pub reason: String,
+}
+
+impl ComplexityError {
+    /// Machine-readable error code returned to clients
+    pub fn code(&self) -> &'static str {
+        "invalid_complexity"
+    }
+}
+
+impl std::fmt::Display for ComplexityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Cannot read '{}' as Big-O notation: {}", self.input, self.reason)
+    }
+}
+
+impl std::error::Error for ComplexityError {}
+
+/// Growth rate of a bound, in the form `c^n · n^poly · (log n)^log · (log log n)^loglog`
+///
+/// Factorial and faster growth (`n!`, `n^n`, `2^(n²)`) forms a class of its
+/// own above every exponential.
+#[derive(Debug, Clone, Copy)]
+pub struct Growth {
+    /// Growth is factorial or faster
+    pub super_exponential: bool,
+    /// Base of the exponential factor; `1` when there is none
+    pub base: f64,
+    pub poly: f64,
+    pub log: f64,
+    pub loglog: f64,
+}
+
+impl Growth {
+    const CONSTANT: Growth = Growth {
+        super_exponential: false,
+        base: 1.0,
+        poly: 0.0,
+        log: 0.0,
+        loglog: 0.0,
+    };
+
+    const LINEAR: Growth = Growth {
+        poly: 1.0,
+        ..Growth::CONSTANT
+    };
+
+    const FACTORIAL: Growth = Growth {
+        super_exponential: true,
+        ..Growth::LINEAR
+    };
+
+    fn is_constant(&self) -> bool {
+        *self == Growth::CONSTANT
+    }
+
+    fn times(self, other: Growth) -> Growth {
+        Growth {
+            super_exponential: self.super_exponential || other.super_exponential,
+            base: self.base * other.base,
+            poly: self.poly + other.poly,
+            log: self.log + other.log,
+            loglog: self.loglog + other.loglog,
+        }
+    }
+
+    fn powf(self, exponent: f64) -> Growth {
+        Growth {
+            super_exponential: self.super_exponential,
+            base: self.base.powf(exponent),
+            poly: self.poly * exponent,
+            log: self.log * exponent,
+            loglog: self.loglog * exponent,
+        }
+    }
+
+    /// `log` of a quantity growing like `self`
+    fn log(self) -> Growth {
+        if self.super_exponential {
+            // log n! ~ n log n
+            Growth { log: 1.0, ..Growth::LINEAR }
+        } else if self.base > 1.0 {
+            Growth::LINEAR
+        } else if self.poly > 0.0 {
+            Growth { log: 1.0, ..Growth::CONSTANT }
+        } else if self.log > 0.0 {
+            Growth { loglog: 1.0, ..Growth::CONSTANT }
+        } else {
+            Growth::CONSTANT
+        }
+    }
+
+    fn key(&self) -> [f64; 5] {
+        [
+            if self.super_exponential { 1.0 } else { 0.0 },
+            self.base,
+            self.poly,
+            self.log,
+            self.loglog,
+        ]
+    }
+}
+
+impl PartialEq for Growth {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Growth {}
+
+impl Ord for Growth {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key()
+            .iter()
+            .zip(other.key().iter())
+            .map(|(a, b)| a.total_cmp(b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Growth {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(char),
+    /// A superscript exponent such as `²`
+    Superscript(f64),
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || c == '.' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            let number: String = chars[start..i].iter().collect();
+            tokens.push(Token::Number(number.parse().map_err(|_| format!("bad number '{}'", number))?));
+        } else if c.is_alphabetic() {
+            let start = i;
+            // `n²` is `n` squared, not a variable named `n²`
+            while i < chars.len() && ((chars[i].is_alphanumeric() && superscript_digit(chars[i]).is_none()) || chars[i] == '_') {
+                i += 1;
+            }
+            let ident: String = chars[start..i].iter().collect();
+            // `log2`, `log_2` and `log_b` are all `log`; the base is a constant factor
+            let ident = if ident.starts_with("log") { "log".to_string() } else { ident };
+            tokens.push(Token::Ident(ident));
+        } else if let Some(digit) = superscript_digit(c) {
+            let mut exponent = digit;
+            i += 1;
+            while let Some(digit) = chars.get(i).copied().and_then(superscript_digit) {
+                exponent = exponent * 10.0 + digit;
+                i += 1;
+            }
+            tokens.push(Token::Superscript(exponent));
+        } else {
+            let op = match c {
+                '·' | '×' | '⋅' => '*',
+                '−' => '-',
+                '+' | '-' | '*' | '/' | '^' | '(' | ')' | ',' | '!' | '√' => c,
+                other => return Err(format!("unexpected character '{}'", other)),
+            };
+            tokens.push(Token::Op(op));
+            i += 1;
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn superscript_digit(c: char) -> Option<f64> {
+    let digit = match c {
+        '⁰' => 0,
+        '¹' => 1,
+        '²' => 2,
+        '³' => 3,
+        '⁴' => 4,
+        '⁵' => 5,
+        '⁶' => 6,
+        '⁷' => 7,
+        '⁸' => 8,
+        '⁹' => 9,
+        _ => return None,
+    };
+    Some(digit as f64)
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    Number(f64),
+    Var,
+    Sum(Vec<Expr>),
+    Product(Vec<Expr>),
+    Quotient(Box<Expr>, Box<Expr>),
+    Power(Box<Expr>, Box<Expr>),
+    Log(Box<Expr>),
+    Factorial(Box<Expr>),
+    Min(Vec<Expr>),
+    Max(Vec<Expr>),
+}
+
+impl Expr {
+    fn constant(&self) -> Option<f64> {
+        match self {
+            Expr::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn growth(&self) -> Growth {
+        match self {
+            Expr::Number(_) => Growth::CONSTANT,
+            Expr::Var => Growth::LINEAR,
+            Expr::Sum(terms) | Expr::Max(terms) => terms.iter().map(Expr::growth).max().unwrap_or(Growth::CONSTANT),
+            Expr::Min(terms) => terms.iter().map(Expr::growth).min().unwrap_or(Growth::CONSTANT),
+            Expr::Product(factors) => factors.iter().map(Expr::growth).fold(Growth::CONSTANT, Growth::times),
+            Expr::Quotient(numerator, denominator) => numerator.growth().times(denominator.growth().powf(-1.0)),
+            Expr::Log(argument) => argument.growth().log(),
+            Expr::Factorial(argument) => {
+                if argument.growth().is_constant() {
+                    Growth::CONSTANT
+                } else {
+                    Growth::FACTORIAL
+                }
+            }
+            Expr::Power(base, exponent) => match (base.constant(), exponent.constant()) {
+                (_, Some(exponent)) => base.growth().powf(exponent),
+                (Some(base), None) => {
+                    let exponent = exponent.growth();
+                    if base <= 1.0 || exponent.is_constant() {
+                        Growth::CONSTANT
+                    } else if exponent.super_exponential || exponent.base > 1.0 || exponent.poly > 1.0 {
+                        Growth::FACTORIAL
+                    } else if exponent.poly > 0.0 {
+                        // 2^n, and 2^√n as a slower exponential
+                        Growth { base: base.powf(exponent.poly), ..Growth::CONSTANT }
+                    } else {
+                        // c^(log n) is polynomial
+                        Growth { poly: exponent.log, ..Growth::CONSTANT }
+                    }
+                }
+                // n^n, b^d with both growing
+                (None, None) => Growth::FACTORIAL,
+            },
+        }
+    }
+}
+
+/// Deepest nesting of parentheses, powers and operators accepted, far
+/// beyond any real bound; the parser and the parsed expression recurse
+const MAX_DEPTH: usize = 64;
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    /// Go one level deeper, unless that is deeper than [`MAX_DEPTH`]
+    fn descend(&mut self) -> Result<(), String> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(format!("nested more than {} levels deep", MAX_DEPTH));
+        }
+        Ok(())
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat(&mut self, op: char) -> bool {
+        if self.peek() == Some(&Token::Op(op)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, op: char) -> Result<(), String> {
+        if self.eat(op) {
+            Ok(())
+        } else {
+            Err(format!("expected '{}'", op))
+        }
+    }
+
+    /// `a + b - c`; subtracted terms count like added ones
+    fn sum(&mut self) -> Result<Expr, String> {
+        self.descend()?;
+        let mut terms = vec![self.product()?];
+        while self.eat('+') || self.eat('-') {
+            terms.push(self.product()?);
+        }
+        self.depth -= 1;
+        Ok(if terms.len() == 1 { terms.remove(0) } else { Expr::Sum(terms) })
+    }
+
+    /// `a * b`, `a / b` and juxtaposition as in `n log n`
+    fn product(&mut self) -> Result<Expr, String> {
+        let depth = self.depth;
+        let mut expr = self.power()?;
+        loop {
+            if self.eat('/') {
+                // Each quotient nests the ones before it
+                self.descend()?;
+                expr = Expr::Quotient(Box::new(expr), Box::new(self.power()?));
+            } else if self.eat('*') || self.starts_operand() {
+                let factor = self.power()?;
+                match &mut expr {
+                    Expr::Product(factors) => factors.push(factor),
+                    _ => expr = Expr::Product(vec![expr, factor]),
+                }
+            } else {
+                self.depth = depth;
+                return Ok(expr);
+            }
+        }
+    }
+
+    fn starts_operand(&self) -> bool {
+        matches!(
+            self.peek(),
+            Some(Token::Number(_) | Token::Ident(_) | Token::Op('(') | Token::Op('√'))
+        )
+    }
+
+    /// `a^b`, right-associative, and superscripts as in `n²`
+    fn power(&mut self) -> Result<Expr, String> {
+        self.descend()?;
+        let base = self.postfix()?;
+        let expr = if let Some(Token::Superscript(exponent)) = self.peek() {
+            let exponent = *exponent;
+            self.pos += 1;
+            Expr::Power(Box::new(base), Box::new(Expr::Number(exponent)))
+        } else if self.eat('^') {
+            Expr::Power(Box::new(base), Box::new(self.power()?))
+        } else {
+            base
+        };
+        self.depth -= 1;
+        Ok(expr)
+    }
+
+    /// `n!`
+    fn postfix(&mut self) -> Result<Expr, String> {
+        let depth = self.depth;
+        let mut expr = self.atom()?;
+        while self.eat('!') {
+            self.descend()?;
+            expr = Expr::Factorial(Box::new(expr));
+        }
+        self.depth = depth;
+        Ok(expr)
+    }
+
+    fn atom(&mut self) -> Result<Expr, String> {
+        self.descend()?;
+        let expr = self.operand();
+        self.depth -= 1;
+        expr
+    }
+
+    fn operand(&mut self) -> Result<Expr, String> {
+        let token = self.peek().cloned().ok_or("unexpected end of expression")?;
+        self.pos += 1;
+        match token {
+            Token::Number(value) => Ok(Expr::Number(value)),
+            Token::Op('(') => {
+                let expr = self.sum()?;
+                self.expect(')')?;
+                Ok(expr)
+            }
+            Token::Op('√') => Ok(Expr::Power(Box::new(self.power()?), Box::new(Expr::Number(0.5)))),
+            Token::Ident(name) => match name.as_str() {
+                "log" | "lg" | "ln" => {
+                    // `log^2 n` and `log² n` mean `(log n)^2`
+                    let exponent = if let Some(Token::Superscript(exponent)) = self.peek() {
+                        let exponent = *exponent;
+                        self.pos += 1;
+                        Some(Expr::Number(exponent))
+                    } else if self.eat('^') {
+                        Some(self.atom()?)
+                    } else {
+                        None
+                    };
+                    let log = Expr::Log(Box::new(self.argument()?));
+                    Ok(match exponent {
+                        Some(exponent) => Expr::Power(Box::new(log), Box::new(exponent)),
+                        None => log,
+                    })
+                }
+                "sqrt" => Ok(Expr::Power(Box::new(self.argument()?), Box::new(Expr::Number(0.5)))),
+                "min" | "max" => {
+                    self.expect('(')?;
+                    let mut arguments = vec![self.sum()?];
+                    while self.eat(',') {
+                        arguments.push(self.sum()?);
+                    }
+                    self.expect(')')?;
+                    Ok(if name == "min" { Expr::Min(arguments) } else { Expr::Max(arguments) })
+                }
+                _ => Ok(Expr::Var),
+            },
+            Token::Op(op) => Err(format!("unexpected '{}'", op)),
+            Token::Superscript(_) => Err("superscript without a base".to_string()),
+        }
+    }
+
+    /// Argument of `log` or `sqrt`: parenthesized, or the next power as in `log n²`
+    fn argument(&mut self) -> Result<Expr, String> {
+        self.power()
+    }
+}
+
+/// Time and space bounds of an algorithm or data structure
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub struct Complexity {
+    /// Best-case time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>, example = "O(n)")]
+    pub best: Option<BigO>,
+    /// Average-case time; for data structures, the expected cost per operation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>, example = "O(n log n)")]
+    pub average: Option<BigO>,
+    /// Worst-case time; for data structures, the worst cost of any one operation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>, example = "O(n²)")]
+    pub worst: Option<BigO>,
+    /// Auxiliary space for algorithms, total space for data structures
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>, example = "O(log n)")]
+    pub space: Option<BigO>,
+    /// Expected (average or amortized) cost of each operation, e.g. `insert` or `search`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[schema(value_type = BTreeMap<String, String>)]
+    pub operations: BTreeMap<String, BigO>,
+}
+
+impl Complexity {
+    pub fn is_empty(&self) -> bool {
+        *self == Complexity::default()
+    }
+
+    /// Cost of one operation by name
+    pub fn operation(&self, name: &str) -> Option<&BigO> {
+        self.operations.get(name)
+    }
+
+    /// Read a free-form summary such as `O(log n) average, O(n) worst case`
+    /// or `O(1) push/pop`
+    ///
+    /// Each comma-separated part is a bound followed by what it applies to:
+    /// `best`, `average` (also `expected`, `amortized`), `worst` or `space`,
+    /// otherwise a list of operations separated by `/`. An unqualified bound
+    /// is taken as the worst case. Parts without valid notation are skipped.
+    pub fn from_summary(summary: &str) -> Complexity {
+        let mut complexity = Complexity::default();
+
+        for part in split_top_level(summary) {
+            let Some((bound, qualifier)) = leading_bound(part) else {
+                continue;
+            };
+            let qualifier = qualifier.to_lowercase();
+            let words: Vec<&str> = qualifier
+                .split(|c: char| c.is_whitespace() || c == '-')
+                .filter(|word| !word.is_empty())
+                .collect();
+            let has = |word: &str| words.contains(&word);
+
+            let operations: Vec<String> = words
+                .iter()
+                .filter(|word| !matches!(**word, "best" | "average" | "expected" | "amortized" | "worst" | "case" | "for" | "all" | "operations" | "time"))
+                .copied()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .split('/')
+                .map(|name| operation_name(name.trim()))
+                .filter(|name| !name.is_empty())
+                .collect();
+
+            if has("space") || has("memory") {
+                complexity.space = Some(bound);
+            } else if !operations.is_empty() {
+                for name in operations {
+                    complexity.operations.insert(name, bound.clone());
+                }
+            } else if has("best") {
+                complexity.best = Some(bound);
+            } else if has("average") || has("expected") || has("amortized") {
+                complexity.average = Some(bound);
+            } else {
+                complexity.worst = Some(bound);
+            }
+        }
+
+        complexity
+    }
+}
+
+/// Canonical operation name: `insertion` is `insert`, `find max` is `find_max`
+fn operation_name(name: &str) -> String {
+    let name = match name {
+        "insertion" => "insert",
+        "deletion" | "removal" => "delete",
+        "lookup" => "search",
+        other => other,
+    };
+    name.split_whitespace().collect::<Vec<_>>().join("_")
+}
+
+/// Split on commas that are not inside parentheses
+fn split_top_level(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' | ';' if depth == 0 => {
+                parts.push(&text[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// The `O(...)` a summary part starts with, and the words after it
+fn leading_bound(part: &str) -> Option<(BigO, &str)> {
+    let part = part.trim();
+    let open = part.find('(')?;
+    let mut depth = 0usize;
+    for (i, c) in part.char_indices().skip_while(|(i, _)| *i < open) {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    let bound = part[..=i].parse().ok()?;
+                    return Some((bound, &part[i + 1..]));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn o(text: &str) -> BigO {
+        text.parse().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    #[test]
+    fn test_parses_common_notation() {
+        for text in [
+            "O(1)",
+            "O(n²)",
+            "O(n log n)",
+            "O((V + E) log V)",
+            "O(b^d)",
+            "O(m*n)",
+            "O(log min(a,b))",
+            "O(n log log n)",
+            "O(n!)",
+            "O(√n)",
+            "O(log^2 n)",
+            "Θ(n)",
+            "n / log n",
+        ] {
+            o(text);
+        }
+        assert_eq!(o("O( n   log n )").to_string(), "O(n log n)");
+        assert!("O(n".parse::<BigO>().is_err());
+        assert!("O(n ++)".parse::<BigO>().is_err());
+        assert_eq!("O()".parse::<BigO>().unwrap_err().code(), "invalid_complexity");
+    }
+
+    #[test]
+    fn test_rejects_deep_nesting() {
+        o(&format!("O({}n{})", "(".repeat(15), ")".repeat(15)));
+        o(&format!("O(n{})", "!".repeat(30)));
+        for text in [
+            format!("O({}n{})", "(".repeat(100_000), ")".repeat(100_000)),
+            format!("O({}n)", "n^".repeat(100_000)),
+            format!("O({}n)", "√".repeat(100_000)),
+            format!("O(n{})", "/n".repeat(100_000)),
+            format!("O(n{})", "!".repeat(100_000)),
+            format!("O({}n)", "log^".repeat(100_000)),
+        ] {
+            let error = text.parse::<BigO>().unwrap_err();
+            assert_eq!(error.reason, "nested more than 64 levels deep");
+        }
+        assert!(serde_json::from_value::<BigO>(serde_json::json!(format!("O({}n)", "n^".repeat(100_000)))).is_err());
+        o(&format!("O({})", vec!["n"; 10_000].join(" * ")));
+    }
+
+    #[test]
+    fn test_orders_by_growth() {
+        let ascending = [
+            "O(1)",
+            "O(log log n)",
+            "O(log n)",
+            "O(log² n)",
+            "O(√n)",
+            "O(n / log n)",
+            "O(n)",
+            "O(n log log n)",
+            "O(n log n)",
+            "O(n^1.5)",
+            "O(n²)",
+            "O(n³)",
+            "O(2^n)",
+            "O(3^n)",
+            "O(n!)",
+        ];
+        for pair in ascending.windows(2) {
+            assert!(o(pair[0]) < o(pair[1]), "{} < {}", pair[0], pair[1]);
+        }
+
+        assert!(o("O(V + E)").same_growth(&o("O(n)")));
+        assert!(o("O(m*n)").same_growth(&o("O(n²)")));
+        assert!(o("O(2n + 5)").same_growth(&o("O(n)")));
+        assert!(o("O(log2 n)").same_growth(&o("O(ln n)")));
+        assert!(o("O(log min(a,b))").same_growth(&o("O(log n)")));
+        assert!(o("O(E log E)") < o("O(V²)"));
+        assert_ne!(o("O(V + E)"), o("O(n)"));
+    }
+
+    #[test]
+    fn test_reads_free_form_summaries() {
+        let bst = Complexity::from_summary("O(log n) average, O(n) worst case");
+        assert_eq!(bst.average, Some(o("O(log n)")));
+        assert_eq!(bst.worst, Some(o("O(n)")));
+
+        let heap = Complexity::from_summary("O(1) find max/min, O(log n) insertion/deletion");
+        assert_eq!(heap.operation("find_max"), Some(&o("O(1)")));
+        assert_eq!(heap.operation("insert"), Some(&o("O(log n)")));
+        assert_eq!(heap.operation("delete"), Some(&o("O(log n)")));
+
+        let euclid = Complexity::from_summary("O(log min(a,b))");
+        assert_eq!(euclid.worst, Some(o("O(log min(a,b))")));
+
+        assert!(Complexity::from_summary("Varies by implementation").is_empty());
+    }
+
+    #[test]
+    fn test_serializes_as_notation() {
+        let complexity = Complexity {
+            worst: Some(o("O(n²)")),
+            operations: BTreeMap::from([("push".to_string(), o("O(1)"))]),
+            ..Complexity::default()
+        };
+        let json = serde_json::to_value(&complexity).unwrap();
+        assert_eq!(json, serde_json::json!({"worst": "O(n²)", "operations": {"push": "O(1)"}}));
+        assert_eq!(serde_json::from_value::<Complexity>(json).unwrap(), complexity);
+        assert!(serde_json::from_value::<Complexity>(serde_json::json!({"worst": "O(n"})).is_err());
+    }
+}
@@ -1,20 +1,36 @@
 //! Data structure detection and classification system
 
+use analyzer_types::v2::{CollectionUsage, Relation, TypeDefinition};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use utoipa::ToSchema;
+use crate::algorithms::{mentions, LowercaseNames};
+use crate::complexity::Complexity;
+use crate::limits::AnalysisBudget;
+use crate::syntax::Syntax;
+pub mod collections;
 pub mod data;
+pub mod fit;
+pub mod shapes;
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
 pub struct DataStructureInfo {
     pub name: String,
     pub category: DataStructureCategory,
+    /// Human-readable summary, e.g. `O(1) push/pop`
     pub complexity: String,
+    /// Cost per operation, worst case and space
+    #[serde(default)]
+    pub bounds: Complexity,
     pub description: String,
     pub wikipedia_link: String,
     pub examples: Vec<String>,
+    /// Typed edges to other catalog entries
+    #[serde(default)]
+    pub relations: Vec<Relation>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
 pub enum DataStructureCategory {
     Linear,
     Tree,
@@ -45,12 +61,20 @@ impl std::fmt::Display for DataStructureCategory {
 
 pub struct DataStructureDetector {
     pub data_structures: HashMap<String, DataStructureInfo>,
+    names_lower: LowercaseNames,
+}
+
+impl Default for DataStructureDetector {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DataStructureDetector {
     pub fn new() -> Self {
         let mut detector = Self {
             data_structures: HashMap::new(),
+            names_lower: LowercaseNames::default(),
         };
         detector.initialize_data_structures();
         detector
@@ -66,17 +90,40 @@ impl DataStructureDetector {
     }
 
     pub fn detect_data_structures(&self, code: &str) -> Vec<DetectedDataStructure> {
+        self.detect_data_structures_within(code, &AnalysisBudget::unlimited())
+    }
+
+    /// Detect data structures, stopping early once the budget runs out
+    pub fn detect_data_structures_within(&self, code: &str, budget: &AnalysisBudget) -> Vec<DetectedDataStructure> {
         let mut detected = Vec::new();
         
+        // Normalize the input once instead of once per catalog entry
+        let code_lower = code.to_lowercase();
+        let code_normalized = normalize_whitespace(code);
+        let type_shapes = shapes::type_shapes(code);
+        
         for (name, info) in &self.data_structures {
+            if !budget.check() {
+                break;
+            }
+            
+            // User-defined types shaped like the entry detect it whatever they are called
+            let definitions: Vec<TypeDefinition> = type_shapes
+                .iter()
+                .filter(|shape| shape.data_structure == name)
+                .map(|shape| shape.definition.clone())
+                .collect();
+            
             // Check for data structure patterns in the code
-            if self.pattern_matches(code, info) {
+            if !definitions.is_empty() || self.pattern_matches(code, &code_lower, &code_normalized, info) {
                 detected.push(DetectedDataStructure {
                     name: name.clone(),
                     category: info.category.clone(),
                     complexity: info.complexity.clone(),
                     description: info.description.clone(),
                     wikipedia_link: info.wikipedia_link.clone(),
+                    relations: info.relations.clone(),
+                    definitions,
                 });
             }
         }
@@ -84,16 +131,36 @@ impl DataStructureDetector {
         detected
     }
 
-    fn pattern_matches(&self, code: &str, data_structure_info: &DataStructureInfo) -> bool {
+    /// The `std::collections` types the code uses and the cost of each call
+    /// on them; see [`collections`]. Collections are linked to the catalog
+    /// entry they implement when it is in this catalog.
+    pub fn collection_usage(&self, code: &str, syntax: &Syntax) -> Vec<CollectionUsage> {
+        let mut usages = collections::collection_usage(code, syntax);
+        for usage in &mut usages {
+            usage.data_structure = usage.data_structure.take().filter(|name| self.data_structures.contains_key(name));
+        }
+        usages
+    }
+
+    fn pattern_matches(&self, code: &str, code_lower: &str, code_normalized: &str, data_structure_info: &DataStructureInfo) -> bool {
+        // Wikipedia imports have no examples and are matched on their name
+        // as whole words only; the category keywords below would otherwise
+        // report every imported tree wherever the code says "tree"
+        let names = self.data_structures.values().map(|info| info.name.as_str());
+        let name_lower = self.names_lower.get(names, &data_structure_info.name);
+        if data_structure_info.examples.is_empty() {
+            return mentions(code_lower, &name_lower);
+        }
+        
         // Check if any of the examples are found in the code
         for example in &data_structure_info.examples {
-            if code.contains(example) || self.fuzzy_match(code, example) {
+            if code.contains(example) || self.fuzzy_match(code_normalized, example) {
                 return true;
             }
         }
         
         // Check for data structure name in the code
-        if code.to_lowercase().contains(&data_structure_info.name.to_lowercase()) {
+        if code_lower.contains(name_lower.as_ref()) {
             return true;
         }
         
@@ -111,7 +178,7 @@ impl DataStructureDetector {
         };
         
         for keyword in category_keywords {
-            if code.to_lowercase().contains(keyword) {
+            if code_lower.contains(keyword) {
                 return true;
             }
         }
@@ -119,12 +186,9 @@ impl DataStructureDetector {
         false
     }
 
-    fn fuzzy_match(&self, code: &str, pattern: &str) -> bool {
+    fn fuzzy_match(&self, code_normalized: &str, pattern: &str) -> bool {
         // Simple fuzzy matching - could be enhanced with more sophisticated algorithms
-        let code_normalized = code.replace(" ", "").replace("\n", "").replace("\t", "");
-        let pattern_normalized = pattern.replace(" ", "").replace("\n", "").replace("\t", "");
-        
-        code_normalized.contains(&pattern_normalized)
+        code_normalized.contains(&normalize_whitespace(pattern))
     }
     
     pub fn get_data_structures_by_category(&self, category: DataStructureCategory) -> Vec<&DataStructureInfo> {
@@ -149,11 +213,20 @@ impl DataStructureDetector {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Strip spaces, tabs and newlines so examples match regardless of formatting
+fn normalize_whitespace(text: &str) -> String {
+    text.chars().filter(|c| !matches!(c, ' ' | '\n' | '\t')).collect()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DetectedDataStructure {
     pub name: String,
     pub category: DataStructureCategory,
     pub complexity: String,
     pub description: String,
     pub wikipedia_link: String,
+    pub relations: Vec<Relation>,
+    /// Types in the code whose shape matched, see [`shapes`]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub definitions: Vec<TypeDefinition>,
 }
\ No newline at end of file
@@ -0,0 +1,572 @@
+//! Which `std::collections` types the code uses, and how
+//!
+//! Every collection the code creates (`Vec::new()`, `vec![]`,
+//! `collect::<HashSet<_>>()`, a `let` annotated with a collection type),
+//! takes as a parameter or declares as a struct field is reported with the
+//! methods called on it. Each call carries the cost the standard library
+//! documents for it, so `queue.remove(0)` on a `Vec` reads `O(n)` where the
+//! same call on a `VecDeque` reads `O(1)`.
+//!
+//! Calls inside a `for`, `while` or `loop` body, or inside a closure passed
+//! to a per-element method such as `filter`, are marked as run in a loop.
+//!
+//! Calls are attributed by name: to the variable of the enclosing function,
+//! or to `self.field` for fields. Input that does not parse as a file is
+//! retried as a block of statements, then function by function.
+
+use analyzer_types::v2::{CollectionOperation, CollectionUsage, SourceSpan};
+use std::collections::HashMap;
+use std::ops::Range;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{Expr, ExprMethodCall, Fields, FnArg, GenericArgument, Lit, Member, Pat, PathArguments, Signature, Type};
+
+use super::shapes::source_span;
+use crate::syntax::Syntax;
+
+/// The collections of `std::collections` that are tracked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Collection {
+    Vec,
+    VecDeque,
+    LinkedList,
+    HashMap,
+    BTreeMap,
+    HashSet,
+    BTreeSet,
+    BinaryHeap,
+}
+
+const AMORTIZED: Option<&str> = Some("amortized");
+const EXPECTED: Option<&str> = Some("expected");
+
+/// Associated functions that create a collection
+const CONSTRUCTORS: [&str; 6] = ["new", "with_capacity", "from", "from_iter", "default", "new_in"];
+
+/// Methods whose closure arguments run once per element
+pub(crate) const PER_ELEMENT: [&str; 16] = [
+    "map", "filter", "filter_map", "flat_map", "for_each", "any", "all", "find", "find_map", "position", "fold",
+    "take_while", "skip_while", "inspect", "retain", "sort_by_key",
+];
+
+impl Collection {
+    pub const ALL: [Collection; 8] = [
+        Collection::Vec,
+        Collection::VecDeque,
+        Collection::LinkedList,
+        Collection::HashMap,
+        Collection::BTreeMap,
+        Collection::HashSet,
+        Collection::BTreeSet,
+        Collection::BinaryHeap,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Collection::Vec => "Vec",
+            Collection::VecDeque => "VecDeque",
+            Collection::LinkedList => "LinkedList",
+            Collection::HashMap => "HashMap",
+            Collection::BTreeMap => "BTreeMap",
+            Collection::HashSet => "HashSet",
+            Collection::BTreeSet => "BTreeSet",
+            Collection::BinaryHeap => "BinaryHeap",
+        }
+    }
+
+    pub fn named(name: &str) -> Option<Collection> {
+        Collection::ALL.into_iter().find(|collection| collection.as_str() == name)
+    }
+
+    /// The catalog entry the collection implements
+    pub fn data_structure(&self) -> Option<&'static str> {
+        match self {
+            Collection::Vec => Some("Dynamic Array"),
+            Collection::VecDeque => Some("Queue"),
+            Collection::LinkedList => Some("Linked List"),
+            Collection::HashMap => Some("Hash Table"),
+            Collection::HashSet => Some("HashSet"),
+            Collection::BinaryHeap => Some("Binary Heap"),
+            Collection::BTreeMap | Collection::BTreeSet => None,
+        }
+    }
+
+    /// Documented cost of an operation, and whether it is amortized or
+    /// expected; `None` for methods that are not tracked
+    pub fn cost(&self, operation: &str) -> Option<(&'static str, Option<&'static str>)> {
+        use Collection::*;
+
+        let cost = match (self, operation) {
+            (Vec, "push") => ("O(1)", AMORTIZED),
+            (Vec, "pop" | "swap_remove" | "get" | "get_mut" | "first" | "last") => ("O(1)", None),
+            (Vec, "insert" | "remove" | "remove(0)" | "contains" | "retain" | "dedup" | "drain" | "reverse" | "split_off") => ("O(n)", None),
+            (Vec, "sort" | "sort_by" | "sort_by_key" | "sort_unstable" | "sort_unstable_by" | "sort_unstable_by_key") => ("O(n log n)", None),
+            (Vec | VecDeque, "binary_search" | "binary_search_by" | "binary_search_by_key") => ("O(log n)", None),
+            (Vec | VecDeque, "append" | "extend") => ("O(m)", AMORTIZED),
+
+            (VecDeque, "push_back" | "push_front") => ("O(1)", AMORTIZED),
+            (VecDeque, "pop_back" | "pop_front" | "remove(0)" | "get" | "get_mut" | "front" | "back" | "swap_remove_back" | "swap_remove_front") => ("O(1)", None),
+            (VecDeque, "insert" | "remove" | "contains" | "retain" | "drain" | "make_contiguous") => ("O(n)", None),
+
+            (LinkedList, "push_back" | "push_front" | "pop_back" | "pop_front" | "front" | "back" | "append") => ("O(1)", None),
+            (LinkedList, "contains" | "split_off") => ("O(n)", None),
+
+            (HashMap, "insert" | "get" | "get_mut" | "remove" | "contains_key" | "entry") => ("O(1)", EXPECTED),
+            (HashSet, "insert" | "get" | "remove" | "contains" | "take" | "replace") => ("O(1)", EXPECTED),
+            (BTreeMap, "insert" | "get" | "get_mut" | "remove" | "contains_key" | "entry" | "range" | "first_key_value" | "last_key_value" | "pop_first" | "pop_last") => ("O(log n)", None),
+            (BTreeSet, "insert" | "get" | "remove" | "contains" | "range" | "first" | "last" | "pop_first" | "pop_last") => ("O(log n)", None),
+            (HashMap | HashSet | BTreeMap | BTreeSet, "retain") => ("O(n)", None),
+
+            (BinaryHeap, "push") => ("O(1)", EXPECTED),
+            (BinaryHeap, "pop") => ("O(log n)", None),
+            (BinaryHeap, "peek") => ("O(1)", None),
+            (BinaryHeap, "into_sorted_vec") => ("O(n log n)", None),
+            (BinaryHeap, "append") => ("O(n + m)", None),
+            (BinaryHeap, "retain") => ("O(n)", None),
+            (_, "clone") => ("O(n)", None),
+            _ => return None,
+        };
+        Some(cost)
+    }
+}
+
+/// The collections of the code, in source order; `syntax` is the code parsed
+pub fn collection_usage(code: &str, syntax: &Syntax) -> Vec<CollectionUsage> {
+    let mut usage = Usage {
+        code,
+        base: 0,
+        prefix: 0,
+        bindings: HashMap::new(),
+        loops: 0,
+        usages: Vec::new(),
+    };
+
+    match syntax {
+        Syntax::File(file) => {
+            usage.declare_fields(file);
+            usage.visit_file(file);
+        }
+        Syntax::Block(block) => {
+            usage.prefix = 1;
+            usage.declare_fields(block);
+            usage.visit_block(block);
+        }
+        Syntax::Functions(functions) => {
+            for (start, item) in functions {
+                usage.base = *start;
+                usage.bindings.retain(|name, _| name.starts_with("self."));
+                usage.visit_item_fn(item);
+            }
+        }
+    }
+
+    let mut usages = usage.usages;
+    usages.sort_by_key(|usage| (usage.span.start_line, usage.span.start_column));
+    usages
+}
+
+struct Usage<'a> {
+    code: &'a str,
+    /// Offset in `code` of the text being visited
+    base: usize,
+    /// Bytes added in front of that text so it parses
+    prefix: usize,
+    /// Variables, and `self.`-prefixed fields, to their index in `usages`
+    bindings: HashMap<String, usize>,
+    /// Loops and per-element closures around the expression being visited
+    loops: usize,
+    usages: Vec<CollectionUsage>,
+}
+
+impl Usage<'_> {
+    fn span(&self, range: Range<usize>) -> SourceSpan {
+        let at = |offset: usize| (self.base + offset).saturating_sub(self.prefix).min(self.code.len());
+        let start = at(range.start);
+        source_span(self.code, start..at(range.end).max(start + 1).min(self.code.len()))
+    }
+
+    fn record(&mut self, collection: Collection, binding: Option<String>, range: Range<usize>) {
+        let span = self.span(range);
+        if let Some(binding) = &binding {
+            self.bindings.insert(binding.clone(), self.usages.len());
+        }
+        self.usages.push(CollectionUsage {
+            collection: collection.as_str().to_string(),
+            binding,
+            data_structure: collection.data_structure().map(str::to_string),
+            span,
+            operations: Vec::new(),
+        });
+    }
+
+    /// Struct fields of collection types, before the methods that use them
+    fn declare_fields<'ast>(&mut self, node: &'ast impl Traverse<'ast>) {
+        struct Declarations<'u, 'a>(&'u mut Usage<'a>);
+
+        impl<'ast> Visit<'ast> for Declarations<'_, '_> {
+            fn visit_item_struct(&mut self, item: &'ast syn::ItemStruct) {
+                if let Fields::Named(fields) = &item.fields {
+                    for field in &fields.named {
+                        if let (Some(ident), Some(collection)) = (&field.ident, type_collection(&field.ty)) {
+                            self.0.record(collection, Some(format!("self.{}", ident)), field.span().byte_range());
+                        }
+                    }
+                }
+                visit::visit_item_struct(self, item);
+            }
+        }
+
+        node.traverse(&mut Declarations(self));
+    }
+
+    fn declare_parameters(&mut self, signature: &Signature) {
+        for input in &signature.inputs {
+            if let FnArg::Typed(parameter) = input {
+                if let (Some(name), Some(collection)) = (pattern_name(&parameter.pat), type_collection(&parameter.ty)) {
+                    self.record(collection, Some(name), parameter.span().byte_range());
+                }
+            }
+        }
+    }
+
+    /// The usage a method receiver refers to
+    fn receiver(&self, expr: &Expr) -> Option<usize> {
+        match expr {
+            Expr::Paren(paren) => self.receiver(&paren.expr),
+            Expr::Reference(reference) => self.receiver(&reference.expr),
+            _ => self.bindings.get(&binding_name(expr)?).copied(),
+        }
+    }
+
+    /// Run `visit` with the function's own variables, then drop them
+    fn in_function(&mut self, signature: &Signature, visit: impl FnOnce(&mut Self)) {
+        let outer = self.bindings.clone();
+        let loops = std::mem::take(&mut self.loops);
+        self.declare_parameters(signature);
+        visit(self);
+        self.bindings = outer;
+        self.loops = loops;
+    }
+
+    /// Run `visit` as the body of one more loop
+    fn in_loop(&mut self, visit: impl FnOnce(&mut Self)) {
+        self.loops += 1;
+        visit(self);
+        self.loops -= 1;
+    }
+}
+
+impl<'ast> Visit<'ast> for Usage<'_> {
+    fn visit_item_fn(&mut self, item: &'ast syn::ItemFn) {
+        self.in_function(&item.sig, |usage| visit::visit_item_fn(usage, item));
+    }
+
+    fn visit_impl_item_fn(&mut self, item: &'ast syn::ImplItemFn) {
+        self.in_function(&item.sig, |usage| visit::visit_impl_item_fn(usage, item));
+    }
+
+    fn visit_local(&mut self, local: &'ast syn::Local) {
+        let (pat, annotation) = match &local.pat {
+            Pat::Type(typed) => (&*typed.pat, type_collection(&typed.ty)),
+            pat => (pat, None),
+        };
+        let init = local.init.as_ref().map(|init| &*init.expr);
+        let collection = annotation.or_else(|| init.and_then(constructor));
+
+        match (collection, pattern_name(pat)) {
+            (Some(collection), Some(name)) => {
+                let range = init.map_or_else(|| local.span(), |init| init.span()).byte_range();
+                self.record(collection, Some(name), range);
+                // The initializer is recorded; only what is inside it is left
+                if let Some(init) = init {
+                    visit::visit_expr(self, init);
+                }
+                if let Some(diverge) = local.init.as_ref().and_then(|init| init.diverge.as_ref()) {
+                    self.visit_expr(&diverge.1);
+                }
+            }
+            _ => visit::visit_local(self, local),
+        }
+    }
+
+    fn visit_expr_assign(&mut self, assign: &'ast syn::ExprAssign) {
+        match (constructor(&assign.right), binding_name(&assign.left)) {
+            (Some(collection), Some(name)) => {
+                self.record(collection, Some(name), assign.right.span().byte_range());
+                visit::visit_expr(self, &assign.right);
+            }
+            _ => visit::visit_expr_assign(self, assign),
+        }
+    }
+
+    fn visit_expr_struct(&mut self, expr: &'ast syn::ExprStruct) {
+        for field in &expr.fields {
+            // Initializing a declared field creates nothing new
+            let declared = match &field.member {
+                Member::Named(ident) => self.bindings.contains_key(&format!("self.{}", ident)),
+                Member::Unnamed(_) => false,
+            };
+            if declared && constructor(&field.expr).is_some() {
+                visit::visit_expr(self, &field.expr);
+            } else {
+                self.visit_expr(&field.expr);
+            }
+        }
+        if let Some(rest) = &expr.rest {
+            self.visit_expr(rest);
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        if let Some(collection) = constructor(expr) {
+            self.record(collection, None, expr.span().byte_range());
+        }
+        visit::visit_expr(self, expr);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        if let Some(index) = self.receiver(&call.receiver) {
+            let operation = operation_name(call);
+            let collection = Collection::named(&self.usages[index].collection);
+            if let Some((cost, note)) = collection.and_then(|collection| collection.cost(&operation)) {
+                let span = self.span(call.method.span().byte_range().start..call.span().byte_range().end);
+                self.usages[index].operations.push(CollectionOperation {
+                    operation,
+                    cost: cost.to_string(),
+                    note: note.map(str::to_string),
+                    in_loop: self.loops > 0,
+                    span,
+                });
+            }
+        }
+        self.visit_expr(&call.receiver);
+        let per_element = PER_ELEMENT.iter().any(|name| call.method == name);
+        for arg in &call.args {
+            if per_element && matches!(arg, Expr::Closure(_)) {
+                self.in_loop(|usage| usage.visit_expr(arg));
+            } else {
+                self.visit_expr(arg);
+            }
+        }
+    }
+
+    fn visit_expr_for_loop(&mut self, expr: &'ast syn::ExprForLoop) {
+        // The iterated expression is evaluated once
+        self.visit_expr(&expr.expr);
+        self.in_loop(|usage| {
+            usage.visit_pat(&expr.pat);
+            usage.visit_block(&expr.body);
+        });
+    }
+
+    fn visit_expr_while(&mut self, expr: &'ast syn::ExprWhile) {
+        self.in_loop(|usage| visit::visit_expr_while(usage, expr));
+    }
+
+    fn visit_expr_loop(&mut self, expr: &'ast syn::ExprLoop) {
+        self.in_loop(|usage| visit::visit_expr_loop(usage, expr));
+    }
+}
+
+/// A parsed file or block whose struct definitions can be walked
+trait Traverse<'ast> {
+    fn traverse(&'ast self, visitor: &mut impl Visit<'ast>);
+}
+
+impl<'ast> Traverse<'ast> for syn::File {
+    fn traverse(&'ast self, visitor: &mut impl Visit<'ast>) {
+        visitor.visit_file(self);
+    }
+}
+
+impl<'ast> Traverse<'ast> for syn::Block {
+    fn traverse(&'ast self, visitor: &mut impl Visit<'ast>) {
+        visitor.visit_block(self);
+    }
+}
+
+/// The method as reported: its name, or `remove(0)` for removing the
+/// first element
+fn operation_name(call: &ExprMethodCall) -> String {
+    let first_index = call.args.len() == 1
+        && matches!(&call.args[0], Expr::Lit(literal) if matches!(&literal.lit, Lit::Int(int) if int.base10_digits() == "0"));
+    if call.method == "remove" && first_index {
+        "remove(0)".to_string()
+    } else {
+        call.method.to_string()
+    }
+}
+
+/// The collection an expression creates, if it visibly creates one
+fn constructor(expr: &Expr) -> Option<Collection> {
+    match expr {
+        Expr::Call(call) => {
+            let Expr::Path(path) = &*call.func else {
+                return None;
+            };
+            let segments: Vec<_> = path.path.segments.iter().collect();
+            match segments.as_slice() {
+                [.., collection, function] if CONSTRUCTORS.iter().any(|name| function.ident == name) => {
+                    Collection::named(&collection.ident.to_string())
+                }
+                _ => None,
+            }
+        }
+        Expr::Macro(macro_call) if macro_call.mac.path.is_ident("vec") => Some(Collection::Vec),
+        Expr::MethodCall(call) if call.method == "to_vec" => Some(Collection::Vec),
+        Expr::MethodCall(call) if call.method == "collect" => {
+            let turbofish = call.turbofish.as_ref()?;
+            turbofish.args.iter().find_map(|arg| match arg {
+                GenericArgument::Type(ty) => type_collection(ty),
+                _ => None,
+            })
+        }
+        Expr::Paren(paren) => constructor(&paren.expr),
+        _ => None,
+    }
+}
+
+/// The collection a type names, looking through references
+fn type_collection(ty: &Type) -> Option<Collection> {
+    match ty {
+        Type::Reference(reference) => type_collection(&reference.elem),
+        Type::Paren(paren) => type_collection(&paren.elem),
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+            if !matches!(segment.arguments, PathArguments::AngleBracketed(_) | PathArguments::None) {
+                return None;
+            }
+            Collection::named(&segment.ident.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// `name` for a variable, `self.name` for a field of `self`
+pub(crate) fn binding_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Path(path) => path.path.get_ident().map(|ident| ident.to_string()),
+        Expr::Field(field) => match (&*field.base, &field.member) {
+            (Expr::Path(base), Member::Named(ident)) if base.path.is_ident("self") => Some(format!("self.{}", ident)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn pattern_name(pat: &Pat) -> Option<String> {
+    match pat {
+        Pat::Ident(ident) => Some(ident.ident.to_string()),
+        Pat::Type(typed) => pattern_name(&typed.pat),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `collection binding: operation cost, ...` per usage
+    fn summary(code: &str) -> Vec<String> {
+        collection_usage(code, &Syntax::parse(code))
+            .into_iter()
+            .map(|usage| {
+                let operations: Vec<String> = usage
+                    .operations
+                    .iter()
+                    .map(|op| match &op.note {
+                        Some(note) => format!("{} {} {}", op.operation, op.cost, note),
+                        None => format!("{} {}", op.operation, op.cost),
+                    })
+                    .collect();
+                format!("{} {}: {}", usage.collection, usage.binding.unwrap_or_default(), operations.join(", "))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_reports_operations_with_costs() {
+        let code = r#"
+use std::collections::{BTreeMap, BinaryHeap, HashSet, VecDeque};
+
+fn process(jobs: &mut Vec<u32>, seen: &HashSet<u32>) -> u32 {
+    let mut queue = VecDeque::new();
+    let mut heap: BinaryHeap<u32> = BinaryHeap::with_capacity(8);
+    let index: BTreeMap<u32, usize> = jobs.iter().copied().zip(0..).collect();
+    while !jobs.is_empty() {
+        let job = jobs.remove(0);
+        if !seen.contains(&job) && !jobs.contains(&job) {
+            queue.push_back(job);
+            heap.push(job);
+        }
+    }
+    jobs.sort();
+    queue.remove(0);
+    index.get(&1);
+    heap.pop().unwrap_or_default()
+}
+"#;
+        assert_eq!(
+            summary(code),
+            [
+                "Vec jobs: remove(0) O(n), contains O(n), sort O(n log n)",
+                "HashSet seen: contains O(1) expected",
+                "VecDeque queue: push_back O(1) amortized, remove(0) O(1)",
+                "BinaryHeap heap: push O(1) expected, pop O(log n)",
+                "BTreeMap index: get O(log n)",
+            ]
+        );
+        let jobs: Vec<bool> = collection_usage(code, &Syntax::parse(code))[0].operations.iter().map(|op| op.in_loop).collect();
+        assert_eq!(jobs, [true, true, false]);
+    }
+
+    #[test]
+    fn test_fields_temporaries_and_snippets() {
+        let code = r#"
+struct Cache { keys: Vec<String>, hits: HashMap<String, u32> }
+
+impl Cache {
+    fn new() -> Self {
+        Cache { keys: Vec::new(), hits: HashMap::new() }
+    }
+
+    fn touch(&mut self, key: String) {
+        if !self.keys.contains(&key) {
+            self.keys.insert(0, key.clone());
+        }
+        *self.hits.entry(key).or_default() += 1;
+        let sorted = self.keys.iter().cloned().collect::<BTreeSet<_>>();
+        sorted.first();
+    }
+}
+"#;
+        assert_eq!(
+            summary(code),
+            [
+                "Vec self.keys: contains O(n), insert O(n)",
+                "HashMap self.hits: entry O(1) expected",
+                "BTreeSet sorted: first O(log n)",
+            ]
+        );
+
+        // Statements outside any function, which do not parse as a file
+        let snippet = "let mut v = vec![3, 1, 2];\nv.sort_unstable();\nlet total: i32 = v.iter().sum();\nreport(HashSet::<i32>::from_iter(v.clone()));\n";
+        let usages = collection_usage(snippet, &Syntax::parse(snippet));
+        assert_eq!(summary(snippet), ["Vec v: sort_unstable O(n log n), clone O(n)", "HashSet : "]);
+        assert_eq!(usages[0].span, SourceSpan { start_line: 1, start_column: 13, end_line: 1, end_column: 25 });
+        assert_eq!(usages[0].operations[0].span, SourceSpan { start_line: 2, start_column: 3, end_line: 2, end_column: 17 });
+    }
+
+    /// Parsing this aborted the process with a stack overflow
+    #[test]
+    fn test_deeply_nested_code_is_skipped() {
+        let code = format!("fn f() {{ let x = {}1{}; }}", "(".repeat(1000), ")".repeat(1000));
+        assert!(collection_usage(&code, &Syntax::parse(&code)).is_empty());
+
+        let code = format!(
+            "fn deep() {{ let x = {}1; }}\nfn shallow(v: Vec<u32>) {{ v.contains(&1); }}\nfn broken( {{",
+            "-".repeat(5000)
+        );
+        assert_eq!(summary(&code), ["Vec v: contains O(n)"]);
+    }
+}
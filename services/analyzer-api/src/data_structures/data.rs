@@ -1,6 +1,22 @@
 //! Comprehensive data structure database
 
 use super::{DataStructureInfo, DataStructureCategory};
+use crate::complexity::{BigO, Complexity};
+use analyzer_types::v2::{Relation, RelationKind};
+
+fn big_o(notation: &str) -> BigO {
+    notation.parse().unwrap_or_else(|e| panic!("built-in catalog: {}", e))
+}
+
+/// Expected cost per operation, the worst cost of any operation, and space
+fn operations(costs: &[(&str, &str)], worst: &str, space: &str) -> Complexity {
+    Complexity {
+        worst: Some(big_o(worst)),
+        space: Some(big_o(space)),
+        operations: costs.iter().map(|(name, cost)| (name.to_string(), big_o(cost))).collect(),
+        ..Complexity::default()
+    }
+}
 
 /// Returns a comprehensive list of data structures with detailed information
 pub fn get_data_structures_database() -> Vec<DataStructureInfo> {
@@ -10,28 +26,49 @@ pub fn get_data_structures_database() -> Vec<DataStructureInfo> {
             name: "Array".to_string(),
             category: DataStructureCategory::Linear,
             complexity: "O(1) access, O(n) search".to_string(),
+            bounds: operations(&[("access", "O(1)"), ("search", "O(n)")], "O(n)", "O(n)"),
             description: "A collection of elements identified by array index or key, stored in contiguous memory locations.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Array_data_structure".to_string(),
             examples: vec![
                 "let arr = [1, 2, 3, 4, 5];".to_string(),
                 "arr[0] = 10;".to_string(),
             ],
+            relations: Vec::new(),
         },
         DataStructureInfo {
             name: "Linked List".to_string(),
             category: DataStructureCategory::Linear,
             complexity: "O(n) access, O(1) insertion/deletion".to_string(),
+            bounds: operations(&[("access", "O(n)"), ("search", "O(n)"), ("insert", "O(1)"), ("delete", "O(1)")], "O(n)", "O(n)"),
             description: "A linear collection of data elements whose order is not given by their physical placement in memory. Instead, each element points to the next.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Linked_list".to_string(),
             examples: vec![
                 "struct ListNode { val: i32, next: Option<Box<ListNode>> }".to_string(),
                 "list.next = Some(Box::new(ListNode::new(val)));".to_string(),
             ],
+            relations: vec![
+                Relation::data_structure(RelationKind::AlternativeTo, "Dynamic Array"),
+            ],
+        },
+        DataStructureInfo {
+            name: "Doubly Linked List".to_string(),
+            category: DataStructureCategory::Linear,
+            complexity: "O(n) access, O(1) insertion/deletion at a known node".to_string(),
+            bounds: operations(&[("access", "O(n)"), ("search", "O(n)"), ("insert", "O(1)"), ("delete", "O(1)")], "O(n)", "O(n)"),
+            description: "A linked list whose nodes point to both the next and the previous node, so it can be walked and spliced in either direction.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Doubly_linked_list".to_string(),
+            examples: vec![
+                "struct Node<T> { value: T, next: Option<Rc<RefCell<Node<T>>>>, prev: Option<Weak<RefCell<Node<T>>>> }".to_string(),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::VariantOf, "Linked List"),
+            ],
         },
         DataStructureInfo {
             name: "Dynamic Array".to_string(),
             category: DataStructureCategory::Linear,
             complexity: "O(1) amortized insertion, O(1) access".to_string(),
+            bounds: operations(&[("access", "O(1)"), ("push", "O(1)"), ("pop", "O(1)"), ("insert", "O(n)"), ("delete", "O(n)"), ("search", "O(n)")], "O(n)", "O(n)"),
             description: "A resizable array that allows elements to be added or removed, automatically managing its size.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Dynamic_array".to_string(),
             examples: vec![
@@ -39,29 +76,84 @@ pub fn get_data_structures_database() -> Vec<DataStructureInfo> {
                 "vec.push(1);".to_string(),
                 "vec![1, 2, 3];".to_string(),
             ],
+            relations: vec![
+                Relation::data_structure(RelationKind::VariantOf, "Array"),
+                Relation::data_structure(RelationKind::AlternativeTo, "Linked List"),
+            ],
         },
 
         // Tree Data Structures
+        DataStructureInfo {
+            name: "Tree".to_string(),
+            category: DataStructureCategory::Tree,
+            complexity: "O(n) search, O(1) insertion under a known node".to_string(),
+            bounds: operations(&[("search", "O(n)"), ("insert", "O(1)"), ("traverse", "O(n)")], "O(n)", "O(n)"),
+            description: "A hierarchy of nodes in which every node except the root has exactly one parent and any number of children.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Tree_(abstract_data_type)".to_string(),
+            examples: vec![
+                "struct TreeNode { value: i32, children: Vec<TreeNode> }".to_string(),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::VariantOf, "Graph"),
+            ],
+        },
+        DataStructureInfo {
+            name: "Binary Tree".to_string(),
+            category: DataStructureCategory::Tree,
+            complexity: "O(n) search, O(1) insertion under a known node".to_string(),
+            bounds: operations(&[("search", "O(n)"), ("insert", "O(1)"), ("traverse", "O(n)")], "O(n)", "O(n)"),
+            description: "A tree in which every node has at most two children, referred to as the left child and the right child.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Binary_tree".to_string(),
+            examples: vec![
+                "struct TreeNode { val: i32, left: Option<Box<TreeNode>>, right: Option<Box<TreeNode>> }".to_string(),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::VariantOf, "Tree"),
+            ],
+        },
         DataStructureInfo {
             name: "Binary Search Tree".to_string(),
             category: DataStructureCategory::Tree,
             complexity: "O(log n) average, O(n) worst case".to_string(),
+            bounds: operations(&[("search", "O(log n)"), ("insert", "O(log n)"), ("delete", "O(log n)")], "O(n)", "O(n)"),
             description: "A tree data structure where each node has at most two children, and the left subtree contains only nodes with keys less than the node's key.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Binary_search_tree".to_string(),
             examples: vec![
                 "struct BST { root: Option<Box<Node>> }".to_string(),
                 "fn insert(&mut self, value: i32) { /* recursive insertion */ }".to_string(),
             ],
+            relations: vec![
+                Relation::data_structure(RelationKind::VariantOf, "Binary Tree"),
+                Relation::algorithm(RelationKind::Prerequisite, "Binary Search"),
+            ],
         },
         DataStructureInfo {
             name: "AVL Tree".to_string(),
             category: DataStructureCategory::Tree,
             complexity: "O(log n) for all operations".to_string(),
+            bounds: operations(&[("search", "O(log n)"), ("insert", "O(log n)"), ("delete", "O(log n)")], "O(log n)", "O(n)"),
             description: "A self-balancing binary search tree where the difference between heights of left and right subtrees cannot be more than one for all nodes.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/AVL_tree".to_string(),
             examples: vec![
                 "struct AVLNode { value: i32, height: i32, left: Option<Box<AVLNode>>, right: Option<Box<AVLNode>> }".to_string(),
             ],
+            relations: vec![
+                Relation::data_structure(RelationKind::VariantOf, "Binary Search Tree"),
+            ],
+        },
+        DataStructureInfo {
+            name: "Trie".to_string(),
+            category: DataStructureCategory::Tree,
+            complexity: "O(m) search/insert/delete for a key of length m".to_string(),
+            bounds: operations(&[("search", "O(m)"), ("insert", "O(m)"), ("delete", "O(m)")], "O(m)", "O(n)"),
+            description: "A search tree over strings in which every edge is labelled with a character, so a key is spelled by the path from the root to its node.".to_string(),
+            wikipedia_link: "https://en.wikipedia.org/wiki/Trie".to_string(),
+            examples: vec![
+                "struct TrieNode { children: HashMap<char, TrieNode>, is_end: bool }".to_string(),
+            ],
+            relations: vec![
+                Relation::data_structure(RelationKind::VariantOf, "Tree"),
+            ],
         },
 
         // Hash-Based Data Structures
@@ -69,6 +161,7 @@ pub fn get_data_structures_database() -> Vec<DataStructureInfo> {
             name: "Hash Table".to_string(),
             category: DataStructureCategory::HashBased,
             complexity: "O(1) average case for search/insert/delete".to_string(),
+            bounds: operations(&[("search", "O(1)"), ("insert", "O(1)"), ("delete", "O(1)")], "O(n)", "O(n)"),
             description: "A data structure that implements an associative array, mapping keys to values using a hash function.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Hash_table".to_string(),
             examples: vec![
@@ -76,11 +169,16 @@ pub fn get_data_structures_database() -> Vec<DataStructureInfo> {
                 "let mut map = HashMap::new();".to_string(),
                 "map.insert(key, value);".to_string(),
             ],
+            relations: vec![
+                Relation::algorithm(RelationKind::Uses, "Hash Table"),
+                Relation::data_structure(RelationKind::Uses, "Dynamic Array"),
+            ],
         },
         DataStructureInfo {
             name: "HashSet".to_string(),
             category: DataStructureCategory::HashBased,
             complexity: "O(1) average case for insert/contains/remove".to_string(),
+            bounds: operations(&[("contains", "O(1)"), ("insert", "O(1)"), ("delete", "O(1)")], "O(n)", "O(n)"),
             description: "A set implementation that uses a hash table for storage, providing fast lookup times.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Set_(abstract_data_type)".to_string(),
             examples: vec![
@@ -88,6 +186,9 @@ pub fn get_data_structures_database() -> Vec<DataStructureInfo> {
                 "let mut set = HashSet::new();".to_string(),
                 "set.insert(value);".to_string(),
             ],
+            relations: vec![
+                Relation::data_structure(RelationKind::VariantOf, "Hash Table"),
+            ],
         },
 
         // Heap Data Structures
@@ -95,6 +196,7 @@ pub fn get_data_structures_database() -> Vec<DataStructureInfo> {
             name: "Binary Heap".to_string(),
             category: DataStructureCategory::Heap,
             complexity: "O(1) find max/min, O(log n) insertion/deletion".to_string(),
+            bounds: operations(&[("find_min", "O(1)"), ("insert", "O(log n)"), ("delete_min", "O(log n)"), ("search", "O(n)")], "O(n)", "O(n)"),
             description: "A complete binary tree that satisfies the heap property, where parents are compared to their children.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Binary_heap".to_string(),
             examples: vec![
@@ -102,6 +204,9 @@ pub fn get_data_structures_database() -> Vec<DataStructureInfo> {
                 "let mut heap = BinaryHeap::new();".to_string(),
                 "heap.push(value);".to_string(),
             ],
+            relations: vec![
+                Relation::data_structure(RelationKind::Uses, "Dynamic Array"),
+            ],
         },
 
         // Queue Data Structures
@@ -109,6 +214,7 @@ pub fn get_data_structures_database() -> Vec<DataStructureInfo> {
             name: "Queue".to_string(),
             category: DataStructureCategory::Queue,
             complexity: "O(1) enqueue/dequeue".to_string(),
+            bounds: operations(&[("enqueue", "O(1)"), ("dequeue", "O(1)"), ("peek", "O(1)"), ("search", "O(n)")], "O(n)", "O(n)"),
             description: "A collection in which elements are added at one end (rear) and removed from the other end (front), following FIFO (First In First Out) principle.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Queue_(abstract_data_type)".to_string(),
             examples: vec![
@@ -117,6 +223,10 @@ pub fn get_data_structures_database() -> Vec<DataStructureInfo> {
                 "queue.push_back(value);".to_string(),
                 "queue.pop_front();".to_string(),
             ],
+            relations: vec![
+                Relation::data_structure(RelationKind::AlternativeTo, "Stack"),
+                Relation::data_structure(RelationKind::Uses, "Dynamic Array"),
+            ],
         },
 
         // Stack Data Structures
@@ -124,6 +234,7 @@ pub fn get_data_structures_database() -> Vec<DataStructureInfo> {
             name: "Stack".to_string(),
             category: DataStructureCategory::Stack,
             complexity: "O(1) push/pop".to_string(),
+            bounds: operations(&[("push", "O(1)"), ("pop", "O(1)"), ("peek", "O(1)"), ("search", "O(n)")], "O(n)", "O(n)"),
             description: "A collection that follows the LIFO (Last In First Out) principle, where the last element added is the first one to be removed.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Stack_(abstract_data_type)".to_string(),
             examples: vec![
@@ -131,6 +242,10 @@ pub fn get_data_structures_database() -> Vec<DataStructureInfo> {
                 "stack.push(value);".to_string(),
                 "stack.pop();".to_string(),
             ],
+            relations: vec![
+                Relation::data_structure(RelationKind::AlternativeTo, "Queue"),
+                Relation::data_structure(RelationKind::Uses, "Dynamic Array"),
+            ],
         },
 
         // Graph Data Structures
@@ -138,12 +253,16 @@ pub fn get_data_structures_database() -> Vec<DataStructureInfo> {
             name: "Graph".to_string(),
             category: DataStructureCategory::Graph,
             complexity: "Varies by implementation".to_string(),
+            bounds: operations(&[("add_vertex", "O(1)"), ("add_edge", "O(1)"), ("neighbors", "O(d)")], "O(V + E)", "O(V + E)"),
             description: "A collection of nodes (vertices) and edges connecting some pairs of nodes, representing relationships between objects.".to_string(),
             wikipedia_link: "https://en.wikipedia.org/wiki/Graph_(abstract_data_type)".to_string(),
             examples: vec![
                 "struct Graph { vertices: Vec<Node>, edges: Vec<Edge> }".to_string(),
                 "adjacency_list: HashMap<Node, Vec<Node>>".to_string(),
             ],
+            relations: vec![
+                Relation::data_structure(RelationKind::Uses, "Dynamic Array"),
+            ],
         },
     ]
 }
\ No newline at end of file
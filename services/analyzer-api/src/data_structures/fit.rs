@@ -0,0 +1,166 @@
+//! Collections that fit the way the code uses them better
+//!
+//! Works on the calls `collections::collection_usage` reports:
+//!
+//! - `contains` on a `Vec`, `VecDeque` or `LinkedList` inside a loop
+//!   suggests a `HashSet`
+//! - `remove(0)` on a `Vec` suggests a `VecDeque` and its `pop_front`
+//! - a `Vec` sorted in a loop, or more than once, then `pop`ped suggests a
+//!   `BinaryHeap`
+//!
+//! Each suggestion cites the call and its cost on both collections, as
+//! `Collection::cost` documents them.
+
+use analyzer_types::v2::{CollectionOperation, CollectionUsage, Suggestion};
+
+use super::collections::Collection;
+
+/// Suggestions for the collections of the code, in source order
+pub fn suggestions(usages: &[CollectionUsage]) -> Vec<Suggestion> {
+    let mut suggestions = Vec::new();
+    for usage in usages {
+        let Some(collection) = Collection::named(&usage.collection) else {
+            continue;
+        };
+        let linear = matches!(collection, Collection::Vec | Collection::VecDeque | Collection::LinkedList);
+
+        if let Some(call) = usage.operations.iter().find(|op| linear && op.in_loop && op.operation == "contains") {
+            suggestions.push(suggestion(
+                usage,
+                call,
+                Collection::HashSet,
+                "contains",
+                "runs in a loop and scans the whole collection on every call",
+            ));
+        }
+
+        if collection != Collection::Vec {
+            continue;
+        }
+
+        if let Some(call) = usage.operations.iter().find(|op| op.operation == "remove(0)") {
+            suggestions.push(suggestion(
+                usage,
+                call,
+                Collection::VecDeque,
+                "pop_front",
+                "shifts every remaining element down by one",
+            ));
+        }
+
+        let sorts: Vec<&CollectionOperation> = usage.operations.iter().filter(|op| op.operation.starts_with("sort")).collect();
+        let repeated = sorts.iter().find(|sort| {
+            let popped = usage.operations.iter().any(|op| op.operation == "pop" && position(op) > position(sort));
+            popped && (sort.in_loop || sorts.len() > 1)
+        });
+        if let Some(call) = repeated {
+            suggestions.push(suggestion(
+                usage,
+                call,
+                Collection::BinaryHeap,
+                "pop",
+                "re-sorts the whole collection to `pop` its largest element",
+            ));
+        }
+    }
+
+    suggestions.sort_by_key(|suggestion| (suggestion.span.start_line, suggestion.span.start_column));
+    suggestions
+}
+
+fn position(operation: &CollectionOperation) -> (usize, usize) {
+    (operation.span.start_line, operation.span.start_column)
+}
+
+/// `suggested` in place of `usage`, whose `call` becomes `replacement`
+fn suggestion(usage: &CollectionUsage, call: &CollectionOperation, suggested: Collection, replacement: &str, why: &str) -> Suggestion {
+    let (after, note) = suggested.cost(replacement).unwrap_or(("O(1)", None));
+    let target = usage.binding.as_deref().unwrap_or(&usage.collection);
+    let after_text = match note {
+        Some(note) => format!("{} {}", after, note),
+        None => after.to_string(),
+    };
+    let message = format!(
+        "Line {}: `{}.{}` {}, {} on a {}; a {} does it with `{}` in {}",
+        call.span.start_line,
+        target,
+        call.operation,
+        why,
+        call.cost,
+        usage.collection,
+        suggested.as_str(),
+        replacement,
+        after_text
+    );
+
+    Suggestion {
+        collection: usage.collection.clone(),
+        binding: usage.binding.clone(),
+        suggested: suggested.as_str().to_string(),
+        operation: call.operation.clone(),
+        span: call.span,
+        before: call.cost.clone(),
+        after: after.to_string(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_structures::collections::collection_usage;
+    use crate::syntax::Syntax;
+
+    #[test]
+    fn test_suggests_collections_for_observed_operations() {
+        let code = r#"
+fn schedule(jobs: &mut Vec<u32>, blocked: &[u32]) -> Vec<u32> {
+    let mut done = Vec::new();
+    let mut ready = Vec::new();
+    while !jobs.is_empty() {
+        let job = jobs.remove(0);
+        if done.contains(&job) || blocked.iter().any(|b| *b == job) {
+            continue;
+        }
+        ready.push(job);
+        ready.sort();
+        done.push(ready.pop().unwrap());
+    }
+    done
+}
+"#;
+        let suggestions = suggestions(&collection_usage(code, &Syntax::parse(code)));
+        let summary: Vec<String> = suggestions
+            .iter()
+            .map(|s| format!("{} {} -> {}: {} {} -> {}", s.binding.as_deref().unwrap_or_default(), s.collection, s.suggested, s.operation, s.before, s.after))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                "jobs Vec -> VecDeque: remove(0) O(n) -> O(1)",
+                "done Vec -> HashSet: contains O(n) -> O(1)",
+                "ready Vec -> BinaryHeap: sort O(n log n) -> O(log n)",
+            ]
+        );
+        assert_eq!(
+            suggestions[1].message,
+            "Line 7: `done.contains` runs in a loop and scans the whole collection on every call, O(n) on a Vec; a HashSet does it with `contains` in O(1) expected"
+        );
+        assert_eq!(suggestions[0].span.start_line, 6);
+    }
+
+    #[test]
+    fn test_single_calls_outside_loops_are_left_alone() {
+        let code = r#"
+fn median(values: &[u32]) -> Option<u32> {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    if sorted.contains(&0) {
+        return None;
+    }
+    sorted.pop()
+}
+"#;
+        assert!(suggestions(&collection_usage(code, &Syntax::parse(code))).is_empty());
+    }
+}
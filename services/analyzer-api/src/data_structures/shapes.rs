@@ -0,0 +1,567 @@
+//! Data structures recognised by the shape of user-defined types
+//!
+//! A struct with `left` and `right` fields of type `Option<Box<Self>>` is a
+//! binary tree whatever it is called. Every struct, enum and type alias of
+//! the code is parsed with `syn` and classified by what its fields link to:
+//!
+//! - links to its own type through `Box`, `Rc`, `Arc`, `Weak`, references or
+//!   raw pointers make a linked list, doubly linked list, binary tree, tree
+//!   or trie, depending on how many there are, whether one of them points
+//!   back and whether children are keyed by character;
+//! - adjacency lists such as `Vec<Vec<usize>>` or `HashMap<K, Vec<K>>` make
+//!   a graph;
+//! - a `Vec` of nodes that link each other by index, as `Option<usize>` or a
+//!   `NodeId(usize)`, is an arena, classified by those indices the same way.
+//!
+//! Input is often a snippet that does not parse as a whole, so each item is
+//! cut out of the code and parsed on its own.
+
+use analyzer_types::v2::{SourceSpan, TypeDefinition};
+use std::ops::Range;
+use syn::spanned::Spanned;
+use syn::{Expr, Fields, GenericArgument, Item, Lit, PathArguments, PathSegment, Type};
+
+use crate::functions::{is_keyword_at, skip_non_code};
+use crate::syntax;
+
+pub const LINKED_LIST: &str = "Linked List";
+pub const DOUBLY_LINKED_LIST: &str = "Doubly Linked List";
+pub const BINARY_TREE: &str = "Binary Tree";
+pub const TREE: &str = "Tree";
+pub const TRIE: &str = "Trie";
+pub const GRAPH: &str = "Graph";
+
+/// Shapes from the most to the least specific; an enum whose variants
+/// differ takes the first one any variant has
+const SHAPES: [&str; 6] = [TRIE, GRAPH, TREE, BINARY_TREE, DOUBLY_LINKED_LIST, LINKED_LIST];
+
+/// Child arrays at least this long are indexed by symbol: digits, letters, bytes
+const ALPHABET_MIN: usize = 10;
+
+/// A user-defined type that implements a catalog data structure
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeShape {
+    /// Name of the catalog entry
+    pub data_structure: &'static str,
+    pub definition: TypeDefinition,
+}
+
+/// Types of the code whose shape is that of a known data structure, in
+/// source order
+pub fn type_shapes(code: &str) -> Vec<TypeShape> {
+    let definitions = definitions(code);
+    let newtypes: Vec<&str> = definitions.iter().filter(|d| d.index_newtype).map(|d| d.name.as_str()).collect();
+    // Plain integers only link when wrapped (`Option<usize>`); a named index
+    // type links on its own
+    let is_index = |ty: &Type| match type_name(ty)?.as_str() {
+        "usize" | "u32" => Some(false),
+        name if newtypes.contains(&name) => Some(true),
+        _ => None,
+    };
+
+    let mut shapes: Vec<TypeShape> = Vec::new();
+    let mut add = |definition: &Definition, data_structure: &'static str, evidence: String| {
+        if !shapes.iter().any(|s| s.data_structure == data_structure && s.definition.name == definition.name) {
+            shapes.push(TypeShape {
+                data_structure,
+                definition: TypeDefinition {
+                    name: definition.name.clone(),
+                    span: source_span(code, definition.range.clone()),
+                    evidence,
+                },
+            });
+        }
+    };
+
+    for definition in &definitions {
+        let is_own = |ty: &Type| type_name(ty).filter(|name| *name == definition.name || name == "Self").map(|_| false);
+        if let Some((shape, evidence)) = shape_of(code, definition, &is_own) {
+            add(definition, shape, evidence);
+        }
+
+        let fields = definition.variants.iter().flat_map(|(_, fields)| fields);
+        if let Some(field) = fields.clone().find(|field| is_adjacency(&field.ty, &is_index)) {
+            add(definition, GRAPH, field.text(code));
+        }
+
+        for field in fields.filter(|_| definition.is_struct) {
+            let node = arena_node(&field.ty).and_then(|name| {
+                definitions.iter().find(|d| d.name == name && d.name != definition.name && !d.index_newtype)
+            });
+            if let Some((shape, evidence)) = node.and_then(|node| shape_of(code, node, &is_index)) {
+                add(definition, shape, format!("{}; {}", field.text(code), evidence));
+            }
+        }
+    }
+
+    // The spans of everything parsed above are no longer needed; without
+    // this the thread's source map keeps every analyzed file
+    proc_macro2::extra::invalidate_current_thread_spans();
+    shapes
+}
+
+/// A struct, enum or type alias and its fields
+struct Definition {
+    name: String,
+    /// Byte range of the item in the code, from its keyword
+    range: Range<usize>,
+    is_struct: bool,
+    /// Fields by enum variant; a struct or alias has one unnamed variant
+    variants: Vec<(Option<String>, Vec<Field>)>,
+    /// A tuple struct or alias of a single integer, like `NodeId(usize)`
+    index_newtype: bool,
+}
+
+struct Field {
+    /// Field name; empty for tuple fields
+    label: String,
+    ty: Type,
+    /// Byte range in the code, from the name to the end of the type
+    range: Range<usize>,
+}
+
+impl Field {
+    /// The field as written, on one line
+    fn text(&self, code: &str) -> String {
+        code[self.range.clone()].split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+}
+
+/// How many nodes a link reaches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fanout {
+    One,
+    /// An array of links
+    Fixed(usize),
+    /// A map of links keyed by `char` or `u8`
+    ByCharacter,
+    Many,
+}
+
+struct Link<'a> {
+    field: &'a Field,
+    fanout: Fanout,
+    /// Through `Weak`, which points back without owning
+    weak: bool,
+}
+
+/// Every struct, enum and type alias that parses, in source order
+fn definitions(code: &str) -> Vec<Definition> {
+    let bytes = code.as_bytes();
+    let mut definitions = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if let Some(next) = skip_non_code(bytes, i) {
+            i = next;
+            continue;
+        }
+        let is_item = ["struct", "enum", "type"].iter().any(|keyword| is_keyword_at(bytes, i, keyword.as_bytes()));
+        if let Some(end) = is_item.then(|| item_end(bytes, i)).flatten() {
+            if let Some(definition) = parse_definition(code, i..end) {
+                definitions.push(definition);
+                i = end;
+                continue;
+            }
+        }
+        i += 1;
+    }
+
+    definitions
+}
+
+/// End of the item whose keyword starts at `start`: just past the `;` or
+/// the closing brace of its body
+fn item_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut i = start;
+    loop {
+        if let Some(next) = skip_non_code(bytes, i) {
+            i = next;
+            continue;
+        }
+        match bytes.get(i)? {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' => depth = depth.checked_sub(1)?,
+            b'}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            b';' if depth == 0 => return Some(i + 1),
+            _ => {}
+        }
+        i += 1;
+    }
+}
+
+fn parse_definition(code: &str, range: Range<usize>) -> Option<Definition> {
+    let base = range.start;
+    let field = |label: String, ty: &Type, start: usize| Field {
+        label,
+        ty: ty.clone(),
+        range: base + start..base + ty.span().byte_range().end.max(start),
+    };
+    let fields = |fields: &Fields| -> Vec<Field> {
+        fields
+            .iter()
+            .map(|f| match &f.ident {
+                Some(ident) => field(ident.to_string(), &f.ty, ident.span().byte_range().start),
+                None => field(String::new(), &f.ty, f.ty.span().byte_range().start),
+            })
+            .collect()
+    };
+
+    let (name, is_struct, variants, index_newtype) = match syntax::parse::<Item>(&code[range.clone()])? {
+        Item::Struct(item) => {
+            let index_newtype = matches!(&item.fields, Fields::Unnamed(f) if f.unnamed.len() == 1 && is_integer(&f.unnamed[0].ty));
+            (item.ident, true, vec![(None, fields(&item.fields))], index_newtype)
+        }
+        Item::Enum(item) => {
+            let variants = item.variants.iter().map(|v| (Some(v.ident.to_string()), fields(&v.fields))).collect();
+            (item.ident, false, variants, false)
+        }
+        Item::Type(item) => {
+            let start = item.ty.span().byte_range().start;
+            let index_newtype = is_integer(&item.ty);
+            (item.ident, false, vec![(None, vec![field(String::new(), &item.ty, start)])], index_newtype)
+        }
+        _ => return None,
+    };
+
+    Some(Definition {
+        name: name.to_string(),
+        range,
+        is_struct,
+        variants,
+        index_newtype,
+    })
+}
+
+/// The most specific shape any variant of the type has through links to
+/// `target`, with the linking fields as evidence
+fn shape_of(code: &str, definition: &Definition, target: &dyn Fn(&Type) -> Option<bool>) -> Option<(&'static str, String)> {
+    definition
+        .variants
+        .iter()
+        .filter_map(|(variant, fields)| {
+            let links: Vec<Link> = fields
+                .iter()
+                .filter_map(|field| {
+                    let (fanout, weak) = link(&field.ty, target, Fanout::One, false, false)?;
+                    Some(Link { field, fanout, weak })
+                })
+                .collect();
+            let shape = classify(&links)?;
+            let evidence = links.iter().map(|link| link.field.text(code)).collect::<Vec<_>>().join(", ");
+            Some(match variant {
+                Some(variant) => (shape, format!("{}({})", variant, evidence)),
+                None => (shape, evidence),
+            })
+        })
+        .min_by_key(|(shape, _)| SHAPES.iter().position(|s| s == shape))
+}
+
+/// How `ty` reaches a type `target` accepts, if it does. `target` says
+/// whether the bare type counts as a link; otherwise it must be wrapped
+/// in a pointer, option or collection.
+fn link(ty: &Type, target: &dyn Fn(&Type) -> Option<bool>, fanout: Fanout, weak: bool, wrapped: bool) -> Option<(Fanout, bool)> {
+    if let Some(bare) = target(ty) {
+        return (wrapped || bare).then_some((fanout, weak));
+    }
+    // The outermost collection decides how many nodes are reached
+    let within = |inner: Fanout| if fanout == Fanout::One { inner } else { fanout };
+
+    match ty {
+        Type::Array(array) => {
+            let inner = array_len(&array.len).map_or(Fanout::Many, Fanout::Fixed);
+            link(&array.elem, target, within(inner), weak, true)
+        }
+        Type::Slice(slice) => link(&slice.elem, target, within(Fanout::Many), weak, true),
+        Type::Ptr(pointer) => link(&pointer.elem, target, fanout, weak, true),
+        Type::Reference(reference) => link(&reference.elem, target, fanout, weak, true),
+        Type::Paren(paren) => link(&paren.elem, target, fanout, weak, wrapped),
+        Type::Group(group) => link(&group.elem, target, fanout, weak, wrapped),
+        Type::Tuple(tuple) => tuple.elems.iter().find_map(|elem| link(elem, target, fanout, weak, wrapped)),
+        Type::Path(path) => {
+            let segment = path.path.segments.last()?;
+            let args = type_args(segment);
+            match segment.ident.to_string().as_str() {
+                "Weak" => link(args.first()?, target, fanout, true, true),
+                "Box" | "Rc" | "Arc" | "Option" | "RefCell" | "Cell" | "Mutex" | "RwLock" | "NonNull" => {
+                    link(args.first()?, target, fanout, weak, true)
+                }
+                "Vec" | "VecDeque" | "LinkedList" | "HashSet" | "BTreeSet" | "BinaryHeap" | "SmallVec" => {
+                    link(args.first()?, target, within(Fanout::Many), weak, true)
+                }
+                "HashMap" | "BTreeMap" => {
+                    let inner = if is_character(args.first()?) { Fanout::ByCharacter } else { Fanout::Many };
+                    link(args.get(1)?, target, within(inner), weak, true)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn classify(links: &[Link]) -> Option<&'static str> {
+    let is_trie = |link: &&Link| match link.fanout {
+        Fanout::ByCharacter => true,
+        Fanout::Fixed(n) => n >= ALPHABET_MIN,
+        _ => false,
+    };
+    if links.iter().filter(|link| !link.weak).any(|link| is_trie(&link)) {
+        return Some(TRIE);
+    }
+
+    let forward: Vec<&Link> = links.iter().filter(|link| !link.weak && !is_back_link(&link.field.label)).collect();
+    if forward.iter().any(|link| matches!(link.fanout, Fanout::Many | Fanout::Fixed(3..))) {
+        let edges = forward.iter().any(|link| link.fanout == Fanout::Many && is_edge_list(&link.field.label));
+        return Some(if edges { GRAPH } else { TREE });
+    }
+
+    let count: usize = forward
+        .iter()
+        .map(|link| match link.fanout {
+            Fanout::Fixed(n) => n,
+            _ => 1,
+        })
+        .sum();
+    match count {
+        0 => None,
+        1 if forward.len() < links.len() => Some(DOUBLY_LINKED_LIST),
+        1 => Some(LINKED_LIST),
+        2 => Some(BINARY_TREE),
+        _ => Some(TREE),
+    }
+}
+
+/// Field names of links that point back: `prev`, `previous`, `parent`
+fn is_back_link(label: &str) -> bool {
+    let label = label.to_lowercase();
+    label.contains("prev") || label.contains("parent") || label.starts_with("pred") || label == "back"
+}
+
+/// Field names of links to arbitrary other nodes rather than children
+fn is_edge_list(label: &str) -> bool {
+    let label = label.to_lowercase();
+    ["edge", "neighbo", "adj", "succ"].iter().any(|word| label.contains(word))
+}
+
+/// `Vec<Vec<usize>>`, `[Vec<(usize, u32)>; N]`, `HashMap<K, Vec<K>>`,
+/// `BTreeMap<K, HashSet<K>>` and the like
+fn is_adjacency(ty: &Type, is_index: &dyn Fn(&Type) -> Option<bool>) -> bool {
+    let is_vertex = |ty: &Type| is_index(ty).is_some();
+    match ty {
+        Type::Array(array) => is_collection_of(&array.elem, &is_vertex),
+        Type::Slice(slice) => is_collection_of(&slice.elem, &is_vertex),
+        Type::Path(path) => {
+            let Some(segment) = path.path.segments.last() else {
+                return false;
+            };
+            let args = type_args(segment);
+            match (segment.ident.to_string().as_str(), args.as_slice()) {
+                ("Vec" | "VecDeque" | "Box", [inner]) => is_collection_of(inner, &is_vertex),
+                ("HashMap" | "BTreeMap", [key, value]) => is_collection_of(value, &|ty: &Type| ty == *key),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// A collection of vertices, or of tuples that start with one, such as
+/// `(usize, u32)` for a weighted edge; maps count by their keys
+fn is_collection_of(ty: &Type, is_vertex: &dyn Fn(&Type) -> bool) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    let Some(segment) = path.path.segments.last() else {
+        return false;
+    };
+    let element = match segment.ident.to_string().as_str() {
+        "Vec" | "VecDeque" | "HashSet" | "BTreeSet" | "HashMap" | "BTreeMap" | "SmallVec" => type_args(segment).first().copied(),
+        _ => None,
+    };
+    match element {
+        Some(Type::Tuple(tuple)) => tuple.elems.first().is_some_and(is_vertex),
+        Some(element) => is_vertex(element),
+        None => false,
+    }
+}
+
+/// Name of the node type an arena field stores, as in `Vec<Node>` or
+/// `Vec<Option<Node>>`
+fn arena_node(ty: &Type) -> Option<String> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Vec" {
+        return None;
+    }
+    let element = *type_args(segment).first()?;
+    match type_name(element)?.as_str() {
+        "Option" => {
+            let Type::Path(option) = element else {
+                return None;
+            };
+            type_name(type_args(option.path.segments.last()?).first()?)
+        }
+        _ => type_name(element),
+    }
+}
+
+fn type_args(segment: &PathSegment) -> Vec<&Type> {
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Last path segment of a type, as in `Node` for `crate::tree::Node<T>`
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last().map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
+
+fn is_integer(ty: &Type) -> bool {
+    type_name(ty).is_some_and(|name| matches!(name.as_str(), "usize" | "u32" | "u64"))
+}
+
+fn is_character(ty: &Type) -> bool {
+    type_name(ty).is_some_and(|name| matches!(name.as_str(), "char" | "u8"))
+}
+
+fn array_len(len: &Expr) -> Option<usize> {
+    match len {
+        Expr::Lit(literal) => match &literal.lit {
+            Lit::Int(int) => int.base10_parse().ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+pub(crate) fn source_span(code: &str, range: Range<usize>) -> SourceSpan {
+    let position = |offset: usize| {
+        let before = &code[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+    };
+    let last = code[..range.end].char_indices().next_back().map_or(range.start, |(i, _)| i);
+    let (start_line, start_column) = position(range.start);
+    let (end_line, end_column) = position(last);
+    SourceSpan { start_line, start_column, end_line, end_column }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shapes(code: &str) -> Vec<(&'static str, String)> {
+        type_shapes(code).into_iter().map(|shape| (shape.data_structure, shape.definition.name)).collect()
+    }
+
+    #[test]
+    fn test_self_referential_types() {
+        let code = r#"
+struct Foo { left: Option<Box<Foo>>, right: Option<Box<Foo>>, value: i32 }
+
+pub struct Node<T> {
+    value: T,
+    next: Option<Rc<RefCell<Node<T>>>>,
+    prev: Option<Weak<RefCell<Node<T>>>>,
+}
+
+enum List { Cons(i32, Box<List>), Nil }
+
+struct Dir { name: String, parent: Option<Weak<Dir>>, children: Vec<Rc<Dir>> }
+
+struct TrieNode { children: HashMap<char, TrieNode>, terminal: bool }
+
+struct ByteTrie { children: [Option<Box<ByteTrie>>; 256] }
+
+struct Vertex { id: u32, neighbours: Vec<Rc<RefCell<Vertex>>> }
+
+struct Point { x: f64, y: f64 }
+"#;
+        assert_eq!(
+            shapes(code),
+            [
+                (BINARY_TREE, "Foo".to_string()),
+                (DOUBLY_LINKED_LIST, "Node".to_string()),
+                (LINKED_LIST, "List".to_string()),
+                (TREE, "Dir".to_string()),
+                (TRIE, "TrieNode".to_string()),
+                (TRIE, "ByteTrie".to_string()),
+                (GRAPH, "Vertex".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_adjacency_lists_and_arenas() {
+        let code = r#"
+type Adjacency = Vec<Vec<usize>>;
+
+struct Network<K> { links: HashMap<K, Vec<K>>, weights: BTreeMap<K, HashMap<K, u32>> }
+
+struct Grid { cells: Vec<Vec<i32>> }
+
+#[derive(Clone, Copy)]
+struct NodeId(usize);
+
+struct Tree { nodes: Vec<TreeNode>, root: Option<NodeId> }
+struct TreeNode { key: i32, left: Option<NodeId>, right: Option<NodeId> }
+
+struct Pool { slots: Vec<Option<Slot>> }
+struct Slot { value: u64, next: Option<usize> }
+"#;
+        assert_eq!(
+            shapes(code),
+            [
+                (GRAPH, "Adjacency".to_string()),
+                (GRAPH, "Network".to_string()),
+                (BINARY_TREE, "Tree".to_string()),
+                (LINKED_LIST, "Pool".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_names_span_and_evidence() {
+        let code = "let root = None;\n// struct Fake { next: Box<Fake> }\nstruct Foo {\n    left: Option<Box<Foo>>,\n    right:   Option<Box<Foo>>,\n}\nfn main() {}\n";
+        let found = type_shapes(code);
+        assert_eq!(found.len(), 1);
+        let definition = &found[0].definition;
+        assert_eq!(definition.name, "Foo");
+        assert_eq!(definition.span, SourceSpan { start_line: 3, start_column: 1, end_line: 6, end_column: 1 });
+        assert_eq!(definition.evidence, "left: Option<Box<Foo>>, right: Option<Box<Foo>>");
+
+        let found = type_shapes("enum Tree { Leaf(u8), Node(Box<Tree>, Box<Tree>) }");
+        assert_eq!(found[0].data_structure, BINARY_TREE);
+        assert_eq!(found[0].definition.evidence, "Node(Box<Tree>, Box<Tree>)");
+    }
+
+    #[test]
+    fn test_deeply_nested_types_are_skipped() {
+        let code = format!(
+            "struct Deep {{ next: {}Deep{} }}\nstruct List {{ next: Option<Box<List>> }}\n",
+            "Box<".repeat(5_000),
+            ">".repeat(5_000)
+        );
+        assert_eq!(shapes(&code), [(LINKED_LIST, "List".to_string())]);
+    }
+}
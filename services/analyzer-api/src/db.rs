@@ -1,8 +1,55 @@
-use sqlx::{MySql, Pool, MySqlPool};
+use sqlx::{Executor, MySql, Pool, MySqlPool};
 use std::env;
-use crate::models::{analysis_result::NewAnalysisResult, code_sample::NewCodeSample, wikipedia_algorithm::WikipediaAlgorithm};
+use analyzer_types::v2::{BatchResponse, JobStatus};
+use analyzer_types::ErrorResponse;
+use crate::models::{analysis_job::AnalysisJob, analysis_batch::{AnalysisBatch, BatchFileRecord, NewAnalysisBatch}, analysis_result::{AnalysisRecord, NewAnalysisResult}, api_key::{ApiKey, KeyUsage, NewApiKey}, catalog_change::{Actor, CatalogAuditEntry, CatalogChange, CatalogKind, CatalogOverride, ChangeAction, ChangeStatus, NewCatalogChange}, code_sample::{NewCodeSample, SamplePurge}, wikipedia_algorithm::{StoredWikipediaAlgorithm, WikipediaAlgorithm, WikipediaDataStructure, WikipediaList, WikipediaPurge, WikipediaSyncPlan}};
 use uuid::Uuid;
-use chrono::Utc;
+use chrono::{DateTime, NaiveDate, Utc};
+use crate::rate_limit::Plan;
+
+/// Every table [`Database::init`] creates, in creation order
+pub const TABLES: [&str; 13] = [
+    "code_samples",
+    "analysis_results",
+    "wikipedia_algorithms",
+    "wikipedia_data_structures",
+    "api_keys",
+    "plans",
+    "api_usage",
+    "analysis_batches",
+    "analysis_batch_files",
+    "analysis_jobs",
+    "catalog_changes",
+    "catalog_overrides",
+    "catalog_audit_log",
+];
+
+/// Columns added to `wikipedia_algorithms` for change tracking
+const WIKIPEDIA_TRACKING_COLUMNS: [(&str, &str); 4] = [
+    ("revision_id", "BIGINT UNSIGNED"),
+    ("updated_at", "TIMESTAMP NULL"),
+    ("last_seen_at", "TIMESTAMP NULL"),
+    ("removed_at", "TIMESTAMP NULL"),
+];
+
+/// Samples deleted per statement when purging
+const PURGE_CHUNK: usize = 500;
+
+/// Rows the fetch binaries that `icalds-admin` replaced stored instead of
+/// articles: placeholders titled `Algorithm from <category>`, rows pointing
+/// at the list pages themselves, and hardcoded samples whose descriptions
+/// never came from Wikipedia
+const WIKIPEDIA_STUB_CONDITION: &str = "(title LIKE 'Algorithm from %' OR url IN (?, ?) OR (revision_id IS NULL AND description IN (?, ?, ?, ?, ?)))";
+
+const WIKIPEDIA_STUB_VALUES: [&str; 7] = [
+    "https://en.wikipedia.org/wiki/List_of_algorithms",
+    "https://en.wikipedia.org/wiki/List_of_data_structures",
+    "An efficient, general-purpose, comparison-based sorting algorithm.",
+    "An efficient sorting algorithm that uses a divide-and-conquer approach.",
+    "A search algorithm that finds the position of a target value within a sorted array.",
+    "An algorithm for finding the shortest paths between nodes in a graph.",
+    "A test algorithm",
+];
 
 pub struct Database {
     pool: Pool<MySql>,
@@ -17,10 +64,22 @@ impl Database {
         Ok(Self { pool })
     }
     
+    /// Create a pool that only connects on first use
+    pub fn connect_lazy(database_url: &str) -> Result<Self, sqlx::Error> {
+        let pool = MySqlPool::connect_lazy(database_url)?;
+        Ok(Self { pool })
+    }
+    
     pub fn pool(&self) -> &Pool<MySql> {
         &self.pool
     }
     
+    /// Round-trip a trivial query to check the database is reachable
+    pub async fn ping(&self) -> Result<(), sqlx::Error> {
+        sqlx::query("SELECT 1").execute(&self.pool).await?;
+        Ok(())
+    }
+    
     pub async fn init(&self) -> Result<(), sqlx::Error> {
         // Create tables if they don't exist
         sqlx::query(
@@ -55,6 +114,7 @@ impl Database {
         .execute(&self.pool)
         .await?;
         
+        // One row per article; rows no longer listed upstream are soft-deleted
         sqlx::query(
             r#"
             CREATE TABLE IF NOT EXISTS wikipedia_algorithms (
@@ -63,73 +123,868 @@ impl Database {
                 url VARCHAR(512) NOT NULL,
                 category VARCHAR(100) NOT NULL,
                 description TEXT,
-                complexity VARCHAR(50),
-                fetched_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+                complexity VARCHAR(255),
+                revision_id BIGINT UNSIGNED,
+                fetched_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMP NULL,
+                last_seen_at TIMESTAMP NULL,
+                removed_at TIMESTAMP NULL,
+                UNIQUE KEY uq_wikipedia_algorithms_url (url)
+            )
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+        self.migrate_wikipedia_algorithms().await?;
+        
+        // Same shape as wikipedia_algorithms, for the List of data structures
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS wikipedia_data_structures (
+                id CHAR(36) PRIMARY KEY,
+                title VARCHAR(255) NOT NULL,
+                url VARCHAR(512) NOT NULL,
+                category VARCHAR(100) NOT NULL,
+                description TEXT,
+                complexity VARCHAR(255),
+                revision_id BIGINT UNSIGNED,
+                fetched_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMP NULL,
+                last_seen_at TIMESTAMP NULL,
+                removed_at TIMESTAMP NULL,
+                UNIQUE KEY uq_wikipedia_data_structures_url (url)
+            )
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+        
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS api_keys (
+                id CHAR(36) PRIMARY KEY,
+                name VARCHAR(100) NOT NULL,
+                key_hash CHAR(64) NOT NULL UNIQUE,
+                role VARCHAR(20) NOT NULL,
+                plan VARCHAR(50) NOT NULL DEFAULT 'free',
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                revoked_at TIMESTAMP NULL
+            )
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+        
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS plans (
+                name VARCHAR(50) PRIMARY KEY,
+                requests_per_minute INT UNSIGNED NOT NULL,
+                burst INT UNSIGNED NOT NULL,
+                daily_quota INT UNSIGNED NOT NULL
+            )
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+        
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS api_usage (
+                client VARCHAR(100) NOT NULL,
+                api_key_id CHAR(36),
+                day DATE NOT NULL,
+                requests INT UNSIGNED NOT NULL DEFAULT 0,
+                PRIMARY KEY (client, day)
+            )
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+        
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS analysis_batches (
+                id CHAR(36) PRIMARY KEY,
+                name VARCHAR(255),
+                language VARCHAR(50) NOT NULL,
+                owner_key_id CHAR(36),
+                inventory JSON,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            )
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+        // Batches from before batches had owners are left with a NULL owner, like anonymous ones
+        if !self.column_exists("analysis_batches", "owner_key_id").await? {
+            sqlx::query("ALTER TABLE analysis_batches ADD COLUMN owner_key_id CHAR(36) AFTER language").execute(&self.pool).await?;
+        }
+        
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS analysis_batch_files (
+                batch_id CHAR(36) NOT NULL,
+                position INT UNSIGNED NOT NULL,
+                path VARCHAR(1024) NOT NULL,
+                analysis_result_id CHAR(36),
+                error VARCHAR(100),
+                PRIMARY KEY (batch_id, position),
+                FOREIGN KEY (batch_id) REFERENCES analysis_batches(id),
+                FOREIGN KEY (analysis_result_id) REFERENCES analysis_results(id)
+            )
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+        
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS analysis_jobs (
+                id CHAR(36) PRIMARY KEY,
+                status VARCHAR(20) NOT NULL,
+                owner_key_id CHAR(36),
+                request JSON NOT NULL,
+                total INT UNSIGNED NOT NULL,
+                completed INT UNSIGNED NOT NULL DEFAULT 0,
+                attempts INT UNSIGNED NOT NULL DEFAULT 0,
+                result JSON,
+                error_code VARCHAR(100),
+                error_message TEXT,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                INDEX idx_analysis_jobs_status (status, created_at)
+            )
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+        // Jobs from before jobs had owners are left with a NULL owner, like anonymous ones
+        if !self.column_exists("analysis_jobs", "owner_key_id").await? {
+            sqlx::query("ALTER TABLE analysis_jobs ADD COLUMN owner_key_id CHAR(36) AFTER status").execute(&self.pool).await?;
+        }
+        
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS catalog_changes (
+                id CHAR(36) PRIMARY KEY,
+                kind VARCHAR(32) NOT NULL,
+                name VARCHAR(255) NOT NULL,
+                action VARCHAR(16) NOT NULL,
+                entry JSON,
+                diff JSON NOT NULL,
+                status VARCHAR(16) NOT NULL,
+                author VARCHAR(255) NOT NULL,
+                author_key_id CHAR(36),
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                reviewer VARCHAR(255),
+                reviewer_key_id CHAR(36),
+                reviewed_at TIMESTAMP NULL,
+                INDEX idx_catalog_changes_status (status, created_at)
             )
             "#
         )
         .execute(&self.pool)
         .await?;
         
+        // Approved entries laid over the built-in catalog; a NULL entry removes it
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS catalog_overrides (
+                kind VARCHAR(32) NOT NULL,
+                name VARCHAR(255) NOT NULL,
+                entry JSON,
+                change_id CHAR(36) NOT NULL,
+                updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (kind, name),
+                FOREIGN KEY (change_id) REFERENCES catalog_changes(id)
+            )
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+        
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS catalog_audit_log (
+                id CHAR(36) PRIMARY KEY,
+                change_id CHAR(36) NOT NULL,
+                kind VARCHAR(32) NOT NULL,
+                name VARCHAR(255) NOT NULL,
+                action VARCHAR(16) NOT NULL,
+                status VARCHAR(16) NOT NULL,
+                actor VARCHAR(255) NOT NULL,
+                actor_key_id CHAR(36),
+                diff JSON NOT NULL,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                INDEX idx_catalog_audit_entry (kind, name, created_at),
+                FOREIGN KEY (change_id) REFERENCES catalog_changes(id)
+            )
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+        
+        for plan in Plan::defaults() {
+            sqlx::query(
+                "INSERT IGNORE INTO plans (name, requests_per_minute, burst, daily_quota) VALUES (?, ?, ?, ?)"
+            )
+            .bind(&plan.name)
+            .bind(plan.requests_per_minute)
+            .bind(plan.burst)
+            .bind(plan.daily_quota)
+            .execute(&self.pool)
+            .await?;
+        }
+        
         Ok(())
     }
     
     pub async fn save_code_sample(&self, code_sample: NewCodeSample) -> Result<Uuid, sqlx::Error> {
+        insert_code_sample(&self.pool, code_sample).await
+    }
+    
+    pub async fn save_analysis_result(&self, analysis_result: NewAnalysisResult) -> Result<Uuid, sqlx::Error> {
+        insert_analysis_result(&self.pool, analysis_result).await
+    }
+    
+    /// Store a batch with all of its samples and results in one transaction
+    pub async fn save_analysis_batch(&self, batch: NewAnalysisBatch) -> Result<Uuid, sqlx::Error> {
         let id = Uuid::new_v4();
+        let inventory_json = serde_json::to_string(&batch.inventory).unwrap_or("{}".to_string());
+        let mut tx = self.pool.begin().await?;
         
         sqlx::query(
-            "INSERT INTO code_samples (id, code, language, created_at) VALUES (?, ?, ?, ?)"
+            "INSERT INTO analysis_batches (id, name, language, owner_key_id, inventory, created_at) VALUES (?, ?, ?, ?, ?, ?)"
         )
         .bind(id.to_string())
-        .bind(code_sample.code)
-        .bind(code_sample.language)
+        .bind(&batch.name)
+        .bind(&batch.language)
+        .bind(batch.owner_key_id.map(|key_id| key_id.to_string()))
+        .bind(inventory_json)
         .bind(Utc::now())
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
         
+        for (position, file) in batch.files.into_iter().enumerate() {
+            let analysis_result_id = match file.analysis {
+                Some((code_sample, mut analysis_result)) => {
+                    analysis_result.code_sample_id = insert_code_sample(&mut *tx, code_sample).await?;
+                    Some(insert_analysis_result(&mut *tx, analysis_result).await?.to_string())
+                }
+                None => None,
+            };
+            
+            sqlx::query(
+                "INSERT INTO analysis_batch_files (batch_id, position, path, analysis_result_id, error) VALUES (?, ?, ?, ?, ?)"
+            )
+            .bind(id.to_string())
+            .bind(position as u32)
+            .bind(file.path)
+            .bind(analysis_result_id)
+            .bind(file.error)
+            .execute(&mut *tx)
+            .await?;
+        }
+        
+        tx.commit().await?;
         Ok(id)
     }
     
-    pub async fn save_analysis_result(&self, analysis_result: NewAnalysisResult) -> Result<Uuid, sqlx::Error> {
+    pub async fn get_analysis_batch(&self, id: Uuid) -> Result<Option<AnalysisBatch>, sqlx::Error> {
+        let Some(row) = sqlx::query_as::<_, AnalysisBatchRow>(
+            "SELECT id, name, language, owner_key_id, CAST(inventory AS CHAR) AS inventory, created_at FROM analysis_batches WHERE id = ?"
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await? else {
+            return Ok(None);
+        };
+        
+        let files = sqlx::query_as::<_, BatchFileRow>(
+            "SELECT path, analysis_result_id, error FROM analysis_batch_files WHERE batch_id = ? ORDER BY position"
+        )
+        .bind(id.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+        
+        Ok(Some(AnalysisBatch {
+            id: Uuid::parse_str(&row.id).unwrap_or_default(),
+            name: row.name,
+            language: row.language,
+            owner_key_id: row.owner_key_id.and_then(|id| Uuid::parse_str(&id).ok()),
+            inventory: parse_json_column(row.inventory),
+            files: files.into_iter().map(|file| file.into()).collect(),
+            created_at: row.created_at,
+        }))
+    }
+    
+    /// Store a new job in the `queued` state, owned by the key that submitted it
+    pub async fn create_job(&self, request_json: &str, total: u32, owner_key_id: Option<Uuid>) -> Result<AnalysisJob, sqlx::Error> {
         let id = Uuid::new_v4();
+        let now = Utc::now();
+        
+        sqlx::query(
+            "INSERT INTO analysis_jobs (id, status, owner_key_id, request, total, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(id.to_string())
+        .bind(JobStatus::Queued.as_str())
+        .bind(owner_key_id.map(|key_id| key_id.to_string()))
+        .bind(request_json)
+        .bind(total)
+        .bind(now)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+        
+        Ok(AnalysisJob {
+            id,
+            status: JobStatus::Queued,
+            owner_key_id,
+            total,
+            completed: 0,
+            error: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+    
+    /// Move a queued job to `running` and return its request and owner, or `None` if
+    /// another worker got there first or the job is no longer queued
+    pub async fn claim_job(&self, id: Uuid) -> Result<Option<(String, Option<Uuid>)>, sqlx::Error> {
+        let claimed = sqlx::query(
+            "UPDATE analysis_jobs SET status = ?, attempts = attempts + 1, completed = 0, updated_at = ? WHERE id = ? AND status = ?"
+        )
+        .bind(JobStatus::Running.as_str())
+        .bind(Utc::now())
+        .bind(id.to_string())
+        .bind(JobStatus::Queued.as_str())
+        .execute(&self.pool)
+        .await?;
+        
+        if claimed.rows_affected() == 0 {
+            return Ok(None);
+        }
         
-        // Convert Vec<String> to JSON strings
-        let patterns_json = serde_json::to_string(&analysis_result.patterns).unwrap_or("[]".to_string());
-        let algorithms_json = serde_json::to_string(&analysis_result.algorithms).unwrap_or("[]".to_string());
-        let detailed_algorithms_json = serde_json::to_string(&analysis_result.detailed_algorithms).unwrap_or("[]".to_string());
-        let data_structures_json = serde_json::to_string(&analysis_result.data_structures).unwrap_or("[]".to_string());
-        let recommendations_json = serde_json::to_string(&analysis_result.recommendations).unwrap_or("[]".to_string());
+        let row = sqlx::query_as::<_, (String, Option<String>)>(
+            "SELECT CAST(request AS CHAR), owner_key_id FROM analysis_jobs WHERE id = ?"
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
         
+        Ok(row.map(|(request, owner_key_id)| (request, owner_key_id.and_then(|id| Uuid::parse_str(&id).ok()))))
+    }
+    
+    /// Progress only moves forward, so updates arriving out of order are harmless
+    pub async fn update_job_progress(&self, id: Uuid, completed: u32) -> Result<(), sqlx::Error> {
         sqlx::query(
-            "INSERT INTO analysis_results (id, code_sample_id, patterns, algorithms, detailed_algorithms, data_structures, complexity, recommendations, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"
+            "UPDATE analysis_jobs SET completed = GREATEST(completed, ?), updated_at = ? WHERE id = ? AND status = ?"
         )
+        .bind(completed)
+        .bind(Utc::now())
         .bind(id.to_string())
-        .bind(analysis_result.code_sample_id.to_string())
-        .bind(patterns_json)
-        .bind(algorithms_json)
-        .bind(detailed_algorithms_json)
-        .bind(data_structures_json)
-        .bind(analysis_result.complexity)
-        .bind(recommendations_json)
+        .bind(JobStatus::Running.as_str())
+        .execute(&self.pool)
+        .await?;
+        
+        Ok(())
+    }
+    
+    pub async fn finish_job(&self, id: Uuid, result: &BatchResponse) -> Result<(), sqlx::Error> {
+        let result_json = serde_json::to_string(result).unwrap_or("{}".to_string());
+        
+        sqlx::query(
+            "UPDATE analysis_jobs SET status = ?, completed = total, result = ?, updated_at = ? WHERE id = ?"
+        )
+        .bind(JobStatus::Done.as_str())
+        .bind(result_json)
         .bind(Utc::now())
+        .bind(id.to_string())
         .execute(&self.pool)
         .await?;
         
-        Ok(id)
+        Ok(())
     }
     
-    pub async fn save_wikipedia_algorithm(&self, wiki_algo: WikipediaAlgorithm) -> Result<Uuid, sqlx::Error> {
+    pub async fn fail_job(&self, id: Uuid, error: &ErrorResponse) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE analysis_jobs SET status = ?, error_code = ?, error_message = ?, updated_at = ? WHERE id = ?"
+        )
+        .bind(JobStatus::Failed.as_str())
+        .bind(&error.error)
+        .bind(&error.message)
+        .bind(Utc::now())
+        .bind(id.to_string())
+        .execute(&self.pool)
+        .await?;
+        
+        Ok(())
+    }
+    
+    pub async fn get_job(&self, id: Uuid) -> Result<Option<AnalysisJob>, sqlx::Error> {
+        let row = sqlx::query_as::<_, AnalysisJobRow>(
+            "SELECT id, status, owner_key_id, total, completed, error_code, error_message, created_at, updated_at FROM analysis_jobs WHERE id = ?"
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
+        
+        Ok(row.map(|row| row.into()))
+    }
+    
+    /// The result of a finished job; `None` while it is still queued or running
+    pub async fn get_job_result(&self, id: Uuid) -> Result<Option<BatchResponse>, sqlx::Error> {
+        let result = sqlx::query_scalar::<_, Option<String>>(
+            "SELECT CAST(result AS CHAR) FROM analysis_jobs WHERE id = ?"
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
+        
+        Ok(result.flatten().and_then(|json| serde_json::from_str(&json).ok()))
+    }
+    
+    /// Recover after a restart: jobs left running are queued again, or marked
+    /// failed once they have been started `max_attempts` times. Returns the
+    /// ids of all queued jobs, oldest first.
+    pub async fn recover_jobs(&self, max_attempts: u32) -> Result<Vec<Uuid>, sqlx::Error> {
+        let now = Utc::now();
+        
+        sqlx::query(
+            "UPDATE analysis_jobs SET status = ?, error_code = 'interrupted', error_message = 'The server stopped while the job was running', updated_at = ? WHERE status = ? AND attempts >= ?"
+        )
+        .bind(JobStatus::Failed.as_str())
+        .bind(now)
+        .bind(JobStatus::Running.as_str())
+        .bind(max_attempts)
+        .execute(&self.pool)
+        .await?;
+        
+        sqlx::query("UPDATE analysis_jobs SET status = ?, completed = 0, updated_at = ? WHERE status = ?")
+            .bind(JobStatus::Queued.as_str())
+            .bind(now)
+            .bind(JobStatus::Running.as_str())
+            .execute(&self.pool)
+            .await?;
+        
+        let ids = sqlx::query_scalar::<_, String>(
+            "SELECT id FROM analysis_jobs WHERE status = ? ORDER BY created_at"
+        )
+        .bind(JobStatus::Queued.as_str())
+        .fetch_all(&self.pool)
+        .await?;
+        
+        Ok(ids.iter().filter_map(|id| Uuid::parse_str(id).ok()).collect())
+    }
+    
+    /// Record a proposed catalog change and its first audit entry
+    pub async fn create_catalog_change(&self, change: NewCatalogChange) -> Result<CatalogChange, sqlx::Error> {
         let id = Uuid::new_v4();
+        let now = Utc::now();
+        let entry_json = change.entry.as_ref().map(|entry| entry.to_string());
+        let mut tx = self.pool.begin().await?;
         
         sqlx::query(
-            "INSERT INTO wikipedia_algorithms (id, title, url, category, description, complexity, fetched_at) VALUES (?, ?, ?, ?, ?, ?, ?)"
+            "INSERT INTO catalog_changes (id, kind, name, action, entry, diff, status, author, author_key_id, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
         )
         .bind(id.to_string())
-        .bind(&wiki_algo.title)
-        .bind(&wiki_algo.url)
-        .bind(&wiki_algo.category)
-        .bind(&wiki_algo.description)
-        .bind(&wiki_algo.complexity)
+        .bind(change.kind.as_str())
+        .bind(&change.name)
+        .bind(change.action.as_str())
+        .bind(entry_json)
+        .bind(change.diff.to_string())
+        .bind(ChangeStatus::Pending.as_str())
+        .bind(&change.author.name)
+        .bind(change.author.key_id.map(|key_id| key_id.to_string()))
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+        
+        insert_catalog_audit(&mut *tx, id, &change.kind, &change.name, change.action, ChangeStatus::Pending, &change.author, &change.diff).await?;
+        tx.commit().await?;
+        
+        Ok(CatalogChange {
+            id,
+            kind: change.kind,
+            name: change.name,
+            action: change.action,
+            entry: change.entry,
+            diff: change.diff,
+            status: ChangeStatus::Pending,
+            author: change.author,
+            created_at: now,
+            reviewer: None,
+            reviewed_at: None,
+        })
+    }
+    
+    pub async fn get_catalog_change(&self, id: Uuid) -> Result<Option<CatalogChange>, sqlx::Error> {
+        let row = sqlx::query_as::<_, CatalogChangeRow>(&format!("{} WHERE id = ?", CATALOG_CHANGE_SELECT))
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await?;
+        
+        Ok(row.map(|row| row.into()))
+    }
+    
+    /// Most recent changes first, optionally only those with `status`
+    pub async fn list_catalog_changes(&self, status: Option<ChangeStatus>, limit: u32) -> Result<Vec<CatalogChange>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, CatalogChangeRow>(&format!(
+            "{} WHERE (? IS NULL OR status = ?) ORDER BY created_at DESC LIMIT ?",
+            CATALOG_CHANGE_SELECT
+        ))
+        .bind(status.map(|status| status.as_str()))
+        .bind(status.map(|status| status.as_str()))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        
+        Ok(rows.into_iter().map(|row| row.into()).collect())
+    }
+    
+    /// Approve a pending change and apply it to the overlay in one
+    /// transaction. `diff` is what approval changes in the catalog as it is
+    /// now. Returns `false` if the change was no longer pending.
+    pub async fn approve_catalog_change(&self, change: &CatalogChange, reviewer: &Actor, diff: &serde_json::Value) -> Result<bool, sqlx::Error> {
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
+        
+        if !review_catalog_change(&mut *tx, change.id, ChangeStatus::Approved, reviewer, now).await? {
+            return Ok(false);
+        }
+        
+        sqlx::query(
+            "INSERT INTO catalog_overrides (kind, name, entry, change_id, updated_at) VALUES (?, ?, ?, ?, ?) ON DUPLICATE KEY UPDATE entry = VALUES(entry), change_id = VALUES(change_id), updated_at = VALUES(updated_at)"
+        )
+        .bind(change.kind.as_str())
+        .bind(&change.name)
+        .bind(change.entry.as_ref().map(|entry| entry.to_string()))
+        .bind(change.id.to_string())
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+        
+        insert_catalog_audit(&mut *tx, change.id, &change.kind, &change.name, change.action, ChangeStatus::Approved, reviewer, diff).await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+    
+    /// Reject a pending change; returns `false` if it was no longer pending
+    pub async fn reject_catalog_change(&self, change: &CatalogChange, reviewer: &Actor) -> Result<bool, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        
+        if !review_catalog_change(&mut *tx, change.id, ChangeStatus::Rejected, reviewer, Utc::now()).await? {
+            return Ok(false);
+        }
+        
+        insert_catalog_audit(&mut *tx, change.id, &change.kind, &change.name, change.action, ChangeStatus::Rejected, reviewer, &change.diff).await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+    
+    /// All approved entries to lay over the built-in catalog
+    pub async fn get_catalog_overrides(&self) -> Result<Vec<CatalogOverride>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, CatalogOverrideRow>(
+            "SELECT kind, name, CAST(entry AS CHAR) AS entry FROM catalog_overrides ORDER BY updated_at"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        
+        Ok(rows.into_iter().filter_map(|row| row.try_into().ok()).collect())
+    }
+    
+    /// Audit entries, most recent first, optionally for one kind or entry
+    pub async fn get_catalog_audit(&self, kind: Option<CatalogKind>, name: Option<&str>, limit: u32) -> Result<Vec<CatalogAuditEntry>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, CatalogAuditRow>(
+            "SELECT id, change_id, kind, name, action, status, actor, actor_key_id, CAST(diff AS CHAR) AS diff, created_at FROM catalog_audit_log WHERE (? IS NULL OR kind = ?) AND (? IS NULL OR name = ?) ORDER BY created_at DESC LIMIT ?"
+        )
+        .bind(kind.map(|kind| kind.as_str()))
+        .bind(kind.map(|kind| kind.as_str()))
+        .bind(name)
+        .bind(name)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        
+        Ok(rows.into_iter().map(|row| row.into()).collect())
+    }
+    
+    /// Schema changes [`Database::init`] would make, described for a dry run
+    pub async fn pending_migrations(&self) -> Result<Vec<String>, sqlx::Error> {
+        let mut pending = Vec::new();
+        for table in TABLES {
+            let exists: i64 = sqlx::query_scalar(
+                "SELECT COUNT(*) FROM information_schema.TABLES WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ?"
+            )
+            .bind(table)
+            .fetch_one(&self.pool)
+            .await?;
+            if exists == 0 {
+                pending.push(format!("create table {}", table));
+            }
+        }
+        if !pending.iter().any(|step| step == "create table analysis_batches") && !self.column_exists("analysis_batches", "owner_key_id").await? {
+            pending.push("add column analysis_batches.owner_key_id".to_string());
+        }
+        if !pending.iter().any(|step| step == "create table analysis_jobs") && !self.column_exists("analysis_jobs", "owner_key_id").await? {
+            pending.push("add column analysis_jobs.owner_key_id".to_string());
+        }
+        // A missing wikipedia_algorithms table is created with the current schema
+        if pending.iter().any(|step| step == "create table wikipedia_algorithms") {
+            return Ok(pending);
+        }
+        
+        for (column, _) in WIKIPEDIA_TRACKING_COLUMNS {
+            if !self.column_exists("wikipedia_algorithms", column).await? {
+                pending.push(format!("add column wikipedia_algorithms.{}", column));
+            }
+        }
+        let complexity_width: Option<i64> = sqlx::query_scalar(
+            "SELECT CAST(CHARACTER_MAXIMUM_LENGTH AS SIGNED) FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'wikipedia_algorithms' AND COLUMN_NAME = 'complexity'"
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .flatten();
+        if complexity_width.is_some_and(|width| width < 255) {
+            pending.push("widen wikipedia_algorithms.complexity to 255 characters".to_string());
+        }
+        if !self.wikipedia_algorithms_url_is_unique().await? {
+            pending.push("drop duplicate wikipedia_algorithms rows and add unique key uq_wikipedia_algorithms_url".to_string());
+        }
+        Ok(pending)
+    }
+    
+    /// Bring a `wikipedia_algorithms` table from before URLs were unique up
+    /// to date: add the tracking columns, keep the latest row of each URL
+    /// and add the unique key
+    async fn migrate_wikipedia_algorithms(&self) -> Result<(), sqlx::Error> {
+        for (column, definition) in WIKIPEDIA_TRACKING_COLUMNS {
+            if !self.column_exists("wikipedia_algorithms", column).await? {
+                self.pool.execute(format!("ALTER TABLE wikipedia_algorithms ADD COLUMN {} {}", column, definition).as_str()).await?;
+            }
+        }
+        sqlx::query("ALTER TABLE wikipedia_algorithms MODIFY complexity VARCHAR(255)").execute(&self.pool).await?;
+        
+        if !self.wikipedia_algorithms_url_is_unique().await? {
+            let mut tx = self.pool.begin().await?;
+            sqlx::query(
+                "DELETE older FROM wikipedia_algorithms older JOIN wikipedia_algorithms newer ON older.url = newer.url AND (older.fetched_at < newer.fetched_at OR (older.fetched_at = newer.fetched_at AND older.id < newer.id))"
+            )
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query("UPDATE wikipedia_algorithms SET last_seen_at = fetched_at WHERE last_seen_at IS NULL")
+                .execute(&mut *tx)
+                .await?;
+            tx.commit().await?;
+            sqlx::query("ALTER TABLE wikipedia_algorithms ADD UNIQUE KEY uq_wikipedia_algorithms_url (url)")
+                .execute(&self.pool)
+                .await?;
+        }
+        Ok(())
+    }
+    
+    async fn column_exists(&self, table: &str, column: &str) -> Result<bool, sqlx::Error> {
+        let exists: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ? AND COLUMN_NAME = ?"
+        )
+        .bind(table)
+        .bind(column)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(exists > 0)
+    }
+    
+    async fn wikipedia_algorithms_url_is_unique(&self) -> Result<bool, sqlx::Error> {
+        let unique: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM information_schema.STATISTICS WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'wikipedia_algorithms' AND INDEX_NAME = 'uq_wikipedia_algorithms_url'"
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(unique > 0)
+    }
+    
+    /// Insert or update the row of the article's URL, marking it seen now
+    pub async fn save_wikipedia_algorithm(&self, wiki_algo: &WikipediaAlgorithm) -> Result<(), sqlx::Error> {
+        upsert_wikipedia_entry(&self.pool, WikipediaList::Algorithms, wiki_algo, Utc::now()).await
+    }
+    
+    /// Apply a planned fetch of one list in one transaction
+    pub async fn apply_wikipedia_sync(&self, list: WikipediaList, plan: &WikipediaSyncPlan) -> Result<(), sqlx::Error> {
+        let now = Utc::now();
+        let table = list.table();
+        let mut tx = self.pool.begin().await?;
+        
+        for entry in plan.added.iter().chain(&plan.changed) {
+            upsert_wikipedia_entry(&mut *tx, list, entry, now).await?;
+        }
+        for entry in &plan.unchanged {
+            sqlx::query(&format!(
+                "UPDATE {} SET last_seen_at = ?, revision_id = COALESCE(?, revision_id) WHERE url = ?",
+                table
+            ))
+            .bind(now)
+            .bind(entry.revision_id)
+            .bind(&entry.url)
+            .execute(&mut *tx)
+            .await?;
+        }
+        for entry in &plan.removed {
+            sqlx::query(&format!("UPDATE {} SET removed_at = ? WHERE url = ? AND removed_at IS NULL", table))
+                .bind(now)
+                .bind(&entry.url)
+                .execute(&mut *tx)
+                .await?;
+        }
+        
+        tx.commit().await?;
+        Ok(())
+    }
+    
+    /// Algorithms currently listed upstream
+    pub async fn get_all_wikipedia_algorithms(&self) -> Result<Vec<WikipediaAlgorithm>, sqlx::Error> {
+        self.get_listed_wikipedia_entries(WikipediaList::Algorithms).await
+    }
+    
+    /// Data structures currently listed upstream
+    pub async fn get_all_wikipedia_data_structures(&self) -> Result<Vec<WikipediaDataStructure>, sqlx::Error> {
+        self.get_listed_wikipedia_entries(WikipediaList::DataStructures).await
+    }
+    
+    async fn get_listed_wikipedia_entries(&self, list: WikipediaList) -> Result<Vec<WikipediaAlgorithm>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, WikipediaAlgorithmRow>(&format!(
+            "SELECT title, url, category, description, complexity, revision_id, last_seen_at, removed_at FROM {} WHERE removed_at IS NULL ORDER BY title",
+            list.table()
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+        
+        Ok(rows.into_iter().map(|row| StoredWikipediaAlgorithm::from(row).algorithm).collect())
+    }
+    
+    /// Every row of a list, soft-deleted ones included, to plan a fetch against
+    pub async fn get_stored_wikipedia_entries(&self, list: WikipediaList) -> Result<Vec<StoredWikipediaAlgorithm>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, WikipediaAlgorithmRow>(&format!(
+            "SELECT title, url, category, description, complexity, revision_id, last_seen_at, removed_at FROM {} ORDER BY title",
+            list.table()
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+        
+        Ok(rows.into_iter().map(|row| row.into()).collect())
+    }
+    
+    /// Delete the rows of a list that are not live articles: placeholder
+    /// stubs, and rows soft-deleted because the list no longer links them.
+    /// With `dry_run` only counts them.
+    pub async fn purge_wikipedia_entries(&self, list: WikipediaList, dry_run: bool) -> Result<WikipediaPurge, sqlx::Error> {
+        let stubs = format!("{} WHERE removed_at IS NULL AND {}", list.table(), WIKIPEDIA_STUB_CONDITION);
+        let removed = format!("{} WHERE removed_at IS NOT NULL", list.table());
+        let mut tx = self.pool.begin().await?;
+        
+        let mut purge = WikipediaPurge::default();
+        if dry_run {
+            let sql = format!("SELECT COUNT(*) FROM {}", stubs);
+            let mut query = sqlx::query_scalar::<_, i64>(&sql);
+            for value in WIKIPEDIA_STUB_VALUES {
+                query = query.bind(value);
+            }
+            purge.stubs = query.fetch_one(&mut *tx).await? as u64;
+            let sql = format!("SELECT COUNT(*) FROM {}", removed);
+            purge.removed = sqlx::query_scalar::<_, i64>(&sql).fetch_one(&mut *tx).await? as u64;
+        } else {
+            let sql = format!("DELETE FROM {}", stubs);
+            let mut query = sqlx::query(&sql);
+            for value in WIKIPEDIA_STUB_VALUES {
+                query = query.bind(value);
+            }
+            purge.stubs = query.execute(&mut *tx).await?.rows_affected();
+            let sql = format!("DELETE FROM {}", removed);
+            purge.removed = sqlx::query(&sql).execute(&mut *tx).await?.rows_affected();
+        }
+        
+        tx.commit().await?;
+        Ok(purge)
+    }
+    
+    /// Delete code samples created before `cutoff`, with their analysis
+    /// results. Samples whose results belong to a batch are kept, since the
+    /// batch still lists them. With `dry_run` only counts what would go.
+    pub async fn purge_code_samples(&self, cutoff: DateTime<Utc>, dry_run: bool) -> Result<SamplePurge, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        let ids: Vec<String> = sqlx::query_scalar(
+            "SELECT id FROM code_samples WHERE created_at < ? AND id NOT IN (SELECT r.code_sample_id FROM analysis_results r JOIN analysis_batch_files f ON f.analysis_result_id = r.id)"
+        )
+        .bind(cutoff)
+        .fetch_all(&mut *tx)
+        .await?;
+        
+        let mut purge = SamplePurge { cutoff, samples: 0, results: 0, dry_run };
+        for chunk in ids.chunks(PURGE_CHUNK) {
+            let placeholders = vec!["?"; chunk.len()].join(", ");
+            if dry_run {
+                let sql = format!("SELECT COUNT(*) FROM analysis_results WHERE code_sample_id IN ({})", placeholders);
+                let mut query = sqlx::query_scalar::<_, i64>(&sql);
+                for id in chunk {
+                    query = query.bind(id);
+                }
+                purge.results += query.fetch_one(&mut *tx).await? as u64;
+                purge.samples += chunk.len() as u64;
+                continue;
+            }
+            
+            let sql = format!("DELETE FROM analysis_results WHERE code_sample_id IN ({})", placeholders);
+            let mut query = sqlx::query(&sql);
+            for id in chunk {
+                query = query.bind(id);
+            }
+            purge.results += query.execute(&mut *tx).await?.rows_affected();
+            
+            let sql = format!("DELETE FROM code_samples WHERE id IN ({})", placeholders);
+            let mut query = sqlx::query(&sql);
+            for id in chunk {
+                query = query.bind(id);
+            }
+            purge.samples += query.execute(&mut *tx).await?.rows_affected();
+        }
+        
+        tx.commit().await?;
+        Ok(purge)
+    }
+    
+    pub async fn get_recent_analysis_results(&self, limit: u32) -> Result<Vec<AnalysisRecord>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, AnalysisRecordRow>(
+            "SELECT id, code_sample_id, CAST(patterns AS CHAR) AS patterns, CAST(algorithms AS CHAR) AS algorithms, CAST(detailed_algorithms AS CHAR) AS detailed_algorithms, CAST(data_structures AS CHAR) AS data_structures, complexity, CAST(recommendations AS CHAR) AS recommendations, created_at FROM analysis_results ORDER BY created_at DESC LIMIT ?"
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        
+        Ok(rows.into_iter().map(|row| row.into()).collect())
+    }
+    
+    pub async fn get_analysis_result(&self, id: Uuid) -> Result<Option<AnalysisRecord>, sqlx::Error> {
+        let row = sqlx::query_as::<_, AnalysisRecordRow>(
+            "SELECT id, code_sample_id, CAST(patterns AS CHAR) AS patterns, CAST(algorithms AS CHAR) AS algorithms, CAST(detailed_algorithms AS CHAR) AS detailed_algorithms, CAST(data_structures AS CHAR) AS data_structures, complexity, CAST(recommendations AS CHAR) AS recommendations, created_at FROM analysis_results WHERE id = ?"
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
+        
+        Ok(row.map(|row| row.into()))
+    }
+    
+    pub async fn save_api_key(&self, api_key: NewApiKey) -> Result<Uuid, sqlx::Error> {
+        let id = Uuid::new_v4();
+        
+        sqlx::query(
+            "INSERT INTO api_keys (id, name, key_hash, role, plan, created_at) VALUES (?, ?, ?, ?, ?, ?)"
+        )
+        .bind(id.to_string())
+        .bind(&api_key.name)
+        .bind(&api_key.key_hash)
+        .bind(&api_key.role)
+        .bind(&api_key.plan)
         .bind(Utc::now())
         .execute(&self.pool)
         .await?;
@@ -137,18 +992,373 @@ impl Database {
         Ok(id)
     }
     
-    pub async fn get_all_wikipedia_algorithms(&self) -> Result<Vec<WikipediaAlgorithm>, sqlx::Error> {
-        let algorithms = sqlx::query_as::<_, WikipediaAlgorithmRow>(
-            "SELECT title, url, category, description, complexity FROM wikipedia_algorithms ORDER BY title"
+    /// Insert the key unless a key with the same hash already exists
+    pub async fn ensure_api_key(&self, api_key: NewApiKey) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT IGNORE INTO api_keys (id, name, key_hash, role, plan, created_at) VALUES (?, ?, ?, ?, ?, ?)"
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(&api_key.name)
+        .bind(&api_key.key_hash)
+        .bind(&api_key.role)
+        .bind(&api_key.plan)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await?;
+        
+        Ok(())
+    }
+    
+    pub async fn find_active_api_key(&self, key_hash: &str) -> Result<Option<ApiKey>, sqlx::Error> {
+        let row = sqlx::query_as::<_, ApiKeyRow>(
+            "SELECT id, name, role, plan, created_at, revoked_at FROM api_keys WHERE key_hash = ? AND revoked_at IS NULL"
+        )
+        .bind(key_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+        
+        Ok(row.map(|row| row.into()))
+    }
+    
+    pub async fn list_api_keys(&self) -> Result<Vec<ApiKey>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, ApiKeyRow>(
+            "SELECT id, name, role, plan, created_at, revoked_at FROM api_keys ORDER BY created_at"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        
+        Ok(rows.into_iter().map(|row| row.into()).collect())
+    }
+    
+    /// Mark a key as revoked; returns `false` if no active key had that id
+    pub async fn revoke_api_key(&self, id: Uuid) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE api_keys SET revoked_at = ? WHERE id = ? AND revoked_at IS NULL"
+        )
+        .bind(Utc::now())
+        .bind(id.to_string())
+        .execute(&self.pool)
+        .await?;
+        
+        Ok(result.rows_affected() > 0)
+    }
+    
+    pub async fn get_plans(&self) -> Result<Vec<Plan>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, PlanRow>(
+            "SELECT name, requests_per_minute, burst, daily_quota FROM plans"
         )
         .fetch_all(&self.pool)
         .await?;
         
-        Ok(algorithms.into_iter().map(|row| row.into()).collect())
+        Ok(rows.into_iter().map(|row| row.into()).collect())
     }
+    
+    /// Requests recorded for `client` on `day`
+    pub async fn get_usage(&self, client: &str, day: NaiveDate) -> Result<u32, sqlx::Error> {
+        let requests: Option<u32> = sqlx::query_scalar(
+            "SELECT requests FROM api_usage WHERE client = ? AND day = ?"
+        )
+        .bind(client)
+        .bind(day)
+        .fetch_optional(&self.pool)
+        .await?;
+        
+        Ok(requests.unwrap_or(0))
+    }
+    
+    pub async fn record_usage(&self, client: &str, api_key_id: Option<Uuid>, day: NaiveDate, requests: u32) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO api_usage (client, api_key_id, day, requests) VALUES (?, ?, ?, ?) ON DUPLICATE KEY UPDATE requests = requests + VALUES(requests)"
+        )
+        .bind(client)
+        .bind(api_key_id.map(|id| id.to_string()))
+        .bind(day)
+        .bind(requests)
+        .execute(&self.pool)
+        .await?;
+        
+        Ok(())
+    }
+    
+    /// Per-client usage between `from` and `to`, inclusive
+    pub async fn get_usage_report(&self, from: NaiveDate, to: NaiveDate) -> Result<Vec<KeyUsage>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, KeyUsageRow>(
+            "SELECT u.client, u.api_key_id, k.name, k.plan, u.day, u.requests FROM api_usage u LEFT JOIN api_keys k ON k.id = u.api_key_id WHERE u.day BETWEEN ? AND ? ORDER BY u.day DESC, u.requests DESC"
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+        
+        Ok(rows.into_iter().map(|row| row.into()).collect())
+    }
+}
+
+async fn upsert_wikipedia_entry<'e, E: Executor<'e, Database = MySql>>(executor: E, list: WikipediaList, entry: &WikipediaAlgorithm, now: DateTime<Utc>) -> Result<(), sqlx::Error> {
+    sqlx::query(&format!(
+        "INSERT INTO {} (id, title, url, category, description, complexity, revision_id, fetched_at, updated_at, last_seen_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?) ON DUPLICATE KEY UPDATE title = VALUES(title), category = VALUES(category), description = VALUES(description), complexity = VALUES(complexity), revision_id = COALESCE(VALUES(revision_id), revision_id), updated_at = VALUES(updated_at), last_seen_at = VALUES(last_seen_at), removed_at = NULL",
+        list.table()
+    ))
+    .bind(Uuid::new_v4().to_string())
+    .bind(&entry.title)
+    .bind(&entry.url)
+    .bind(&entry.category)
+    .bind(&entry.description)
+    .bind(&entry.complexity)
+    .bind(entry.revision_id)
+    .bind(now)
+    .bind(now)
+    .bind(now)
+    .execute(executor)
+    .await?;
+    
+    Ok(())
 }
 
 // Helper struct for database queries
+async fn insert_code_sample<'e, E: Executor<'e, Database = MySql>>(executor: E, code_sample: NewCodeSample) -> Result<Uuid, sqlx::Error> {
+    let id = Uuid::new_v4();
+    
+    sqlx::query(
+        "INSERT INTO code_samples (id, code, language, created_at) VALUES (?, ?, ?, ?)"
+    )
+    .bind(id.to_string())
+    .bind(code_sample.code)
+    .bind(code_sample.language)
+    .bind(Utc::now())
+    .execute(executor)
+    .await?;
+    
+    Ok(id)
+}
+
+async fn insert_analysis_result<'e, E: Executor<'e, Database = MySql>>(executor: E, analysis_result: NewAnalysisResult) -> Result<Uuid, sqlx::Error> {
+    let id = Uuid::new_v4();
+    
+    // Convert Vec<String> to JSON strings
+    let patterns_json = serde_json::to_string(&analysis_result.patterns).unwrap_or("[]".to_string());
+    let algorithms_json = serde_json::to_string(&analysis_result.algorithms).unwrap_or("[]".to_string());
+    let detailed_algorithms_json = serde_json::to_string(&analysis_result.detailed_algorithms).unwrap_or("[]".to_string());
+    let data_structures_json = serde_json::to_string(&analysis_result.data_structures).unwrap_or("[]".to_string());
+    let recommendations_json = serde_json::to_string(&analysis_result.recommendations).unwrap_or("[]".to_string());
+    
+    sqlx::query(
+        "INSERT INTO analysis_results (id, code_sample_id, patterns, algorithms, detailed_algorithms, data_structures, complexity, recommendations, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"
+    )
+    .bind(id.to_string())
+    .bind(analysis_result.code_sample_id.to_string())
+    .bind(patterns_json)
+    .bind(algorithms_json)
+    .bind(detailed_algorithms_json)
+    .bind(data_structures_json)
+    .bind(analysis_result.complexity)
+    .bind(recommendations_json)
+    .bind(Utc::now())
+    .execute(executor)
+    .await?;
+    
+    Ok(id)
+}
+
+/// Move a pending change to `status`; `false` if it was no longer pending
+async fn review_catalog_change<'e, E: Executor<'e, Database = MySql>>(executor: E, id: Uuid, status: ChangeStatus, reviewer: &Actor, at: DateTime<Utc>) -> Result<bool, sqlx::Error> {
+    let reviewed = sqlx::query(
+        "UPDATE catalog_changes SET status = ?, reviewer = ?, reviewer_key_id = ?, reviewed_at = ? WHERE id = ? AND status = ?"
+    )
+    .bind(status.as_str())
+    .bind(&reviewer.name)
+    .bind(reviewer.key_id.map(|key_id| key_id.to_string()))
+    .bind(at)
+    .bind(id.to_string())
+    .bind(ChangeStatus::Pending.as_str())
+    .execute(executor)
+    .await?;
+    
+    Ok(reviewed.rows_affected() == 1)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn insert_catalog_audit<'e, E: Executor<'e, Database = MySql>>(
+    executor: E,
+    change_id: Uuid,
+    kind: &CatalogKind,
+    name: &str,
+    action: ChangeAction,
+    status: ChangeStatus,
+    actor: &Actor,
+    diff: &serde_json::Value,
+) -> Result<Uuid, sqlx::Error> {
+    let id = Uuid::new_v4();
+    
+    sqlx::query(
+        "INSERT INTO catalog_audit_log (id, change_id, kind, name, action, status, actor, actor_key_id, diff, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+    )
+    .bind(id.to_string())
+    .bind(change_id.to_string())
+    .bind(kind.as_str())
+    .bind(name)
+    .bind(action.as_str())
+    .bind(status.as_str())
+    .bind(&actor.name)
+    .bind(actor.key_id.map(|key_id| key_id.to_string()))
+    .bind(diff.to_string())
+    .bind(Utc::now())
+    .execute(executor)
+    .await?;
+    
+    Ok(id)
+}
+
+const CATALOG_CHANGE_SELECT: &str = "SELECT id, kind, name, action, CAST(entry AS CHAR) AS entry, CAST(diff AS CHAR) AS diff, status, author, author_key_id, created_at, reviewer, reviewer_key_id, reviewed_at FROM catalog_changes";
+
+#[derive(sqlx::FromRow)]
+struct CatalogChangeRow {
+    id: String,
+    kind: String,
+    name: String,
+    action: String,
+    entry: Option<String>,
+    diff: Option<String>,
+    status: String,
+    author: String,
+    author_key_id: Option<String>,
+    created_at: DateTime<Utc>,
+    reviewer: Option<String>,
+    reviewer_key_id: Option<String>,
+    reviewed_at: Option<DateTime<Utc>>,
+}
+
+fn actor(name: String, key_id: Option<String>) -> Actor {
+    Actor {
+        name,
+        key_id: key_id.and_then(|id| Uuid::parse_str(&id).ok()),
+    }
+}
+
+impl From<CatalogChangeRow> for CatalogChange {
+    fn from(row: CatalogChangeRow) -> Self {
+        CatalogChange {
+            id: Uuid::parse_str(&row.id).unwrap_or_default(),
+            kind: row.kind.parse().unwrap_or(CatalogKind::Algorithm),
+            name: row.name,
+            action: row.action.parse().unwrap_or(ChangeAction::Update),
+            entry: row.entry.and_then(|json| serde_json::from_str(&json).ok()),
+            diff: parse_json_column(row.diff),
+            status: row.status.parse().unwrap_or(ChangeStatus::Rejected),
+            author: actor(row.author, row.author_key_id),
+            created_at: row.created_at,
+            reviewer: row.reviewer.map(|name| actor(name, row.reviewer_key_id)),
+            reviewed_at: row.reviewed_at,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct CatalogOverrideRow {
+    kind: String,
+    name: String,
+    entry: Option<String>,
+}
+
+impl TryFrom<CatalogOverrideRow> for CatalogOverride {
+    type Error = String;
+    
+    fn try_from(row: CatalogOverrideRow) -> Result<Self, Self::Error> {
+        Ok(CatalogOverride {
+            kind: row.kind.parse()?,
+            name: row.name,
+            entry: row.entry.map(|json| serde_json::from_str(&json)).transpose().map_err(|e| e.to_string())?,
+        })
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct CatalogAuditRow {
+    id: String,
+    change_id: String,
+    kind: String,
+    name: String,
+    action: String,
+    status: String,
+    actor: String,
+    actor_key_id: Option<String>,
+    diff: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<CatalogAuditRow> for CatalogAuditEntry {
+    fn from(row: CatalogAuditRow) -> Self {
+        CatalogAuditEntry {
+            id: Uuid::parse_str(&row.id).unwrap_or_default(),
+            change_id: Uuid::parse_str(&row.change_id).unwrap_or_default(),
+            kind: row.kind.parse().unwrap_or(CatalogKind::Algorithm),
+            name: row.name,
+            action: row.action.parse().unwrap_or(ChangeAction::Update),
+            status: row.status.parse().unwrap_or(ChangeStatus::Pending),
+            actor: actor(row.actor, row.actor_key_id),
+            diff: parse_json_column(row.diff),
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct AnalysisJobRow {
+    id: String,
+    status: String,
+    owner_key_id: Option<String>,
+    total: u32,
+    completed: u32,
+    error_code: Option<String>,
+    error_message: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<AnalysisJobRow> for AnalysisJob {
+    fn from(row: AnalysisJobRow) -> Self {
+        AnalysisJob {
+            id: Uuid::parse_str(&row.id).unwrap_or_default(),
+            status: row.status.parse().unwrap_or(JobStatus::Failed),
+            owner_key_id: row.owner_key_id.and_then(|id| Uuid::parse_str(&id).ok()),
+            total: row.total,
+            completed: row.completed,
+            error: row
+                .error_code
+                .map(|code| ErrorResponse::new(&code, row.error_message.unwrap_or_default())),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct AnalysisBatchRow {
+    id: String,
+    name: Option<String>,
+    language: String,
+    owner_key_id: Option<String>,
+    inventory: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(sqlx::FromRow)]
+struct BatchFileRow {
+    path: String,
+    analysis_result_id: Option<String>,
+    error: Option<String>,
+}
+
+impl From<BatchFileRow> for BatchFileRecord {
+    fn from(row: BatchFileRow) -> Self {
+        BatchFileRecord {
+            path: row.path,
+            analysis_id: row.analysis_result_id.and_then(|id| Uuid::parse_str(&id).ok()),
+            error: row.error,
+        }
+    }
+}
+
 #[derive(sqlx::FromRow)]
 struct WikipediaAlgorithmRow {
     title: String,
@@ -156,16 +1366,124 @@ struct WikipediaAlgorithmRow {
     category: String,
     description: Option<String>,
     complexity: Option<String>,
+    revision_id: Option<u64>,
+    last_seen_at: Option<DateTime<Utc>>,
+    removed_at: Option<DateTime<Utc>>,
 }
 
-impl From<WikipediaAlgorithmRow> for WikipediaAlgorithm {
+impl From<WikipediaAlgorithmRow> for StoredWikipediaAlgorithm {
     fn from(row: WikipediaAlgorithmRow) -> Self {
-        WikipediaAlgorithm {
-            title: row.title,
-            url: row.url,
-            category: row.category,
-            description: row.description,
-            complexity: row.complexity,
+        StoredWikipediaAlgorithm {
+            algorithm: WikipediaAlgorithm {
+                title: row.title,
+                url: row.url,
+                category: row.category,
+                description: row.description,
+                complexity: row.complexity,
+                revision_id: row.revision_id,
+            },
+            last_seen_at: row.last_seen_at,
+            removed_at: row.removed_at,
         }
     }
-}
\ No newline at end of file
+}
+#[derive(sqlx::FromRow)]
+struct AnalysisRecordRow {
+    id: String,
+    code_sample_id: String,
+    patterns: Option<String>,
+    algorithms: Option<String>,
+    detailed_algorithms: Option<String>,
+    data_structures: Option<String>,
+    complexity: Option<String>,
+    recommendations: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+/// Parse a JSON column, treating NULL or malformed data as empty
+fn parse_json_column<T: serde::de::DeserializeOwned + Default>(value: Option<String>) -> T {
+    value
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+impl From<AnalysisRecordRow> for AnalysisRecord {
+    fn from(row: AnalysisRecordRow) -> Self {
+        AnalysisRecord {
+            id: Uuid::parse_str(&row.id).unwrap_or_default(),
+            code_sample_id: Uuid::parse_str(&row.code_sample_id).unwrap_or_default(),
+            patterns: parse_json_column(row.patterns),
+            algorithms: parse_json_column(row.algorithms),
+            detailed_algorithms: parse_json_column(row.detailed_algorithms),
+            data_structures: parse_json_column(row.data_structures),
+            complexity: row.complexity.unwrap_or_default(),
+            recommendations: parse_json_column(row.recommendations),
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct ApiKeyRow {
+    id: String,
+    name: String,
+    role: String,
+    plan: String,
+    created_at: DateTime<Utc>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<ApiKeyRow> for ApiKey {
+    fn from(row: ApiKeyRow) -> Self {
+        ApiKey {
+            id: Uuid::parse_str(&row.id).unwrap_or_default(),
+            name: row.name,
+            role: row.role,
+            plan: row.plan,
+            created_at: row.created_at,
+            revoked_at: row.revoked_at,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct PlanRow {
+    name: String,
+    requests_per_minute: u32,
+    burst: u32,
+    daily_quota: u32,
+}
+
+impl From<PlanRow> for Plan {
+    fn from(row: PlanRow) -> Self {
+        Plan {
+            name: row.name,
+            requests_per_minute: row.requests_per_minute,
+            burst: row.burst,
+            daily_quota: row.daily_quota,
+        }
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct KeyUsageRow {
+    client: String,
+    api_key_id: Option<String>,
+    name: Option<String>,
+    plan: Option<String>,
+    day: NaiveDate,
+    requests: u32,
+}
+
+impl From<KeyUsageRow> for KeyUsage {
+    fn from(row: KeyUsageRow) -> Self {
+        KeyUsage {
+            client: row.client,
+            api_key_id: row.api_key_id.and_then(|id| Uuid::parse_str(&id).ok()),
+            name: row.name,
+            plan: row.plan,
+            day: row.day,
+            requests: row.requests,
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! Locating the functions of a source file
+//!
+//! Streamed analyses run the detectors function by function. Input is
+//! often a snippet that does not compile, so this is a lenient scan for
+//! `fn name ... { ... }` rather than a parse: comments, string and char
+//! literals are skipped, and braces are matched to find each body. Nested
+//! functions stay part of the function that contains them; methods inside
+//! `impl` and `trait` blocks are found on their own.
+
+use std::ops::Range;
+
+/// A function and where it sits in the source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceFunction {
+    pub name: String,
+    /// 1-based line of the `fn` keyword
+    pub line: usize,
+    /// Byte range from the `fn` keyword to the closing brace
+    pub span: Range<usize>,
+}
+
+/// Functions with a body, in source order
+pub fn split_functions(code: &str) -> Vec<SourceFunction> {
+    let bytes = code.as_bytes();
+    let mut functions = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if let Some(next) = skip_non_code(bytes, i) {
+            i = next;
+            continue;
+        }
+        if is_keyword_at(bytes, i, b"fn") {
+            if let Some(function) = function_at(code, i) {
+                i = function.span.end;
+                functions.push(function);
+                continue;
+            }
+        }
+        i += 1;
+    }
+
+    functions
+}
+
+/// The code with comments and literals blanked out, keeping line breaks
+pub fn strip_non_code(code: &str) -> String {
+    let bytes = code.as_bytes();
+    let mut stripped = String::with_capacity(code.len());
+    let mut i = 0;
+    let mut kept = 0;
+
+    while i < bytes.len() {
+        match skip_non_code(bytes, i) {
+            Some(next) => {
+                stripped.push_str(&code[kept..i]);
+                stripped.extend(code[i..next].chars().map(|c| if c == '\n' { '\n' } else { ' ' }));
+                i = next;
+                kept = next;
+            }
+            None => i += 1,
+        }
+    }
+    stripped.push_str(&code[kept..]);
+    stripped
+}
+
+/// Read the function whose `fn` keyword starts at `start`, if it has a body
+fn function_at(code: &str, start: usize) -> Option<SourceFunction> {
+    let bytes = code.as_bytes();
+    let name_start = skip_whitespace(bytes, start + 2);
+    let name_end = name_start + bytes[name_start..].iter().take_while(|&&b| is_ident_byte(b)).count();
+    if name_end == name_start {
+        // `fn(u32) -> u32` is a pointer type, not a definition
+        return None;
+    }
+
+    // The body opens at the first brace outside the signature's parentheses;
+    // a semicolon first means a declaration without a body
+    let mut i = name_end;
+    let mut parens = 0usize;
+    let body_start = loop {
+        if let Some(next) = skip_non_code(bytes, i) {
+            i = next;
+            continue;
+        }
+        match bytes.get(i)? {
+            b'(' | b'[' => parens += 1,
+            b')' | b']' => parens = parens.saturating_sub(1),
+            b';' if parens == 0 => return None,
+            b'{' if parens == 0 => break i,
+            _ => {}
+        }
+        i += 1;
+    };
+
+    let mut depth = 0usize;
+    let mut i = body_start;
+    let end = loop {
+        if let Some(next) = skip_non_code(bytes, i) {
+            i = next;
+            continue;
+        }
+        match bytes.get(i)? {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    break i + 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    };
+
+    Some(SourceFunction {
+        name: code[name_start..name_end].to_string(),
+        line: code[..start].matches('\n').count() + 1,
+        span: start..end,
+    })
+}
+
+/// If a comment or literal starts at `i`, the index just past it
+pub(crate) fn skip_non_code(bytes: &[u8], i: usize) -> Option<usize> {
+    let rest = &bytes[i..];
+    if rest.starts_with(b"//") {
+        let end = rest.iter().position(|&b| b == b'\n').unwrap_or(rest.len());
+        return Some(i + end);
+    }
+    if rest.starts_with(b"/*") {
+        // Block comments nest in Rust
+        let mut depth = 0usize;
+        let mut j = i;
+        while j < bytes.len() {
+            if bytes[j..].starts_with(b"/*") {
+                depth += 1;
+                j += 2;
+            } else if bytes[j..].starts_with(b"*/") {
+                depth -= 1;
+                j += 2;
+                if depth == 0 {
+                    return Some(j);
+                }
+            } else {
+                j += 1;
+            }
+        }
+        return Some(bytes.len());
+    }
+
+    let preceded_by_ident = i > 0 && is_ident_byte(bytes[i - 1]);
+    match rest.first()? {
+        b'"' => Some(skip_string(bytes, i + 1)),
+        b'r' | b'b' if !preceded_by_ident => skip_prefixed_string(bytes, i),
+        b'\'' => skip_char(bytes, i),
+        _ => None,
+    }
+}
+
+/// Index past the closing quote of a string whose contents start at `i`
+fn skip_string(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Byte strings `b"..."` and raw strings `r"..."`, `r#"..."#`, `br#"..."#`
+fn skip_prefixed_string(bytes: &[u8], i: usize) -> Option<usize> {
+    let mut j = i;
+    if bytes[j] == b'b' {
+        j += 1;
+    }
+    let raw = bytes.get(j) == Some(&b'r');
+    if raw {
+        j += 1;
+    }
+    let hashes = bytes[j..].iter().take_while(|&&b| b == b'#').count();
+    j += hashes;
+    if bytes.get(j) != Some(&b'"') || (!raw && hashes > 0) || j == i {
+        return None;
+    }
+    if !raw {
+        return Some(skip_string(bytes, j + 1));
+    }
+
+    let mut terminator = vec![b'"'];
+    terminator.extend(std::iter::repeat_n(b'#', hashes));
+    let body = j + 1;
+    Some(
+        bytes[body..]
+            .windows(terminator.len())
+            .position(|window| window == terminator.as_slice())
+            .map_or(bytes.len(), |pos| body + pos + terminator.len()),
+    )
+}
+
+/// Char literals; a quote that starts a lifetime or label is left alone
+fn skip_char(bytes: &[u8], i: usize) -> Option<usize> {
+    match bytes.get(i + 1)? {
+        b'\\' => {
+            let close = bytes[i + 2..].iter().position(|&b| b == b'\'')?;
+            Some(i + 2 + close + 1)
+        }
+        _ => {
+            // The literal may hold a multi-byte character
+            let width = std::str::from_utf8(&bytes[i + 1..(i + 5).min(bytes.len())])
+                .or_else(|e| std::str::from_utf8(&bytes[i + 1..i + 1 + e.valid_up_to()]))
+                .ok()?
+                .chars()
+                .next()?
+                .len_utf8();
+            (bytes.get(i + 1 + width) == Some(&b'\'')).then_some(i + 2 + width)
+        }
+    }
+}
+
+fn skip_whitespace(bytes: &[u8], i: usize) -> usize {
+    i + bytes[i..].iter().take_while(|b| b.is_ascii_whitespace()).count()
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+pub(crate) fn is_keyword_at(bytes: &[u8], i: usize, keyword: &[u8]) -> bool {
+    bytes[i..].starts_with(keyword)
+        && (i == 0 || !is_ident_byte(bytes[i - 1]))
+        && bytes.get(i + keyword.len()).is_none_or(|&b| !is_ident_byte(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(code: &str) -> Vec<(String, usize)> {
+        split_functions(code).into_iter().map(|f| (f.name, f.line)).collect()
+    }
+
+    #[test]
+    fn test_finds_functions_and_methods() {
+        let code = r#"use std::collections::HashMap;
+
+fn bubble_sort(v: &mut [i32]) {
+    fn helper() {}
+    for i in 0..v.len() {}
+}
+
+impl Graph {
+    pub async fn bfs<'a>(&'a self, start: usize) -> Vec<usize> where Self: Sized {
+        vec![]
+    }
+}
+
+trait Search {
+    fn find(&self) -> Option<usize>;
+    fn contains(&self) -> bool { self.find().is_some() }
+}
+"#;
+        assert_eq!(
+            names(code),
+            vec![
+                ("bubble_sort".to_string(), 3),
+                ("bfs".to_string(), 9),
+                ("contains".to_string(), 16),
+            ]
+        );
+
+        let first = &split_functions(code)[0];
+        assert!(code[first.span.clone()].starts_with("fn bubble_sort"));
+        assert!(code[first.span.clone()].ends_with("{}\n}"));
+    }
+
+    #[test]
+    fn test_ignores_braces_in_comments_and_literals() {
+        let code = "fn a() {\n    let s = \"}\"; // }\n    let c = '}'; let r = r#\"}\"#;\n    /* } /* } */ */\n}\nfn b() {}\n";
+        assert_eq!(names(code), vec![("a".to_string(), 1), ("b".to_string(), 6)]);
+
+        let stripped = strip_non_code(code);
+        assert_eq!(stripped.lines().count(), code.lines().count());
+        assert_eq!(stripped.matches('}').count(), 2);
+        assert!(stripped.starts_with("fn a() {\n    let s =    ; "));
+    }
+
+    #[test]
+    fn test_ignores_fn_types_and_unclosed_bodies() {
+        assert!(names("type F = fn(u32) -> u32;\nlet define = 1;").is_empty());
+        assert!(names("fn broken() {\n    if x {").is_empty());
+    }
+}
@@ -0,0 +1,165 @@
+//! Liveness and readiness reporting
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::algorithms::AlgorithmDetector;
+use crate::data_structures::DataStructureDetector;
+
+/// Crate version from Cargo.toml
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Short git revision captured by build.rs
+pub const GIT_REVISION: &str = env!("GIT_REVISION");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Fail,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CheckResult {
+    pub name: String,
+    pub status: CheckStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl CheckResult {
+    pub fn pass(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Pass,
+            detail: None,
+        }
+    }
+
+    pub fn fail(name: &str, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Fail,
+            detail: Some(detail.into()),
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.status == CheckStatus::Pass
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct HealthReport {
+    pub status: String,
+    pub version: String,
+    pub git_revision: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub checks: Vec<CheckResult>,
+}
+
+impl HealthReport {
+    /// Report for the liveness probe: the process is up and serving
+    pub fn live() -> Self {
+        Self {
+            status: "alive".to_string(),
+            version: VERSION.to_string(),
+            git_revision: GIT_REVISION.to_string(),
+            checks: Vec::new(),
+        }
+    }
+
+    /// Report for the readiness probe; ready only when every check passed
+    pub fn ready(checks: Vec<CheckResult>) -> Self {
+        let ready = checks.iter().all(CheckResult::passed);
+        Self {
+            status: if ready { "ready" } else { "not_ready" }.to_string(),
+            version: VERSION.to_string(),
+            git_revision: GIT_REVISION.to_string(),
+            checks,
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.status == "ready"
+    }
+}
+
+/// Check that both catalogs were loaded
+pub fn check_catalogs(
+    algorithm_detector: &AlgorithmDetector,
+    data_structure_detector: &DataStructureDetector,
+) -> CheckResult {
+    if algorithm_detector.algorithms.is_empty() {
+        CheckResult::fail("catalog", "algorithm catalog is empty")
+    } else if data_structure_detector.data_structures.is_empty() {
+        CheckResult::fail("catalog", "data structure catalog is empty")
+    } else {
+        CheckResult::pass("catalog")
+    }
+}
+
+const SELF_TEST_ALGORITHM: &str = r#"
+fn bubble_sort(arr: &mut [i32]) {
+    for i in 0..arr.len() {
+        for j in 0..arr.len() - 1 - i {
+            if arr[j] > arr[j + 1] { arr.swap(j, j + 1); }
+        }
+    }
+}
+"#;
+
+const SELF_TEST_DATA_STRUCTURE: &str = r#"
+use std::collections::VecDeque;
+fn main() {
+    let mut queue = VecDeque::new();
+    queue.push_back(1);
+}
+"#;
+
+/// Run the detectors on known samples and check they find what they should
+pub fn run_detector_self_test(
+    algorithm_detector: &AlgorithmDetector,
+    data_structure_detector: &DataStructureDetector,
+) -> Vec<CheckResult> {
+    let mut checks = Vec::new();
+
+    let algorithms = algorithm_detector.detect_algorithms(SELF_TEST_ALGORITHM);
+    checks.push(if algorithms.iter().any(|alg| alg.name == "Bubble Sort") {
+        CheckResult::pass("algorithm_detector")
+    } else {
+        CheckResult::fail("algorithm_detector", "Bubble Sort sample was not detected")
+    });
+
+    let data_structures = data_structure_detector.detect_data_structures(SELF_TEST_DATA_STRUCTURE);
+    checks.push(if data_structures.iter().any(|ds| ds.name == "Queue") {
+        CheckResult::pass("data_structure_detector")
+    } else {
+        CheckResult::fail("data_structure_detector", "Queue sample was not detected")
+    });
+
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_test_passes_with_builtin_catalogs() {
+        let algorithm_detector = AlgorithmDetector::new();
+        let data_structure_detector = DataStructureDetector::new();
+
+        assert!(check_catalogs(&algorithm_detector, &data_structure_detector).passed());
+        let checks = run_detector_self_test(&algorithm_detector, &data_structure_detector);
+        assert!(checks.iter().all(CheckResult::passed), "{:?}", checks);
+    }
+
+    #[test]
+    fn test_readiness_requires_all_checks() {
+        let report = HealthReport::ready(vec![CheckResult::pass("a"), CheckResult::fail("b", "down")]);
+        assert!(!report.is_ready());
+        assert!(HealthReport::ready(vec![CheckResult::pass("a")]).is_ready());
+        assert_eq!(HealthReport::live().version, VERSION);
+    }
+}
@@ -0,0 +1,125 @@
+//! Background analysis jobs
+//!
+//! Jobs are stored in the `analysis_jobs` table, which is the source of
+//! truth; the in-memory queue only carries the ids of queued jobs to the
+//! workers. A worker claims a job by switching it from `queued` to
+//! `running` in the database, so an id delivered twice is processed once.
+//! On startup, jobs left `running` by a previous process are queued again,
+//! or marked failed once they have used up their attempts.
+
+use std::env;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+use uuid::Uuid;
+
+/// Default number of jobs processed at the same time
+pub const DEFAULT_JOB_WORKERS: usize = 2;
+
+/// Default number of times a job is started before it is given up
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobSettings {
+    pub workers: usize,
+    pub max_attempts: u32,
+}
+
+impl Default for JobSettings {
+    fn default() -> Self {
+        Self {
+            workers: DEFAULT_JOB_WORKERS,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+}
+
+impl JobSettings {
+    /// Read `ANALYZER_JOB_WORKERS` and `ANALYZER_JOB_MAX_ATTEMPTS`, falling back to the defaults
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            workers: env::var("ANALYZER_JOB_WORKERS")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .filter(|&workers| workers > 0)
+                .unwrap_or(defaults.workers),
+            max_attempts: env::var("ANALYZER_JOB_MAX_ATTEMPTS")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .filter(|&attempts| attempts > 0)
+                .unwrap_or(defaults.max_attempts),
+        }
+    }
+}
+
+/// Hands queued job ids to a fixed pool of workers
+#[derive(Clone)]
+pub struct JobQueue {
+    sender: mpsc::UnboundedSender<Uuid>,
+}
+
+impl JobQueue {
+    /// Spawn `workers` tasks that run `handler` for each enqueued job id
+    pub fn start<F, Fut>(workers: usize, handler: F) -> Self
+    where
+        F: Fn(Uuid) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let handler = Arc::new(handler);
+
+        for _ in 0..workers.max(1) {
+            let receiver = receiver.clone();
+            let handler = handler.clone();
+            tokio::spawn(async move {
+                loop {
+                    // Idle workers wait for the lock; the holder waits for the next id
+                    let next = receiver.lock().await.recv().await;
+                    match next {
+                        Some(id) => handler(id).await,
+                        None => break,
+                    }
+                }
+            });
+        }
+
+        Self { sender }
+    }
+
+    pub fn enqueue(&self, id: Uuid) {
+        if self.sender.send(id).is_err() {
+            eprintln!("Job workers have stopped; job {} stays queued until restart", id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[tokio::test]
+    async fn test_workers_process_every_job() {
+        let (done_tx, mut done_rx) = mpsc::unbounded_channel();
+        let queue = JobQueue::start(3, move |id| {
+            let done_tx = done_tx.clone();
+            async move {
+                tokio::task::yield_now().await;
+                done_tx.send(id).unwrap();
+            }
+        });
+
+        let ids: HashSet<Uuid> = (0..10).map(|_| Uuid::new_v4()).collect();
+        for id in &ids {
+            queue.enqueue(*id);
+        }
+
+        let mut processed = HashSet::new();
+        while processed.len() < ids.len() {
+            processed.insert(done_rx.recv().await.unwrap());
+        }
+        assert_eq!(processed, ids);
+    }
+}
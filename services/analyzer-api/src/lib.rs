@@ -2,4 +2,20 @@ pub mod db;
 pub mod models;
 pub mod algorithms;
 pub mod data_structures;
-pub mod wikipedia;
\ No newline at end of file
+pub mod wikipedia;
+pub mod limits;
+pub mod auth;
+pub mod rate_limit;
+pub mod metrics;
+pub mod health;
+pub mod openapi;
+pub mod versioning;
+pub mod batch;
+pub mod jobs;
+pub mod functions;
+pub mod catalog;
+pub mod complexity;
+pub mod relations;
+pub mod admin;
+pub mod anti_patterns;
+pub mod syntax;
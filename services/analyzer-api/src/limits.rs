@@ -0,0 +1,225 @@
+//! Request size limits, analysis time budgets and input validation
+
+use std::env;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Default maximum size of a request body, in bytes
+pub const DEFAULT_MAX_PAYLOAD_BYTES: usize = 256 * 1024;
+
+/// Default time budget for a single analysis, in milliseconds
+pub const DEFAULT_ANALYSIS_TIMEOUT_MS: u64 = 2_000;
+
+/// Default maximum size of a batch request or archive upload, in bytes
+pub const DEFAULT_MAX_BATCH_BYTES: usize = 8 * 1024 * 1024;
+
+/// Default maximum number of files in one batch
+pub const DEFAULT_MAX_BATCH_FILES: usize = 200;
+
+/// Default time budget for a whole batch, in milliseconds
+pub const DEFAULT_BATCH_TIMEOUT_MS: u64 = 60_000;
+
+/// Share of control characters above which input is treated as binary
+const MAX_CONTROL_CHAR_RATIO: f64 = 0.1;
+
+/// Limits applied to incoming analysis requests
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnalysisLimits {
+    pub max_payload_bytes: usize,
+    pub analysis_timeout: Duration,
+    pub max_batch_bytes: usize,
+    pub max_batch_files: usize,
+    /// Number of files of one batch analyzed at the same time
+    pub batch_concurrency: usize,
+    /// Time after which the files of a batch not yet analyzed are skipped
+    pub batch_timeout: Duration,
+}
+
+impl Default for AnalysisLimits {
+    fn default() -> Self {
+        Self {
+            max_payload_bytes: DEFAULT_MAX_PAYLOAD_BYTES,
+            analysis_timeout: Duration::from_millis(DEFAULT_ANALYSIS_TIMEOUT_MS),
+            max_batch_bytes: DEFAULT_MAX_BATCH_BYTES,
+            max_batch_files: DEFAULT_MAX_BATCH_FILES,
+            batch_concurrency: std::thread::available_parallelism().map_or(4, |n| n.get()),
+            batch_timeout: Duration::from_millis(DEFAULT_BATCH_TIMEOUT_MS),
+        }
+    }
+}
+
+impl AnalysisLimits {
+    /// Read limits from `ANALYZER_MAX_PAYLOAD_BYTES`, `ANALYZER_ANALYSIS_TIMEOUT_MS`,
+    /// `ANALYZER_MAX_BATCH_BYTES`, `ANALYZER_MAX_BATCH_FILES`,
+    /// `ANALYZER_BATCH_CONCURRENCY` and `ANALYZER_BATCH_TIMEOUT_MS`, falling
+    /// back to the defaults when a variable is missing or invalid
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        let max_payload_bytes = env_usize("ANALYZER_MAX_PAYLOAD_BYTES").unwrap_or(defaults.max_payload_bytes);
+
+        let analysis_timeout = env::var("ANALYZER_ANALYSIS_TIMEOUT_MS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(defaults.analysis_timeout);
+
+        Self {
+            max_payload_bytes,
+            analysis_timeout,
+            max_batch_bytes: env_usize("ANALYZER_MAX_BATCH_BYTES").unwrap_or(defaults.max_batch_bytes),
+            max_batch_files: env_usize("ANALYZER_MAX_BATCH_FILES").unwrap_or(defaults.max_batch_files),
+            batch_concurrency: env_usize("ANALYZER_BATCH_CONCURRENCY")
+                .filter(|&n| n > 0)
+                .unwrap_or(defaults.batch_concurrency),
+            batch_timeout: env::var("ANALYZER_BATCH_TIMEOUT_MS")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.batch_timeout),
+        }
+    }
+
+    /// Budget for one request; a client may ask for less time than the server allows, never more
+    pub fn budget_for(&self, requested_ms: Option<u64>) -> AnalysisBudget {
+        let timeout = match requested_ms {
+            Some(ms) => self.analysis_timeout.min(Duration::from_millis(ms)),
+            None => self.analysis_timeout,
+        };
+        AnalysisBudget::new(timeout)
+    }
+}
+
+fn env_usize(name: &str) -> Option<usize> {
+    env::var(name).ok().and_then(|value| value.parse().ok())
+}
+
+/// Time budget shared by the detectors working on one request.
+///
+/// Detectors call [`AnalysisBudget::check`] between units of work and stop
+/// early once it returns `false`; the budget remembers that it cut work
+/// short so the response can be flagged as partial.
+#[derive(Debug)]
+pub struct AnalysisBudget {
+    deadline: Option<Instant>,
+    exceeded: AtomicBool,
+}
+
+impl AnalysisBudget {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            deadline: Some(Instant::now() + timeout),
+            exceeded: AtomicBool::new(false),
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Self {
+            deadline: None,
+            exceeded: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns `true` while there is time left
+    pub fn check(&self) -> bool {
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => {
+                self.exceeded.store(true, Ordering::Relaxed);
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// Time left before the deadline; `None` when unlimited
+    pub fn remaining(&self) -> Option<Duration> {
+        self.deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Whether any detector stopped early because the budget ran out
+    pub fn exceeded(&self) -> bool {
+        self.exceeded.load(Ordering::Relaxed)
+    }
+}
+
+/// Reasons a submission is rejected before analysis
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputError {
+    Empty,
+    Binary,
+}
+
+impl InputError {
+    /// Machine-readable error code returned to clients
+    pub fn code(&self) -> &'static str {
+        match self {
+            InputError::Empty => "empty_input",
+            InputError::Binary => "binary_input",
+        }
+    }
+}
+
+impl std::fmt::Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Empty => write!(f, "The submitted code is empty"),
+            InputError::Binary => write!(f, "The submitted code looks like binary data, not source text"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Reject empty submissions and binary blobs smuggled through a JSON string
+pub fn validate_code(code: &str) -> Result<(), InputError> {
+    if code.trim().is_empty() {
+        return Err(InputError::Empty);
+    }
+
+    if code.contains('\0') {
+        return Err(InputError::Binary);
+    }
+
+    let total = code.chars().count();
+    let control = code
+        .chars()
+        .filter(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+        .count();
+
+    if control as f64 / total as f64 > MAX_CONTROL_CHAR_RATIO {
+        return Err(InputError::Binary);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_code() {
+        assert_eq!(validate_code("fn main() {}\n"), Ok(()));
+        assert_eq!(validate_code("  \n\t"), Err(InputError::Empty));
+        assert_eq!(validate_code("fn\0main"), Err(InputError::Binary));
+        assert_eq!(validate_code("\u{1}\u{2}\u{3}ab"), Err(InputError::Binary));
+    }
+
+    #[test]
+    fn test_budget_for_caps_requested_time() {
+        let limits = AnalysisLimits {
+            max_payload_bytes: 1024,
+            analysis_timeout: Duration::from_millis(0),
+            ..AnalysisLimits::default()
+        };
+        let budget = limits.budget_for(Some(60_000));
+        assert!(!budget.check());
+        assert!(budget.exceeded());
+        assert_eq!(budget.remaining(), Some(Duration::ZERO));
+
+        let budget = AnalysisBudget::unlimited();
+        assert!(budget.check());
+        assert!(!budget.exceeded());
+        assert_eq!(budget.remaining(), None);
+    }
+}
@@ -1,181 +1,218 @@
-use actix_web::{web, App, HttpResponse, HttpServer, Result, middleware::Logger};
+use actix_web::{web, error, App, HttpRequest, HttpResponse, HttpServer, middleware::{from_fn, Logger}};
 use actix_cors::Cors; // Add CORS import
-use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 // Add dotenv import
 use dotenv::dotenv;
 
-mod db;
-mod models;
-mod algorithms;
-mod data_structures;
+use analyzer_api::{auth, catalog, db, health, jobs, limits, metrics, models, openapi, rate_limit, versioning};
+use analyzer_api::auth::Action;
+use analyzer_api::openapi::{SecurityAddon, VersionAddon};
+use analyzer_api::models::error_response::ErrorResponse;
+use utoipa::OpenApi;
 
-#[derive(Serialize, Deserialize)]
-struct CodeAnalysis {
-    code: String,
-}
+mod routes;
+
+/// Route prefixes registered by `shared_routes`
+const SHARED_ROUTES: &[&str] = &["/analyses", "/admin/api-keys", "/admin/usage"];
+
+const VERSION_ADDON: VersionAddon = VersionAddon { shared: SHARED_ROUTES };
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Analyzer API",
+        description = "Detects algorithms and data structures in source code",
+        license(name = "MIT")
+    ),
+    paths(
+        routes::health::health_live,
+        routes::health::health_ready,
+        routes::analysis::analyze_v1,
+        routes::analysis::analyze_v2,
+        routes::stream::analyze_stream,
+        routes::batches::analyze_batch,
+        routes::batches::get_batch,
+        routes::jobs::create_job,
+        routes::jobs::get_job,
+        routes::jobs::get_job_result,
+        routes::history::list_analyses,
+        routes::history::get_analysis,
+        routes::admin::create_api_key,
+        routes::admin::list_api_keys,
+        routes::admin::revoke_api_key,
+        routes::admin::get_usage,
+        routes::catalog::list_catalog_algorithms,
+        routes::catalog::get_catalog_algorithm,
+        routes::catalog::list_catalog_data_structures,
+        routes::catalog::get_catalog_data_structure,
+        routes::catalog::list_catalog_categories,
+        routes::catalog::get_catalog_graph,
+        routes::catalog::propose_algorithm,
+        routes::catalog::propose_algorithm_update,
+        routes::catalog::propose_algorithm_deletion,
+        routes::catalog::propose_data_structure,
+        routes::catalog::propose_data_structure_update,
+        routes::catalog::propose_data_structure_deletion,
+        routes::catalog::list_catalog_changes,
+        routes::catalog::get_catalog_change,
+        routes::catalog::approve_catalog_change,
+        routes::catalog::reject_catalog_change,
+        routes::catalog::get_catalog_audit,
+    ),
+    modifiers(&SecurityAddon, &VERSION_ADDON),
+    tags(
+        (name = "health", description = "Liveness and readiness probes"),
+        (name = "analysis", description = "Code analysis and history"),
+        (name = "jobs", description = "Asynchronous analysis jobs"),
+        (name = "catalog", description = "Reference catalog of algorithms and data structures"),
+        (name = "admin", description = "API keys and usage")
+    )
+)]
+struct ApiDoc;
 
-#[derive(Serialize)]
-struct AnalysisResult {
-    patterns: Vec<String>,
-    algorithms: Vec<String>,
-    detailed_algorithms: Vec<algorithms::DetectedAlgorithm>,
-    data_structures: Vec<String>,
-    detailed_data_structures: Vec<data_structures::DetectedDataStructure>,
-    complexity: String,
-    recommendations: Vec<String>,
+async fn openapi_json() -> HttpResponse {
+    HttpResponse::Ok().json(ApiDoc::openapi())
 }
 
-#[derive(Serialize)]
-struct HealthCheck {
-    status: String,
-    version: String,
+/// Routes that are the same in every API version: `/v1` mounts the `/v2`
+/// handlers, so these paths answer with the `/v2` types there too
+fn shared_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/analyses")
+            .wrap(from_fn(rate_limit::enforce))
+            .wrap(from_fn(|req, next| auth::require(Action::ReadHistory, req, next)))
+            .route("", web::get().to(routes::history::list_analyses))
+            .route("/{id}", web::get().to(routes::history::get_analysis)),
+    )
+    .service(
+        web::scope("/admin/api-keys")
+            .wrap(from_fn(rate_limit::enforce))
+            .wrap(from_fn(|req, next| auth::require(Action::ManageKeys, req, next)))
+            .route("", web::get().to(routes::admin::list_api_keys))
+            .route("", web::post().to(routes::admin::create_api_key))
+            .route("/{id}", web::delete().to(routes::admin::revoke_api_key)),
+    )
+    .service(
+        web::resource("/admin/usage")
+            .wrap(from_fn(rate_limit::enforce))
+            .wrap(from_fn(|req, next| auth::require(Action::ReadUsage, req, next)))
+            .route(web::get().to(routes::admin::get_usage)),
+    );
 }
 
-// Application state with database connection
-struct AppState {
-    db: Arc<db::Database>,
-    algorithm_detector: Arc<algorithms::AlgorithmDetector>,
-    data_structure_detector: Arc<data_structures::DataStructureDetector>,
+/// Authorization and rate limiting for a single route, for paths whose
+/// methods need different permissions
+fn guarded(action: Action, route: actix_web::Route) -> actix_web::Route {
+    route
+        .wrap(from_fn(rate_limit::enforce))
+        .wrap(from_fn(move |req, next| auth::require(action, req, next)))
 }
 
-async fn health_check() -> Result<HttpResponse> {
-    let health = HealthCheck {
-        status: "healthy".to_string(),
-        version: "0.1.0".to_string(),
-    };
-    Ok(HttpResponse::Ok().json(health))
+fn v1_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/analyze")
+            .wrap(from_fn(rate_limit::enforce))
+            .wrap(from_fn(|req, next| auth::require(Action::Analyze, req, next)))
+            .route(web::post().to(routes::analysis::analyze_v1)),
+    );
+    shared_routes(cfg);
 }
 
-async fn analyze_code(
-    item: web::Json<CodeAnalysis>,
-    data: web::Data<AppState>,
-) -> Result<HttpResponse> {
-    // This is a simplified analysis - in a real implementation, 
-    // this would contain actual logic to analyze code
-    let result = analyze_code_logic(&item.code, &data.algorithm_detector, &data.data_structure_detector);
-    
-    // Save code sample to database
-    let new_code_sample = models::code_sample::NewCodeSample {
-        code: item.code.clone(),
-        language: Some("rust".to_string()),
-    };
-    
-    match data.db.save_code_sample(new_code_sample).await {
-        Ok(code_sample_id) => {
-            // Convert detailed algorithms to the database model
-            let detailed_algorithms: Vec<models::analysis_result::DetailedAlgorithm> = result
-                .detailed_algorithms
-                .iter()
-                .map(|alg| models::analysis_result::DetailedAlgorithm {
-                    name: alg.name.clone(),
-                    category: alg.category.to_string(),
-                    complexity: alg.complexity.clone(),
-                    description: alg.description.clone(),
-                    wikipedia_link: alg.wikipedia_link.clone(),
-                })
-                .collect();
-            
-            // Save analysis result to database
-            let new_analysis_result = models::analysis_result::NewAnalysisResult {
-                code_sample_id,
-                patterns: result.patterns.clone(),
-                algorithms: result.algorithms.clone(),
-                detailed_algorithms,
-                data_structures: result.data_structures.clone(),
-                complexity: result.complexity.clone(),
-                recommendations: result.recommendations.clone(),
-            };
-            
-            match data.db.save_analysis_result(new_analysis_result).await {
-                Ok(_) => {
-                    println!("Saved analysis result to database");
-                }
-                Err(e) => {
-                    eprintln!("Failed to save analysis result: {}", e);
-                }
-            }
-        }
-        Err(e) => {
-            eprintln!("Failed to save code sample: {}", e);
-        }
-    }
-    
-    Ok(HttpResponse::Ok().json(result))
+fn v2_routes(cfg: &mut web::ServiceConfig, max_batch_bytes: usize) {
+    cfg.service(
+        web::resource("/analyze/batch")
+            .app_data(web::PayloadConfig::new(max_batch_bytes))
+            .wrap(from_fn(rate_limit::enforce))
+            .wrap(from_fn(|req, next| auth::require(Action::Analyze, req, next)))
+            .route(web::post().to(routes::batches::analyze_batch)),
+    )
+    .service(
+        web::resource("/jobs")
+            .app_data(
+                web::JsonConfig::default()
+                    .limit(max_batch_bytes)
+                    .error_handler(json_error_handler),
+            )
+            .wrap(from_fn(rate_limit::enforce))
+            .wrap(from_fn(|req, next| auth::require(Action::Analyze, req, next)))
+            .route(web::post().to(routes::jobs::create_job)),
+    )
+    .service(
+        web::scope("/jobs/{id}")
+            .wrap(from_fn(rate_limit::enforce))
+            .wrap(from_fn(|req, next| auth::require(Action::ReadHistory, req, next)))
+            .route("", web::get().to(routes::jobs::get_job))
+            .route("/result", web::get().to(routes::jobs::get_job_result)),
+    )
+    .service(
+        web::scope("/catalog")
+            .route("/algorithms", guarded(Action::ReadCatalog, web::get().to(routes::catalog::list_catalog_algorithms)))
+            .route("/algorithms", guarded(Action::WriteCatalog, web::post().to(routes::catalog::propose_algorithm)))
+            .route("/algorithms/{name}", guarded(Action::ReadCatalog, web::get().to(routes::catalog::get_catalog_algorithm)))
+            .route("/algorithms/{name}", guarded(Action::WriteCatalog, web::put().to(routes::catalog::propose_algorithm_update)))
+            .route("/algorithms/{name}", guarded(Action::WriteCatalog, web::delete().to(routes::catalog::propose_algorithm_deletion)))
+            .route("/data-structures", guarded(Action::ReadCatalog, web::get().to(routes::catalog::list_catalog_data_structures)))
+            .route("/data-structures", guarded(Action::WriteCatalog, web::post().to(routes::catalog::propose_data_structure)))
+            .route("/data-structures/{name}", guarded(Action::ReadCatalog, web::get().to(routes::catalog::get_catalog_data_structure)))
+            .route("/data-structures/{name}", guarded(Action::WriteCatalog, web::put().to(routes::catalog::propose_data_structure_update)))
+            .route("/data-structures/{name}", guarded(Action::WriteCatalog, web::delete().to(routes::catalog::propose_data_structure_deletion)))
+            .route("/categories", guarded(Action::ReadCatalog, web::get().to(routes::catalog::list_catalog_categories)))
+            .route("/graph", guarded(Action::ReadCatalog, web::get().to(routes::catalog::get_catalog_graph)))
+            .route("/changes", guarded(Action::WriteCatalog, web::get().to(routes::catalog::list_catalog_changes)))
+            .route("/changes/{id}", guarded(Action::WriteCatalog, web::get().to(routes::catalog::get_catalog_change)))
+            .route("/changes/{id}/approve", guarded(Action::ReviewCatalog, web::post().to(routes::catalog::approve_catalog_change)))
+            .route("/changes/{id}/reject", guarded(Action::ReviewCatalog, web::post().to(routes::catalog::reject_catalog_change)))
+            .route("/audit", guarded(Action::WriteCatalog, web::get().to(routes::catalog::get_catalog_audit))),
+    )
+    .service(
+        web::scope("/batches")
+            .wrap(from_fn(rate_limit::enforce))
+            .wrap(from_fn(|req, next| auth::require(Action::ReadHistory, req, next)))
+            .route("/{id}", web::get().to(routes::batches::get_batch)),
+    )
+    .service(
+        web::resource("/analyze/stream")
+            .wrap(from_fn(rate_limit::enforce))
+            .wrap(from_fn(|req, next| auth::require(Action::Analyze, req, next)))
+            .route(web::post().to(routes::stream::analyze_stream)),
+    )
+    .service(
+        web::resource("/analyze")
+            .wrap(from_fn(rate_limit::enforce))
+            .wrap(from_fn(|req, next| auth::require(Action::Analyze, req, next)))
+            .route(web::post().to(routes::analysis::analyze_v2)),
+    );
+    shared_routes(cfg);
 }
 
-fn analyze_code_logic(
-    code: &str, 
-    algorithm_detector: &algorithms::AlgorithmDetector,
-    data_structure_detector: &data_structures::DataStructureDetector
-) -> AnalysisResult {
-    let mut patterns = Vec::new();
-    let mut algorithms = Vec::new();
-    let mut data_structures = Vec::new();
-    let mut recommendations = Vec::new();
-    
-    // Detect algorithms using the new system
-    let detected_algorithms = algorithm_detector.detect_algorithms(code);
-    
-    // Extract algorithm names for the simple list
-    for alg in &detected_algorithms {
-        algorithms.push(alg.name.clone());
-    }
-    
-    // Detect data structures
-    let detected_data_structures = data_structure_detector.detect_data_structures(code);
-    
-    // Extract data structure names for the simple list
-    for ds in &detected_data_structures {
-        data_structures.push(ds.name.clone());
-    }
-    
-    // Simple pattern matching for demonstration
-    if code.contains("for") && code.contains("..<") {
-        patterns.push("Range-based loop".to_string());
-    }
-    
-    if code.contains("fn ") && code.contains("recursive") {
-        patterns.push("Recursive function".to_string());
-    }
-    
-    // Simple complexity estimation
-    let complexity = if code.lines().count() > 100 {
-        "High".to_string()
-    } else if code.lines().count() > 50 {
-        "Medium".to_string()
-    } else {
-        "Low".to_string()
+/// Turn body extraction failures into JSON errors that say what went wrong
+fn json_error_handler(err: error::JsonPayloadError, _req: &HttpRequest) -> error::Error {
+    let (response, error_code, message) = match &err {
+        error::JsonPayloadError::Overflow { limit } | error::JsonPayloadError::OverflowKnownLength { limit, .. } => (
+            HttpResponse::PayloadTooLarge(),
+            "payload_too_large",
+            format!("Request body exceeds the limit of {} bytes", limit),
+        ),
+        error::JsonPayloadError::ContentType => (
+            HttpResponse::UnsupportedMediaType(),
+            "unsupported_media_type",
+            "Request body must be sent as application/json".to_string(),
+        ),
+        error::JsonPayloadError::Deserialize(e) => (
+            HttpResponse::BadRequest(),
+            "invalid_json",
+            format!("Request body must be valid UTF-8 JSON: {}", e),
+        ),
+        _ => (
+            HttpResponse::BadRequest(),
+            "invalid_request",
+            err.to_string(),
+        ),
     };
     
-    // Basic recommendations
-    if code.lines().count() > 100 {
-        recommendations.push("Consider breaking this into smaller functions".to_string());
-    }
-    
-    if !code.contains("///") && !code.contains("//") {
-        recommendations.push("Add documentation comments".to_string());
-    }
-    
-    // Add educational recommendations based on detected algorithms
-    for alg in &detected_algorithms {
-        recommendations.push(format!("Learn more about {} at {}", alg.name, alg.wikipedia_link));
-    }
-    
-    // Add educational recommendations based on detected data structures
-    for ds in &detected_data_structures {
-        recommendations.push(format!("Learn more about {} at {}", ds.name, ds.wikipedia_link));
-    }
-    
-    AnalysisResult {
-        patterns,
-        algorithms,
-        detailed_algorithms: detected_algorithms,
-        data_structures,
-        detailed_data_structures: detected_data_structures,
-        complexity,
-        recommendations,
-    }
+    let mut response = response;
+    let body = response.json(ErrorResponse::new(error_code, message));
+    error::InternalError::from_response(err, body).into()
 }
 
 #[actix_web::main]
@@ -188,19 +225,83 @@ async fn main() -> std::io::Result<()> {
     let db = db::Database::new().await.expect("Failed to connect to database");
     db.init().await.expect("Failed to initialize database");
     
-    // Initialize algorithm detector
-    let algorithm_detector = algorithms::AlgorithmDetector::new();
+    // Seed an admin key so the key management endpoints can be reached
+    if let Ok(key) = std::env::var("ANALYZER_ADMIN_API_KEY") {
+        let bootstrap_key = models::api_key::NewApiKey {
+            name: "bootstrap-admin".to_string(),
+            key_hash: auth::hash_api_key(&key),
+            role: auth::Role::Admin.to_string(),
+            plan: "internal".to_string(),
+        };
+        db.ensure_api_key(bootstrap_key).await.expect("Failed to store bootstrap admin key");
+    }
     
-    // Initialize data structure detector
-    let data_structure_detector = data_structures::DataStructureDetector::new();
+    let db = Arc::new(db);
+    let authenticator = web::Data::new(auth::Authenticator::from_env(db.clone()));
     
-    let app_state = web::Data::new(AppState {
-        db: Arc::new(db),
-        algorithm_detector: Arc::new(algorithm_detector),
-        data_structure_detector: Arc::new(data_structure_detector),
+    let plans = db.get_plans().await.expect("Failed to load rate-limit plans");
+    let rate_limiter = web::Data::new(
+        rate_limit::RateLimiter::new(db.clone(), plans).with_trusted_proxies(rate_limit::trusted_proxies_from_env()),
+    );
+    
+    // Built-in catalogs with the Wikipedia imports merged in and the curated overrides applied
+    let overrides = db.get_catalog_overrides().await.unwrap_or_else(|e| {
+        eprintln!("Failed to load catalog overrides, using the built-in catalogs: {}", e);
+        Vec::new()
+    });
+    let imported_algorithms = db.get_all_wikipedia_algorithms().await.unwrap_or_else(|e| {
+        eprintln!("Failed to load Wikipedia algorithms, leaving them out of the catalog: {}", e);
+        Vec::new()
+    });
+    let imported_data_structures = db.get_all_wikipedia_data_structures().await.unwrap_or_else(|e| {
+        eprintln!("Failed to load Wikipedia data structures, leaving them out of the catalog: {}", e);
+        Vec::new()
     });
+    let (algorithm_detector, data_structure_detector) =
+        catalog::build_detectors(&overrides, &imported_algorithms, &imported_data_structures);
     
-    println!("Starting analyzer API server at http://localhost:8081");
+    let analysis_limits = limits::AnalysisLimits::from_env();
+    let max_payload_bytes = analysis_limits.max_payload_bytes;
+    let max_batch_bytes = analysis_limits.max_batch_bytes;
+    
+    let metrics = web::Data::new(metrics::Metrics::new());
+    let swagger_ui = web::Data::new(openapi::SwaggerUi::from_env());
+    
+    let self_test = health::run_detector_self_test(&algorithm_detector, &data_structure_detector);
+    for check in self_test.iter().filter(|check| !check.passed()) {
+        eprintln!("Detector self-test failed: {} ({})", check.name, check.detail.as_deref().unwrap_or(""));
+    }
+    
+    let app_state = web::Data::new(routes::AppState {
+        db,
+        algorithm_detector: catalog::Live::new(algorithm_detector),
+        data_structure_detector: catalog::Live::new(data_structure_detector),
+        catalog_reload: tokio::sync::Mutex::new(()),
+        limits: analysis_limits,
+        metrics: metrics.clone(),
+        self_test,
+    });
+    
+    // Start the job workers, then hand them the jobs a previous run left behind
+    let job_settings = jobs::JobSettings::from_env();
+    let worker_state = app_state.clone();
+    let job_queue = web::Data::new(jobs::JobQueue::start(job_settings.workers, move |id| {
+        routes::jobs::process_job(id, worker_state.clone())
+    }));
+    let pending_jobs = app_state
+        .db
+        .recover_jobs(job_settings.max_attempts)
+        .await
+        .expect("Failed to recover analysis jobs");
+    for id in pending_jobs {
+        job_queue.enqueue(id);
+    }
+    
+    println!(
+        "Starting analyzer API server {} ({}) at http://localhost:8081",
+        health::VERSION,
+        health::GIT_REVISION
+    );
     
     HttpServer::new(move || {
         // Configure CORS
@@ -208,16 +309,75 @@ async fn main() -> std::io::Result<()> {
             .allow_any_origin()
             .allow_any_method()
             .allow_any_header()
+            .expose_any_header()
             .supports_credentials();
         
         App::new()
             .app_data(app_state.clone())
+            .app_data(authenticator.clone())
+            .app_data(rate_limiter.clone())
+            .app_data(job_queue.clone())
+            .app_data(metrics.clone())
+            .app_data(swagger_ui.clone())
+            .app_data(
+                web::JsonConfig::default()
+                    .limit(max_payload_bytes)
+                    .error_handler(json_error_handler),
+            )
             .wrap(cors) // Add CORS middleware
             .wrap(Logger::default())
-            .route("/health", web::get().to(health_check))
-            .route("/analyze", web::post().to(analyze_code))
+            .wrap(from_fn(metrics::track))
+            .route("/health", web::get().to(routes::health::health_live))
+            .route("/health/live", web::get().to(routes::health::health_live))
+            .route("/health/ready", web::get().to(routes::health::health_ready))
+            // Operational data, so admin-only like /v2/admin/usage; not rate
+            // limited so that scrapes do not leave gaps
+            .service(
+                web::resource("/metrics")
+                    .wrap(from_fn(|req, next| auth::require(Action::ReadUsage, req, next)))
+                    .route(web::get().to(metrics::metrics_endpoint)),
+            )
+            .route("/openapi.json", web::get().to(openapi_json))
+            .route("/docs", web::get().to(openapi::docs_page))
+            .route("/docs/{file}", web::get().to(openapi::docs_asset))
+            .service(web::scope("/v2").configure(|cfg| v2_routes(cfg, max_batch_bytes)))
+            .service(
+                web::scope("/v1")
+                    .wrap(from_fn(|req, next| versioning::deprecated(&versioning::V1, req, next)))
+                    .configure(v1_routes),
+            )
+            // Unversioned aliases of /v1; registered last because the empty scope matches every path
+            .service(
+                web::scope("")
+                    .wrap(from_fn(|req, next| versioning::deprecated(&versioning::UNVERSIONED, req, next)))
+                    .configure(v1_routes),
+            )
     })
     .bind("0.0.0.0:8081")?
     .run()
     .await
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../docs/API/openapi.json");
+
+    /// Fails when the handlers changed without regenerating the checked-in spec.
+    /// Run with `UPDATE_OPENAPI=1` to rewrite `docs/API/openapi.json`.
+    #[test]
+    fn test_openapi_spec_is_up_to_date() {
+        let generated = ApiDoc::openapi().to_pretty_json().unwrap() + "\n";
+        if std::env::var_os("UPDATE_OPENAPI").is_some() {
+            std::fs::write(SPEC_PATH, &generated).unwrap();
+        }
+
+        let checked_in = std::fs::read_to_string(SPEC_PATH).unwrap_or_default();
+        let generated: serde_json::Value = serde_json::from_str(&generated).unwrap();
+        let checked_in: serde_json::Value = serde_json::from_str(&checked_in).unwrap_or_default();
+        assert!(
+            generated == checked_in,
+            "docs/API/openapi.json is out of date; rerun the tests with UPDATE_OPENAPI=1"
+        );
+    }
+}
@@ -0,0 +1,171 @@
+//! Prometheus metrics for the analyzer API
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::middleware::Next;
+use actix_web::{web, Error, HttpResponse};
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry, TextEncoder,
+};
+use std::time::{Duration, Instant};
+
+/// Buckets for analysis durations, in seconds
+const ANALYSIS_BUCKETS: &[f64] = &[0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0];
+
+/// Label for the size of an analyzed input
+pub fn size_class(bytes: usize) -> &'static str {
+    match bytes {
+        0..=1_023 => "lt_1kb",
+        1_024..=10_239 => "1kb_10kb",
+        10_240..=102_399 => "10kb_100kb",
+        _ => "gte_100kb",
+    }
+}
+
+pub struct Metrics {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_duration: HistogramVec,
+    analysis_duration: HistogramVec,
+    detections: IntCounterVec,
+    db_save_failures: IntCounterVec,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new_custom(Some("analyzer".to_string()), None)
+            .expect("valid metrics prefix");
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests by route, method and status"),
+            &["route", "method", "status"],
+        )
+        .unwrap();
+        let http_duration = HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "HTTP request latency by route and method"),
+            &["route", "method"],
+        )
+        .unwrap();
+        let analysis_duration = HistogramVec::new(
+            HistogramOpts::new("analysis_duration_seconds", "Time spent analyzing one submission")
+                .buckets(ANALYSIS_BUCKETS.to_vec()),
+            &["language", "size"],
+        )
+        .unwrap();
+        let detections = IntCounterVec::new(
+            Opts::new("detections_total", "Detected algorithms and data structures"),
+            &["kind", "name", "category"],
+        )
+        .unwrap();
+        let db_save_failures = IntCounterVec::new(
+            Opts::new("db_save_failures_total", "Failed database writes by table"),
+            &["table"],
+        )
+        .unwrap();
+
+        registry.register(Box::new(http_requests.clone())).unwrap();
+        registry.register(Box::new(http_duration.clone())).unwrap();
+        registry.register(Box::new(analysis_duration.clone())).unwrap();
+        registry.register(Box::new(detections.clone())).unwrap();
+        registry.register(Box::new(db_save_failures.clone())).unwrap();
+
+        Self {
+            registry,
+            http_requests,
+            http_duration,
+            analysis_duration,
+            detections,
+            db_save_failures,
+        }
+    }
+
+    pub fn observe_request(&self, route: &str, method: &str, status: u16, elapsed: Duration) {
+        self.http_requests
+            .with_label_values(&[route, method, &status.to_string()])
+            .inc();
+        self.http_duration
+            .with_label_values(&[route, method])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn observe_analysis(&self, language: &str, input_bytes: usize, elapsed: Duration) {
+        self.analysis_duration
+            .with_label_values(&[language, size_class(input_bytes)])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// `kind` is `algorithm` or `data_structure`
+    pub fn record_detection(&self, kind: &str, name: &str, category: &str) {
+        self.detections.with_label_values(&[kind, name, category]).inc();
+    }
+
+    pub fn record_db_save_failure(&self, table: &str) {
+        self.db_save_failures.with_label_values(&[table]).inc();
+    }
+
+    /// Render all metrics in the Prometheus text format
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .expect("text encoding cannot fail");
+        String::from_utf8(buffer).expect("metrics are valid UTF-8")
+    }
+}
+
+/// Middleware body: count requests and time them per route
+pub async fn track(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let metrics = req.app_data::<web::Data<Metrics>>().cloned();
+    let method = req.method().to_string();
+    // Label by route pattern, not raw path, to keep label cardinality bounded
+    let route = req.match_pattern().unwrap_or_else(|| "unmatched".to_string());
+    let start = Instant::now();
+
+    let result = next.call(req).await;
+
+    if let Some(metrics) = metrics {
+        // Errors raised by inner middleware (auth, rate limits) still count
+        let status = match &result {
+            Ok(res) => res.status().as_u16(),
+            Err(e) => e.as_response_error().status_code().as_u16(),
+        };
+        metrics.observe_request(&route, &method, status, start.elapsed());
+    }
+
+    result
+}
+
+pub async fn metrics_endpoint(metrics: web::Data<Metrics>) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(metrics.render())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_includes_recorded_values() {
+        let metrics = Metrics::new();
+        metrics.observe_request("/analyze", "POST", 200, Duration::from_millis(5));
+        metrics.observe_analysis("rust", 2_048, Duration::from_millis(3));
+        metrics.record_detection("algorithm", "Bubble Sort", "Sorting");
+        metrics.record_db_save_failure("code_samples");
+
+        let text = metrics.render();
+        assert!(text.contains(r#"analyzer_http_requests_total{method="POST",route="/analyze",status="200"} 1"#));
+        assert!(text.contains(r#"analyzer_analysis_duration_seconds_count{language="rust",size="1kb_10kb"} 1"#));
+        assert!(text.contains(r#"analyzer_detections_total{category="Sorting",kind="algorithm",name="Bubble Sort"} 1"#));
+        assert!(text.contains(r#"analyzer_db_save_failures_total{table="code_samples"} 1"#));
+    }
+}
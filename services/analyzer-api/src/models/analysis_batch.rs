@@ -0,0 +1,50 @@
+use analyzer_types::v2::Inventory;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::analysis_result::NewAnalysisResult;
+use super::code_sample::NewCodeSample;
+
+/// One file of a batch about to be stored
+#[derive(Debug, Clone)]
+pub struct NewBatchFile {
+    pub path: String,
+    /// The sample and its result; `code_sample_id` is assigned when saving
+    pub analysis: Option<(NewCodeSample, NewAnalysisResult)>,
+    /// Error code for files that were not analyzed
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewAnalysisBatch {
+    pub name: Option<String>,
+    pub language: String,
+    /// Key that submitted the batch; `None` for anonymous callers
+    pub owner_key_id: Option<Uuid>,
+    pub inventory: Inventory,
+    pub files: Vec<NewBatchFile>,
+}
+
+/// A file of a stored batch, pointing at its analysis when there is one
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BatchFileRecord {
+    pub path: String,
+    pub analysis_id: Option<Uuid>,
+    pub error: Option<String>,
+}
+
+/// A stored batch submission
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AnalysisBatch {
+    pub id: Uuid,
+    pub name: Option<String>,
+    pub language: String,
+    /// Key that submitted the batch; `None` for anonymous callers
+    #[serde(skip)]
+    pub owner_key_id: Option<Uuid>,
+    pub inventory: Inventory,
+    pub files: Vec<BatchFileRecord>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,34 @@
+use analyzer_types::v2::{Job, JobProgress, JobStatus};
+use analyzer_types::ErrorResponse;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// A stored job, without its request and result
+#[derive(Debug, Clone)]
+pub struct AnalysisJob {
+    pub id: Uuid,
+    pub status: JobStatus,
+    /// Key that submitted the job; `None` for anonymous callers
+    pub owner_key_id: Option<Uuid>,
+    pub total: u32,
+    pub completed: u32,
+    pub error: Option<ErrorResponse>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<AnalysisJob> for Job {
+    fn from(job: AnalysisJob) -> Self {
+        Job {
+            id: job.id.to_string(),
+            status: job.status,
+            progress: JobProgress {
+                completed: job.completed as usize,
+                total: job.total as usize,
+            },
+            created_at: job.created_at.to_rfc3339(),
+            updated_at: job.updated_at.to_rfc3339(),
+            error: job.error,
+        }
+    }
+}
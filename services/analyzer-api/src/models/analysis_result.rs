@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
+use utoipa::ToSchema;
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct AnalysisResult {
@@ -15,7 +16,7 @@ pub struct AnalysisResult {
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DetailedAlgorithm {
     pub name: String,
     pub category: String,
@@ -33,4 +34,17 @@ pub struct NewAnalysisResult {
     pub data_structures: Vec<String>,
     pub complexity: String,
     pub recommendations: Vec<String>,
-}
\ No newline at end of file
+}
+/// A stored analysis with its JSON columns decoded
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AnalysisRecord {
+    pub id: Uuid,
+    pub code_sample_id: Uuid,
+    pub patterns: Vec<String>,
+    pub algorithms: Vec<String>,
+    pub detailed_algorithms: Vec<DetailedAlgorithm>,
+    pub data_structures: Vec<String>,
+    pub complexity: String,
+    pub recommendations: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
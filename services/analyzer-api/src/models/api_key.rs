@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ApiKey {
+    pub id: Uuid,
+    pub name: String,
+    pub role: String,
+    pub plan: String,
+    pub created_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewApiKey {
+    pub name: String,
+    pub key_hash: String,
+    pub role: String,
+    pub plan: String,
+}
+
+/// Requests made by one client on one day
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct KeyUsage {
+    pub client: String,
+    pub api_key_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub plan: Option<String>,
+    pub day: NaiveDate,
+    pub requests: u32,
+}
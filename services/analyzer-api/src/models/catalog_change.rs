@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use utoipa::ToSchema;
+
+pub use analyzer_types::v2::CatalogKind;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeAction {
+    Create,
+    Update,
+    Delete,
+}
+
+impl ChangeAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChangeAction::Create => "create",
+            ChangeAction::Update => "update",
+            ChangeAction::Delete => "delete",
+        }
+    }
+}
+
+impl std::str::FromStr for ChangeAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "create" => Ok(ChangeAction::Create),
+            "update" => Ok(ChangeAction::Update),
+            "delete" => Ok(ChangeAction::Delete),
+            other => Err(format!("unknown change action '{}'", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl ChangeStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChangeStatus::Pending => "pending",
+            ChangeStatus::Approved => "approved",
+            ChangeStatus::Rejected => "rejected",
+        }
+    }
+}
+
+impl std::str::FromStr for ChangeStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(ChangeStatus::Pending),
+            "approved" => Ok(ChangeStatus::Approved),
+            "rejected" => Ok(ChangeStatus::Rejected),
+            other => Err(format!("unknown change status '{}'", other)),
+        }
+    }
+}
+
+/// Who made or reviewed a change
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Actor {
+    /// Name of the API key
+    pub name: String,
+    /// `None` for anonymous callers
+    pub key_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewCatalogChange {
+    pub kind: CatalogKind,
+    pub name: String,
+    pub action: ChangeAction,
+    pub entry: Option<serde_json::Value>,
+    pub diff: serde_json::Value,
+    pub author: Actor,
+}
+
+/// A proposed change to one catalog entry
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CatalogChange {
+    pub id: Uuid,
+    pub kind: CatalogKind,
+    pub name: String,
+    pub action: ChangeAction,
+    /// The proposed entry; absent for deletions
+    #[schema(value_type = Option<Object>)]
+    pub entry: Option<serde_json::Value>,
+    /// Changed fields with their `before` and `after` values, as of the proposal
+    #[schema(value_type = Object)]
+    pub diff: serde_json::Value,
+    pub status: ChangeStatus,
+    pub author: Actor,
+    pub created_at: DateTime<Utc>,
+    pub reviewer: Option<Actor>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+/// One step in the history of a catalog entry
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CatalogAuditEntry {
+    pub id: Uuid,
+    pub change_id: Uuid,
+    pub kind: CatalogKind,
+    pub name: String,
+    pub action: ChangeAction,
+    /// `pending` when the change was proposed, then `approved` or `rejected`
+    pub status: ChangeStatus,
+    pub actor: Actor,
+    /// Changed fields with their `before` and `after` values at this step
+    #[schema(value_type = Object)]
+    pub diff: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+/// An approved entry laid over the built-in catalog
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatalogOverride {
+    pub kind: CatalogKind,
+    pub name: String,
+    /// `None` removes the entry
+    pub entry: Option<serde_json::Value>,
+}
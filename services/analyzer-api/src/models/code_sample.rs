@@ -15,4 +15,13 @@ pub struct CodeSample {
 pub struct NewCodeSample {
     pub code: String,
     pub language: Option<String>,
-}
\ No newline at end of file
+}
+/// What purging old code samples removed, or would remove on a dry run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SamplePurge {
+    /// Samples created before this were eligible
+    pub cutoff: DateTime<Utc>,
+    pub samples: u64,
+    pub results: u64,
+    pub dry_run: bool,
+}
@@ -0,0 +1,3 @@
+//! The error body lives in the shared types crate so clients can decode it
+
+pub use analyzer_types::ErrorResponse;
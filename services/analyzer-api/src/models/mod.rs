@@ -1,3 +1,8 @@
+pub mod analysis_batch;
+pub mod analysis_job;
 pub mod analysis_result;
+pub mod api_key;
+pub mod catalog_change;
 pub mod code_sample;
-pub mod wikipedia_algorithm;
\ No newline at end of file
+pub mod error_response;
+pub mod wikipedia_algorithm;
@@ -1,10 +1,119 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct WikipediaAlgorithm {
     pub title: String,
+    /// Article URL; unique in the table
     pub url: String,
     pub category: String,
     pub description: Option<String>,
     pub complexity: Option<String>,
-}
\ No newline at end of file
+    /// Revision of the article the fields were read from
+    #[serde(default)]
+    pub revision_id: Option<u64>,
+}
+
+/// An entry of the List of data structures. It has the same fields as an
+/// algorithm; `category` holds a data structure category label and
+/// `complexity` the per-operation costs, e.g. `O(1) search, O(n) worst`.
+pub type WikipediaDataStructure = WikipediaAlgorithm;
+
+/// What purging a Wikipedia table removed, or would remove on a dry run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WikipediaPurge {
+    /// Placeholder rows stored by the old fetch binaries instead of articles
+    pub stubs: u64,
+    /// Rows soft-deleted because the list no longer links them
+    pub removed: u64,
+}
+
+/// The Wikipedia lists that are imported, each into its own table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WikipediaList {
+    Algorithms,
+    DataStructures,
+}
+
+impl WikipediaList {
+    pub const ALL: [WikipediaList; 2] = [WikipediaList::Algorithms, WikipediaList::DataStructures];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WikipediaList::Algorithms => "algorithms",
+            WikipediaList::DataStructures => "data_structures",
+        }
+    }
+
+    pub fn table(&self) -> &'static str {
+        match self {
+            WikipediaList::Algorithms => "wikipedia_algorithms",
+            WikipediaList::DataStructures => "wikipedia_data_structures",
+        }
+    }
+}
+
+impl WikipediaAlgorithm {
+    /// Whether the stored fields differ, ignoring the revision, which moves
+    /// with every edit of the article
+    pub fn differs_from(&self, other: &WikipediaAlgorithm) -> bool {
+        (&self.title, &self.category, &self.description, &self.complexity)
+            != (&other.title, &other.category, &other.description, &other.complexity)
+    }
+}
+
+/// A row of `wikipedia_algorithms`, including soft-deleted ones
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredWikipediaAlgorithm {
+    pub algorithm: WikipediaAlgorithm,
+    pub last_seen_at: Option<DateTime<Utc>>,
+    /// Set once the article is no longer listed upstream
+    pub removed_at: Option<DateTime<Utc>>,
+}
+
+/// What one fetch changes in `wikipedia_algorithms`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WikipediaSyncPlan {
+    /// Articles not stored yet, or listed again after being removed
+    pub added: Vec<WikipediaAlgorithm>,
+    /// Articles whose fields differ from the stored row
+    pub changed: Vec<WikipediaAlgorithm>,
+    /// Articles listed again with the same fields; only their revision may have moved
+    pub unchanged: Vec<WikipediaAlgorithm>,
+    /// Stored articles no longer listed
+    pub removed: Vec<WikipediaAlgorithm>,
+}
+
+impl WikipediaSyncPlan {
+    pub fn report(&self) -> WikipediaSyncReport {
+        let titles = |algorithms: &[WikipediaAlgorithm]| algorithms.iter().map(|a| a.title.clone()).collect();
+        WikipediaSyncReport {
+            added: titles(&self.added),
+            changed: titles(&self.changed),
+            removed: titles(&self.removed),
+            unchanged: self.unchanged.len(),
+        }
+    }
+}
+
+/// Summary of a fetch, by article title
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WikipediaSyncReport {
+    pub added: Vec<String>,
+    pub changed: Vec<String>,
+    pub removed: Vec<String>,
+    pub unchanged: usize,
+}
+
+impl std::fmt::Display for WikipediaSyncReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} added, {} changed, {} removed, {} unchanged",
+            self.added.len(),
+            self.changed.len(),
+            self.removed.len(),
+            self.unchanged
+        )
+    }
+}
@@ -0,0 +1,202 @@
+//! OpenAPI document support
+//!
+//! The spec itself is derived from the handler annotations in the route
+//! modules of the `analyzer-api` binary; this module holds the pieces shared with it: the security schemes and
+//! the interactive docs page served at `/docs`.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use actix_web::web::{self, Bytes};
+use actix_web::HttpResponse;
+use utoipa::openapi::path::{Operation, PathItem};
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::openapi::{Deprecated, OpenApi};
+use utoipa::Modify;
+
+use crate::versioning;
+
+/// Name of the `X-API-Key` security scheme
+pub const API_KEY_SCHEME: &str = "api_key";
+
+/// Name of the `Authorization: Bearer` security scheme
+pub const BEARER_SCHEME: &str = "bearer";
+
+/// Registers the two ways of presenting an API key
+pub struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            API_KEY_SCHEME,
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(crate::auth::API_KEY_HEADER))),
+        );
+        components.add_security_scheme(
+            BEARER_SCHEME,
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+    }
+}
+
+/// Appended to the `/v1` copies of shared operations
+const SHARED_NOTE: &str = "Shared with `/v2`: this route uses the same handler and the `/v2` \
+    request and response types, so unlike `/v1/analyze` its schema is not frozen.";
+
+/// Documents `/v1` next to `/v2`
+///
+/// Handlers are annotated with their `/v2` path. Routes under one of the
+/// `shared` prefixes are served unchanged under `/v1`, so they are copied
+/// there unless `/v1` documents its own, with a note that they take the
+/// `/v2` types; all `/v1` operations are marked deprecated.
+pub struct VersionAddon {
+    /// Route prefixes, relative to the version, served by both versions
+    pub shared: &'static [&'static str],
+}
+
+impl Modify for VersionAddon {
+    fn modify(&self, openapi: &mut OpenApi) {
+        let paths = &mut openapi.paths.paths;
+        let copies: Vec<(String, PathItem)> = paths
+            .iter()
+            .filter_map(|(path, item)| {
+                let rest = path.strip_prefix(versioning::V1.successor)?;
+                if !self.shared.iter().any(|prefix| rest.starts_with(prefix)) {
+                    return None;
+                }
+                let v1_path = format!("{}{}", versioning::V1.prefix, rest);
+                if paths.contains_key(&v1_path) {
+                    return None;
+                }
+                let mut item = item.clone();
+                for operation in operations(&mut item) {
+                    operation.operation_id = operation.operation_id.take().map(|id| id + "_v1");
+                    operation.description = Some(match operation.description.take() {
+                        Some(description) => format!("{description}\n\n{SHARED_NOTE}"),
+                        None => SHARED_NOTE.to_string(),
+                    });
+                }
+                Some((v1_path, item))
+            })
+            .collect();
+        paths.extend(copies);
+
+        let v1_prefix = format!("{}/", versioning::V1.prefix);
+        for (_, item) in paths.iter_mut().filter(|(path, _)| path.starts_with(&v1_prefix)) {
+            for operation in operations(item) {
+                operation.deprecated = Some(Deprecated::True);
+            }
+        }
+    }
+}
+
+fn operations(item: &mut PathItem) -> impl Iterator<Item = &mut Operation> {
+    [
+        &mut item.get,
+        &mut item.put,
+        &mut item.post,
+        &mut item.delete,
+        &mut item.patch,
+    ]
+    .into_iter()
+    .filter_map(Option::as_mut)
+}
+
+/// Swagger UI release the docs page is written against, as vendored by
+/// `scripts/vendor_swagger_ui.sh` and the Dockerfile
+pub const SWAGGER_UI_VERSION: &str = "5.17.14";
+
+/// Files of the `swagger-ui-dist` package the docs page needs
+const SWAGGER_UI_FILES: [&str; 2] = ["swagger-ui.css", "swagger-ui-bundle.js"];
+
+/// Interactive docs rendering `/openapi.json` with Swagger UI; every asset
+/// comes from this origin, so the page works offline and under a
+/// `script-src 'self'` content security policy
+const DOCS_PAGE: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="utf-8">
+    <title>Analyzer API</title>
+    <link rel="stylesheet" href="/docs/swagger-ui.css">
+</head>
+<body>
+    <div id="swagger-ui"></div>
+    <script src="/docs/swagger-ui-bundle.js"></script>
+    <script src="/docs/swagger-initializer.js"></script>
+</body>
+</html>
+"##;
+
+const SWAGGER_INITIALIZER: &str = r##"window.ui = SwaggerUIBundle({ url: "/openapi.json", dom_id: "#swagger-ui" });
+"##;
+
+/// Vendored Swagger UI assets, read once at startup
+pub struct SwaggerUi {
+    /// `SWAGGER_UI_FILES` in order; empty when they are not installed
+    files: Vec<Bytes>,
+}
+
+impl SwaggerUi {
+    /// Reads the assets from `ANALYZER_SWAGGER_UI_DIR`, by default
+    /// `static/swagger-ui` under the working directory. `/docs` answers
+    /// `503` when any of them is missing; `/openapi.json` is unaffected.
+    pub fn from_env() -> Self {
+        let dir = env::var_os("ANALYZER_SWAGGER_UI_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("static/swagger-ui"));
+        let files: Result<Vec<Bytes>, _> = SWAGGER_UI_FILES
+            .iter()
+            .map(|file| fs::read(dir.join(file)).map(Bytes::from))
+            .collect();
+        match files {
+            Ok(files) => Self { files },
+            Err(e) => {
+                eprintln!("Swagger UI assets not found in {} ({}); /docs is disabled", dir.display(), e);
+                Self { files: Vec::new() }
+            }
+        }
+    }
+
+    fn installed(&self) -> bool {
+        !self.files.is_empty()
+    }
+}
+
+fn docs_unavailable() -> HttpResponse {
+    HttpResponse::ServiceUnavailable()
+        .content_type("text/plain; charset=utf-8")
+        .body(format!(
+            "Swagger UI {} is not installed; run scripts/vendor_swagger_ui.sh or set ANALYZER_SWAGGER_UI_DIR. \
+             The OpenAPI document is available at /openapi.json.\n",
+            SWAGGER_UI_VERSION
+        ))
+}
+
+pub async fn docs_page(swagger_ui: web::Data<SwaggerUi>) -> HttpResponse {
+    if !swagger_ui.installed() {
+        return docs_unavailable();
+    }
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(DOCS_PAGE)
+}
+
+/// The stylesheet, bundle and initializer `DOCS_PAGE` links to
+pub async fn docs_asset(file: web::Path<String>, swagger_ui: web::Data<SwaggerUi>) -> HttpResponse {
+    if file.as_str() == "swagger-initializer.js" {
+        return HttpResponse::Ok()
+            .content_type("text/javascript; charset=utf-8")
+            .body(SWAGGER_INITIALIZER);
+    }
+    let Some(index) = SWAGGER_UI_FILES.iter().position(|name| *name == file.as_str()) else {
+        return HttpResponse::NotFound().finish();
+    };
+    if !swagger_ui.installed() {
+        return docs_unavailable();
+    }
+    let content_type = if file.ends_with(".css") { "text/css; charset=utf-8" } else { "text/javascript; charset=utf-8" };
+    HttpResponse::Ok()
+        .content_type(content_type)
+        .body(swagger_ui.files[index].clone())
+}
@@ -0,0 +1,694 @@
+//! Per-client rate limiting and daily quotas
+//!
+//! Each client (an API key, or the remote IP for anonymous callers) gets a
+//! token bucket sized by its plan, plus a daily request quota. Plans and
+//! daily usage live in the `plans` and `api_usage` tables; the buckets and
+//! the running daily counters are kept in memory and usage is written back
+//! to the database asynchronously.
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderName, HeaderValue, RETRY_AFTER};
+use actix_web::http::StatusCode;
+use actix_web::middleware::Next;
+use actix_web::{web, Error, HttpMessage, HttpRequest, HttpResponse, ResponseError};
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::auth::Principal;
+use crate::db::Database;
+use crate::models::error_response::ErrorResponse;
+
+/// Plan used when a key's plan is unknown and for anonymous callers
+pub const DEFAULT_PLAN: &str = "free";
+
+/// Tracked clients below which idle ones are not swept
+const MIN_SWEEP: usize = 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Plan {
+    pub name: String,
+    pub requests_per_minute: u32,
+    /// Bucket capacity: how many requests may arrive back to back
+    pub burst: u32,
+    pub daily_quota: u32,
+}
+
+impl Plan {
+    /// Plans created in a fresh database
+    pub fn defaults() -> Vec<Plan> {
+        vec![
+            Plan {
+                name: "free".to_string(),
+                requests_per_minute: 30,
+                burst: 10,
+                daily_quota: 1_000,
+            },
+            Plan {
+                name: "team".to_string(),
+                requests_per_minute: 300,
+                burst: 50,
+                daily_quota: 50_000,
+            },
+            Plan {
+                name: "internal".to_string(),
+                requests_per_minute: 6_000,
+                burst: 500,
+                daily_quota: 10_000_000,
+            },
+        ]
+    }
+
+    fn refill_per_second(&self) -> f64 {
+        self.requests_per_minute as f64 / 60.0
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+    /// When the bucket is full again if nothing is taken from it; from then
+    /// on it is no different from a new one and can be dropped
+    full_at: Instant,
+}
+
+impl TokenBucket {
+    fn full(plan: &Plan, now: Instant) -> Self {
+        Self {
+            tokens: plan.burst as f64,
+            last_refill: now,
+            full_at: now,
+        }
+    }
+
+    fn refill(&mut self, plan: &Plan, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * plan.refill_per_second()).min(plan.burst as f64);
+        self.last_refill = now;
+    }
+
+    /// Take `tokens`, going below zero if there are not that many; later
+    /// requests then wait until the bucket has refilled
+    fn take(&mut self, plan: &Plan, tokens: u32, now: Instant) {
+        self.tokens -= tokens as f64;
+        let missing = plan.burst as f64 - self.tokens;
+        self.full_at = now + std::time::Duration::from_secs_f64(missing / plan.refill_per_second());
+    }
+
+    /// Seconds until at least one token is available
+    fn seconds_until_token(&self, plan: &Plan) -> u64 {
+        let missing = (1.0 - self.tokens).max(0.0);
+        (missing / plan.refill_per_second()).ceil() as u64
+    }
+
+    /// Seconds until the bucket is full again
+    fn seconds_until_full(&self, plan: &Plan) -> u64 {
+        let missing = (plan.burst as f64 - self.tokens).max(0.0);
+        (missing / plan.refill_per_second()).ceil() as u64
+    }
+}
+
+/// Outcome of a rate-limit check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Decision {
+    Allowed(RateLimitStatus),
+    Limited(RateLimitError),
+}
+
+/// Values reported in the `X-RateLimit-*` and `X-Quota-*` headers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset_seconds: u64,
+    pub quota_limit: u32,
+    pub quota_remaining: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RateLimitError {
+    /// The token bucket is empty
+    TooManyRequests { retry_after_seconds: u64, limit: u32 },
+    /// The daily quota is used up
+    QuotaExceeded { retry_after_seconds: u64, quota: u32 },
+    /// Less of the daily quota is left than a batch needs
+    QuotaInsufficient { retry_after_seconds: u64, remaining: u32, requested: u32 },
+}
+
+impl RateLimitError {
+    pub fn retry_after_seconds(&self) -> u64 {
+        match self {
+            RateLimitError::TooManyRequests { retry_after_seconds, .. }
+            | RateLimitError::QuotaExceeded { retry_after_seconds, .. }
+            | RateLimitError::QuotaInsufficient { retry_after_seconds, .. } => *retry_after_seconds,
+        }
+    }
+}
+
+impl std::fmt::Display for RateLimitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RateLimitError::TooManyRequests { retry_after_seconds, limit } => write!(
+                f,
+                "Rate limit of {} requests exceeded, retry in {} seconds",
+                limit, retry_after_seconds
+            ),
+            RateLimitError::QuotaExceeded { retry_after_seconds, quota } => write!(
+                f,
+                "Daily quota of {} requests used up, retry in {} seconds",
+                quota, retry_after_seconds
+            ),
+            RateLimitError::QuotaInsufficient { retry_after_seconds, remaining, requested } => write!(
+                f,
+                "This request counts as {} requests but only {} are left in today's quota, retry in {} seconds",
+                requested, remaining, retry_after_seconds
+            ),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct RateLimitErrorBody {
+    #[serde(flatten)]
+    error: ErrorResponse,
+    retry_after_seconds: u64,
+}
+
+impl ResponseError for RateLimitError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::TOO_MANY_REQUESTS
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let error = match self {
+            RateLimitError::TooManyRequests { .. } => "rate_limited",
+            RateLimitError::QuotaExceeded { .. } | RateLimitError::QuotaInsufficient { .. } => "quota_exceeded",
+        };
+        HttpResponse::TooManyRequests()
+            .insert_header((RETRY_AFTER, self.retry_after_seconds().to_string()))
+            .json(RateLimitErrorBody {
+                error: ErrorResponse::new(error, self.to_string()),
+                retry_after_seconds: self.retry_after_seconds(),
+            })
+    }
+}
+
+/// Seconds until the next UTC midnight, when daily quotas reset
+fn seconds_until_tomorrow() -> u64 {
+    let now = Utc::now();
+    let tomorrow = (now.date_naive() + chrono::Duration::days(1))
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_utc();
+    (tomorrow - now).num_seconds().max(1) as u64
+}
+
+pub struct RateLimiter {
+    db: Arc<Database>,
+    plans: HashMap<String, Plan>,
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+    daily: Mutex<HashMap<String, (NaiveDate, u32)>>,
+    /// Clients tracked at which the next sweep runs
+    sweep_at: AtomicUsize,
+    /// Reverse proxies whose `X-Forwarded-For` names the client
+    trusted_proxies: Vec<IpAddr>,
+}
+
+impl RateLimiter {
+    pub fn new(db: Arc<Database>, plans: Vec<Plan>) -> Self {
+        let mut plans: HashMap<String, Plan> = plans.into_iter().map(|p| (p.name.clone(), p)).collect();
+        for plan in Plan::defaults() {
+            plans.entry(plan.name.clone()).or_insert(plan);
+        }
+
+        Self {
+            db,
+            plans,
+            buckets: Mutex::new(HashMap::new()),
+            daily: Mutex::new(HashMap::new()),
+            sweep_at: AtomicUsize::new(MIN_SWEEP),
+            trusted_proxies: Vec::new(),
+        }
+    }
+
+    /// Believe `X-Forwarded-For` from these addresses only
+    pub fn with_trusted_proxies(mut self, proxies: Vec<IpAddr>) -> Self {
+        self.trusted_proxies = proxies;
+        self
+    }
+
+    /// Address an anonymous caller is limited by: the peer, unless it is a
+    /// trusted proxy. Then it is the last address of `forwarded_for` that
+    /// is not a trusted proxy, since each proxy appends the address it got
+    /// the request from and anything before that came from the client.
+    pub fn client_ip(&self, peer: Option<IpAddr>, forwarded_for: &[&str]) -> Option<IpAddr> {
+        let mut client = peer?;
+        let mut hops = forwarded_for.iter().rev().flat_map(|header| header.rsplit(','));
+        while self.trusted_proxies.contains(&client) {
+            match hops.next().and_then(parse_ip) {
+                Some(ip) => client = ip,
+                None => break,
+            }
+        }
+        Some(client)
+    }
+
+    pub fn has_plan(&self, name: &str) -> bool {
+        self.plans.contains_key(name)
+    }
+
+    /// Names of every plan, sorted
+    pub fn plan_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.plans.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    pub fn plan(&self, name: &str) -> &Plan {
+        self.plans
+            .get(name)
+            .or_else(|| self.plans.get(DEFAULT_PLAN))
+            .expect("default plan is always present")
+    }
+
+    /// Make sure today's usage for `client` has been loaded from the database
+    async fn load_daily_usage(&self, client: &str, today: NaiveDate) {
+        let loaded = matches!(self.daily.lock().unwrap().get(client), Some((day, _)) if *day == today);
+        if loaded {
+            return;
+        }
+
+        let used = self.db.get_usage(client, today).await.unwrap_or_else(|e| {
+            eprintln!("Failed to load usage for {}: {}", client, e);
+            0
+        });
+        self.daily
+            .lock()
+            .unwrap()
+            .entry(client.to_string())
+            .and_modify(|entry| {
+                if entry.0 != today {
+                    *entry = (today, used);
+                }
+            })
+            .or_insert((today, used));
+    }
+
+    /// Take one token and one unit of quota for `client`, if available
+    pub fn decide(&self, client: &str, plan: &Plan, today: NaiveDate, now: Instant) -> Decision {
+        let mut daily = self.daily.lock().unwrap();
+        let mut buckets = self.buckets.lock().unwrap();
+        if daily.len().max(buckets.len()) >= self.sweep_at.load(Ordering::Relaxed) {
+            sweep(&mut buckets, &mut daily, today, now);
+            // Sweeping again only once the maps have doubled keeps inserts O(1) amortized
+            let tracked = daily.len().max(buckets.len());
+            self.sweep_at.store((tracked * 2).max(MIN_SWEEP), Ordering::Relaxed);
+        }
+
+        let used = daily.entry(client.to_string()).or_insert((today, 0));
+        if used.0 != today {
+            *used = (today, 0);
+        }
+
+        if used.1 >= plan.daily_quota {
+            return Decision::Limited(RateLimitError::QuotaExceeded {
+                retry_after_seconds: seconds_until_tomorrow(),
+                quota: plan.daily_quota,
+            });
+        }
+
+        let bucket = buckets
+            .entry(client.to_string())
+            .or_insert_with(|| TokenBucket::full(plan, now));
+        bucket.refill(plan, now);
+
+        if bucket.tokens < 1.0 {
+            return Decision::Limited(RateLimitError::TooManyRequests {
+                retry_after_seconds: bucket.seconds_until_token(plan).max(1),
+                limit: plan.burst,
+            });
+        }
+
+        bucket.take(plan, 1, now);
+        used.1 += 1;
+
+        Decision::Allowed(status(plan, bucket, used.1))
+    }
+
+    /// Take `requests` more tokens and units of quota for `client`, whom
+    /// [`decide`](Self::decide) let through. The bucket may run into debt,
+    /// so batches larger than the burst are possible; the quota may not.
+    pub fn decide_more(&self, client: &str, plan: &Plan, requests: u32, today: NaiveDate, now: Instant) -> Decision {
+        let mut daily = self.daily.lock().unwrap();
+        let mut buckets = self.buckets.lock().unwrap();
+
+        let used = daily.entry(client.to_string()).or_insert((today, 0));
+        if used.0 != today {
+            *used = (today, 0);
+        }
+        let remaining = plan.daily_quota.saturating_sub(used.1);
+        if remaining < requests {
+            return Decision::Limited(RateLimitError::QuotaInsufficient {
+                retry_after_seconds: seconds_until_tomorrow(),
+                remaining,
+                requested: requests,
+            });
+        }
+
+        let bucket = buckets
+            .entry(client.to_string())
+            .or_insert_with(|| TokenBucket::full(plan, now));
+        bucket.refill(plan, now);
+        bucket.take(plan, requests, now);
+        used.1 += requests;
+
+        Decision::Allowed(status(plan, bucket, used.1))
+    }
+
+    pub async fn check(&self, client: &str, api_key_id: Option<uuid::Uuid>, plan_name: &str) -> Decision {
+        let today = Utc::now().date_naive();
+        self.load_daily_usage(client, today).await;
+
+        let plan = self.plan(plan_name);
+        let decision = self.decide(client, plan, today, Instant::now());
+        if let Decision::Allowed(_) = decision {
+            self.record_usage(client, api_key_id, today, 1);
+        }
+        decision
+    }
+
+    /// Charge `client` for `requests` more requests; see [`decide_more`](Self::decide_more)
+    pub async fn check_more(&self, client: &Client, requests: u32) -> Decision {
+        let today = Utc::now().date_naive();
+        self.load_daily_usage(&client.key, today).await;
+
+        let plan = self.plan(&client.plan);
+        let decision = self.decide_more(&client.key, plan, requests, today, Instant::now());
+        if let Decision::Allowed(_) = decision {
+            self.record_usage(&client.key, client.api_key_id, today, requests);
+        }
+        decision
+    }
+
+    fn record_usage(&self, client: &str, api_key_id: Option<uuid::Uuid>, today: NaiveDate, requests: u32) {
+        let db = self.db.clone();
+        let client = client.to_string();
+        actix_web::rt::spawn(async move {
+            if let Err(e) = db.record_usage(&client, api_key_id, today, requests).await {
+                eprintln!("Failed to record usage for {}: {}", client, e);
+            }
+        });
+    }
+}
+
+fn status(plan: &Plan, bucket: &TokenBucket, used: u32) -> RateLimitStatus {
+    RateLimitStatus {
+        limit: plan.burst,
+        remaining: bucket.tokens.max(0.0).floor() as u32,
+        reset_seconds: bucket.seconds_until_full(plan),
+        quota_limit: plan.daily_quota,
+        quota_remaining: plan.daily_quota.saturating_sub(used),
+    }
+}
+
+/// Who [`enforce`] charged a request to, kept in the request extensions
+#[derive(Debug, Clone)]
+pub struct Client {
+    /// `key:<id>` or `ip:<address>`
+    pub key: String,
+    pub api_key_id: Option<uuid::Uuid>,
+    pub plan: String,
+}
+
+/// Charge the caller of `req` as for `requests` requests in all, the one
+/// [`enforce`] took included; batches count one request per file. Requests
+/// that [`enforce`] did not see are not charged.
+pub async fn charge(req: &HttpRequest, requests: usize) -> Result<(), RateLimitError> {
+    let Some(limiter) = req.app_data::<web::Data<RateLimiter>>() else {
+        return Ok(());
+    };
+    let Some(client) = req.extensions().get::<Client>().cloned() else {
+        return Ok(());
+    };
+    let more = u32::try_from(requests.saturating_sub(1)).unwrap_or(u32::MAX);
+    if more == 0 {
+        return Ok(());
+    }
+
+    match limiter.check_more(&client, more).await {
+        Decision::Allowed(status) => {
+            // Reported in the headers instead of the status before the charge
+            req.extensions_mut().insert(status);
+            Ok(())
+        }
+        Decision::Limited(e) => Err(e),
+    }
+}
+
+/// Forget clients that have nothing to remember: buckets that have refilled
+/// completely and counters of past days
+fn sweep(
+    buckets: &mut HashMap<String, TokenBucket>,
+    daily: &mut HashMap<String, (NaiveDate, u32)>,
+    today: NaiveDate,
+    now: Instant,
+) {
+    buckets.retain(|_, bucket| bucket.full_at > now);
+    daily.retain(|_, (day, _)| *day == today);
+}
+
+/// Trusted proxies from `ANALYZER_TRUSTED_PROXIES`, comma-separated IP
+/// addresses; none when unset, so `X-Forwarded-For` is ignored
+pub fn trusted_proxies_from_env() -> Vec<IpAddr> {
+    std::env::var("ANALYZER_TRUSTED_PROXIES")
+        .unwrap_or_default()
+        .split(',')
+        .filter(|entry| !entry.trim().is_empty())
+        .filter_map(|entry| {
+            let ip = parse_ip(entry);
+            if ip.is_none() {
+                eprintln!("Ignoring '{}' in ANALYZER_TRUSTED_PROXIES: not an IP address", entry.trim());
+            }
+            ip
+        })
+        .collect()
+}
+
+/// An `X-Forwarded-For` entry, with or without a port
+fn parse_ip(entry: &str) -> Option<IpAddr> {
+    let entry = entry.trim();
+    entry
+        .parse()
+        .ok()
+        .or_else(|| entry.parse::<SocketAddr>().ok().map(|addr| addr.ip()))
+}
+
+fn insert_header(res: &mut ServiceResponse<impl MessageBody>, name: &'static str, value: impl ToString) {
+    if let Ok(value) = HeaderValue::from_str(&value.to_string()) {
+        res.headers_mut().insert(HeaderName::from_static(name), value);
+    }
+}
+
+/// Middleware body: throttle the caller resolved by [`crate::auth::require`].
+///
+/// Must run inside the auth middleware so the [`Principal`] is known; callers
+/// without one are keyed by [`RateLimiter::client_ip`] on the default plan.
+pub async fn enforce(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let limiter = req
+        .app_data::<web::Data<RateLimiter>>()
+        .cloned()
+        .expect("RateLimiter is not registered as app data");
+
+    let principal = req.extensions().get::<Principal>().cloned();
+    let (key, api_key_id, plan) = match principal {
+        Some(Principal { key_id: Some(id), plan, .. }) => (format!("key:{}", id), Some(id), plan),
+        _ => {
+            let forwarded_for: Vec<&str> = req
+                .headers()
+                .get_all("x-forwarded-for")
+                .filter_map(|value| value.to_str().ok())
+                .collect();
+            let ip = limiter.client_ip(req.peer_addr().map(|addr| addr.ip()), &forwarded_for);
+            let ip = ip.map_or("unknown".to_string(), |ip| ip.to_string());
+            (format!("ip:{}", ip), None, DEFAULT_PLAN.to_string())
+        }
+    };
+
+    let status = match limiter.check(&key, api_key_id, &plan).await {
+        Decision::Allowed(status) => status,
+        Decision::Limited(e) => return Err(e.into()),
+    };
+    req.extensions_mut().insert(Client { key, api_key_id, plan });
+
+    let mut res = next.call(req).await?;
+    let status = res.request().extensions().get::<RateLimitStatus>().cloned().unwrap_or(status);
+    insert_header(&mut res, "x-ratelimit-limit", status.limit);
+    insert_header(&mut res, "x-ratelimit-remaining", status.remaining);
+    insert_header(&mut res, "x-ratelimit-reset", status.reset_seconds);
+    insert_header(&mut res, "x-quota-limit", status.quota_limit);
+    insert_header(&mut res, "x-quota-remaining", status.quota_remaining);
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn limiter() -> RateLimiter {
+        let db = Arc::new(Database::connect_lazy("mysql://localhost:3306/icalds").unwrap());
+        RateLimiter::new(db, Vec::new())
+    }
+
+    #[actix_web::test]
+    async fn test_token_bucket_refills() {
+        let limiter = limiter();
+        let plan = Plan {
+            name: "tiny".to_string(),
+            requests_per_minute: 60,
+            burst: 2,
+            daily_quota: 100,
+        };
+        let today = Utc::now().date_naive();
+        let start = Instant::now();
+
+        assert!(matches!(limiter.decide("c", &plan, today, start), Decision::Allowed(_)));
+        assert!(matches!(limiter.decide("c", &plan, today, start), Decision::Allowed(_)));
+        match limiter.decide("c", &plan, today, start) {
+            Decision::Limited(RateLimitError::TooManyRequests { retry_after_seconds, .. }) => {
+                assert_eq!(retry_after_seconds, 1)
+            }
+            other => panic!("expected rate limit, got {:?}", other),
+        }
+
+        // One request per second refills
+        let later = start + Duration::from_secs(1);
+        assert!(matches!(limiter.decide("c", &plan, today, later), Decision::Allowed(_)));
+
+        // Other clients have their own bucket
+        assert!(matches!(limiter.decide("d", &plan, today, start), Decision::Allowed(_)));
+    }
+
+    #[actix_web::test]
+    async fn test_daily_quota() {
+        let limiter = limiter();
+        let plan = Plan {
+            name: "quota".to_string(),
+            requests_per_minute: 6_000,
+            burst: 100,
+            daily_quota: 2,
+        };
+        let today = Utc::now().date_naive();
+        let now = Instant::now();
+
+        match limiter.decide("c", &plan, today, now) {
+            Decision::Allowed(status) => assert_eq!(status.quota_remaining, 1),
+            other => panic!("expected allowed, got {:?}", other),
+        }
+        assert!(matches!(limiter.decide("c", &plan, today, now), Decision::Allowed(_)));
+        assert!(matches!(
+            limiter.decide("c", &plan, today, now),
+            Decision::Limited(RateLimitError::QuotaExceeded { .. })
+        ));
+
+        // A new day resets the quota
+        let tomorrow = today + chrono::Duration::days(1);
+        assert!(matches!(limiter.decide("c", &plan, tomorrow, now), Decision::Allowed(_)));
+    }
+
+    #[actix_web::test]
+    async fn test_batches_are_charged_per_file() {
+        let limiter = limiter();
+        let plan = Plan {
+            name: "batch".to_string(),
+            requests_per_minute: 60,
+            burst: 5,
+            daily_quota: 20,
+        };
+        let today = Utc::now().date_naive();
+        let now = Instant::now();
+
+        // A batch of 10 files: one request through `decide`, nine more; the
+        // bucket runs into debt, the quota does not
+        assert!(matches!(limiter.decide("c", &plan, today, now), Decision::Allowed(_)));
+        match limiter.decide_more("c", &plan, 9, today, now) {
+            Decision::Allowed(status) => {
+                assert_eq!(status.remaining, 0);
+                assert_eq!(status.quota_remaining, 10);
+            }
+            other => panic!("expected allowed, got {:?}", other),
+        }
+        match limiter.decide("c", &plan, today, now + Duration::from_secs(5)) {
+            Decision::Limited(RateLimitError::TooManyRequests { retry_after_seconds, .. }) => {
+                assert_eq!(retry_after_seconds, 1)
+            }
+            other => panic!("expected rate limited, got {:?}", other),
+        }
+
+        // Eleven more files do not fit in the quota left
+        let later = now + Duration::from_secs(60);
+        assert!(matches!(limiter.decide("c", &plan, today, later), Decision::Allowed(_)));
+        assert!(matches!(
+            limiter.decide_more("c", &plan, 10, today, later),
+            Decision::Limited(RateLimitError::QuotaInsufficient { remaining: 9, requested: 10, .. })
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_idle_clients_are_swept() {
+        let limiter = limiter();
+        let plan = limiter.plan(DEFAULT_PLAN).clone();
+        let yesterday = Utc::now().date_naive() - chrono::Duration::days(1);
+        let start = Instant::now();
+        for client in 0..MIN_SWEEP {
+            limiter.decide(&format!("ip:{}", client), &plan, yesterday, start);
+        }
+
+        // Once every bucket has refilled and the day is over, the next new
+        // client sweeps them all
+        let later = start + Duration::from_secs(60);
+        limiter.decide("ip:new", &plan, yesterday + chrono::Duration::days(1), later);
+        assert_eq!(limiter.buckets.lock().unwrap().len(), 1);
+        assert_eq!(limiter.daily.lock().unwrap().len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn test_unknown_plan_falls_back_to_default() {
+        let limiter = limiter();
+        assert_eq!(limiter.plan("missing").name, DEFAULT_PLAN);
+        assert!(!limiter.has_plan("missing"));
+        assert_eq!(limiter.plan_names(), ["free", "internal", "team"]);
+    }
+
+    #[actix_web::test]
+    async fn test_forwarded_for_is_only_believed_from_trusted_proxies() {
+        let ip = |text: &str| text.parse::<IpAddr>().unwrap();
+        let client = ip("203.0.113.7");
+        let proxy = ip("10.0.0.2");
+
+        // Without trusted proxies the header is ignored, whoever sends it
+        let limiter = limiter();
+        assert_eq!(limiter.client_ip(Some(client), &["198.51.100.1"]), Some(client));
+        assert_eq!(limiter.client_ip(Some(proxy), &["198.51.100.1"]), Some(proxy));
+        assert_eq!(limiter.client_ip(None, &[]), None);
+
+        // Behind trusted proxies the client is the last untrusted hop; what
+        // it wrote into the header itself comes before that
+        let limiter = limiter.with_trusted_proxies(vec![proxy, ip("10.0.0.1")]);
+        assert_eq!(limiter.client_ip(Some(client), &["198.51.100.1"]), Some(client));
+        assert_eq!(limiter.client_ip(Some(proxy), &["198.51.100.1, 203.0.113.7"]), Some(client));
+        assert_eq!(limiter.client_ip(Some(proxy), &["198.51.100.1", "203.0.113.7:4711, 10.0.0.1"]), Some(client));
+        assert_eq!(limiter.client_ip(Some(proxy), &["10.0.0.1"]), Some(ip("10.0.0.1")));
+        assert_eq!(limiter.client_ip(Some(proxy), &["garbage"]), Some(proxy));
+        assert_eq!(limiter.client_ip(Some(proxy), &["[2001:db8::1]:80"]), Some(ip("2001:db8::1")));
+    }
+}
@@ -0,0 +1,270 @@
+//! The relation graph of both catalogs
+//!
+//! Every catalog entry lists typed relations to other entries (Heap Sort
+//! uses a Binary Heap, an AVL Tree is a variant of a Binary Search Tree).
+//! This module collects them into one graph and renders it as JSON,
+//! Graphviz DOT or a Mermaid flowchart. Relations whose target is not in
+//! the catalogs are left out, and symmetric relations appear once.
+
+use analyzer_types::v2::{CatalogKind, RelationKind};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::algorithms::AlgorithmDetector;
+use crate::catalog::{slug, CatalogEntry};
+use crate::data_structures::DataStructureDetector;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphFormat {
+    #[default]
+    Json,
+    Dot,
+    Mermaid,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+pub struct GraphQuery {
+    /// `json` (default), `dot` for Graphviz or `mermaid`
+    pub format: Option<GraphFormat>,
+}
+
+/// A catalog entry in the graph
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct GraphNode {
+    /// Catalog and slug, e.g. `algorithm:heap-sort`
+    pub id: String,
+    pub name: String,
+    pub kind: CatalogKind,
+    /// Human-readable category, e.g. `Sorting`
+    pub category: String,
+}
+
+/// A relation between two nodes, by node id
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+    pub kind: RelationKind,
+}
+
+/// Both catalogs as nodes, and their relations as edges
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct CatalogGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+fn node_id(kind: CatalogKind, name: &str) -> String {
+    format!("{}:{}", kind.as_str(), slug(name))
+}
+
+fn node<T: CatalogEntry>(kind: CatalogKind, entry: &T) -> GraphNode {
+    GraphNode {
+        id: node_id(kind, entry.name()),
+        name: entry.name().to_string(),
+        kind,
+        category: entry.category_label(),
+    }
+}
+
+impl CatalogGraph {
+    pub fn build(algorithms: &AlgorithmDetector, data_structures: &DataStructureDetector) -> Self {
+        let mut nodes: Vec<GraphNode> = algorithms
+            .algorithms
+            .values()
+            .map(|entry| node(CatalogKind::Algorithm, entry))
+            .chain(data_structures.data_structures.values().map(|entry| node(CatalogKind::DataStructure, entry)))
+            .collect();
+        nodes.sort_by(|a, b| (a.kind, &a.name).cmp(&(b.kind, &b.name)));
+        let known: BTreeSet<&str> = nodes.iter().map(|node| node.id.as_str()).collect();
+
+        let relations = algorithms
+            .algorithms
+            .values()
+            .map(|entry| (CatalogKind::Algorithm, &entry.name, &entry.relations))
+            .chain(
+                data_structures
+                    .data_structures
+                    .values()
+                    .map(|entry| (CatalogKind::DataStructure, &entry.name, &entry.relations)),
+            );
+
+        // Keyed so symmetric relations listed on both ends collapse into one edge
+        let mut edges = BTreeSet::new();
+        for (kind, name, relations) in relations {
+            let from = node_id(kind, name);
+            for relation in relations {
+                let to = node_id(relation.target_kind, &relation.target);
+                if !known.contains(to.as_str()) || to == from {
+                    continue;
+                }
+                let key = if relation.kind.is_symmetric() && to < from {
+                    (to.clone(), from.clone(), relation.kind)
+                } else {
+                    (from.clone(), to.clone(), relation.kind)
+                };
+                edges.insert(key);
+            }
+        }
+
+        CatalogGraph {
+            nodes,
+            edges: edges
+                .into_iter()
+                .map(|(from, to, kind)| GraphEdge { from, to, kind })
+                .collect(),
+        }
+    }
+
+    /// Graphviz DOT, one cluster per catalog
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph catalog {\n    rankdir=LR;\n");
+        for (kind, label, shape) in [
+            (CatalogKind::Algorithm, "Algorithms", "box"),
+            (CatalogKind::DataStructure, "Data structures", "ellipse"),
+        ] {
+            dot.push_str(&format!(
+                "    subgraph cluster_{} {{\n        label=\"{}\";\n        node [shape={}];\n",
+                kind.as_str(),
+                label,
+                shape
+            ));
+            for node in self.nodes.iter().filter(|node| node.kind == kind) {
+                dot.push_str(&format!("        \"{}\" [label=\"{}\"];\n", node.id, dot_escape(&node.name)));
+            }
+            dot.push_str("    }\n");
+        }
+        for edge in &self.edges {
+            let style = match edge.kind {
+                RelationKind::Uses => "",
+                RelationKind::VariantOf => ", style=bold",
+                RelationKind::AlternativeTo => ", style=dashed, dir=none",
+                RelationKind::Prerequisite => ", style=dotted",
+            };
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\" [label=\"{}\"{}];\n",
+                edge.from,
+                edge.to,
+                label(edge.kind),
+                style
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// A Mermaid flowchart, one subgraph per catalog
+    pub fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("flowchart LR\n");
+        for (kind, label) in [
+            (CatalogKind::Algorithm, "Algorithms"),
+            (CatalogKind::DataStructure, "Data structures"),
+        ] {
+            mermaid.push_str(&format!("    subgraph {} [{}]\n", kind.as_str(), label));
+            for node in self.nodes.iter().filter(|node| node.kind == kind) {
+                let name = node.name.replace('"', "#quot;");
+                let shape = match kind {
+                    CatalogKind::Algorithm => format!("[\"{}\"]", name),
+                    CatalogKind::DataStructure => format!("([\"{}\"])", name),
+                };
+                mermaid.push_str(&format!("        {}{}\n", mermaid_id(&node.id), shape));
+            }
+            mermaid.push_str("    end\n");
+        }
+        for edge in &self.edges {
+            let arrow = match edge.kind {
+                RelationKind::Uses => "-->",
+                RelationKind::VariantOf => "==>",
+                RelationKind::AlternativeTo => "-.-",
+                RelationKind::Prerequisite => "-.->",
+            };
+            mermaid.push_str(&format!(
+                "    {} {}|{}| {}\n",
+                mermaid_id(&edge.from),
+                arrow,
+                label(edge.kind),
+                mermaid_id(&edge.to)
+            ));
+        }
+        mermaid
+    }
+}
+
+fn label(kind: RelationKind) -> &'static str {
+    match kind {
+        RelationKind::Uses => "uses",
+        RelationKind::VariantOf => "variant of",
+        RelationKind::AlternativeTo => "alternative to",
+        RelationKind::Prerequisite => "prerequisite",
+    }
+}
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Mermaid ids are plain words: `algorithm:heap-sort` becomes `algorithm_heap_sort`
+fn mermaid_id(id: &str) -> String {
+    id.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph() -> CatalogGraph {
+        CatalogGraph::build(&AlgorithmDetector::new(), &DataStructureDetector::new())
+    }
+
+    #[test]
+    fn test_built_in_relations_resolve() {
+        let algorithms = AlgorithmDetector::new();
+        let data_structures = DataStructureDetector::new();
+        let relations = algorithms
+            .algorithms
+            .values()
+            .flat_map(|entry| &entry.relations)
+            .chain(data_structures.data_structures.values().flat_map(|entry| &entry.relations));
+
+        for relation in relations {
+            let found = match relation.target_kind {
+                CatalogKind::Algorithm => algorithms.algorithms.contains_key(&relation.target),
+                CatalogKind::DataStructure => data_structures.data_structures.contains_key(&relation.target),
+            };
+            assert!(found, "unknown relation target '{}'", relation.target);
+        }
+    }
+
+    #[test]
+    fn test_symmetric_relations_appear_once() {
+        let graph = graph();
+        let between = |a: &str, b: &str| {
+            graph
+                .edges
+                .iter()
+                .filter(|edge| (edge.from == a && edge.to == b) || (edge.from == b && edge.to == a))
+                .count()
+        };
+        assert_eq!(between("algorithm:quick-sort", "algorithm:merge-sort"), 1);
+        assert!(graph.edges.iter().any(|edge| edge.from == "algorithm:heap-sort"
+            && edge.to == "data_structure:binary-heap"
+            && edge.kind == RelationKind::Uses));
+    }
+
+    #[test]
+    fn test_renders_dot_and_mermaid() {
+        let graph = graph();
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph catalog {"));
+        assert!(dot.contains("\"algorithm:dijkstra-s-algorithm\" [label=\"Dijkstra's Algorithm\"];"));
+        assert!(dot.contains("\"algorithm:heap-sort\" -> \"data_structure:binary-heap\" [label=\"uses\"];"));
+
+        let mermaid = graph.to_mermaid();
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("data_structure_binary_heap([\"Binary Heap\"])"));
+        assert!(mermaid.contains("algorithm_heap_sort -->|uses| data_structure_binary_heap"));
+        assert_eq!(mermaid.matches("subgraph").count(), 2);
+    }
+}
@@ -0,0 +1,67 @@
+//! HTTP handlers, one module per feature; `main.rs` mounts them under
+//! each API version
+
+pub mod admin;
+pub mod analysis;
+pub mod batches;
+pub mod catalog;
+pub mod health;
+pub mod history;
+pub mod jobs;
+pub mod stream;
+
+use actix_web::{web, HttpResponse};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use analyzer_api::models::error_response::ErrorResponse;
+use analyzer_api::{algorithms, auth, data_structures, db, limits, metrics};
+
+/// State shared by every handler
+pub struct AppState {
+    pub db: Arc<db::Database>,
+    /// Built-in catalogs with the approved overrides applied; replaced on approval
+    pub algorithm_detector: analyzer_api::catalog::Live<algorithms::AlgorithmDetector>,
+    pub data_structure_detector: analyzer_api::catalog::Live<data_structures::DataStructureDetector>,
+    /// Held while the detectors are rebuilt, so a slower rebuild from older
+    /// overrides cannot replace a newer one
+    pub catalog_reload: tokio::sync::Mutex<()>,
+    pub limits: limits::AnalysisLimits,
+    pub metrics: web::Data<metrics::Metrics>,
+    /// Detector self-test results from startup
+    pub self_test: Vec<analyzer_api::health::CheckResult>,
+}
+
+pub fn bad_request(error: &str, message: impl Into<String>) -> HttpResponse {
+    HttpResponse::BadRequest().json(ErrorResponse::new(error, message))
+}
+
+/// Jobs and batches are visible to the key that submitted them and to
+/// admins. Anonymous callers share one identity, so they see each other's.
+/// Anyone else gets the same `404` as for an unknown id, so ids cannot be probed.
+pub fn may_read(owner_key_id: Option<Uuid>, principal: &auth::Principal) -> bool {
+    principal.role == auth::Role::Admin || owner_key_id == principal.key_id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_submissions_are_only_visible_to_their_owner_and_admins() {
+        let principal = |key_id: Option<Uuid>, role| auth::Principal {
+            key_id,
+            name: "caller".to_string(),
+            role,
+            plan: "free".to_string(),
+        };
+        let owner = Uuid::new_v4();
+
+        assert!(may_read(Some(owner), &principal(Some(owner), auth::Role::Analyst)));
+        assert!(!may_read(Some(owner), &principal(Some(Uuid::new_v4()), auth::Role::Analyst)));
+        assert!(!may_read(Some(owner), &principal(None, auth::Role::Analyst)));
+        assert!(may_read(Some(owner), &principal(Some(Uuid::new_v4()), auth::Role::Admin)));
+        assert!(may_read(None, &principal(None, auth::Role::Analyst)));
+        assert!(!may_read(None, &principal(Some(owner), auth::Role::Analyst)));
+    }
+}
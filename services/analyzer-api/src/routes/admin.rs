@@ -0,0 +1,158 @@
+//! `/admin`: API keys and usage
+
+use actix_web::{web, HttpResponse, Result};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use analyzer_api::{auth, models, rate_limit};
+use analyzer_api::models::error_response::ErrorResponse;
+
+use super::{bad_request, AppState};
+
+#[derive(Deserialize, ToSchema)]
+pub struct CreateApiKey {
+    name: String,
+    role: auth::Role,
+    /// Rate-limit plan; defaults to the free plan
+    plan: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct CreatedApiKey {
+    id: Uuid,
+    name: String,
+    role: auth::Role,
+    plan: String,
+    /// Shown once; only the hash is stored
+    key: String,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct UsageQuery {
+    /// Number of days to report, counting back from today
+    days: Option<u32>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/v2/admin/api-keys",
+    tag = "admin",
+    request_body = CreateApiKey,
+    responses(
+        (status = 201, description = "The new key; the raw key is only shown here", body = CreatedApiKey),
+        (status = 400, description = "The plan does not exist", body = ErrorResponse),
+        (status = 403, description = "Role may not manage keys", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn create_api_key(
+    item: web::Json<CreateApiKey>,
+    data: web::Data<AppState>,
+    limiter: web::Data<rate_limit::RateLimiter>,
+) -> Result<HttpResponse> {
+    let item = item.into_inner();
+    let plan = item.plan.unwrap_or_else(|| rate_limit::DEFAULT_PLAN.to_string());
+    // An unknown plan would silently get the default plan's limits
+    if !limiter.has_plan(&plan) {
+        return Ok(bad_request(
+            "unknown_plan",
+            format!("Unknown plan '{}'; expected one of: {}", plan, limiter.plan_names().join(", ")),
+        ));
+    }
+    let key = auth::generate_api_key();
+    
+    let new_api_key = models::api_key::NewApiKey {
+        name: item.name.clone(),
+        key_hash: auth::hash_api_key(&key),
+        role: item.role.to_string(),
+        plan: plan.clone(),
+    };
+    
+    match data.db.save_api_key(new_api_key).await {
+        Ok(id) => Ok(HttpResponse::Created().json(CreatedApiKey {
+            id,
+            name: item.name,
+            role: item.role,
+            plan,
+            key,
+        })),
+        Err(e) => {
+            eprintln!("Failed to save API key: {}", e);
+            Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to save API key")))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/admin/api-keys",
+    tag = "admin",
+    responses(
+        (status = 200, description = "All keys, including revoked ones", body = [models::api_key::ApiKey]),
+        (status = 403, description = "Role may not manage keys", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn list_api_keys(data: web::Data<AppState>) -> Result<HttpResponse> {
+    match data.db.list_api_keys().await {
+        Ok(keys) => Ok(HttpResponse::Ok().json(keys)),
+        Err(e) => {
+            eprintln!("Failed to list API keys: {}", e);
+            Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to list API keys")))
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v2/admin/api-keys/{id}",
+    tag = "admin",
+    params(("id" = Uuid, Path, description = "API key id")),
+    responses(
+        (status = 204, description = "The key was revoked"),
+        (status = 404, description = "No active API key with that id", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn revoke_api_key(
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    match data.db.revoke_api_key(path.into_inner()).await {
+        Ok(true) => Ok(HttpResponse::NoContent().finish()),
+        Ok(false) => Ok(HttpResponse::NotFound().json(ErrorResponse::new("not_found", "No active API key with that id"))),
+        Err(e) => {
+            eprintln!("Failed to revoke API key: {}", e);
+            Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to revoke API key")))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/admin/usage",
+    tag = "admin",
+    params(UsageQuery),
+    responses(
+        (status = 200, description = "Requests per client and day", body = [models::api_key::KeyUsage]),
+        (status = 403, description = "Role may not read usage", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn get_usage(
+    query: web::Query<UsageQuery>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let days = query.days.unwrap_or(1).clamp(1, 90);
+    let today = chrono::Utc::now().date_naive();
+    let from = today - chrono::Duration::days(days as i64 - 1);
+    
+    match data.db.get_usage_report(from, today).await {
+        Ok(usage) => Ok(HttpResponse::Ok().json(usage)),
+        Err(e) => {
+            eprintln!("Failed to load usage: {}", e);
+            Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to load usage")))
+        }
+    }
+}
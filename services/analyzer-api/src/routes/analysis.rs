@@ -0,0 +1,379 @@
+//! `/analyze`: running the detectors on one submission, rendered per API version
+
+use actix_web::{web, HttpResponse, Result};
+use uuid::Uuid;
+
+use analyzer_api::{algorithms, anti_patterns, data_structures, limits, models, syntax};
+use analyzer_api::models::error_response::ErrorResponse;
+use analyzer_types::{v1, v2};
+
+use super::AppState;
+
+/// Outcome of one analysis, rendered per API version
+pub struct AnalysisResult {
+    patterns: Vec<String>,
+    algorithms: Vec<String>,
+    detailed_algorithms: Vec<algorithms::DetectedAlgorithm>,
+    data_structures: Vec<String>,
+    detailed_data_structures: Vec<data_structures::DetectedDataStructure>,
+    collections: Vec<v2::CollectionUsage>,
+    suggestions: Vec<v2::Suggestion>,
+    anti_patterns: Vec<v2::AntiPattern>,
+    complexity: String,
+    recommendations: Vec<String>,
+    /// Set when the time budget ran out and the results are partial
+    timed_out: bool,
+}
+
+impl AnalysisResult {
+    /// The frozen v1 shape, with enum variant names as categories
+    pub fn to_v1(&self) -> v1::AnalysisResult {
+        v1::AnalysisResult {
+            patterns: self.patterns.clone(),
+            algorithms: self.algorithms.clone(),
+            detailed_algorithms: self
+                .detailed_algorithms
+                .iter()
+                .map(|alg| v1::DetectedAlgorithm {
+                    name: alg.name.clone(),
+                    category: format!("{:?}", alg.category),
+                    complexity: alg.complexity.clone(),
+                    description: alg.description.clone(),
+                    wikipedia_link: alg.wikipedia_link.clone(),
+                })
+                .collect(),
+            data_structures: self.data_structures.clone(),
+            detailed_data_structures: self
+                .detailed_data_structures
+                .iter()
+                .map(|ds| v1::DetectedDataStructure {
+                    name: ds.name.clone(),
+                    category: format!("{:?}", ds.category),
+                    complexity: ds.complexity.clone(),
+                    description: ds.description.clone(),
+                    wikipedia_link: ds.wikipedia_link.clone(),
+                })
+                .collect(),
+            complexity: self.complexity.clone(),
+            recommendations: self.recommendations.clone(),
+            timed_out: self.timed_out,
+        }
+    }
+    
+    pub fn to_v2(&self, language: &str) -> v2::AnalysisResponse {
+        v2::AnalysisResponse {
+            language: language.to_string(),
+            complexity: self.complexity.clone(),
+            timed_out: self.timed_out,
+            algorithms: self.detailed_algorithms.iter().map(algorithm_finding).collect(),
+            data_structures: self.detailed_data_structures.iter().map(data_structure_finding).collect(),
+            collections: self.collections.clone(),
+            suggestions: self.suggestions.clone(),
+            anti_patterns: self.anti_patterns.clone(),
+            patterns: self.patterns.clone(),
+            recommendations: self.recommendations.clone(),
+        }
+    }
+}
+
+pub fn algorithm_finding(alg: &algorithms::DetectedAlgorithm) -> v2::Finding {
+    v2::Finding {
+        name: alg.name.clone(),
+        category: alg.category.to_string(),
+        complexity: alg.complexity.clone(),
+        description: alg.description.clone(),
+        wikipedia_link: alg.wikipedia_link.clone(),
+        relations: alg.relations.clone(),
+        definitions: Vec::new(),
+    }
+}
+
+pub fn data_structure_finding(ds: &data_structures::DetectedDataStructure) -> v2::Finding {
+    v2::Finding {
+        name: ds.name.clone(),
+        category: ds.category.to_string(),
+        complexity: ds.complexity.clone(),
+        description: ds.description.clone(),
+        wikipedia_link: ds.wikipedia_link.clone(),
+        relations: ds.relations.clone(),
+        definitions: ds.definitions.clone(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/analyze",
+    tag = "analysis",
+    request_body = v1::AnalyzeRequest,
+    responses(
+        (status = 200, description = "Detected algorithms, data structures and recommendations", body = v1::AnalysisResult),
+        (status = 400, description = "Empty, binary or malformed input", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "Role may not analyze code", body = ErrorResponse),
+        (status = 413, description = "Payload exceeds the size limit", body = ErrorResponse),
+        (status = 429, description = "Rate limit or daily quota exceeded", body = ErrorResponse)
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn analyze_v1(
+    item: web::Json<v1::AnalyzeRequest>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let item = item.into_inner();
+    
+    if let Err(e) = limits::validate_code(&item.code) {
+        return Ok(HttpResponse::BadRequest().json(ErrorResponse::new(e.code(), e.to_string())));
+    }
+    
+    let result = run_analysis(item.code, v2::DEFAULT_LANGUAGE, item.time_budget_ms, &data).await?;
+    Ok(HttpResponse::Ok().json(result.to_v1()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v2/analyze",
+    tag = "analysis",
+    request_body = v2::AnalyzeRequest,
+    responses(
+        (status = 200, description = "Detected algorithms, data structures and recommendations", body = v2::AnalysisResponse),
+        (status = 400, description = "Empty, binary or malformed input, or an unsupported language", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "Role may not analyze code", body = ErrorResponse),
+        (status = 413, description = "Payload exceeds the size limit", body = ErrorResponse),
+        (status = 429, description = "Rate limit or daily quota exceeded", body = ErrorResponse)
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn analyze_v2(
+    item: web::Json<v2::AnalyzeRequest>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let item = item.into_inner();
+    
+    if let Err(e) = check_analyze_request(&item) {
+        return Ok(HttpResponse::BadRequest().json(e));
+    }
+    
+    let result = run_analysis(item.code, &item.language, item.time_budget_ms, &data).await?;
+    Ok(HttpResponse::Ok().json(result.to_v2(&item.language)))
+}
+
+pub fn check_analyze_request(item: &v2::AnalyzeRequest) -> std::result::Result<(), ErrorResponse> {
+    if item.language != v2::DEFAULT_LANGUAGE {
+        return Err(ErrorResponse::new(
+            "unsupported_language",
+            format!("Language '{}' is not supported; use '{}'", item.language, v2::DEFAULT_LANGUAGE),
+        ));
+    }
+    limits::validate_code(&item.code).map_err(|e| ErrorResponse::new(e.code(), e.to_string()))
+}
+
+/// Analyze validated code and store the sample and its result
+pub async fn run_analysis(
+    code: String,
+    language: &str,
+    time_budget_ms: Option<u64>,
+    data: &AppState,
+) -> Result<AnalysisResult> {
+    let result = analyze(&code, language, time_budget_ms, data).await?;
+    let (new_code_sample, mut new_analysis_result) = analysis_records(code, language, &result);
+    
+    match data.db.save_code_sample(new_code_sample).await {
+        Ok(code_sample_id) => {
+            new_analysis_result.code_sample_id = code_sample_id;
+            match data.db.save_analysis_result(new_analysis_result).await {
+                Ok(_) => {
+                    println!("Saved analysis result to database");
+                }
+                Err(e) => {
+                    eprintln!("Failed to save analysis result: {}", e);
+                    data.metrics.record_db_save_failure("analysis_results");
+                }
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to save code sample: {}", e);
+            data.metrics.record_db_save_failure("code_samples");
+        }
+    }
+    
+    Ok(result)
+}
+
+/// Analyze validated code and count what was found
+pub async fn analyze(
+    code: &str,
+    language: &str,
+    time_budget_ms: Option<u64>,
+    data: &AppState,
+) -> Result<AnalysisResult> {
+    // Run the detectors on the blocking pool so large inputs don't stall the workers
+    let budget = data.limits.budget_for(time_budget_ms);
+    let algorithm_detector = data.algorithm_detector.load();
+    let data_structure_detector = data.data_structure_detector.load();
+    let input = code.to_string();
+    let started = std::time::Instant::now();
+    let result = web::block(move || {
+        analyze_code_logic(&input, &algorithm_detector, &data_structure_detector, &budget)
+    })
+    .await?;
+    data.metrics.observe_analysis(language, code.len(), started.elapsed());
+    
+    for alg in &result.detailed_algorithms {
+        data.metrics.record_detection("algorithm", &alg.name, &alg.category.to_string());
+    }
+    for ds in &result.detailed_data_structures {
+        data.metrics.record_detection("data_structure", &ds.name, &ds.category.to_string());
+    }
+    
+    Ok(result)
+}
+
+/// Database rows for one analysis; `code_sample_id` is set once the sample is saved
+pub fn analysis_records(
+    code: String,
+    language: &str,
+    result: &AnalysisResult,
+) -> (models::code_sample::NewCodeSample, models::analysis_result::NewAnalysisResult) {
+    let new_code_sample = models::code_sample::NewCodeSample {
+        code,
+        language: Some(language.to_string()),
+    };
+    
+    // Convert detailed algorithms to the database model
+    let detailed_algorithms = result
+        .detailed_algorithms
+        .iter()
+        .map(|alg| models::analysis_result::DetailedAlgorithm {
+            name: alg.name.clone(),
+            category: alg.category.to_string(),
+            complexity: alg.complexity.clone(),
+            description: alg.description.clone(),
+            wikipedia_link: alg.wikipedia_link.clone(),
+        })
+        .collect();
+    
+    let new_analysis_result = models::analysis_result::NewAnalysisResult {
+        code_sample_id: Uuid::nil(),
+        patterns: result.patterns.clone(),
+        algorithms: result.algorithms.clone(),
+        detailed_algorithms,
+        data_structures: result.data_structures.clone(),
+        complexity: result.complexity.clone(),
+        recommendations: result.recommendations.clone(),
+    };
+    
+    (new_code_sample, new_analysis_result)
+}
+
+pub fn analyze_code_logic(
+    code: &str, 
+    algorithm_detector: &algorithms::AlgorithmDetector,
+    data_structure_detector: &data_structures::DataStructureDetector,
+    budget: &limits::AnalysisBudget,
+) -> AnalysisResult {
+    let mut patterns = Vec::new();
+    let mut algorithms = Vec::new();
+    let mut data_structures = Vec::new();
+    let mut recommendations = Vec::new();
+    
+    // Detect algorithms using the new system
+    let detected_algorithms = algorithm_detector.detect_algorithms_within(code, budget);
+    
+    // Extract algorithm names for the simple list
+    for alg in &detected_algorithms {
+        algorithms.push(alg.name.clone());
+    }
+    
+    // Detect data structures
+    let detected_data_structures = data_structure_detector.detect_data_structures_within(code, budget);
+    
+    // Extract data structure names for the simple list
+    for ds in &detected_data_structures {
+        data_structures.push(ds.name.clone());
+    }
+    
+    // Parsed once for the detectors below that walk the syntax tree
+    let syntax = budget.check().then(|| syntax::Syntax::parse(code));
+
+    // Standard-library collections and the cost of what is called on them
+    let collections = match &syntax {
+        Some(syntax) if budget.check() => data_structure_detector.collection_usage(code, syntax),
+        _ => Vec::new(),
+    };
+    let suggestions = data_structures::fit::suggestions(&collections);
+    
+    // Performance smells
+    let anti_patterns = match &syntax {
+        Some(syntax) if budget.check() => anti_patterns::anti_patterns(code, syntax, &collections),
+        _ => Vec::new(),
+    };
+    
+    // Simple pattern matching for demonstration
+    if code.contains("for") && code.contains("..<") {
+        patterns.push("Range-based loop".to_string());
+    }
+    
+    if code.contains("fn ") && code.contains("recursive") {
+        patterns.push("Recursive function".to_string());
+    }
+    
+    // Simple complexity estimation
+    let complexity = if code.lines().count() > 100 {
+        "High".to_string()
+    } else if code.lines().count() > 50 {
+        "Medium".to_string()
+    } else {
+        "Low".to_string()
+    };
+    
+    // Performance smells lead, most severe first
+    let mut by_severity: Vec<&v2::AntiPattern> = anti_patterns.iter().collect();
+    by_severity.sort_by_key(|anti_pattern| std::cmp::Reverse(anti_pattern.severity));
+    for anti_pattern in by_severity {
+        recommendations.push(format!(
+            "Line {} ({} severity): {}",
+            anti_pattern.span.start_line,
+            anti_pattern.severity.as_str(),
+            anti_pattern.message
+        ));
+    }
+    
+    // Collections that fit the observed operations better
+    for suggestion in &suggestions {
+        recommendations.push(suggestion.message.clone());
+    }
+    
+    // General advice, after the findings specific to this code
+    if code.lines().count() > 100 {
+        recommendations.push("Consider breaking this into smaller functions".to_string());
+    }
+    
+    if !code.contains("///") && !code.contains("//") {
+        recommendations.push("Add documentation comments".to_string());
+    }
+    
+    // Add educational recommendations based on detected algorithms
+    for alg in &detected_algorithms {
+        recommendations.push(format!("Learn more about {} at {}", alg.name, alg.wikipedia_link));
+    }
+    
+    // Add educational recommendations based on detected data structures
+    for ds in &detected_data_structures {
+        recommendations.push(format!("Learn more about {} at {}", ds.name, ds.wikipedia_link));
+    }
+    
+    AnalysisResult {
+        patterns,
+        algorithms,
+        detailed_algorithms: detected_algorithms,
+        data_structures,
+        detailed_data_structures: detected_data_structures,
+        collections,
+        suggestions,
+        anti_patterns,
+        complexity,
+        recommendations,
+        timed_out: budget.exceeded(),
+    }
+}
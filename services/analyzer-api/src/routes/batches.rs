@@ -0,0 +1,244 @@
+//! `/v2/analyze/batch` and `/v2/batches`: many files analyzed and stored as one submission
+
+use actix_web::http::header::CONTENT_TYPE;
+use actix_web::{web, HttpRequest, HttpResponse, Result};
+use futures_util::{stream, StreamExt};
+use serde::Deserialize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use analyzer_api::{auth, batch, limits, models, rate_limit};
+use analyzer_api::models::error_response::ErrorResponse;
+use analyzer_types::v2;
+
+use super::{bad_request, may_read, AppState};
+use super::analysis::{analysis_records, analyze, AnalysisResult};
+
+#[derive(Deserialize, IntoParams)]
+pub struct BatchQuery {
+    /// Label for the stored submission (archive uploads only)
+    name: Option<String>,
+    /// Source language (archive uploads only); defaults to `rust`
+    language: Option<String>,
+    /// Time budget per file in milliseconds (archive uploads only)
+    time_budget_ms: Option<u64>,
+}
+
+/// Raw archive bytes; only used to document the upload as binary
+#[derive(ToSchema)]
+#[schema(value_type = String, format = Binary)]
+#[allow(dead_code)]
+pub struct ArchiveUpload(Vec<u8>);
+
+#[utoipa::path(
+    post,
+    path = "/v2/analyze/batch",
+    tag = "analysis",
+    params(BatchQuery),
+    request_body(
+        description = "A JSON list of files, or a tar, tar.gz or zip archive of source files",
+        content(
+            (v2::BatchRequest = "application/json"),
+            (ArchiveUpload = "application/x-tar"),
+            (ArchiveUpload = "application/gzip"),
+            (ArchiveUpload = "application/zip")
+        )
+    ),
+    responses(
+        (status = 200, description = "Per-file results and the inventory of the whole batch", body = v2::BatchResponse),
+        (status = 400, description = "Empty or oversized batch, an empty or binary file, unreadable archive or unsupported language", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "Role may not analyze code", body = ErrorResponse),
+        (status = 413, description = "Request body exceeds the batch size limit, or a file exceeds the per-file limit", body = ErrorResponse),
+        (status = 415, description = "Unsupported content type", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded, or less daily quota left than the batch has files", body = ErrorResponse)
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn analyze_batch(
+    req: HttpRequest,
+    body: web::Bytes,
+    query: web::Query<BatchQuery>,
+    principal: auth::Principal,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let query = query.into_inner();
+    let limits = &data.limits;
+    let content_type = req
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("");
+    
+    let (request, skipped) = if content_type.starts_with("application/json") {
+        match serde_json::from_slice::<v2::BatchRequest>(&body) {
+            Ok(request) => (request, Vec::new()),
+            Err(e) => return Ok(bad_request("invalid_json", e.to_string())),
+        }
+    } else if let Some(kind) = batch::ArchiveKind::from_content_type(content_type) {
+        let language = query.language.unwrap_or_else(|| v2::DEFAULT_LANGUAGE.to_string());
+        match batch::extract_archive(kind, &body, &language, limits.max_batch_bytes) {
+            Ok(extracted) => (
+                v2::BatchRequest {
+                    name: query.name,
+                    files: extracted.files,
+                    language,
+                    time_budget_ms: query.time_budget_ms,
+                },
+                extracted.skipped,
+            ),
+            Err(e) => return Ok(bad_request(e.code(), e.to_string())),
+        }
+    } else {
+        return Ok(HttpResponse::UnsupportedMediaType().json(ErrorResponse::new(
+            "unsupported_media_type",
+            "Send application/json, application/x-tar, application/gzip or application/zip",
+        )));
+    };
+    
+    if let Err(e) = check_batch_request(&request, &data.limits) {
+        return Ok(batch_rejected(e));
+    }
+    rate_limit::charge(&req, request.files.len()).await?;
+    
+    Ok(HttpResponse::Ok().json(run_batch(request, skipped, principal.key_id, &data, |_| {}).await))
+}
+
+/// Reject batches that cannot be analyzed at all
+pub fn check_batch_request(request: &v2::BatchRequest, limits: &limits::AnalysisLimits) -> std::result::Result<(), ErrorResponse> {
+    if request.language != v2::DEFAULT_LANGUAGE {
+        return Err(ErrorResponse::new(
+            "unsupported_language",
+            format!("Language '{}' is not supported; use '{}'", request.language, v2::DEFAULT_LANGUAGE),
+        ));
+    }
+    batch::check_batch(&request.files, limits).map_err(|e| ErrorResponse::new(e.code(), e.to_string()))
+}
+
+/// A rejected batch, answered like an oversized or invalid single submission
+pub fn batch_rejected(e: ErrorResponse) -> HttpResponse {
+    if e.error == "file_too_large" {
+        HttpResponse::PayloadTooLarge().json(e)
+    } else {
+        HttpResponse::BadRequest().json(e)
+    }
+}
+
+/// Analyze the files of a checked batch concurrently and store it as one submission
+/// owned by `owner_key_id`. Files not started within the batch time budget are
+/// skipped. `on_progress` is called with the number of files finished so far.
+pub async fn run_batch(
+    request: v2::BatchRequest,
+    skipped: Vec<String>,
+    owner_key_id: Option<Uuid>,
+    data: &AppState,
+    on_progress: impl Fn(usize),
+) -> v2::BatchResponse {
+    let language = request.language.as_str();
+    let finished = AtomicUsize::new(0);
+    let deadline = std::time::Instant::now() + data.limits.batch_timeout;
+    
+    // `buffered` keeps results in request order
+    let outcomes: Vec<(v2::BatchFile, std::result::Result<AnalysisResult, ErrorResponse>)> =
+        stream::iter(request.files)
+            .map(|file| async {
+                // No file may run past the end of the batch budget
+                let left_ms = deadline.saturating_duration_since(std::time::Instant::now()).as_millis() as u64;
+                let outcome = if left_ms == 0 {
+                    Err(ErrorResponse::new("batch_timeout", "The batch ran out of time before this file was analyzed"))
+                } else {
+                    let budget_ms = request.time_budget_ms.map_or(left_ms, |ms| ms.min(left_ms));
+                    analyze(&file.code, language, Some(budget_ms), data)
+                        .await
+                        .map_err(|e| ErrorResponse::new("analysis_failed", e.to_string()))
+                };
+                on_progress(finished.fetch_add(1, Ordering::Relaxed) + 1);
+                (file, outcome)
+            })
+            .buffered(data.limits.batch_concurrency)
+            .collect()
+            .await;
+    
+    let files: Vec<v2::BatchFileResult> = outcomes
+        .iter()
+        .map(|(file, outcome)| match outcome {
+            Ok(result) => v2::BatchFileResult {
+                path: file.path.clone(),
+                result: Some(result.to_v2(language)),
+                error: None,
+            },
+            Err(error) => v2::BatchFileResult {
+                path: file.path.clone(),
+                result: None,
+                error: Some(error.clone()),
+            },
+        })
+        .collect();
+    let inventory = batch::build_inventory(&files, skipped);
+    
+    let new_batch = models::analysis_batch::NewAnalysisBatch {
+        name: request.name,
+        language: language.to_string(),
+        owner_key_id,
+        inventory: inventory.clone(),
+        files: outcomes
+            .into_iter()
+            .map(|(file, outcome)| match outcome {
+                Ok(result) => models::analysis_batch::NewBatchFile {
+                    analysis: Some(analysis_records(file.code, language, &result)),
+                    path: file.path,
+                    error: None,
+                },
+                Err(error) => models::analysis_batch::NewBatchFile {
+                    path: file.path,
+                    analysis: None,
+                    error: Some(error.error),
+                },
+            })
+            .collect(),
+    };
+    
+    let batch_id = match data.db.save_analysis_batch(new_batch).await {
+        Ok(id) => Some(id.to_string()),
+        Err(e) => {
+            eprintln!("Failed to save analysis batch: {}", e);
+            data.metrics.record_db_save_failure("analysis_batches");
+            None
+        }
+    };
+    
+    v2::BatchResponse {
+        batch_id,
+        files,
+        inventory,
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/batches/{id}",
+    tag = "analysis",
+    params(("id" = Uuid, Path, description = "Batch id")),
+    responses(
+        (status = 200, description = "The stored batch with the analysis id of each file", body = models::analysis_batch::AnalysisBatch),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "Role may not read history", body = ErrorResponse),
+        (status = 404, description = "No batch with that id submitted by this key", body = ErrorResponse)
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn get_batch(
+    path: web::Path<Uuid>,
+    principal: auth::Principal,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    match data.db.get_analysis_batch(path.into_inner()).await {
+        Ok(Some(batch)) if may_read(batch.owner_key_id, &principal) => Ok(HttpResponse::Ok().json(batch)),
+        Ok(_) => Ok(HttpResponse::NotFound().json(ErrorResponse::new("not_found", "No batch with that id"))),
+        Err(e) => {
+            eprintln!("Failed to load analysis batch: {}", e);
+            Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to load analysis batch")))
+        }
+    }
+}
@@ -0,0 +1,613 @@
+//! `/v2/catalog`: the reference catalogs and the review of changes to them
+
+use actix_web::http::header::LOCATION;
+use actix_web::{web, HttpResponse, Result};
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use analyzer_api::{algorithms, auth, catalog, data_structures, models, relations};
+use analyzer_api::models::catalog_change::{Actor, CatalogKind, ChangeAction, ChangeStatus};
+use analyzer_api::models::error_response::ErrorResponse;
+
+use super::{bad_request, AppState};
+
+#[utoipa::path(
+    get,
+    path = "/v2/catalog/algorithms",
+    tag = "catalog",
+    params(catalog::CatalogQuery),
+    responses(
+        (status = 200, description = "Matching catalog entries, best name matches first", body = [algorithms::AlgorithmInfo]),
+        (status = 400, description = "Unknown category", body = ErrorResponse)
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn list_catalog_algorithms(
+    query: web::Query<catalog::CatalogQuery>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let detector = data.algorithm_detector.load();
+    match catalog::search(detector.algorithms.values(), &query) {
+        Ok(entries) => Ok(HttpResponse::Ok().json(entries)),
+        Err(e) => Ok(bad_request(e.code(), e.to_string())),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/catalog/algorithms/{name}",
+    tag = "catalog",
+    params(("name" = String, Path, description = "Entry name or slug, e.g. `quick-sort`")),
+    responses(
+        (status = 200, description = "The catalog entry", body = algorithms::AlgorithmInfo),
+        (status = 404, description = "No algorithm with that name", body = ErrorResponse)
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn get_catalog_algorithm(
+    path: web::Path<String>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let detector = data.algorithm_detector.load();
+    match catalog::find(detector.algorithms.values(), &path) {
+        Some(entry) => Ok(HttpResponse::Ok().json(entry)),
+        None => Ok(HttpResponse::NotFound().json(ErrorResponse::new("not_found", "No algorithm with that name"))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/catalog/data-structures",
+    tag = "catalog",
+    params(catalog::CatalogQuery),
+    responses(
+        (status = 200, description = "Matching catalog entries, best name matches first", body = [data_structures::DataStructureInfo]),
+        (status = 400, description = "Unknown category", body = ErrorResponse)
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn list_catalog_data_structures(
+    query: web::Query<catalog::CatalogQuery>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let detector = data.data_structure_detector.load();
+    match catalog::search(detector.data_structures.values(), &query) {
+        Ok(entries) => Ok(HttpResponse::Ok().json(entries)),
+        Err(e) => Ok(bad_request(e.code(), e.to_string())),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/catalog/data-structures/{name}",
+    tag = "catalog",
+    params(("name" = String, Path, description = "Entry name or slug, e.g. `binary-heap`")),
+    responses(
+        (status = 200, description = "The catalog entry", body = data_structures::DataStructureInfo),
+        (status = 404, description = "No data structure with that name", body = ErrorResponse)
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn get_catalog_data_structure(
+    path: web::Path<String>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let detector = data.data_structure_detector.load();
+    match catalog::find(detector.data_structures.values(), &path) {
+        Some(entry) => Ok(HttpResponse::Ok().json(entry)),
+        None => Ok(HttpResponse::NotFound().json(ErrorResponse::new("not_found", "No data structure with that name"))),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/catalog/categories",
+    tag = "catalog",
+    responses((status = 200, description = "Categories of both catalogs with their entry counts", body = catalog::CatalogCategories)),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn list_catalog_categories(data: web::Data<AppState>) -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(catalog::CatalogCategories {
+        algorithms: catalog::categories(data.algorithm_detector.load().algorithms.values()),
+        data_structures: catalog::categories(data.data_structure_detector.load().data_structures.values()),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/catalog/graph",
+    tag = "catalog",
+    params(relations::GraphQuery),
+    responses(
+        (status = 200, description = "Both catalogs as nodes and their relations as edges", content(
+            (relations::CatalogGraph = "application/json"),
+            (String = "text/vnd.graphviz"),
+            (String = "text/vnd.mermaid")
+        )),
+        (status = 400, description = "Unknown format")
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn get_catalog_graph(
+    query: web::Query<relations::GraphQuery>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let graph = relations::CatalogGraph::build(&data.algorithm_detector.load(), &data.data_structure_detector.load());
+    
+    Ok(match query.format.unwrap_or_default() {
+        relations::GraphFormat::Json => HttpResponse::Ok().json(graph),
+        relations::GraphFormat::Dot => HttpResponse::Ok().content_type("text/vnd.graphviz; charset=utf-8").body(graph.to_dot()),
+        relations::GraphFormat::Mermaid => HttpResponse::Ok().content_type("text/vnd.mermaid; charset=utf-8").body(graph.to_mermaid()),
+    })
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct CatalogChangesQuery {
+    /// Only changes with this status, e.g. `pending`
+    status: Option<ChangeStatus>,
+    /// Number of changes to return, at most 100
+    limit: Option<u32>,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct CatalogAuditQuery {
+    /// Only entries of this catalog
+    kind: Option<CatalogKind>,
+    /// Only this entry, by its exact name
+    name: Option<String>,
+    /// Number of audit entries to return, at most 500
+    limit: Option<u32>,
+}
+
+/// The current entry of `kind` with this name or slug, as its canonical name and JSON
+pub fn current_catalog_entry(data: &AppState, kind: CatalogKind, name: &str) -> Option<(String, serde_json::Value)> {
+    fn entry<T: catalog::CatalogEntry + Serialize>(entry: &T) -> (String, serde_json::Value) {
+        (entry.name().to_string(), serde_json::to_value(entry).unwrap_or_default())
+    }
+    match kind {
+        CatalogKind::Algorithm => catalog::find(data.algorithm_detector.load().algorithms.values(), name).map(entry),
+        CatalogKind::DataStructure => catalog::find(data.data_structure_detector.load().data_structures.values(), name).map(entry),
+    }
+}
+
+/// Validate a proposed create or update against the current catalog and record it
+pub async fn propose_entry<T: catalog::CatalogEntry + Serialize>(
+    kind: CatalogKind,
+    path_name: Option<&str>,
+    entry: T,
+    principal: auth::Principal,
+    data: &AppState,
+) -> Result<HttpResponse> {
+    if let Err(e) = catalog::validate_entry(&entry) {
+        return Ok(bad_request(e.code(), e.to_string()));
+    }
+    for relation in entry.relations() {
+        if current_catalog_entry(data, relation.target_kind, &relation.target).is_none_or(|(name, _)| name != relation.target) {
+            return Ok(bad_request(
+                "unknown_relation",
+                format!("No {} named '{}' to relate to", relation.target_kind.as_str().replace('_', " "), relation.target),
+            ));
+        }
+    }
+    let current = current_catalog_entry(data, kind, path_name.unwrap_or(entry.name()));
+    
+    let (action, name, before) = match (path_name, current) {
+        (None, Some((existing, _))) => {
+            return Ok(HttpResponse::Conflict().json(ErrorResponse::new(
+                "entry_exists",
+                format!("'{}' is already in the catalog; propose an update instead", existing),
+            )))
+        }
+        (None, None) => (ChangeAction::Create, entry.name().to_string(), None),
+        (Some(_), None) => return Ok(HttpResponse::NotFound().json(ErrorResponse::new("not_found", "No catalog entry with that name"))),
+        (Some(_), Some((existing, _))) if existing != entry.name() => {
+            return Ok(bad_request(
+                "name_mismatch",
+                format!("The entry is named '{}'; renaming is a deletion plus a creation", existing),
+            ))
+        }
+        (Some(_), Some((existing, before))) => (ChangeAction::Update, existing, Some(before)),
+    };
+    
+    let after = serde_json::to_value(&entry).unwrap_or_default();
+    submit_catalog_change(kind, name, action, before, Some(after), principal, data).await
+}
+
+/// Record a proposed deletion of the entry with this name or slug
+pub async fn propose_deletion(kind: CatalogKind, name: &str, principal: auth::Principal, data: &AppState) -> Result<HttpResponse> {
+    match current_catalog_entry(data, kind, name) {
+        Some((name, before)) => submit_catalog_change(kind, name, ChangeAction::Delete, Some(before), None, principal, data).await,
+        None => Ok(HttpResponse::NotFound().json(ErrorResponse::new("not_found", "No catalog entry with that name"))),
+    }
+}
+
+pub async fn submit_catalog_change(
+    kind: CatalogKind,
+    name: String,
+    action: ChangeAction,
+    before: Option<serde_json::Value>,
+    entry: Option<serde_json::Value>,
+    principal: auth::Principal,
+    data: &AppState,
+) -> Result<HttpResponse> {
+    let new_change = models::catalog_change::NewCatalogChange {
+        kind,
+        name,
+        action,
+        diff: catalog::diff(before.as_ref(), entry.as_ref()),
+        entry,
+        author: actor(principal),
+    };
+    
+    match data.db.create_catalog_change(new_change).await {
+        Ok(change) => Ok(HttpResponse::Accepted()
+            .insert_header((LOCATION, format!("/v2/catalog/changes/{}", change.id)))
+            .json(change)),
+        Err(e) => {
+            eprintln!("Failed to save catalog change: {}", e);
+            data.metrics.record_db_save_failure("catalog_changes");
+            Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to save catalog change")))
+        }
+    }
+}
+
+pub fn actor(principal: auth::Principal) -> Actor {
+    Actor {
+        name: principal.name,
+        key_id: principal.key_id,
+    }
+}
+
+/// Rebuild the detectors from the stored overrides and Wikipedia imports.
+/// Rebuilds run one at a time, so the last one stored has read the latest
+/// overrides.
+pub async fn reload_catalog(data: &AppState) -> std::result::Result<(), sqlx::Error> {
+    let _reloading = data.catalog_reload.lock().await;
+    let overrides = data.db.get_catalog_overrides().await?;
+    let imported_algorithms = data.db.get_all_wikipedia_algorithms().await?;
+    let imported_data_structures = data.db.get_all_wikipedia_data_structures().await?;
+    let (algorithm_detector, data_structure_detector) =
+        catalog::build_detectors(&overrides, &imported_algorithms, &imported_data_structures);
+    data.algorithm_detector.store(algorithm_detector);
+    data.data_structure_detector.store(data_structure_detector);
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "/v2/catalog/algorithms",
+    tag = "catalog",
+    request_body = algorithms::AlgorithmInfo,
+    responses(
+        (status = 202, description = "The proposed addition, pending review", body = models::catalog_change::CatalogChange),
+        (status = 400, description = "Incomplete or malformed entry", body = ErrorResponse),
+        (status = 403, description = "Role may not change the catalog", body = ErrorResponse),
+        (status = 409, description = "An entry with that name exists", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn propose_algorithm(
+    item: web::Json<algorithms::AlgorithmInfo>,
+    principal: auth::Principal,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    propose_entry(CatalogKind::Algorithm, None, item.into_inner(), principal, &data).await
+}
+
+#[utoipa::path(
+    put,
+    path = "/v2/catalog/algorithms/{name}",
+    tag = "catalog",
+    params(("name" = String, Path, description = "Entry name or slug")),
+    request_body = algorithms::AlgorithmInfo,
+    responses(
+        (status = 202, description = "The proposed update, pending review", body = models::catalog_change::CatalogChange),
+        (status = 400, description = "Incomplete or malformed entry, or a different name", body = ErrorResponse),
+        (status = 403, description = "Role may not change the catalog", body = ErrorResponse),
+        (status = 404, description = "No algorithm with that name", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn propose_algorithm_update(
+    path: web::Path<String>,
+    item: web::Json<algorithms::AlgorithmInfo>,
+    principal: auth::Principal,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    propose_entry(CatalogKind::Algorithm, Some(&path), item.into_inner(), principal, &data).await
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v2/catalog/algorithms/{name}",
+    tag = "catalog",
+    params(("name" = String, Path, description = "Entry name or slug")),
+    responses(
+        (status = 202, description = "The proposed deletion, pending review", body = models::catalog_change::CatalogChange),
+        (status = 403, description = "Role may not change the catalog", body = ErrorResponse),
+        (status = 404, description = "No algorithm with that name", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn propose_algorithm_deletion(
+    path: web::Path<String>,
+    principal: auth::Principal,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    propose_deletion(CatalogKind::Algorithm, &path, principal, &data).await
+}
+
+#[utoipa::path(
+    post,
+    path = "/v2/catalog/data-structures",
+    tag = "catalog",
+    request_body = data_structures::DataStructureInfo,
+    responses(
+        (status = 202, description = "The proposed addition, pending review", body = models::catalog_change::CatalogChange),
+        (status = 400, description = "Incomplete or malformed entry", body = ErrorResponse),
+        (status = 403, description = "Role may not change the catalog", body = ErrorResponse),
+        (status = 409, description = "An entry with that name exists", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn propose_data_structure(
+    item: web::Json<data_structures::DataStructureInfo>,
+    principal: auth::Principal,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    propose_entry(CatalogKind::DataStructure, None, item.into_inner(), principal, &data).await
+}
+
+#[utoipa::path(
+    put,
+    path = "/v2/catalog/data-structures/{name}",
+    tag = "catalog",
+    params(("name" = String, Path, description = "Entry name or slug")),
+    request_body = data_structures::DataStructureInfo,
+    responses(
+        (status = 202, description = "The proposed update, pending review", body = models::catalog_change::CatalogChange),
+        (status = 400, description = "Incomplete or malformed entry, or a different name", body = ErrorResponse),
+        (status = 403, description = "Role may not change the catalog", body = ErrorResponse),
+        (status = 404, description = "No data structure with that name", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn propose_data_structure_update(
+    path: web::Path<String>,
+    item: web::Json<data_structures::DataStructureInfo>,
+    principal: auth::Principal,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    propose_entry(CatalogKind::DataStructure, Some(&path), item.into_inner(), principal, &data).await
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v2/catalog/data-structures/{name}",
+    tag = "catalog",
+    params(("name" = String, Path, description = "Entry name or slug")),
+    responses(
+        (status = 202, description = "The proposed deletion, pending review", body = models::catalog_change::CatalogChange),
+        (status = 403, description = "Role may not change the catalog", body = ErrorResponse),
+        (status = 404, description = "No data structure with that name", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn propose_data_structure_deletion(
+    path: web::Path<String>,
+    principal: auth::Principal,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    propose_deletion(CatalogKind::DataStructure, &path, principal, &data).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/catalog/changes",
+    tag = "catalog",
+    params(CatalogChangesQuery),
+    responses(
+        (status = 200, description = "Most recent changes first", body = [models::catalog_change::CatalogChange]),
+        (status = 403, description = "Role may not change the catalog", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn list_catalog_changes(
+    query: web::Query<CatalogChangesQuery>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let limit = query.limit.unwrap_or(20).min(100);
+    
+    match data.db.list_catalog_changes(query.status, limit).await {
+        Ok(changes) => Ok(HttpResponse::Ok().json(changes)),
+        Err(e) => {
+            eprintln!("Failed to list catalog changes: {}", e);
+            Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to list catalog changes")))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/catalog/changes/{id}",
+    tag = "catalog",
+    params(("id" = Uuid, Path, description = "Change id")),
+    responses(
+        (status = 200, description = "The change and its review status", body = models::catalog_change::CatalogChange),
+        (status = 404, description = "No change with that id", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn get_catalog_change(
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    match data.db.get_catalog_change(path.into_inner()).await {
+        Ok(Some(change)) => Ok(HttpResponse::Ok().json(change)),
+        Ok(None) => Ok(HttpResponse::NotFound().json(ErrorResponse::new("not_found", "No change with that id"))),
+        Err(e) => {
+            eprintln!("Failed to load catalog change: {}", e);
+            Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to load catalog change")))
+        }
+    }
+}
+
+/// Load a change for review; the error response if it cannot be reviewed by `reviewer`
+pub async fn reviewable_change(
+    id: Uuid,
+    reviewer: &Actor,
+    data: &AppState,
+) -> std::result::Result<models::catalog_change::CatalogChange, HttpResponse> {
+    let change = match data.db.get_catalog_change(id).await {
+        Ok(Some(change)) => change,
+        Ok(None) => return Err(HttpResponse::NotFound().json(ErrorResponse::new("not_found", "No change with that id"))),
+        Err(e) => {
+            eprintln!("Failed to load catalog change: {}", e);
+            return Err(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to load catalog change")));
+        }
+    };
+    
+    if change.status != ChangeStatus::Pending {
+        return Err(HttpResponse::Conflict().json(ErrorResponse::new(
+            "change_not_pending",
+            format!("The change was already {}", change.status.as_str()),
+        )));
+    }
+    if reviewer.key_id.is_some() && reviewer.key_id == change.author.key_id {
+        return Err(HttpResponse::Forbidden().json(ErrorResponse::new(
+            "self_review",
+            "Changes must be reviewed by someone other than their author",
+        )));
+    }
+    Ok(change)
+}
+
+#[utoipa::path(
+    post,
+    path = "/v2/catalog/changes/{id}/approve",
+    tag = "catalog",
+    params(("id" = Uuid, Path, description = "Change id")),
+    responses(
+        (status = 200, description = "The change was applied to the running detectors", body = models::catalog_change::CatalogChange),
+        (status = 403, description = "Role may not review changes, or the reviewer is the author", body = ErrorResponse),
+        (status = 404, description = "No change with that id", body = ErrorResponse),
+        (status = 409, description = "The change was already reviewed, or the catalog changed since it was proposed", body = ErrorResponse),
+        (status = 503, description = "The change was approved and stored, but the running detectors could not be rebuilt", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn approve_catalog_change(
+    path: web::Path<Uuid>,
+    principal: auth::Principal,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let reviewer = actor(principal);
+    let change = match reviewable_change(path.into_inner(), &reviewer, &data).await {
+        Ok(change) => change,
+        Err(response) => return Ok(response),
+    };
+    
+    // Other changes may have been approved since this one was proposed
+    let current = current_catalog_entry(&data, change.kind, &change.name).filter(|(name, _)| *name == change.name);
+    let conflict = match (change.action, &current) {
+        (ChangeAction::Create, Some(_)) => Some("The entry was added since this change was proposed"),
+        (ChangeAction::Update | ChangeAction::Delete, None) => Some("The entry was removed since this change was proposed"),
+        _ => None,
+    };
+    if let Some(message) = conflict {
+        return Ok(HttpResponse::Conflict().json(ErrorResponse::new("catalog_conflict", message)));
+    }
+    let diff = catalog::diff(current.as_ref().map(|(_, entry)| entry), change.entry.as_ref());
+    
+    match data.db.approve_catalog_change(&change, &reviewer, &diff).await {
+        Ok(true) => {}
+        Ok(false) => return Ok(HttpResponse::Conflict().json(ErrorResponse::new("change_not_pending", "The change was reviewed in the meantime"))),
+        Err(e) => {
+            eprintln!("Failed to approve catalog change: {}", e);
+            data.metrics.record_db_save_failure("catalog_overrides");
+            return Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to approve catalog change")));
+        }
+    }
+    
+    // The approval is stored either way; say so rather than report it as live
+    if let Err(e) = reload_catalog(&data).await {
+        eprintln!("Failed to reload the catalog after approving {}: {}", change.id, e);
+        return Ok(HttpResponse::ServiceUnavailable().json(ErrorResponse::new(
+            "catalog_reload_failed",
+            format!(
+                "Change {} was approved and stored, but the running detectors could not be rebuilt; it takes effect when the server restarts",
+                change.id
+            ),
+        )));
+    }
+    
+    match data.db.get_catalog_change(change.id).await {
+        Ok(Some(change)) => Ok(HttpResponse::Ok().json(change)),
+        _ => Ok(HttpResponse::Ok().json(change)),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/v2/catalog/changes/{id}/reject",
+    tag = "catalog",
+    params(("id" = Uuid, Path, description = "Change id")),
+    responses(
+        (status = 200, description = "The change was rejected", body = models::catalog_change::CatalogChange),
+        (status = 403, description = "Role may not review changes, or the reviewer is the author", body = ErrorResponse),
+        (status = 404, description = "No change with that id", body = ErrorResponse),
+        (status = 409, description = "The change was already reviewed", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn reject_catalog_change(
+    path: web::Path<Uuid>,
+    principal: auth::Principal,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let reviewer = actor(principal);
+    let change = match reviewable_change(path.into_inner(), &reviewer, &data).await {
+        Ok(change) => change,
+        Err(response) => return Ok(response),
+    };
+    
+    match data.db.reject_catalog_change(&change, &reviewer).await {
+        Ok(true) => match data.db.get_catalog_change(change.id).await {
+            Ok(Some(change)) => Ok(HttpResponse::Ok().json(change)),
+            _ => Ok(HttpResponse::Ok().json(change)),
+        },
+        Ok(false) => Ok(HttpResponse::Conflict().json(ErrorResponse::new("change_not_pending", "The change was reviewed in the meantime"))),
+        Err(e) => {
+            eprintln!("Failed to reject catalog change: {}", e);
+            data.metrics.record_db_save_failure("catalog_changes");
+            Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to reject catalog change")))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/catalog/audit",
+    tag = "catalog",
+    params(CatalogAuditQuery),
+    responses(
+        (status = 200, description = "Proposals and reviews, most recent first", body = [models::catalog_change::CatalogAuditEntry]),
+        (status = 403, description = "Role may not change the catalog", body = ErrorResponse)
+    ),
+    security(("api_key" = []), ("bearer" = [])),
+)]
+pub async fn get_catalog_audit(
+    query: web::Query<CatalogAuditQuery>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let limit = query.limit.unwrap_or(50).min(500);
+    
+    match data.db.get_catalog_audit(query.kind, query.name.as_deref(), limit).await {
+        Ok(entries) => Ok(HttpResponse::Ok().json(entries)),
+        Err(e) => {
+            eprintln!("Failed to load catalog audit log: {}", e);
+            Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to load catalog audit log")))
+        }
+    }
+}
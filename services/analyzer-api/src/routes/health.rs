@@ -0,0 +1,46 @@
+//! Liveness and readiness probes
+
+use actix_web::{web, HttpResponse, Result};
+
+use analyzer_api::health;
+
+use super::AppState;
+
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "health",
+    responses((status = 200, description = "The process is up", body = health::HealthReport))
+)]
+pub async fn health_live() -> Result<HttpResponse> {
+    Ok(HttpResponse::Ok().json(health::HealthReport::live()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "Database, catalogs and detectors are ready", body = health::HealthReport),
+        (status = 503, description = "At least one check failed", body = health::HealthReport)
+    )
+)]
+pub async fn health_ready(data: web::Data<AppState>) -> Result<HttpResponse> {
+    let mut checks = Vec::new();
+    
+    let ping = tokio::time::timeout(std::time::Duration::from_secs(2), data.db.ping()).await;
+    checks.push(match ping {
+        Ok(Ok(())) => health::CheckResult::pass("database"),
+        Ok(Err(e)) => health::CheckResult::fail("database", e.to_string()),
+        Err(_) => health::CheckResult::fail("database", "timed out after 2s"),
+    });
+    checks.push(health::check_catalogs(&data.algorithm_detector.load(), &data.data_structure_detector.load()));
+    checks.extend(data.self_test.iter().cloned());
+    
+    let report = health::HealthReport::ready(checks);
+    if report.is_ready() {
+        Ok(HttpResponse::Ok().json(report))
+    } else {
+        Ok(HttpResponse::ServiceUnavailable().json(report))
+    }
+}
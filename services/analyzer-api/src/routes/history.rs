@@ -0,0 +1,69 @@
+//! `/analyses`: stored analyses
+
+use actix_web::{web, HttpResponse, Result};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use analyzer_api::models;
+use analyzer_api::models::error_response::ErrorResponse;
+
+use super::AppState;
+
+#[derive(Deserialize, IntoParams)]
+pub struct HistoryQuery {
+    /// Number of analyses to return, at most 100
+    limit: Option<u32>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/analyses",
+    tag = "analysis",
+    params(HistoryQuery),
+    responses(
+        (status = 200, description = "Most recent analyses first", body = [models::analysis_result::AnalysisRecord]),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "Role may not read history", body = ErrorResponse)
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn list_analyses(
+    query: web::Query<HistoryQuery>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let limit = query.limit.unwrap_or(20).min(100);
+    
+    match data.db.get_recent_analysis_results(limit).await {
+        Ok(records) => Ok(HttpResponse::Ok().json(records)),
+        Err(e) => {
+            eprintln!("Failed to load analysis history: {}", e);
+            Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to load analysis history")))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/analyses/{id}",
+    tag = "analysis",
+    params(("id" = Uuid, Path, description = "Analysis id")),
+    responses(
+        (status = 200, description = "The stored analysis", body = models::analysis_result::AnalysisRecord),
+        (status = 404, description = "No analysis with that id", body = ErrorResponse)
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn get_analysis(
+    path: web::Path<Uuid>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    match data.db.get_analysis_result(path.into_inner()).await {
+        Ok(Some(record)) => Ok(HttpResponse::Ok().json(record)),
+        Ok(None) => Ok(HttpResponse::NotFound().json(ErrorResponse::new("not_found", "No analysis with that id"))),
+        Err(e) => {
+            eprintln!("Failed to load analysis: {}", e);
+            Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to load analysis")))
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! `/v2/jobs`: batches analyzed in the background
+
+use actix_web::http::header::LOCATION;
+use actix_web::{web, error, HttpRequest, HttpResponse, Result};
+use uuid::Uuid;
+
+use analyzer_api::{auth, jobs, rate_limit};
+use analyzer_api::models::error_response::ErrorResponse;
+use analyzer_types::v2;
+
+use super::{may_read, AppState};
+use super::batches::{batch_rejected, check_batch_request, run_batch};
+
+#[utoipa::path(
+    post,
+    path = "/v2/jobs",
+    tag = "jobs",
+    request_body = v2::BatchRequest,
+    responses(
+        (status = 202, description = "The job was queued; poll its status at the `Location` header", body = v2::Job),
+        (status = 400, description = "Empty or oversized batch, an empty or binary file, or an unsupported language", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "Role may not analyze code", body = ErrorResponse),
+        (status = 413, description = "Payload exceeds the batch size limit, or a file exceeds the per-file limit", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded, or less daily quota left than the batch has files", body = ErrorResponse)
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn create_job(
+    req: HttpRequest,
+    item: web::Json<v2::BatchRequest>,
+    principal: auth::Principal,
+    data: web::Data<AppState>,
+    queue: web::Data<jobs::JobQueue>,
+) -> Result<HttpResponse> {
+    let item = item.into_inner();
+    
+    if let Err(e) = check_batch_request(&item, &data.limits) {
+        return Ok(batch_rejected(e));
+    }
+    rate_limit::charge(&req, item.files.len()).await?;
+    
+    let request_json = serde_json::to_string(&item).map_err(error::ErrorInternalServerError)?;
+    match data.db.create_job(&request_json, item.files.len() as u32, principal.key_id).await {
+        Ok(job) => {
+            queue.enqueue(job.id);
+            Ok(HttpResponse::Accepted()
+                .insert_header((LOCATION, format!("/v2/jobs/{}", job.id)))
+                .json(v2::Job::from(job)))
+        }
+        Err(e) => {
+            eprintln!("Failed to create job: {}", e);
+            Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to create job")))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/jobs/{id}",
+    tag = "jobs",
+    params(("id" = Uuid, Path, description = "Job id")),
+    responses(
+        (status = 200, description = "Status and progress of the job", body = v2::Job),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 404, description = "No job with that id submitted by this key", body = ErrorResponse)
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn get_job(
+    path: web::Path<Uuid>,
+    principal: auth::Principal,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    match data.db.get_job(path.into_inner()).await {
+        Ok(Some(job)) if may_read(job.owner_key_id, &principal) => Ok(HttpResponse::Ok().json(v2::Job::from(job))),
+        Ok(_) => Ok(HttpResponse::NotFound().json(ErrorResponse::new("not_found", "No job with that id"))),
+        Err(e) => {
+            eprintln!("Failed to load job: {}", e);
+            Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to load job")))
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v2/jobs/{id}/result",
+    tag = "jobs",
+    params(("id" = Uuid, Path, description = "Job id")),
+    responses(
+        (status = 200, description = "Per-file results and the inventory, once the job is done", body = v2::BatchResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 404, description = "No job with that id submitted by this key", body = ErrorResponse),
+        (status = 409, description = "The job is still queued or running, or it failed", body = ErrorResponse)
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn get_job_result(
+    path: web::Path<Uuid>,
+    principal: auth::Principal,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let id = path.into_inner();
+    let job = match data.db.get_job(id).await {
+        Ok(Some(job)) if may_read(job.owner_key_id, &principal) => job,
+        Ok(_) => return Ok(HttpResponse::NotFound().json(ErrorResponse::new("not_found", "No job with that id"))),
+        Err(e) => {
+            eprintln!("Failed to load job: {}", e);
+            return Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to load job")));
+        }
+    };
+    
+    match job.status {
+        v2::JobStatus::Queued | v2::JobStatus::Running => Ok(HttpResponse::Conflict().json(ErrorResponse::new(
+            "job_pending",
+            format!("The job is {}; poll /v2/jobs/{} until it is done", job.status.as_str(), id),
+        ))),
+        v2::JobStatus::Failed => Ok(HttpResponse::Conflict().json(
+            job.error.unwrap_or_else(|| ErrorResponse::new("job_failed", "The job failed")),
+        )),
+        v2::JobStatus::Done => match data.db.get_job_result(id).await {
+            Ok(Some(result)) => Ok(HttpResponse::Ok().json(result)),
+            Ok(None) => Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "The job result is missing"))),
+            Err(e) => {
+                eprintln!("Failed to load job result: {}", e);
+                Ok(HttpResponse::InternalServerError().json(ErrorResponse::new("database_error", "Failed to load job result")))
+            }
+        },
+    }
+}
+
+/// Worker body: run one queued job and record its outcome
+pub async fn process_job(id: Uuid, data: web::Data<AppState>) {
+    let (request_json, owner_key_id) = match data.db.claim_job(id).await {
+        Ok(Some(claimed)) => claimed,
+        Ok(None) => return,
+        Err(e) => {
+            eprintln!("Failed to claim job {}: {}", id, e);
+            return;
+        }
+    };
+    
+    let outcome = match serde_json::from_str::<v2::BatchRequest>(&request_json) {
+        Ok(request) => match check_batch_request(&request, &data.limits) {
+            Ok(()) => {
+                let db = data.db.clone();
+                let on_progress = move |completed: usize| {
+                    let db = db.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = db.update_job_progress(id, completed as u32).await {
+                            eprintln!("Failed to update progress of job {}: {}", id, e);
+                        }
+                    });
+                };
+                let response = run_batch(request, Vec::new(), owner_key_id, &data, on_progress).await;
+                data.db.finish_job(id, &response).await
+            }
+            Err(error) => data.db.fail_job(id, &error).await,
+        },
+        Err(e) => data.db.fail_job(id, &ErrorResponse::new("invalid_job", e.to_string())).await,
+    };
+    
+    if let Err(e) = outcome {
+        eprintln!("Failed to record the outcome of job {}: {}", id, e);
+        data.metrics.record_db_save_failure("analysis_jobs");
+    }
+}
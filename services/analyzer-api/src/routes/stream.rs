@@ -0,0 +1,171 @@
+//! `/v2/analyze/stream`: results sent as server-sent events while the detectors run
+
+use actix_web::http::header::CACHE_CONTROL;
+use actix_web::{web, error, HttpResponse, Result};
+use futures_util::stream;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+use analyzer_api::{functions, limits};
+use analyzer_api::models::error_response::ErrorResponse;
+use analyzer_types::{sse, v2};
+
+use super::AppState;
+use super::analysis::{algorithm_finding, check_analyze_request, data_structure_finding, run_analysis};
+
+#[utoipa::path(
+    post,
+    path = "/v2/analyze/stream",
+    tag = "analysis",
+    request_body = v2::AnalyzeRequest,
+    responses(
+        (
+            status = 200,
+            description = "Server-Sent Events: a `finding` event per function and detector, each carrying a \
+                `v2.StreamedFinding`, then one `summary` event carrying the `v2.AnalysisResponse`. \
+                A failure after the stream has started ends it with an `error` event carrying an `ErrorResponse`.",
+            content_type = "text/event-stream",
+            body = v2::StreamedFinding
+        ),
+        (status = 400, description = "Empty, binary or malformed input, or an unsupported language", body = ErrorResponse),
+        (status = 401, description = "Missing or invalid API key", body = ErrorResponse),
+        (status = 403, description = "Role may not analyze code", body = ErrorResponse),
+        (status = 413, description = "Payload exceeds the size limit", body = ErrorResponse),
+        (status = 429, description = "Rate limit or daily quota exceeded", body = ErrorResponse)
+    ),
+    security((), ("api_key" = []), ("bearer" = [])),
+)]
+pub async fn analyze_stream(
+    item: web::Json<v2::AnalyzeRequest>,
+    data: web::Data<AppState>,
+) -> Result<HttpResponse> {
+    let item = item.into_inner();
+    
+    if let Err(e) = check_analyze_request(&item) {
+        return Ok(HttpResponse::BadRequest().json(e));
+    }
+    
+    // The analysis runs on its own task and hands over events as they are produced
+    let (sender, receiver) = mpsc::channel(16);
+    actix_web::rt::spawn(stream_analysis(item, data, sender));
+    let events = stream::unfold(receiver, |mut receiver| async move {
+        let event = receiver.recv().await?;
+        Some((Ok::<_, error::Error>(web::Bytes::from(event)), receiver))
+    });
+    
+    Ok(HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header((CACHE_CONTROL, "no-cache"))
+        .streaming(events))
+}
+
+/// Producer side of `analyze_stream`
+///
+/// Runs each detector over each function and sends its findings, then
+/// analyzes the file as a whole for the summary, which is stored like any
+/// other analysis. Findings only the whole-file pass turns up are sent
+/// without a function before the summary. Stops as soon as the client
+/// disconnects.
+pub async fn stream_analysis(item: v2::AnalyzeRequest, data: web::Data<AppState>, sender: mpsc::Sender<String>) {
+    let budget = Arc::new(data.limits.budget_for(item.time_budget_ms));
+    let mut streamed = HashSet::new();
+    
+    'functions: for function in functions::split_functions(&item.code) {
+        for detector in [v2::Detector::Algorithms, v2::Detector::DataStructures] {
+            if !budget.check() {
+                break 'functions;
+            }
+            let code = item.code[function.span.clone()].to_string();
+            let findings = match detect(detector, code, budget.clone(), &data).await {
+                Ok(findings) => findings,
+                Err(e) => {
+                    let error = ErrorResponse::new("analysis_failed", e.to_string());
+                    let _ = send_event(&sender, sse::ERROR, &error).await;
+                    return;
+                }
+            };
+            for finding in findings {
+                streamed.insert((detector, finding.name.clone()));
+                let event = v2::StreamedFinding {
+                    detector,
+                    function: Some(function.name.clone()),
+                    line: Some(function.line),
+                    finding,
+                };
+                if !send_event(&sender, sse::FINDING, &event).await {
+                    return;
+                }
+            }
+        }
+    }
+    
+    // The whole-file pass gets what is left of the budget
+    let remaining_ms = budget.remaining().map(|left| left.as_millis() as u64);
+    let language = item.language;
+    let summary = match run_analysis(item.code, &language, remaining_ms, &data).await {
+        Ok(result) => result.to_v2(&language),
+        Err(e) => {
+            let error = ErrorResponse::new("analysis_failed", e.to_string());
+            let _ = send_event(&sender, sse::ERROR, &error).await;
+            return;
+        }
+    };
+    
+    for (detector, findings) in [
+        (v2::Detector::Algorithms, &summary.algorithms),
+        (v2::Detector::DataStructures, &summary.data_structures),
+    ] {
+        for finding in findings {
+            if streamed.contains(&(detector, finding.name.clone())) {
+                continue;
+            }
+            let event = v2::StreamedFinding {
+                detector,
+                function: None,
+                line: None,
+                finding: finding.clone(),
+            };
+            if !send_event(&sender, sse::FINDING, &event).await {
+                return;
+            }
+        }
+    }
+    let _ = send_event(&sender, sse::SUMMARY, &summary).await;
+}
+
+/// Run one detector over a piece of code on the blocking pool
+pub async fn detect(
+    detector: v2::Detector,
+    code: String,
+    budget: Arc<limits::AnalysisBudget>,
+    data: &AppState,
+) -> std::result::Result<Vec<v2::Finding>, error::BlockingError> {
+    let algorithm_detector = data.algorithm_detector.load();
+    let data_structure_detector = data.data_structure_detector.load();
+    web::block(move || match detector {
+        v2::Detector::Algorithms => algorithm_detector
+            .detect_algorithms_within(&code, &budget)
+            .iter()
+            .map(algorithm_finding)
+            .collect(),
+        v2::Detector::DataStructures => data_structure_detector
+            .detect_data_structures_within(&code, &budget)
+            .iter()
+            .map(data_structure_finding)
+            .collect(),
+    })
+    .await
+}
+
+/// Send one event; `false` once the client has gone away
+pub async fn send_event(sender: &mpsc::Sender<String>, event: &str, data: &impl Serialize) -> bool {
+    match serde_json::to_string(data) {
+        Ok(json) => sender.send(sse::encode(event, &json)).await.is_ok(),
+        Err(e) => {
+            eprintln!("Failed to serialize {} event: {}", event, e);
+            false
+        }
+    }
+}
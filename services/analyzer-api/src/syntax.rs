@@ -0,0 +1,353 @@
+//! Parsing submitted code with `syn` without overflowing the stack
+//!
+//! syn parses, visits and drops syntax trees by recursion, so a couple of
+//! kilobytes of nested brackets, prefix operators, closures or generic
+//! arguments overflow a 2 MiB thread stack. That aborts the process; it is
+//! not a panic. [`nesting`] estimates how deep syn would recurse from the
+//! tokens alone, which proc-macro2 lexes without recursing, and every parse
+//! of submitted code goes through this module, which refuses code that
+//! nests deeper than [`MAX_NESTING`].
+
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+use syn::parse::Parse;
+
+use crate::functions::split_functions;
+
+/// Deepest nesting parsed: far above hand-written code, far below what
+/// overflows a 2 MiB stack
+pub const MAX_NESTING: usize = 128;
+
+/// Operators syn parses by recursing into the rest of the expression or
+/// type: assignments and return types
+const RIGHT_ASSOCIATIVE: [&str; 12] = ["=", "+=", "-=", "*=", "/=", "%=", "^=", "&=", "|=", "<<=", ">>=", "->"];
+
+/// Keywords followed by an expression syn parses by recursing, `else`
+/// because `else if` chains nest
+const RECURSIVE_KEYWORDS: [&str; 9] = ["return", "break", "yield", "move", "if", "else", "match", "while", "for"];
+
+/// Prefix operators, which nest when they follow one another, as in `- -x`
+/// or `&&&u8`. After an operand they are binary, and syn loops over those.
+const PREFIX_OPERATORS: [&str; 5] = ["-", "!", "&", "&&", "*"];
+
+/// Keywords that continue a run of prefix operators, as in `&mut &mut T`
+const TYPE_KEYWORDS: [&str; 5] = ["mut", "const", "dyn", "impl", "ref"];
+
+/// Operators of more than one character, longest first, to split runs of
+/// joint punctuation such as `=-` into operators
+const COMPOUND_OPERATORS: [&str; 24] = [
+    "<<=", ">>=", "...", "..=", "::", "->", "=>", "==", "!=", "<=", ">=", "&&", "||", "+=", "-=", "*=", "/=", "%=",
+    "^=", "&=", "|=", "<<", ">>", "..",
+];
+
+/// Where [`nesting`] is within one bracketed group
+struct Frame {
+    tokens: proc_macro2::token_stream::IntoIter,
+    /// Nesting of the enclosing group
+    base: usize,
+    /// Right-associative operators, recursive keywords and closures since
+    /// the start of the statement or list item
+    operators: usize,
+    /// `<` not yet closed by `>` since the start of the statement; commas
+    /// do not end generic arguments
+    angles: usize,
+    /// Prefix operators since the last operand
+    prefix: usize,
+    /// Punctuation not yet split into operators
+    punct: String,
+    /// Whether the last token ended an operand, so `-` is binary
+    operand: bool,
+    /// Between the `|`s of closure parameters, where commas separate parameters
+    parameters: bool,
+    /// After a block, which ends the statement unless an operator or `else` follows
+    after_block: bool,
+    /// The last token was `'`, so an identifier is a lifetime or label
+    lifetime: bool,
+    /// The last token was `#`, so `!` starts an inner attribute
+    after_hash: bool,
+}
+
+impl Frame {
+    fn new(tokens: TokenStream, base: usize) -> Self {
+        Frame {
+            tokens: tokens.into_iter(),
+            base,
+            operators: 0,
+            angles: 0,
+            prefix: 0,
+            punct: String::new(),
+            operand: false,
+            parameters: false,
+            after_block: false,
+            lifetime: false,
+            after_hash: false,
+        }
+    }
+
+    fn depth(&self) -> usize {
+        self.base + self.operators + self.angles + self.prefix
+    }
+
+    fn end_statement(&mut self) {
+        self.operators = 0;
+        self.angles = 0;
+        self.prefix = 0;
+        self.parameters = false;
+    }
+
+    /// Count a run of joint punctuation, operator by operator
+    fn operators(&mut self) {
+        let punct = std::mem::take(&mut self.punct);
+        let mut rest = punct.as_str();
+        while let Some(first) = rest.chars().next() {
+            let len = COMPOUND_OPERATORS
+                .iter()
+                .find(|operator| rest.starts_with(*operator))
+                .map_or(first.len_utf8(), |operator| operator.len());
+            let (operator, tail) = rest.split_at(len);
+            rest = tail;
+
+            match operator {
+                ";" => self.end_statement(),
+                "," if !self.parameters => {
+                    self.operators = 0;
+                    self.prefix = 0;
+                }
+                "|" if self.parameters => self.parameters = false,
+                // A closure, not a binary or pattern `|`
+                "|" | "||" if !self.operand => {
+                    self.operators += 1;
+                    self.parameters = operator == "|";
+                }
+                "<" => self.angles += 1,
+                "<<" => self.angles += 2,
+                ">" => self.angles = self.angles.saturating_sub(1),
+                ">>" => self.angles = self.angles.saturating_sub(2),
+                "?" => {
+                    self.operand = true;
+                    continue;
+                }
+                _ if RIGHT_ASSOCIATIVE.contains(&operator) => self.operators += 1,
+                _ if PREFIX_OPERATORS.contains(&operator) && !self.operand => self.prefix += operator.len(),
+                _ => {}
+            }
+            self.operand = false;
+        }
+    }
+
+    fn operand(&mut self) {
+        self.operand = true;
+        self.prefix = 0;
+    }
+}
+
+/// How deep syn would recurse to parse `code`, overestimated: one level
+/// per enclosing bracket, plus the prefix operators, open generic
+/// arguments, closures, right-associative operators and recursive keywords
+/// in front of it in its statement. `None` when the code does not lex.
+pub fn nesting(code: &str) -> Option<usize> {
+    let tokens: TokenStream = code.parse().ok()?;
+    let mut frames = vec![Frame::new(tokens, 0)];
+    let mut deepest = 0;
+
+    while let Some(frame) = frames.last_mut() {
+        let token = frame.tokens.next();
+        if !matches!(token, Some(TokenTree::Punct(_))) {
+            frame.operators();
+        }
+        if frame.after_block {
+            frame.after_block = false;
+            let continues = match &token {
+                Some(TokenTree::Punct(punct)) => punct.as_char() != '#',
+                Some(TokenTree::Ident(ident)) => ident == "else",
+                _ => false,
+            };
+            if !continues {
+                frame.end_statement();
+            }
+        }
+
+        let group = match token {
+            None => {
+                frames.pop();
+                continue;
+            }
+            Some(TokenTree::Punct(punct)) => {
+                let c = punct.as_char();
+                // `#` and `#!` start attributes, doc comments included, whose
+                // contents syn keeps as tokens
+                let attribute = c == '#' || (c == '!' && frame.after_hash);
+                frame.after_hash = c == '#';
+                if c == '\'' {
+                    frame.lifetime = true;
+                } else if !attribute {
+                    frame.punct.push(c);
+                    if punct.spacing() == Spacing::Alone {
+                        frame.operators();
+                    }
+                }
+                None
+            }
+            Some(TokenTree::Ident(ident)) => {
+                frame.after_hash = false;
+                if std::mem::take(&mut frame.lifetime) {
+                    // `'a`, as in `&'a &'a T`, continues the prefix operators
+                } else if RECURSIVE_KEYWORDS.iter().any(|keyword| ident == keyword) {
+                    frame.operators += 1;
+                    frame.operand = false;
+                } else if TYPE_KEYWORDS.iter().any(|keyword| ident == keyword) {
+                    frame.operand = false;
+                } else {
+                    frame.operand();
+                }
+                None
+            }
+            Some(TokenTree::Literal(_)) => {
+                frame.after_hash = false;
+                frame.operand();
+                None
+            }
+            Some(TokenTree::Group(group)) => {
+                frame.after_hash = false;
+                Some(group)
+            }
+        };
+        deepest = deepest.max(frame.depth());
+
+        if let Some(group) = group {
+            let base = frame.depth() + 1;
+            deepest = deepest.max(base);
+            frame.operand();
+            frame.after_block = group.delimiter() == Delimiter::Brace;
+            frames.push(Frame::new(group.stream(), base));
+        }
+    }
+    Some(deepest)
+}
+
+/// Whether `code` is shallow enough to hand to syn
+pub fn within_limit(code: &str) -> bool {
+    nesting(code).is_some_and(|depth| depth <= MAX_NESTING)
+}
+
+/// `code` parsed as a `T`, unless it nests too deep or does not parse
+pub fn parse<T: Parse>(code: &str) -> Option<T> {
+    if !within_limit(code) {
+        return None;
+    }
+    syn::parse_str(code).ok()
+}
+
+/// Submitted code, parsed once for the detectors that walk its syntax tree.
+/// Code that is not a whole file is retried as a block of statements, then
+/// function by function, so snippets are analyzed too.
+pub enum Syntax {
+    File(syn::File),
+    /// The code wrapped in braces, so offsets in it are one byte ahead
+    Block(syn::Block),
+    /// The functions that parse on their own, with their offset in the code
+    Functions(Vec<(usize, syn::ItemFn)>),
+}
+
+impl Syntax {
+    pub fn parse(code: &str) -> Self {
+        // Wrapping the code in a block nests it one level deeper
+        if nesting(code).is_some_and(|depth| depth < MAX_NESTING) {
+            if let Ok(file) = syn::parse_file(code) {
+                return Syntax::File(file);
+            }
+            if let Ok(block) = syn::parse_str::<syn::Block>(&format!("{{{}\n}}", code)) {
+                return Syntax::Block(block);
+            }
+        }
+
+        let functions = split_functions(code)
+            .into_iter()
+            .filter_map(|function| Some((function.span.start, parse::<syn::ItemFn>(&code[function.span])?)))
+            .collect();
+        Syntax::Functions(functions)
+    }
+}
+
+/// The spans of the tree are only valid while it is alive; dropping it
+/// clears the thread's source map, which would otherwise keep every
+/// analyzed file. Nothing else on the thread may hold spans meanwhile.
+impl Drop for Syntax {
+    fn drop(&mut self) {
+        proc_macro2::extra::invalidate_current_thread_spans();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nesting_counts_brackets_and_recursive_operators() {
+        assert_eq!(nesting("fn f() { let x = 1; }"), Some(2));
+        assert_eq!(nesting("let x = -(!a);"), Some(4));
+        assert_eq!(nesting("a < b; c == d, e.f()"), Some(1));
+        assert_eq!(nesting("fn f<'a>(x: &'a u8) {}"), Some(2));
+        assert_eq!(nesting("let s = \"((((\";"), Some(1));
+        assert_eq!(nesting("fn f() {"), None);
+    }
+
+    #[test]
+    fn test_nesting_does_not_count_operators_side_by_side() {
+        let alternatives = (0..500).map(|n| format!("-{}", n)).collect::<Vec<_>>().join(" | ");
+        assert_eq!(nesting(&format!("match x {{ {} => 1, _ => 0 }}", alternatives)), Some(3));
+        assert_eq!(nesting(&"let x = a - -b;\n".repeat(500)), Some(2));
+        assert_eq!(nesting(&"/// Doc!\nfn f() -> u8 { if a { 1 } else { 2 } }\n".repeat(500)), Some(5));
+    }
+
+    fn deep_inputs(depth: usize) -> Vec<String> {
+        vec![
+            format!("fn f() {{ let x = {}1{}; }}", "(".repeat(depth), ")".repeat(depth)),
+            format!("fn f() {{ let x = {}1; }}", "-".repeat(depth)),
+            format!("fn f() {{ let x = {}1; }}", "|| ".repeat(depth)),
+            format!("fn f() {{ let x = {}1; }}", "|a, b| ".repeat(depth)),
+            format!("fn f() {{ {}1; }}", "return ".repeat(depth)),
+            format!("fn f() {{ {}1; }}", "a = ".repeat(depth)),
+            format!("fn f() {{ let x = a{}; }}", " = {}".repeat(depth)),
+            format!("fn f() {{ {}{{}} }}", "if a {} else ".repeat(depth)),
+            format!("type T = {}u8{};", "Vec<".repeat(depth), ">".repeat(depth)),
+            format!("type T = {}u8{};", "HashMap<u8, ".repeat(depth), ">".repeat(depth)),
+            format!("type T = {}u8;", "&".repeat(depth)),
+            format!("type T = {}u8;", "&'a mut ".repeat(depth)),
+            format!("type T = {}u8;", "fn() -> ".repeat(depth)),
+        ]
+    }
+
+    /// Each of these aborts the process when syn recurses into it on a
+    /// 2 MiB stack; the test thread has one
+    #[test]
+    fn test_deeply_nested_code_is_not_parsed() {
+        for input in &deep_inputs(2_000) {
+            assert!(!within_limit(input), "{}", &input[..40]);
+            assert!(parse::<syn::File>(input).is_none());
+            assert!(matches!(&Syntax::parse(input), Syntax::Functions(functions) if functions.is_empty()));
+        }
+    }
+
+    #[test]
+    fn test_code_at_the_limit_is_parsed() {
+        for input in &deep_inputs(MAX_NESTING / 2 - 4) {
+            assert!(matches!(&Syntax::parse(input), Syntax::File(_)), "{}", &input[..40]);
+        }
+    }
+
+    #[test]
+    fn test_deep_functions_are_skipped_and_the_rest_parsed() {
+        let code = format!(
+            "fn shallow() {{ let v = vec![1]; }}\nfn deep() {{ let x = {}1{}; }}\nfn broken( {{",
+            "[".repeat(500),
+            "]".repeat(500)
+        );
+        match &Syntax::parse(&code) {
+            Syntax::Functions(functions) => {
+                let names: Vec<String> = functions.iter().map(|(_, item)| item.sig.ident.to_string()).collect();
+                assert_eq!(names, ["shallow"]);
+            }
+            _ => panic!("the code does not parse as a whole"),
+        }
+    }
+}
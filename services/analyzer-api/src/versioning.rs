@@ -0,0 +1,115 @@
+//! API versions and deprecation signalling
+//!
+//! Routes live under `/v1` and `/v2`. The original unversioned routes are
+//! kept as aliases of `/v1` until their sunset date. Responses from an
+//! older version carry a `Deprecation` header, a `Link` to the same route
+//! in the successor version and, once a removal date is set, a `Sunset`
+//! header.
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::error::InternalError;
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue, LINK};
+use actix_web::middleware::Next;
+use actix_web::Error;
+
+/// How an older set of routes points at its replacement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deprecation {
+    /// Path prefix of the deprecated routes; empty for the unversioned ones
+    pub prefix: &'static str,
+    /// Path prefix of the version replacing them
+    pub successor: &'static str,
+    /// HTTP date after which the routes may be removed
+    pub sunset: Option<&'static str>,
+}
+
+/// The unversioned routes, superseded by `/v1`
+pub const UNVERSIONED: Deprecation = Deprecation {
+    prefix: "",
+    successor: "/v1",
+    sunset: Some("Sat, 01 May 2027 00:00:00 GMT"),
+};
+
+/// `/v1`, frozen and superseded by `/v2`; no removal date yet
+pub const V1: Deprecation = Deprecation {
+    prefix: "/v1",
+    successor: "/v2",
+    sunset: None,
+};
+
+impl Deprecation {
+    /// The same route in the successor version
+    pub fn successor_path(&self, path: &str) -> String {
+        format!("{}{}", self.successor, path.strip_prefix(self.prefix).unwrap_or(path))
+    }
+
+    fn apply(&self, headers: &mut HeaderMap, successor: &str) {
+        headers.insert(HeaderName::from_static("deprecation"), HeaderValue::from_static("true"));
+        if let Some(sunset) = self.sunset {
+            headers.insert(HeaderName::from_static("sunset"), HeaderValue::from_static(sunset));
+        }
+        if let Ok(link) = HeaderValue::from_str(&format!("<{}>; rel=\"successor-version\"", successor)) {
+            headers.insert(LINK, link);
+        }
+    }
+}
+
+/// Middleware body: add deprecation headers to every response of a scope,
+/// including errors raised by inner middleware such as auth
+pub async fn deprecated(
+    policy: &'static Deprecation,
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let successor = policy.successor_path(req.path());
+
+    match next.call(req).await {
+        Ok(mut res) => {
+            policy.apply(res.headers_mut(), &successor);
+            Ok(res)
+        }
+        Err(e) => {
+            let mut response = e.error_response();
+            policy.apply(response.headers_mut(), &successor);
+            Err(InternalError::from_response(e, response).into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::middleware::from_fn;
+    use actix_web::{test as actix_test, web, App, HttpResponse};
+
+    #[test]
+    fn test_successor_path() {
+        assert_eq!(UNVERSIONED.successor_path("/analyze"), "/v1/analyze");
+        assert_eq!(V1.successor_path("/v1/analyses/42"), "/v2/analyses/42");
+    }
+
+    #[actix_web::test]
+    async fn test_deprecated_scope_sets_headers() {
+        let app = actix_test::init_service(
+            App::new()
+                .service(
+                    web::scope("/v1")
+                        .wrap(from_fn(|req, next| deprecated(&V1, req, next)))
+                        .route("/analyze", web::post().to(HttpResponse::Ok)),
+                )
+                .service(web::scope("/v2").route("/analyze", web::post().to(HttpResponse::Ok))),
+        )
+        .await;
+
+        let req = actix_test::TestRequest::post().uri("/v1/analyze").to_request();
+        let res = actix_test::call_service(&app, req).await;
+        assert_eq!(res.headers().get("deprecation").unwrap(), "true");
+        assert_eq!(res.headers().get(LINK).unwrap(), "</v2/analyze>; rel=\"successor-version\"");
+        assert!(res.headers().get("sunset").is_none());
+
+        let req = actix_test::TestRequest::post().uri("/v2/analyze").to_request();
+        let res = actix_test::call_service(&app, req).await;
+        assert!(res.headers().get("deprecation").is_none());
+    }
+}
@@ -1,179 +1,297 @@
-//! Wikipedia scraper for algorithm information
+//! Wikipedia scraper for algorithm and data structure information
+//!
+//! The List of algorithms and List of data structures pages give titles,
+//! links and one-line summaries; each listed article is then enriched
+//! through the MediaWiki API with its page summary, the complexity fields
+//! of its infobox and its categories.
 
-use reqwest::Client;
-use scraper::{Html, Selector};
-use crate::algorithms::{AlgorithmCategory, AlgorithmInfo};
+pub mod api;
+pub mod infobox;
+pub mod list;
+pub mod sync;
+
+use crate::algorithms::{AlgorithmCategory, AlgorithmDetector, AlgorithmInfo};
+use crate::catalog;
+use crate::complexity::Complexity;
+use crate::data_structures::{DataStructureCategory, DataStructureDetector, DataStructureInfo};
 use crate::db::Database;
-use crate::models::wikipedia_algorithm::WikipediaAlgorithm;
+use crate::models::wikipedia_algorithm::{WikipediaAlgorithm, WikipediaDataStructure, WikipediaList, WikipediaSyncPlan, WikipediaSyncReport};
+use api::{page_title, MediaWikiClient, PageSummary, WikipediaError};
+use list::{ListedArticle, LIST_OF_ALGORITHMS_TITLE, LIST_OF_DATA_STRUCTURES_TITLE};
 
 pub struct WikipediaScraper {
-    client: Client,
+    api: MediaWikiClient,
     pub db: Database,
 }
 
 impl WikipediaScraper {
+    /// Scraper using the API settings from the environment, see [`api::MediaWikiSettings::from_env`]
     pub fn new(db: Database) -> Self {
-        Self {
-            client: Client::new(),
-            db,
-        }
+        Self::with_client(db, MediaWikiClient::from_env())
+    }
+
+    pub fn with_client(db: Database, api: MediaWikiClient) -> Self {
+        Self { api, db }
     }
 
-    /// Fetch algorithm categories from Wikipedia
+    /// Fetch and parse the List of algorithms page
+    pub async fn fetch_algorithm_list(&self) -> Result<Vec<ListedArticle>, Box<dyn std::error::Error>> {
+        let html = self.api.page_html(LIST_OF_ALGORITHMS_TITLE).await?;
+        Ok(list::parse_algorithm_list(&html))
+    }
+
+    /// Fetch the top-level sections of the List of algorithms page
     pub async fn fetch_algorithm_categories(&self) -> Result<Vec<String>, Box<dyn std::error::Error>> {
-        let url = "https://en.wikipedia.org/wiki/List_of_algorithms";
-        let response = self.client.get(url).send().await?;
-        let body = response.text().await?;
-        let document = Html::parse_document(&body);
-
-        let category_selector = Selector::parse("h2 span.mw-headline").unwrap();
-        let mut categories = Vec::new();
-
-        for element in document.select(&category_selector) {
-            let category = element.text().collect::<String>();
-            // Filter out non-algorithm categories
-            if !category.contains("See also") && !category.contains("References") && !category.contains("External links") {
-                categories.push(category);
+        let html = self.api.page_html(LIST_OF_ALGORITHMS_TITLE).await?;
+        Ok(list::parse_sections(&html))
+    }
+
+    /// Fetch the algorithms listed under one top-level section of the page
+    pub async fn fetch_algorithms_from_category(&self, category: &str) -> Result<Vec<WikipediaAlgorithm>, Box<dyn std::error::Error>> {
+        Ok(self
+            .fetch_algorithm_list()
+            .await?
+            .into_iter()
+            .filter(|listed| listed.section.first().map(String::as_str) == Some(category))
+            .map(Self::to_wikipedia_algorithm)
+            .collect())
+    }
+
+    /// A listed algorithm in the form stored in the database; the list
+    /// gives no complexity, so none is made up
+    pub fn to_wikipedia_algorithm(listed: ListedArticle) -> WikipediaAlgorithm {
+        WikipediaAlgorithm {
+            category: listed.algorithm_category().to_string(),
+            title: listed.title,
+            url: listed.url,
+            description: listed.summary,
+            complexity: None,
+            revision_id: None,
+        }
+    }
+
+    /// A listed algorithm with its article's summary, infobox complexity
+    /// and categories. The lead paragraph replaces the list's one-liner,
+    /// and the categories decide when the list's headings did not.
+    pub async fn enrich(&self, listed: ListedArticle) -> Result<WikipediaAlgorithm, WikipediaError> {
+        let title = page_title(&listed.url).unwrap_or_else(|| listed.title.clone());
+        let summary = self.api.page_summary(&title).await?;
+        let complexity = self.api.infobox_complexity(&title).await?;
+        let categories = self.api.page_categories(&title).await?;
+
+        let mut algorithm = Self::to_wikipedia_algorithm(listed);
+        apply_summary(&mut algorithm, summary);
+        algorithm.complexity = complexity.and_then(|complexity| complexity.summary());
+        if algorithm.category == AlgorithmCategory::Other.to_string() {
+            if let Some(category) = categories
+                .iter()
+                .map(|category| list::category_for_section(std::slice::from_ref(category)))
+                .find(|category| *category != AlgorithmCategory::Other)
+            {
+                algorithm.category = category.to_string();
             }
         }
+        Ok(algorithm)
+    }
 
-        Ok(categories)
+    /// Fetch and parse the List of data structures page
+    pub async fn fetch_data_structure_list(&self) -> Result<Vec<ListedArticle>, Box<dyn std::error::Error>> {
+        let html = self.api.page_html(LIST_OF_DATA_STRUCTURES_TITLE).await?;
+        Ok(list::parse_data_structure_list(&html))
     }
 
-    /// Fetch algorithms from a specific Wikipedia category page
-    pub async fn fetch_algorithms_from_category(&self, category: &str) -> Result<Vec<WikipediaAlgorithm>, Box<dyn std::error::Error>> {
-        // This is a simplified implementation - in a real-world scenario, you would parse the actual Wikipedia page
-        // For now, we'll return some example algorithms
-        let mut algorithms = Vec::new();
-
-        match category {
-            "Sorting algorithms" => {
-                algorithms.push(WikipediaAlgorithm {
-                    title: "Merge Sort".to_string(),
-                    url: "https://en.wikipedia.org/wiki/Merge_sort".to_string(),
-                    category: "Sorting".to_string(),
-                    description: Some("An efficient, general-purpose, comparison-based sorting algorithm.".to_string()),
-                    complexity: Some("O(n log n)".to_string()),
-                });
-                algorithms.push(WikipediaAlgorithm {
-                    title: "Quick Sort".to_string(),
-                    url: "https://en.wikipedia.org/wiki/Quicksort".to_string(),
-                    category: "Sorting".to_string(),
-                    description: Some("An efficient sorting algorithm that uses a divide-and-conquer approach.".to_string()),
-                    complexity: Some("O(n log n)".to_string()),
-                });
-            },
-            "Search algorithms" => {
-                algorithms.push(WikipediaAlgorithm {
-                    title: "Binary Search".to_string(),
-                    url: "https://en.wikipedia.org/wiki/Binary_search_algorithm".to_string(),
-                    category: "Searching".to_string(),
-                    description: Some("A search algorithm that finds the position of a target value within a sorted array.".to_string()),
-                    complexity: Some("O(log n)".to_string()),
-                });
-            },
-            "Graph algorithms" => {
-                algorithms.push(WikipediaAlgorithm {
-                    title: "Dijkstra's Algorithm".to_string(),
-                    url: "https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm".to_string(),
-                    category: "Graph".to_string(),
-                    description: Some("An algorithm for finding the shortest paths between nodes in a graph.".to_string()),
-                    complexity: Some("O((V + E) log V)".to_string()),
-                });
-            },
-            _ => {
-                // Default case - add some generic algorithms
-                algorithms.push(WikipediaAlgorithm {
-                    title: "Algorithm from ".to_string() + category,
-                    url: "https://en.wikipedia.org/wiki/List_of_algorithms".to_string(),
-                    category: category.to_string(),
-                    description: Some("An algorithm in the ".to_string() + category + " category."),
-                    complexity: Some("Varies".to_string()),
-                });
-            }
+    /// A listed data structure in the form stored in the database
+    pub fn to_wikipedia_data_structure(listed: ListedArticle) -> WikipediaDataStructure {
+        WikipediaDataStructure {
+            category: listed.data_structure_category().to_string(),
+            title: listed.title,
+            url: listed.url,
+            description: listed.summary,
+            complexity: None,
+            revision_id: None,
         }
+    }
+
+    /// A listed data structure with its article's summary, the operation
+    /// costs of its infobox and its categories
+    pub async fn enrich_data_structure(&self, listed: ListedArticle) -> Result<WikipediaDataStructure, WikipediaError> {
+        let title = page_title(&listed.url).unwrap_or_else(|| listed.title.clone());
+        let summary = self.api.page_summary(&title).await?;
+        let operations = self.api.infobox_operations(&title).await?;
+        let categories = self.api.page_categories(&title).await?;
 
-        Ok(algorithms)
+        let mut data_structure = Self::to_wikipedia_data_structure(listed);
+        apply_summary(&mut data_structure, summary);
+        data_structure.complexity = operations.and_then(|operations| operations.summary());
+        if data_structure.category == DataStructureCategory::Other.to_string() {
+            if let Some(category) = categories
+                .iter()
+                .map(|category| list::data_structure_category_for(category, &[]))
+                .find(|category| *category != DataStructureCategory::Other)
+            {
+                data_structure.category = category.to_string();
+            }
+        }
+        Ok(data_structure)
     }
 
     /// Convert WikipediaAlgorithm to AlgorithmInfo
     pub fn convert_to_algorithm_info(&self, wiki_algo: WikipediaAlgorithm) -> AlgorithmInfo {
-        // Map Wikipedia categories to our AlgorithmCategory enum
-        let category = match wiki_algo.category.as_str() {
-            "Sorting" => AlgorithmCategory::Sorting,
-            "Searching" => AlgorithmCategory::Searching,
-            "Graph" => AlgorithmCategory::Graph,
-            "Dynamic Programming" => AlgorithmCategory::DynamicProgramming,
-            "Greedy" => AlgorithmCategory::Greedy,
-            "Backtracking" => AlgorithmCategory::Backtracking,
-            "Divide and Conquer" => AlgorithmCategory::DivideConquer,
-            "Mathematical" => AlgorithmCategory::Mathematical,
-            "Cryptographic" => AlgorithmCategory::Cryptographic,
-            "Machine Learning" => AlgorithmCategory::MachineLearning,
-            "String" => AlgorithmCategory::String,
-            "Tree" => AlgorithmCategory::Tree,
-            "Hashing" => AlgorithmCategory::Hashing,
-            _ => AlgorithmCategory::Other,
-        };
+        algorithm_info(wiki_algo)
+    }
 
-        AlgorithmInfo {
-            name: wiki_algo.title,
-            category,
-            complexity: wiki_algo.complexity.unwrap_or_else(|| "Not specified".to_string()),
-            description: wiki_algo.description.unwrap_or_else(|| "No description available".to_string()),
-            wikipedia_link: wiki_algo.url,
-            examples: Vec::new(), // We don't have examples from Wikipedia, so we leave this empty
-        }
+    /// Fetch the list, enrich each article and bring `wikipedia_algorithms`
+    /// in line with it: new articles are inserted, changed ones updated and
+    /// ones no longer listed soft-deleted
+    pub async fn store_wikipedia_algorithms(&self) -> Result<WikipediaSyncReport, Box<dyn std::error::Error>> {
+        self.import(WikipediaList::Algorithms).await
     }
 
-    /// Store Wikipedia algorithms in the database
-    pub async fn store_wikipedia_algorithms(&self) -> Result<usize, Box<dyn std::error::Error>> {
-        let categories = self.fetch_algorithm_categories().await?;
-        let mut total_stored = 0;
-
-        for category in categories {
-            let algorithms = self.fetch_algorithms_from_category(&category).await?;
-            
-            for wiki_algo in algorithms {
-                // Convert to our internal format
-                let algorithm_info = self.convert_to_algorithm_info(wiki_algo.clone());
-                
-                // Store the Wikipedia algorithm in the database
-                match self.db.save_wikipedia_algorithm(wiki_algo).await {
-                    Ok(_) => {
-                        println!("Stored algorithm: {} ({})", algorithm_info.name, algorithm_info.category);
-                        total_stored += 1;
-                    }
-                    Err(e) => {
-                        eprintln!("Failed to store algorithm {}: {}", algorithm_info.name, e);
+    /// Fetch the List of data structures and bring `wikipedia_data_structures`
+    /// in line with it, as [`Self::store_wikipedia_algorithms`] does
+    pub async fn store_wikipedia_data_structures(&self) -> Result<WikipediaSyncReport, Box<dyn std::error::Error>> {
+        self.import(WikipediaList::DataStructures).await
+    }
+
+    /// Fetch and enrich one list and apply it to its table
+    pub async fn import(&self, list: WikipediaList) -> Result<WikipediaSyncReport, Box<dyn std::error::Error>> {
+        let plan = self.plan_import(list).await?;
+        self.db.apply_wikipedia_sync(list, &plan).await?;
+        Ok(plan.report())
+    }
+
+    /// Fetch and enrich one list and compare it with its table, without
+    /// writing anything
+    pub async fn plan_import(&self, list: WikipediaList) -> Result<WikipediaSyncPlan, Box<dyn std::error::Error>> {
+        let fetched = self.fetch_enriched(list).await?;
+        let stored = self.db.get_stored_wikipedia_entries(list).await?;
+        Ok(sync::plan_sync(&stored, fetched))
+    }
+
+    /// Every entry of one list, enriched; an article the API cannot enrich
+    /// is kept as listed
+    pub async fn fetch_enriched(&self, list: WikipediaList) -> Result<Vec<WikipediaAlgorithm>, Box<dyn std::error::Error>> {
+        let listed = match list {
+            WikipediaList::Algorithms => self.fetch_algorithm_list().await?,
+            WikipediaList::DataStructures => self.fetch_data_structure_list().await?,
+        };
+
+        let mut fetched = Vec::new();
+        for item in listed {
+            let enriched = match list {
+                WikipediaList::Algorithms => self.enrich(item.clone()).await,
+                WikipediaList::DataStructures => self.enrich_data_structure(item.clone()).await,
+            };
+            let entry = match enriched {
+                Ok(entry) => entry,
+                Err(e) => {
+                    eprintln!("Failed to enrich {}: {}", item.title, e);
+                    match list {
+                        WikipediaList::Algorithms => Self::to_wikipedia_algorithm(item),
+                        WikipediaList::DataStructures => Self::to_wikipedia_data_structure(item),
                     }
                 }
-            }
+            };
+            fetched.push(entry);
         }
+        Ok(fetched)
+    }
 
-        Ok(total_stored)
+    /// Merge the stored Wikipedia algorithms into `detector`, leaving
+    /// curated entries alone; returns how many entries were added or enriched
+    pub async fn update_algorithm_detector(&self, detector: &mut AlgorithmDetector) -> Result<usize, Box<dyn std::error::Error>> {
+        let imported = self.db.get_all_wikipedia_algorithms().await?;
+        let overrides = self.db.get_catalog_overrides().await?;
+        let merged = catalog::merge_wikipedia_algorithms(&mut detector.algorithms, &imported, &overrides);
+        Ok(merged.added + merged.enriched)
     }
 
-    /// Update the algorithm detector with Wikipedia algorithms
-    pub async fn update_algorithm_detector(&self) -> Result<usize, Box<dyn std::error::Error>> {
-        // This would typically update the in-memory algorithm database
-        // For now, we'll just return the count of algorithms that would be added
-        let categories = self.fetch_algorithm_categories().await?;
-        let mut total_added = 0;
+    /// Merge the stored Wikipedia data structures into `detector`, as
+    /// [`Self::update_algorithm_detector`] does for algorithms
+    pub async fn update_data_structure_detector(&self, detector: &mut DataStructureDetector) -> Result<usize, Box<dyn std::error::Error>> {
+        let imported = self.db.get_all_wikipedia_data_structures().await?;
+        let overrides = self.db.get_catalog_overrides().await?;
+        let merged = catalog::merge_wikipedia_data_structures(&mut detector.data_structures, &imported, &overrides);
+        Ok(merged.added + merged.enriched)
+    }
+}
 
-        for category in categories {
-            let algorithms = self.fetch_algorithms_from_category(&category).await?;
-            total_added += algorithms.len();
-        }
+/// The lead paragraph replaces the list's one-liner; the revision is kept
+/// for change tracking
+fn apply_summary(entry: &mut WikipediaAlgorithm, summary: PageSummary) {
+    if !summary.extract.trim().is_empty() {
+        entry.description = Some(summary.extract.trim().to_string());
+    }
+    entry.revision_id = summary.revision.and_then(|revision| revision.parse().ok());
+}
+
+/// A stored Wikipedia algorithm as a catalog entry, detected by name only
+pub fn algorithm_info(wiki_algo: WikipediaAlgorithm) -> AlgorithmInfo {
+    // Map Wikipedia categories to our AlgorithmCategory enum
+    let category = match wiki_algo.category.as_str() {
+        "Sorting" => AlgorithmCategory::Sorting,
+        "Searching" => AlgorithmCategory::Searching,
+        "Graph" => AlgorithmCategory::Graph,
+        "Dynamic Programming" => AlgorithmCategory::DynamicProgramming,
+        "Greedy" => AlgorithmCategory::Greedy,
+        "Backtracking" => AlgorithmCategory::Backtracking,
+        "Divide and Conquer" => AlgorithmCategory::DivideConquer,
+        "Mathematical" => AlgorithmCategory::Mathematical,
+        "Cryptographic" => AlgorithmCategory::Cryptographic,
+        "Machine Learning" => AlgorithmCategory::MachineLearning,
+        "String" => AlgorithmCategory::String,
+        "Tree" => AlgorithmCategory::Tree,
+        "Hashing" => AlgorithmCategory::Hashing,
+        _ => AlgorithmCategory::Other,
+    };
+
+    let complexity = wiki_algo.complexity.unwrap_or_else(|| "Not specified".to_string());
+    AlgorithmInfo {
+        name: wiki_algo.title,
+        category,
+        bounds: Complexity::from_summary(&complexity),
+        stable: None,
+        in_place: None,
+        complexity,
+        description: wiki_algo.description.unwrap_or_else(|| "No description available".to_string()),
+        wikipedia_link: wiki_algo.url,
+        examples: Vec::new(), // We don't have examples from Wikipedia, so we leave this empty
+        fingerprints: Vec::new(),
+        relations: Vec::new(),
+    }
+}
+
+/// A stored Wikipedia data structure as a catalog entry, detected by name only
+pub fn data_structure_info(wiki_ds: WikipediaDataStructure) -> DataStructureInfo {
+    // Categories are stored by their label
+    let category = match wiki_ds.category.as_str() {
+        "Linear" => DataStructureCategory::Linear,
+        "Tree" => DataStructureCategory::Tree,
+        "Graph" => DataStructureCategory::Graph,
+        "Hash-Based" => DataStructureCategory::HashBased,
+        "Heap" => DataStructureCategory::Heap,
+        "Queue" => DataStructureCategory::Queue,
+        "Stack" => DataStructureCategory::Stack,
+        "Set" => DataStructureCategory::Set,
+        _ => DataStructureCategory::Other,
+    };
 
-        Ok(total_added)
+    let complexity = wiki_ds.complexity.unwrap_or_else(|| "Not specified".to_string());
+    DataStructureInfo {
+        name: wiki_ds.title,
+        category,
+        bounds: Complexity::from_summary(&complexity),
+        complexity,
+        description: wiki_ds.description.unwrap_or_else(|| "No description available".to_string()),
+        wikipedia_link: wiki_ds.url,
+        examples: Vec::new(),
+        relations: Vec::new(),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::wikipedia_algorithm::WikipediaAlgorithm;
 
     #[test]
     fn test_wikipedia_algorithm_creation() {
@@ -183,9 +301,29 @@ mod tests {
             category: "Sorting".to_string(),
             description: Some("A test algorithm".to_string()),
             complexity: Some("O(n)".to_string()),
+            revision_id: None,
         };
 
         assert_eq!(wiki_algo.title, "Test Algorithm");
         assert_eq!(wiki_algo.category, "Sorting");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_data_structure_info() {
+        let wiki_ds = WikipediaDataStructure {
+            title: "Hash table".to_string(),
+            url: "https://en.wikipedia.org/wiki/Hash_table".to_string(),
+            category: DataStructureCategory::HashBased.to_string(),
+            description: None,
+            complexity: Some("O(1) search, O(1) insert, O(n) worst, O(n) space".to_string()),
+            revision_id: Some(1234567),
+        };
+
+        let info = data_structure_info(wiki_ds);
+        assert_eq!(info.category, DataStructureCategory::HashBased);
+        assert_eq!(info.bounds.operation("search").map(ToString::to_string).as_deref(), Some("O(1)"));
+        assert_eq!(info.bounds.worst.as_ref().map(ToString::to_string).as_deref(), Some("O(n)"));
+        assert_eq!(info.description, "No description available");
+        assert!(info.examples.is_empty());
+    }
+}
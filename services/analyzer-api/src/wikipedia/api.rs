@@ -0,0 +1,553 @@
+//! Client for the MediaWiki action and REST APIs
+//!
+//! Every request goes through [`MediaWikiClient::get`], which
+//!
+//! - waits for its turn so requests are at least `min_interval` apart;
+//! - retries connection failures, `429` and `5xx` with exponential backoff,
+//!   honouring `Retry-After`;
+//! - sends the configured User-Agent, as the Wikimedia User-Agent policy asks;
+//! - keeps responses that carry an `ETag` in an on-disk cache and
+//!   revalidates them with `If-None-Match`, so unchanged pages cost a `304`.
+//!
+//! Both endpoints are settings, which is how tests point the client at a
+//! local stand-in server.
+
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use reqwest::header::{ETAG, IF_NONE_MATCH, RETRY_AFTER};
+use reqwest::{Client, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+use super::infobox::{infobox_complexity, infobox_operations, InfoboxComplexity, InfoboxOperations};
+
+pub const DEFAULT_API_URL: &str = "https://en.wikipedia.org/w/api.php";
+
+pub const DEFAULT_REST_URL: &str = "https://en.wikipedia.org/api/rest_v1";
+
+/// Sent unless `ANALYZER_WIKIPEDIA_USER_AGENT` is set; deployments should
+/// set it to include a contact address
+pub const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"), " (algorithm catalog enrichment)");
+
+/// Default gap between two requests, in milliseconds
+pub const DEFAULT_MIN_INTERVAL_MS: u64 = 200;
+
+/// Default number of retries after the first attempt
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Default wait before the first retry, doubled for each further one, in milliseconds
+pub const DEFAULT_BACKOFF_MS: u64 = 500;
+
+pub const DEFAULT_CACHE_DIR: &str = ".cache/wikipedia";
+
+/// Longest wait honoured from a `Retry-After` header
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Characters left as they are in a REST page path, besides alphanumerics
+const TITLE_PATH: &AsciiSet = &NON_ALPHANUMERIC.remove(b'_').remove(b'-').remove(b'.').remove(b'(').remove(b')');
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaWikiSettings {
+    /// Action API endpoint, `.../w/api.php`
+    pub api_url: String,
+    /// REST API base, `.../api/rest_v1`
+    pub rest_url: String,
+    pub user_agent: String,
+    pub min_interval: Duration,
+    pub max_retries: u32,
+    pub backoff: Duration,
+    /// Where revalidatable responses are kept; `None` disables the cache
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl Default for MediaWikiSettings {
+    fn default() -> Self {
+        Self {
+            api_url: DEFAULT_API_URL.to_string(),
+            rest_url: DEFAULT_REST_URL.to_string(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            min_interval: Duration::from_millis(DEFAULT_MIN_INTERVAL_MS),
+            max_retries: DEFAULT_MAX_RETRIES,
+            backoff: Duration::from_millis(DEFAULT_BACKOFF_MS),
+            cache_dir: Some(PathBuf::from(DEFAULT_CACHE_DIR)),
+        }
+    }
+}
+
+impl MediaWikiSettings {
+    /// Read `ANALYZER_WIKIPEDIA_API_URL`, `ANALYZER_WIKIPEDIA_REST_URL`,
+    /// `ANALYZER_WIKIPEDIA_USER_AGENT`, `ANALYZER_WIKIPEDIA_MIN_INTERVAL_MS`,
+    /// `ANALYZER_WIKIPEDIA_MAX_RETRIES`, `ANALYZER_WIKIPEDIA_BACKOFF_MS` and
+    /// `ANALYZER_WIKIPEDIA_CACHE_DIR` (empty disables the cache), falling
+    /// back to the defaults
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let text = |name: &str| env::var(name).ok().filter(|value| !value.is_empty());
+        let millis = |name: &str| env::var(name).ok().and_then(|value| value.parse().ok()).map(Duration::from_millis);
+
+        Self {
+            api_url: text("ANALYZER_WIKIPEDIA_API_URL").unwrap_or(defaults.api_url),
+            rest_url: text("ANALYZER_WIKIPEDIA_REST_URL").unwrap_or(defaults.rest_url),
+            user_agent: text("ANALYZER_WIKIPEDIA_USER_AGENT").unwrap_or(defaults.user_agent),
+            min_interval: millis("ANALYZER_WIKIPEDIA_MIN_INTERVAL_MS").unwrap_or(defaults.min_interval),
+            max_retries: env::var("ANALYZER_WIKIPEDIA_MAX_RETRIES")
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(defaults.max_retries),
+            backoff: millis("ANALYZER_WIKIPEDIA_BACKOFF_MS").unwrap_or(defaults.backoff),
+            cache_dir: match env::var("ANALYZER_WIKIPEDIA_CACHE_DIR") {
+                Ok(dir) if dir.is_empty() => None,
+                Ok(dir) => Some(PathBuf::from(dir)),
+                Err(_) => defaults.cache_dir,
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum WikipediaError {
+    Http(reqwest::Error),
+    /// A final non-success status, after any retries
+    Status(StatusCode),
+    /// An error reported in the body of an action API response
+    Api { code: String, info: String },
+    /// A response without the expected fields
+    Malformed(String),
+}
+
+impl std::fmt::Display for WikipediaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WikipediaError::Http(e) => write!(f, "Request to Wikipedia failed: {}", e),
+            WikipediaError::Status(status) => write!(f, "Wikipedia answered {}", status),
+            WikipediaError::Api { code, info } => write!(f, "MediaWiki API error '{}': {}", code, info),
+            WikipediaError::Malformed(what) => write!(f, "Unexpected Wikipedia response: {}", what),
+        }
+    }
+}
+
+impl std::error::Error for WikipediaError {}
+
+/// The REST summary of a page
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct PageSummary {
+    pub title: String,
+    /// Short description, e.g. "Divide and conquer sorting algorithm"
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Lead paragraph as plain text
+    #[serde(default)]
+    pub extract: String,
+    /// Revision the summary was built from
+    #[serde(default)]
+    pub revision: Option<String>,
+}
+
+/// A cached response body and the validator to revalidate it with
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedResponse {
+    url: String,
+    etag: String,
+    body: String,
+}
+
+pub struct MediaWikiClient {
+    http: Client,
+    settings: MediaWikiSettings,
+    /// Earliest time the next request may be sent
+    next_request: Mutex<Instant>,
+}
+
+impl MediaWikiClient {
+    pub fn new(settings: MediaWikiSettings) -> Self {
+        let http = Client::builder()
+            .user_agent(settings.user_agent.clone())
+            .build()
+            .expect("HTTP client could not be built");
+        Self {
+            http,
+            settings,
+            next_request: Mutex::new(Instant::now()),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(MediaWikiSettings::from_env())
+    }
+
+    pub fn settings(&self) -> &MediaWikiSettings {
+        &self.settings
+    }
+
+    /// Rendered HTML of a page, as the parser sees it
+    pub async fn page_html(&self, title: &str) -> Result<String, WikipediaError> {
+        let response = self.action(&[("action", "parse"), ("page", title), ("prop", "text")]).await?;
+        response["parse"]["text"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| WikipediaError::Malformed(format!("no HTML for '{}'", title)))
+    }
+
+    pub async fn page_summary(&self, title: &str) -> Result<PageSummary, WikipediaError> {
+        let path = utf8_percent_encode(&title.replace(' ', "_"), TITLE_PATH).to_string();
+        let url = format!("{}/page/summary/{}", self.settings.rest_url.trim_end_matches('/'), path);
+        let url = Url::parse(&url).map_err(|e| WikipediaError::Malformed(e.to_string()))?;
+        let body = self.get(url).await?;
+        serde_json::from_str(&body).map_err(|e| WikipediaError::Malformed(e.to_string()))
+    }
+
+    /// Visible categories of a page, without the `Category:` prefix
+    pub async fn page_categories(&self, title: &str) -> Result<Vec<String>, WikipediaError> {
+        let response = self
+            .action(&[
+                ("action", "query"),
+                ("prop", "categories"),
+                ("titles", title),
+                ("clshow", "!hidden"),
+                ("cllimit", "max"),
+            ])
+            .await?;
+        let categories = response["query"]["pages"][0]["categories"]
+            .as_array()
+            .map(|categories| {
+                categories
+                    .iter()
+                    .filter_map(|category| category["title"].as_str())
+                    .map(|title| title.strip_prefix("Category:").unwrap_or(title).to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(categories)
+    }
+
+    /// Complexity fields of the page's infobox, from the wikitext of its lead section
+    pub async fn infobox_complexity(&self, title: &str) -> Result<Option<InfoboxComplexity>, WikipediaError> {
+        Ok(self.lead_wikitext(title).await?.as_deref().and_then(infobox_complexity))
+    }
+
+    /// Per-operation costs in the page's data structure infobox
+    pub async fn infobox_operations(&self, title: &str) -> Result<Option<InfoboxOperations>, WikipediaError> {
+        Ok(self.lead_wikitext(title).await?.as_deref().and_then(infobox_operations))
+    }
+
+    /// Wikitext of the lead section, where the infobox is
+    async fn lead_wikitext(&self, title: &str) -> Result<Option<String>, WikipediaError> {
+        let response = self
+            .action(&[("action", "parse"), ("page", title), ("prop", "wikitext"), ("section", "0")])
+            .await?;
+        Ok(response["parse"]["wikitext"].as_str().map(str::to_string))
+    }
+
+    /// Call the action API; errors in the body become [`WikipediaError::Api`]
+    async fn action(&self, params: &[(&str, &str)]) -> Result<serde_json::Value, WikipediaError> {
+        let common = [("format", "json"), ("formatversion", "2"), ("redirects", "1")];
+        let url = Url::parse_with_params(&self.settings.api_url, params.iter().chain(common.iter()))
+            .map_err(|e| WikipediaError::Malformed(e.to_string()))?;
+        let body = self.get(url).await?;
+        let response: serde_json::Value = serde_json::from_str(&body).map_err(|e| WikipediaError::Malformed(e.to_string()))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(WikipediaError::Api {
+                code: error["code"].as_str().unwrap_or("unknown").to_string(),
+                info: error["info"].as_str().unwrap_or_default().to_string(),
+            });
+        }
+        Ok(response)
+    }
+
+    /// Body of a `GET`, rate-limited, retried and revalidated against the cache
+    async fn get(&self, url: Url) -> Result<String, WikipediaError> {
+        let cached = self.load_cached(&url);
+        let mut attempt = 0;
+
+        let response = loop {
+            self.wait_turn().await;
+            let mut request = self.http.get(url.clone());
+            if let Some(cached) = &cached {
+                request = request.header(IF_NONE_MATCH, &cached.etag);
+            }
+
+            let wait = match request.send().await {
+                Ok(response) if is_transient(response.status()) && attempt < self.settings.max_retries => {
+                    retry_after(&response).unwrap_or_else(|| self.backoff(attempt))
+                }
+                Err(e) if (e.is_connect() || e.is_timeout()) && attempt < self.settings.max_retries => self.backoff(attempt),
+                Ok(response) => break response,
+                Err(e) => return Err(WikipediaError::Http(e)),
+            };
+            tokio::time::sleep(wait).await;
+            attempt += 1;
+        };
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some(cached) = cached {
+                return Ok(cached.body);
+            }
+        }
+        if !response.status().is_success() {
+            return Err(WikipediaError::Status(response.status()));
+        }
+
+        let etag = response.headers().get(ETAG).and_then(|etag| etag.to_str().ok()).map(str::to_string);
+        let body = response.text().await.map_err(WikipediaError::Http)?;
+        if let Some(etag) = etag {
+            self.store_cached(CachedResponse {
+                url: url.to_string(),
+                etag,
+                body: body.clone(),
+            });
+        }
+        Ok(body)
+    }
+
+    async fn wait_turn(&self) {
+        let mut next = self.next_request.lock().await;
+        tokio::time::sleep_until(*next).await;
+        *next = Instant::now() + self.settings.min_interval;
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.settings.backoff.saturating_mul(2u32.saturating_pow(attempt))
+    }
+
+    fn cache_path(&self, url: &Url) -> Option<PathBuf> {
+        let dir = self.settings.cache_dir.as_ref()?;
+        let key = hex::encode(Sha256::digest(url.as_str().as_bytes()));
+        Some(dir.join(format!("{key}.json")))
+    }
+
+    fn load_cached(&self, url: &Url) -> Option<CachedResponse> {
+        let contents = std::fs::read_to_string(self.cache_path(url)?).ok()?;
+        serde_json::from_str::<CachedResponse>(&contents)
+            .ok()
+            .filter(|cached| cached.url == url.as_str())
+    }
+
+    /// A cache that cannot be written only costs a full download next time
+    fn store_cached(&self, response: CachedResponse) {
+        let Some(path) = self.cache_path(&Url::parse(&response.url).unwrap()) else {
+            return;
+        };
+        let written = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| std::fs::write(&path, serde_json::to_vec(&response).unwrap_or_default()));
+        if let Err(e) = written {
+            eprintln!("Failed to cache {}: {}", response.url, e);
+        }
+    }
+}
+
+/// The page title an article URL points to, e.g. `Dijkstra's algorithm`
+/// for `https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm`
+pub fn page_title(url: &str) -> Option<String> {
+    let (_, article) = url.split_once("/wiki/")?;
+    let article = article.split(['#', '?']).next().unwrap_or(article);
+    let title = percent_decode_str(article).decode_utf8_lossy().replace('_', " ");
+    (!title.is_empty()).then_some(title)
+}
+
+fn is_transient(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// `Retry-After` in seconds; the HTTP-date form is not used by Wikimedia
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    let seconds: u64 = response.headers().get(RETRY_AFTER)?.to_str().ok()?.trim().parse().ok()?;
+    Some(Duration::from_secs(seconds).min(MAX_RETRY_AFTER))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// What the stand-in server has seen
+    #[derive(Default)]
+    struct Seen {
+        requests: AtomicUsize,
+        not_modified: AtomicUsize,
+        /// Requests to `/flaky` answered with 503 before it recovers
+        failures_left: AtomicUsize,
+    }
+
+    async fn summary(req: HttpRequest, seen: web::Data<Arc<Seen>>) -> HttpResponse {
+        seen.requests.fetch_add(1, Ordering::SeqCst);
+        if req.headers().get("user-agent").and_then(|ua| ua.to_str().ok()) != Some("test-agent/1.0 (ops@example.org)") {
+            return HttpResponse::BadRequest().finish();
+        }
+        if req.headers().get("if-none-match").and_then(|tag| tag.to_str().ok()) == Some("\"rev-1\"") {
+            seen.not_modified.fetch_add(1, Ordering::SeqCst);
+            return HttpResponse::NotModified().finish();
+        }
+        HttpResponse::Ok().insert_header(("ETag", "\"rev-1\"")).json(serde_json::json!({
+            "title": "Quicksort",
+            "description": "Divide and conquer sorting algorithm",
+            "extract": "Quicksort is an efficient, general-purpose sorting algorithm.",
+            "revision": "1234567"
+        }))
+    }
+
+    async fn flaky(seen: web::Data<Arc<Seen>>) -> HttpResponse {
+        seen.requests.fetch_add(1, Ordering::SeqCst);
+        let failing = seen
+            .failures_left
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| left.checked_sub(1))
+            .is_ok();
+        if failing {
+            HttpResponse::ServiceUnavailable().finish()
+        } else {
+            HttpResponse::Ok().body("{}")
+        }
+    }
+
+    async fn action(query: web::Query<std::collections::HashMap<String, String>>) -> HttpResponse {
+        let response = match (query.get("action").map(String::as_str), query.get("prop").map(String::as_str)) {
+            (Some("query"), Some("categories")) => serde_json::json!({"query": {"pages": [{
+                "title": query["titles"],
+                "categories": [{"ns": 14, "title": "Category:Sorting algorithms"}, {"ns": 14, "title": "Category:Comparison sorts"}]
+            }]}}),
+            (Some("parse"), Some("wikitext")) => serde_json::json!({"parse": {
+                "title": query["page"],
+                "wikitext": "{{Infobox algorithm|time=<math>O(n^2)</math>|average-time=<math>O(n \\log n)</math>}}"
+            }}),
+            (Some("parse"), Some("text")) if query["page"] == "Missing page" => serde_json::json!({
+                "error": {"code": "missingtitle", "info": "The page you specified doesn't exist."}
+            }),
+            _ => return HttpResponse::BadRequest().finish(),
+        };
+        HttpResponse::Ok().json(response)
+    }
+
+    /// Start a stand-in for both APIs and return its base URL
+    fn stand_in(seen: Arc<Seen>) -> String {
+        let server = HttpServer::new(move || {
+            App::new()
+                .app_data(web::Data::new(seen.clone()))
+                .route("/api/rest_v1/page/summary/{title}", web::get().to(summary))
+                .route("/flaky", web::get().to(flaky))
+                .route("/w/api.php", web::get().to(action))
+        })
+        .workers(1)
+        .bind(("127.0.0.1", 0))
+        .unwrap();
+        let address = server.addrs()[0];
+        actix_web::rt::spawn(server.run());
+        format!("http://{address}")
+    }
+
+    fn settings(base: &str, cache_dir: Option<PathBuf>) -> MediaWikiSettings {
+        MediaWikiSettings {
+            api_url: format!("{base}/w/api.php"),
+            rest_url: format!("{base}/api/rest_v1"),
+            user_agent: "test-agent/1.0 (ops@example.org)".to_string(),
+            min_interval: Duration::ZERO,
+            max_retries: 2,
+            backoff: Duration::from_millis(1),
+            cache_dir,
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_revalidates_cached_responses() {
+        let seen = Arc::new(Seen::default());
+        let base = stand_in(seen.clone());
+        let cache_dir = env::temp_dir().join(format!("wikipedia-cache-{}", uuid::Uuid::new_v4()));
+        let client = MediaWikiClient::new(settings(&base, Some(cache_dir.clone())));
+
+        let first = client.page_summary("Quicksort").await.unwrap();
+        assert_eq!(first.description.as_deref(), Some("Divide and conquer sorting algorithm"));
+        assert_eq!(first.revision.as_deref(), Some("1234567"));
+
+        // A fresh client finds the copy on disk and gets a 304 for it
+        let client = MediaWikiClient::new(settings(&base, Some(cache_dir.clone())));
+        assert_eq!(client.page_summary("Quicksort").await.unwrap(), first);
+        assert_eq!(seen.requests.load(Ordering::SeqCst), 2);
+        assert_eq!(seen.not_modified.load(Ordering::SeqCst), 1);
+
+        // The User-Agent is what identifies us
+        let anonymous = MediaWikiClient::new(MediaWikiSettings {
+            user_agent: "reqwest".to_string(),
+            ..settings(&base, None)
+        });
+        assert!(matches!(
+            anonymous.page_summary("Quicksort").await,
+            Err(WikipediaError::Status(StatusCode::BAD_REQUEST))
+        ));
+
+        std::fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[actix_web::test]
+    async fn test_retries_transient_failures() {
+        let seen = Arc::new(Seen::default());
+        let base = stand_in(seen.clone());
+        let client = MediaWikiClient::new(settings(&base, None));
+        let flaky = Url::parse(&format!("{base}/flaky")).unwrap();
+
+        seen.failures_left.store(2, Ordering::SeqCst);
+        assert_eq!(client.get(flaky.clone()).await.unwrap(), "{}");
+        assert_eq!(seen.requests.load(Ordering::SeqCst), 3);
+
+        // One failure more than the retries allow
+        seen.failures_left.store(3, Ordering::SeqCst);
+        assert!(matches!(
+            client.get(flaky).await,
+            Err(WikipediaError::Status(StatusCode::SERVICE_UNAVAILABLE))
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_spaces_requests() {
+        let seen = Arc::new(Seen::default());
+        let base = stand_in(seen.clone());
+        let client = MediaWikiClient::new(MediaWikiSettings {
+            min_interval: Duration::from_millis(50),
+            ..settings(&base, None)
+        });
+        let flaky = Url::parse(&format!("{base}/flaky")).unwrap();
+
+        let started = std::time::Instant::now();
+        for _ in 0..3 {
+            client.get(flaky.clone()).await.unwrap();
+        }
+        assert!(started.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[actix_web::test]
+    async fn test_action_api() {
+        let base = stand_in(Arc::new(Seen::default()));
+        let client = MediaWikiClient::new(settings(&base, None));
+
+        assert_eq!(
+            client.page_categories("Quicksort").await.unwrap(),
+            ["Sorting algorithms", "Comparison sorts"]
+        );
+        let complexity = client.infobox_complexity("Quicksort").await.unwrap().unwrap();
+        assert_eq!(complexity.summary().as_deref(), Some("O(n^2) worst, O(n log n) average"));
+
+        match client.page_html("Missing page").await {
+            Err(WikipediaError::Api { code, .. }) => assert_eq!(code, "missingtitle"),
+            other => panic!("expected an API error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_page_title() {
+        assert_eq!(
+            page_title("https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm").as_deref(),
+            Some("Dijkstra's algorithm")
+        );
+        assert_eq!(
+            page_title("https://en.wikipedia.org/wiki/Knuth%E2%80%93Morris%E2%80%93Pratt_algorithm#History").as_deref(),
+            Some("Knuth–Morris–Pratt algorithm")
+        );
+        assert_eq!(page_title("https://en.wikipedia.org/w/index.php?title=X"), None);
+    }
+}
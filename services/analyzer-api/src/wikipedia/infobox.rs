@@ -0,0 +1,398 @@
+//! Complexity fields of an article's infobox
+//!
+//! Algorithm articles open with `{{Infobox algorithm | time = ... | space = ...}}`
+//! in their wikitext. Values are usually `<math>` markup, sometimes plain
+//! text with `''n''<sup>2</sup>`, and often carry a remark after the bound
+//! (`<math>O(n)</math> auxiliary`). Only the first bound of each field is
+//! kept, and only if it parses as [`BigO`].
+//!
+//! Data structure articles use `{{Infobox data structure}}` instead, with a
+//! cost per operation in fields such as `search_avg` and `insert_worst`.
+
+use crate::complexity::BigO;
+
+/// Bounds read from an infobox, already in `O(...)` notation
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InfoboxComplexity {
+    pub worst_time: Option<String>,
+    pub average_time: Option<String>,
+    pub best_time: Option<String>,
+    pub space: Option<String>,
+}
+
+impl InfoboxComplexity {
+    /// A summary in the form read by [`crate::complexity::Complexity::from_summary`],
+    /// e.g. `O(n²) worst, O(n log n) average, O(n) space`
+    pub fn summary(&self) -> Option<String> {
+        let parts: Vec<String> = [
+            (&self.worst_time, "worst"),
+            (&self.average_time, "average"),
+            (&self.best_time, "best"),
+            (&self.space, "space"),
+        ]
+        .into_iter()
+        .filter_map(|(bound, qualifier)| bound.as_ref().map(|bound| format!("{bound} {qualifier}")))
+        .collect();
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.summary().is_none()
+    }
+}
+
+/// Costs of a data structure's operations read from an infobox, in infobox order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InfoboxOperations {
+    /// Average or amortized cost of each operation, or its worst cost
+    /// where the infobox gives no other
+    pub operations: Vec<(String, String)>,
+    /// The worst cost of any one operation
+    pub worst: Option<String>,
+    pub space: Option<String>,
+}
+
+impl InfoboxOperations {
+    /// A summary in the form read by [`crate::complexity::Complexity::from_summary`],
+    /// e.g. `O(1) search, O(1) insert, O(n) worst, O(n) space`
+    pub fn summary(&self) -> Option<String> {
+        let parts: Vec<String> = self
+            .operations
+            .iter()
+            .map(|(operation, bound)| format!("{bound} {operation}"))
+            .chain(self.worst.iter().map(|bound| format!("{bound} worst")))
+            .chain(self.space.iter().map(|bound| format!("{bound} space")))
+            .collect();
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.summary().is_none()
+    }
+}
+
+/// Per-operation costs from the first infobox of the wikitext, if it has
+/// any. Fields are `<operation>_avg`, `<operation>_amortized` and
+/// `<operation>_worst`; `space` takes its worst bound first.
+pub fn infobox_operations(wikitext: &str) -> Option<InfoboxOperations> {
+    let fields = infobox_fields(wikitext)?;
+
+    let mut costs = InfoboxOperations::default();
+    let mut worst: Option<(BigO, String)> = None;
+    let mut space: Option<(bool, String)> = None;
+    for (name, value) in &fields {
+        let Some((operation, case)) = name.rsplit_once('-') else {
+            continue;
+        };
+        if !matches!(case, "avg" | "amortized" | "worst") {
+            continue;
+        }
+        let Some(bound) = first_bound(value) else {
+            continue;
+        };
+        let is_worst = case == "worst";
+
+        if operation == "space" {
+            if space.as_ref().is_none_or(|(was_worst, _)| is_worst && !was_worst) {
+                space = Some((is_worst, bound));
+            }
+            continue;
+        }
+
+        let operation = operation.replace('-', " ");
+        match costs.operations.iter_mut().find(|(name, _)| *name == operation) {
+            Some(entry) if !is_worst => entry.1 = bound.clone(),
+            Some(_) => {}
+            None => costs.operations.push((operation, bound.clone())),
+        }
+        if is_worst {
+            // `first_bound` only keeps bounds that parse
+            let parsed: BigO = bound.parse().ok()?;
+            if worst.as_ref().is_none_or(|(current, _)| parsed > *current) {
+                worst = Some((parsed, bound));
+            }
+        }
+    }
+
+    costs.worst = worst.map(|(_, bound)| bound);
+    costs.space = space.map(|(_, bound)| bound);
+    (!costs.is_empty()).then_some(costs)
+}
+
+/// Bounds from the first `{{Infobox ...}}` of the wikitext, if it has any
+pub fn infobox_complexity(wikitext: &str) -> Option<InfoboxComplexity> {
+    let fields = infobox_fields(wikitext)?;
+    let field = |names: &[&str]| {
+        fields
+            .iter()
+            .find(|(name, _)| names.contains(&name.as_str()))
+            .and_then(|(_, value)| first_bound(value))
+    };
+
+    let complexity = InfoboxComplexity {
+        worst_time: field(&["time", "worst-time"]),
+        average_time: field(&["average-time"]),
+        best_time: field(&["best-time"]),
+        space: field(&["space", "worst-space"]),
+    };
+    (!complexity.is_empty()).then_some(complexity)
+}
+
+/// `name = value` pairs of the first infobox, names lowercased with `_` as `-`
+pub fn infobox_fields(wikitext: &str) -> Option<Vec<(String, String)>> {
+    // Matched on the bytes of the text itself: lowercasing it first can
+    // change its length before the match, as for `İ` or the Kelvin sign.
+    // ASCII bytes only occur as whole characters, so the offset is a
+    // character boundary.
+    let needle = b"{{infobox";
+    let start = wikitext
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle))?;
+    let body = &wikitext[start + 2..];
+
+    // Split on `|` outside nested templates, links and `<math>`, stopping
+    // at the braces that close the infobox
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut part_start = 0;
+    let mut i = 0;
+    while i < body.len() {
+        let rest = &body[i..];
+        if rest.starts_with("<math") {
+            i += rest.find("</math>").map_or(rest.len(), |end| end + "</math>".len());
+            continue;
+        }
+        if rest.starts_with("{{") || rest.starts_with("[[") {
+            depth += 1;
+            i += 2;
+            continue;
+        }
+        if rest.starts_with("}}") && depth == 0 {
+            break;
+        }
+        if rest.starts_with("}}") || rest.starts_with("]]") {
+            depth = depth.saturating_sub(1);
+            i += 2;
+            continue;
+        }
+        if rest.starts_with('|') && depth == 0 {
+            parts.push(&body[part_start..i]);
+            part_start = i + 1;
+        }
+        i += rest.chars().next().map_or(1, char::len_utf8);
+    }
+    parts.push(&body[part_start..i]);
+
+    Some(
+        parts
+            .into_iter()
+            .skip(1)
+            .filter_map(|part| {
+                let (name, value) = part.split_once('=')?;
+                Some((name.trim().to_lowercase().replace('_', "-"), value.trim().to_string()))
+            })
+            .collect(),
+    )
+}
+
+/// The first bound written in a field value, as `O(...)`
+fn first_bound(value: &str) -> Option<String> {
+    let text = match value.find("<math") {
+        Some(at) => {
+            let open = at + value[at..].find('>')? + 1;
+            let close = open + value[open..].find("</math>")?;
+            math_to_text(&value[open..close])
+        }
+        None => value
+            .replace("''", "")
+            .replace("<sup>", "^(")
+            .replace("</sup>", ")")
+            .replace("&nbsp;", " "),
+    };
+
+    let inner = text
+        .char_indices()
+        .filter(|(_, c)| matches!(c, 'O' | 'Θ' | 'Ω'))
+        .find_map(|(at, letter)| text[at + letter.len_utf8()..].trim_start().strip_prefix('('))?;
+    let mut depth = 1usize;
+    let close = inner.char_indices().find_map(|(i, c)| {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        None
+    })?;
+
+    // Tight and lower bounds are still upper bounds
+    let bound = format!("O({})", inner[..close].trim());
+    bound.parse::<BigO>().ok().map(|_| bound)
+}
+
+/// LaTeX as the bound parser reads it: `O(n \log n)` becomes `O(n log n)`
+fn math_to_text(latex: &str) -> String {
+    let mut text = String::new();
+    // Whether each open brace came from `\sqrt{` and so closes with `)`
+    let mut braces: Vec<bool> = Vec::new();
+    let mut chars = latex.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let mut command = String::new();
+                while let Some(&next) = chars.peek().filter(|next| next.is_ascii_alphabetic()) {
+                    command.push(next);
+                    chars.next();
+                }
+                match command.as_str() {
+                    // `\,` and `\!` are spacing
+                    "" => {
+                        chars.next();
+                        text.push(' ');
+                    }
+                    "log" | "lg" | "ln" => text.push_str(" log "),
+                    "sqrt" if chars.peek() == Some(&'{') => {
+                        chars.next();
+                        braces.push(true);
+                        text.push_str("sqrt(");
+                    }
+                    "sqrt" => text.push('√'),
+                    "cdot" | "times" => text.push('*'),
+                    "Theta" => text.push('Θ'),
+                    "Omega" => text.push('Ω'),
+                    "left" | "right" | "mathcal" | "mathrm" | "operatorname" | "big" | "Big" => {}
+                    other => text.push_str(other),
+                }
+            }
+            '{' => {
+                braces.push(false);
+                // `n^{2}` keeps its exponent grouped
+                if text.ends_with('^') {
+                    text.push('(');
+                    *braces.last_mut().unwrap() = true;
+                }
+            }
+            '}' => {
+                if braces.pop() == Some(true) {
+                    text.push(')');
+                }
+            }
+            // `|V|` is the size of V
+            '|' => {}
+            // `\log_2 n` has a constant base
+            '_' if text.ends_with("log ") => {
+                if chars.next() == Some('{') {
+                    for skipped in chars.by_ref() {
+                        if skipped == '}' {
+                            break;
+                        }
+                    }
+                }
+            }
+            _ => text.push(c),
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ").replace("( ", "(").replace(" )", ")")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reads_algorithm_infobox() {
+        let wikitext = r#"{{Short description|Divide and conquer sorting algorithm}}
+{{Infobox algorithm
+|name={{PAGENAMEBASE}}
+|class=[[Sorting algorithm]]
+|image=[[File:Sorting quicksort anim.gif|Animated visualization of the quicksort algorithm.]]
+|time=<math>O(n^2)</math>
+|best-time=<math>O(n \log n)</math> (simple partition)<br />or <math>O(n)</math> (three-way partition and equal keys)
+|average-time=<math>O(n \log_{2} n)</math>
+|space=<math>O(\log n)</math> auxiliary (Hoare 1962)
+|optimal=No
+}}
+'''Quicksort''' is an efficient, general-purpose [[sorting algorithm]]."#;
+
+        let complexity = infobox_complexity(wikitext).unwrap();
+        assert_eq!(complexity.worst_time.as_deref(), Some("O(n^2)"));
+        assert_eq!(complexity.best_time.as_deref(), Some("O(n log n)"));
+        assert_eq!(complexity.average_time.as_deref(), Some("O(n log n)"));
+        assert_eq!(complexity.space.as_deref(), Some("O(log n)"));
+        assert_eq!(
+            complexity.summary().as_deref(),
+            Some("O(n^2) worst, O(n log n) average, O(n log n) best, O(log n) space")
+        );
+
+        let fields = infobox_fields(wikitext).unwrap();
+        assert!(fields.contains(&("class".to_string(), "[[Sorting algorithm]]".to_string())));
+    }
+
+    #[test]
+    fn test_reads_other_notations() {
+        let wikitext = "{{Infobox algorithm|time=''O''(''n''<sup>2</sup>)|space=<math>\\Theta(|V| + \\sqrt{|E|})</math>}}";
+        let complexity = infobox_complexity(wikitext).unwrap();
+        assert_eq!(complexity.worst_time.as_deref(), Some("O(n^(2))"));
+        assert_eq!(complexity.space.as_deref(), Some("O(V + sqrt(E))"));
+
+        // No infobox, or no bound in it
+        assert_eq!(infobox_complexity("'''Bogosort''' is a sorting algorithm."), None);
+        assert_eq!(infobox_complexity("{{Infobox algorithm|time=varies}}"), None);
+    }
+
+    #[test]
+    fn test_finds_infobox_after_text_that_lowercases_to_another_length() {
+        // `İ` lowercases to three bytes and the Kelvin sign to one
+        for prefix in ["İİİİ", "\u{212A}\u{212A}\u{212A}"] {
+            let wikitext = format!("{} {{{{INFOBOX algorithm|time=<math>O(n)</math>}}}}", prefix);
+            let fields = infobox_fields(&wikitext).unwrap();
+            assert_eq!(fields, [("time".to_string(), "<math>O(n)</math>".to_string())]);
+        }
+    }
+
+    #[test]
+    fn test_reads_data_structure_infobox() {
+        let wikitext = r#"{{Infobox data structure
+|name=Hash table
+|type=Unordered [[associative array]]
+|invented_year=1953
+|space_avg=<math>\Theta(n)</math><ref name="cormen" />
+|space_worst=<math>O(n)</math>
+|search_avg=<math>\Theta(1)</math>
+|search_worst=<math>O(n)</math>
+|insert_avg=<math>\Theta(1)</math>
+|insert_worst=<math>O(n)</math>
+|delete_worst=<math>O(n)</math>
+|find_min_amortized={{math|''O''(''n'')}}
+}}
+A '''hash table''' is a data structure that implements an [[associative array]]."#;
+
+        let costs = infobox_operations(wikitext).unwrap();
+        assert_eq!(
+            costs.operations,
+            [
+                ("search".to_string(), "O(1)".to_string()),
+                ("insert".to_string(), "O(1)".to_string()),
+                // Only a worst case is given
+                ("delete".to_string(), "O(n)".to_string()),
+                ("find min".to_string(), "O(n)".to_string()),
+            ]
+        );
+        assert_eq!(costs.worst.as_deref(), Some("O(n)"));
+        assert_eq!(costs.space.as_deref(), Some("O(n)"));
+        assert_eq!(
+            costs.summary().as_deref(),
+            Some("O(1) search, O(1) insert, O(n) delete, O(n) find min, O(n) worst, O(n) space")
+        );
+
+        // An algorithm infobox has no per-operation fields
+        assert_eq!(infobox_operations("{{Infobox algorithm|time=<math>O(n^2)</math>}}"), None);
+    }
+}
@@ -0,0 +1,405 @@
+//! Parsing the "List of algorithms" and "List of data structures" articles
+//!
+//! Both articles are a tree of headings over bulleted lists, where each
+//! item links to an article and usually follows the link with a colon and
+//! a short summary. Current markup wraps headings as
+//! `<div class="mw-heading"><h2 id="...">Title</h2><span class="mw-editsection">`,
+//! older markup as `<h2><span class="mw-headline">Title</span>...</h2>`;
+//! both are read by their visible text, so neither selector is relied on.
+
+use scraper::{ElementRef, Html, Node, Selector};
+use std::collections::HashSet;
+
+use crate::algorithms::AlgorithmCategory;
+use crate::data_structures::DataStructureCategory;
+
+pub const LIST_OF_ALGORITHMS_URL: &str = "https://en.wikipedia.org/wiki/List_of_algorithms";
+
+pub const LIST_OF_ALGORITHMS_TITLE: &str = "List of algorithms";
+
+pub const LIST_OF_DATA_STRUCTURES_URL: &str = "https://en.wikipedia.org/wiki/List_of_data_structures";
+
+pub const LIST_OF_DATA_STRUCTURES_TITLE: &str = "List of data structures";
+
+const WIKI_ORIGIN: &str = "https://en.wikipedia.org";
+
+/// Sections after the list proper
+const BACK_MATTER: [&str; 7] = [
+    "See also",
+    "References",
+    "External links",
+    "Notes",
+    "Further reading",
+    "Sources",
+    "Bibliography",
+];
+
+/// Page furniture whose lists are not part of the article
+const SKIPPED_CLASSES: [&str; 9] = [
+    "toc",
+    "navbox",
+    "sidebar",
+    "reflist",
+    "references",
+    "mw-references-wrap",
+    "hatnote",
+    "catlinks",
+    "mw-editsection",
+];
+
+/// One linked item of a list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListedArticle {
+    pub title: String,
+    /// Absolute article URL
+    pub url: String,
+    /// Headings from the outermost section down to the one holding the item
+    pub section: Vec<String>,
+    /// First sentence of the text after the link
+    pub summary: Option<String>,
+}
+
+impl ListedArticle {
+    /// Category of an item of the List of algorithms
+    pub fn algorithm_category(&self) -> AlgorithmCategory {
+        category_for_section(&self.section)
+    }
+
+    /// Category of an item of the List of data structures
+    pub fn data_structure_category(&self) -> DataStructureCategory {
+        data_structure_category_for(&self.title, &self.section)
+    }
+}
+
+/// Every algorithm the page lists, in page order, each article once
+pub fn parse_algorithm_list(html: &str) -> Vec<ListedArticle> {
+    parse_list(html)
+}
+
+/// Every data structure the page lists, in page order, each article once.
+/// Primitive and composite types (`Boolean`, `Record`) are types of a
+/// programming language rather than data structures, and are left out.
+pub fn parse_data_structure_list(html: &str) -> Vec<ListedArticle> {
+    parse_list(html)
+        .into_iter()
+        .filter(|item| !item.section.iter().any(|heading| heading.to_lowercase().contains("primitive")))
+        .collect()
+}
+
+fn parse_list(html: &str) -> Vec<ListedArticle> {
+    let document = Html::parse_document(html);
+    let content = Selector::parse("div.mw-parser-output").unwrap();
+    let items = Selector::parse("h2, h3, h4, h5, li").unwrap();
+    let root = document.select(&content).next().unwrap_or_else(|| document.root_element());
+
+    let mut section: Vec<String> = Vec::new();
+    let mut in_back_matter = false;
+    let mut seen = HashSet::new();
+    let mut articles = Vec::new();
+
+    for element in root.select(&items) {
+        if is_skipped(element) {
+            continue;
+        }
+        let name = element.value().name();
+        if let Some(level) = name.strip_prefix('h').and_then(|level| level.parse::<usize>().ok()) {
+            let heading = visible_text(element);
+            if level == 2 {
+                in_back_matter = BACK_MATTER.contains(&heading.as_str());
+            }
+            section.truncate(level.saturating_sub(2));
+            section.push(heading);
+            continue;
+        }
+        if in_back_matter || section.is_empty() {
+            continue;
+        }
+        let Some(item) = listed_article(element, &section) else {
+            continue;
+        };
+        if seen.insert(item.url.clone()) {
+            articles.push(item);
+        }
+    }
+
+    articles
+}
+
+/// Top-level sections of the list, without the back matter
+pub fn parse_sections(html: &str) -> Vec<String> {
+    let mut sections: Vec<String> = Vec::new();
+    for algorithm in parse_algorithm_list(html) {
+        if let Some(top) = algorithm.section.first() {
+            if !sections.contains(top) {
+                sections.push(top.clone());
+            }
+        }
+    }
+    sections
+}
+
+/// Our category for a section of the page, judged from the innermost
+/// heading outwards: "Graph search" under "Combinatorial algorithms" is Graph
+pub fn category_for_section(section: &[String]) -> AlgorithmCategory {
+    const KEYWORDS: [(&[&str], AlgorithmCategory); 13] = [
+        (&["sort"], AlgorithmCategory::Sorting),
+        // Before "graph", which "Cryptography" contains
+        (&["crypt", "cipher", "secret sharing"], AlgorithmCategory::Cryptographic),
+        (&["graph", "network flow", "routing", "shortest path", "spanning tree"], AlgorithmCategory::Graph),
+        (&["string", "substring", "sequence alignment", "sequence matching"], AlgorithmCategory::String),
+        (&["search", "selection algorithm"], AlgorithmCategory::Searching),
+        (&["hash", "checksum"], AlgorithmCategory::Hashing),
+        (&["machine learning", "statistical classification", "neural", "artificial intelligence"], AlgorithmCategory::MachineLearning),
+        (&["dynamic programming"], AlgorithmCategory::DynamicProgramming),
+        (&["greedy"], AlgorithmCategory::Greedy),
+        (&["backtracking"], AlgorithmCategory::Backtracking),
+        (&["divide and conquer"], AlgorithmCategory::DivideConquer),
+        (&["tree"], AlgorithmCategory::Tree),
+        (
+            &["number theor", "numerical", "algebra", "mathemat", "prime", "calculus", "optimization", "root finding"],
+            AlgorithmCategory::Mathematical,
+        ),
+    ];
+
+    section
+        .iter()
+        .rev()
+        .find_map(|heading| {
+            let heading = heading.to_lowercase();
+            KEYWORDS
+                .iter()
+                .find(|(words, _)| words.iter().any(|word| heading.contains(word)))
+                .map(|(_, category)| category.clone())
+        })
+        .unwrap_or(AlgorithmCategory::Other)
+}
+
+/// Our category for an item of the List of data structures, judged from
+/// the innermost heading outwards and then from the title, so "Adjacency
+/// list" under "Graphs" is Graph and "Stack" under "Abstract data types"
+/// is Stack
+pub fn data_structure_category_for(title: &str, section: &[String]) -> DataStructureCategory {
+    const KEYWORDS: [(&[&str], DataStructureCategory); 8] = [
+        (&["queue", "deque"], DataStructureCategory::Queue),
+        (&["heap"], DataStructureCategory::Heap),
+        (&["hash", "associative", "dictionary", "multimap", "bloom filter", "sketch"], DataStructureCategory::HashBased),
+        (&["graph", "adjacency"], DataStructureCategory::Graph),
+        (&["tree", "trie"], DataStructureCategory::Tree),
+        (&["stack"], DataStructureCategory::Stack),
+        (&["set"], DataStructureCategory::Set),
+        (&["array", "list", "linear", "buffer", "rope"], DataStructureCategory::Linear),
+    ];
+
+    section
+        .iter()
+        .rev()
+        .map(String::as_str)
+        .chain(std::iter::once(title))
+        .find_map(|text| {
+            let text = text.to_lowercase();
+            KEYWORDS
+                .iter()
+                .find(|(words, _)| words.iter().any(|word| text.contains(word)))
+                .map(|(_, category)| category.clone())
+        })
+        .unwrap_or(DataStructureCategory::Other)
+}
+
+fn listed_article(item: ElementRef, section: &[String]) -> Option<ListedArticle> {
+    let link = first_article_link(item)?;
+    let title = visible_text(link);
+    if title.is_empty() {
+        return None;
+    }
+    let href = link.value().attr("href")?;
+
+    let text = visible_text(item);
+    let summary = text
+        .find(title.as_str())
+        .map(|at| &text[at + title.len()..])
+        .map(|rest| rest.trim_start_matches(|c: char| c.is_whitespace() || matches!(c, ':' | '-' | '–' | '—' | ',')))
+        .map(first_sentence)
+        .filter(|summary| !summary.is_empty());
+
+    Some(ListedArticle {
+        title,
+        url: format!("{WIKI_ORIGIN}{href}"),
+        section: section.to_vec(),
+        summary,
+    })
+}
+
+/// The first link to an article, outside nested lists and footnotes;
+/// red links and other namespaces (`File:`, `Help:`) do not count
+fn first_article_link(item: ElementRef) -> Option<ElementRef> {
+    item.children().filter_map(ElementRef::wrap).find_map(|child| {
+        let name = child.value().name();
+        if matches!(name, "ul" | "ol" | "dl" | "sup") || has_skipped_class(child) {
+            return None;
+        }
+        if name == "a" {
+            let href = child.value().attr("href")?;
+            let article = href.strip_prefix("/wiki/")?;
+            return (!article.contains(':') && !article.is_empty()).then_some(child);
+        }
+        first_article_link(child)
+    })
+}
+
+/// Text as a reader sees it: no footnote markers, edit links or nested
+/// lists, with whitespace collapsed
+fn visible_text(element: ElementRef) -> String {
+    fn collect(element: ElementRef, out: &mut String) {
+        for child in element.children() {
+            match child.value() {
+                Node::Text(text) => out.push_str(text),
+                Node::Element(inner) => {
+                    if matches!(inner.name(), "ul" | "ol" | "dl" | "sup" | "style" | "script") {
+                        continue;
+                    }
+                    if let Some(inner) = ElementRef::wrap(child).filter(|inner| !has_skipped_class(*inner)) {
+                        collect(inner, out);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut text = String::new();
+    collect(element, &mut text);
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn first_sentence(text: &str) -> String {
+    for (at, c) in text.char_indices() {
+        if c != '.' {
+            continue;
+        }
+        let rest = &text[at + 1..];
+        let next_word = rest.trim_start();
+        // "e.g. a heap" goes on; ". The" ends the sentence
+        if rest.is_empty() || (rest.starts_with(' ') && next_word.starts_with(|c: char| c.is_uppercase())) {
+            return text[..=at].to_string();
+        }
+    }
+    text.trim_end().to_string()
+}
+
+fn has_skipped_class(element: ElementRef) -> bool {
+    element.value().classes().any(|class| SKIPPED_CLASSES.contains(&class)) || element.value().id() == Some("toc")
+}
+
+fn is_skipped(element: ElementRef) -> bool {
+    std::iter::once(element)
+        .chain(element.ancestors().filter_map(ElementRef::wrap))
+        .any(has_skipped_class)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> String {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/wikipedia").join(name);
+        std::fs::read_to_string(path).unwrap()
+    }
+
+    fn find<'a>(algorithms: &'a [ListedArticle], title: &str) -> &'a ListedArticle {
+        algorithms.iter().find(|algorithm| algorithm.title == title).unwrap_or_else(|| panic!("{title} not parsed"))
+    }
+
+    #[test]
+    fn test_parses_current_markup() {
+        let algorithms = parse_algorithm_list(&fixture("list_of_algorithms.html"));
+
+        let dijkstra = find(&algorithms, "Dijkstra's algorithm");
+        assert_eq!(dijkstra.url, "https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm");
+        assert_eq!(dijkstra.section, ["Combinatorial algorithms", "Graph algorithms", "Routing for graphs"]);
+        assert_eq!(
+            dijkstra.summary.as_deref(),
+            Some("computes shortest paths in a graph with non-negative edge weights.")
+        );
+        assert_eq!(dijkstra.algorithm_category(), AlgorithmCategory::Graph);
+
+        // Items nested under another item are listed on their own, and the
+        // parent's summary stops before them
+        let heapsort = find(&algorithms, "Heapsort");
+        assert_eq!(heapsort.summary.as_deref(), Some("convert the list into a heap, keep removing the largest element from the heap and adding it to the end of the list."));
+        assert_eq!(find(&algorithms, "Smoothsort").algorithm_category(), AlgorithmCategory::Sorting);
+        assert_eq!(find(&algorithms, "Quicksort").summary.as_deref(), Some("divide list into two, with all items on the first list coming before all items on the second list."));
+        assert_eq!(find(&algorithms, "RSA").algorithm_category(), AlgorithmCategory::Cryptographic);
+        assert_eq!(find(&algorithms, "Euclidean algorithm").algorithm_category(), AlgorithmCategory::Mathematical);
+        assert_eq!(find(&algorithms, "Bogosort").summary, None);
+
+        let titles: Vec<&str> = algorithms.iter().map(|algorithm| algorithm.title.as_str()).collect();
+        // Red links, the contents box, navboxes, footnotes and the back matter are not algorithms
+        for absent in ["Nonexistent sort", "Sequence sorting", "Sorting algorithm", "List of data structures", "Wikipedia"] {
+            assert!(!titles.contains(&absent), "{absent} was parsed");
+        }
+        // An article listed in two sections is kept once, where it first appears
+        assert_eq!(titles.iter().filter(|&&title| title == "Binary search algorithm").count(), 1);
+        assert!(algorithms.iter().all(|algorithm| !algorithm.summary.as_deref().unwrap_or("").contains('[')));
+
+        assert_eq!(
+            parse_sections(&fixture("list_of_algorithms.html")),
+            ["Combinatorial algorithms", "Computational mathematics", "Computer science"]
+        );
+    }
+
+    #[test]
+    fn test_parses_legacy_markup() {
+        let algorithms = parse_algorithm_list(&fixture("list_of_algorithms_legacy.html"));
+        let titles: Vec<&str> = algorithms.iter().map(|algorithm| algorithm.title.as_str()).collect();
+        assert_eq!(titles, ["Bubble sort", "Merge sort", "Linear search", "Knuth–Morris–Pratt algorithm"]);
+
+        let merge = find(&algorithms, "Merge sort");
+        assert_eq!(merge.section, ["Sequence algorithms", "Sequence sorting"]);
+        assert_eq!(merge.summary.as_deref(), Some("sort the first and second half of the list separately, then merge the sorted lists."));
+        assert_eq!(find(&algorithms, "Knuth–Morris–Pratt algorithm").algorithm_category(), AlgorithmCategory::String);
+        assert_eq!(find(&algorithms, "Linear search").algorithm_category(), AlgorithmCategory::Searching);
+    }
+
+    #[test]
+    fn test_parses_data_structure_list() {
+        let data_structures = parse_data_structure_list(&fixture("list_of_data_structures.html"));
+        let category = |title: &str| find(&data_structures, title).data_structure_category();
+
+        let titles: Vec<&str> = data_structures.iter().map(|item| item.title.as_str()).collect();
+        // Language types, red links, navboxes and the back matter are not data structures
+        for absent in ["Boolean", "Integer", "Record", "Tangled list", "Zipper", "List of algorithms"] {
+            assert!(!titles.contains(&absent), "{absent} was parsed");
+        }
+        assert_eq!(titles.iter().filter(|&&title| title == "Stack").count(), 1);
+
+        let dynamic_array = find(&data_structures, "Dynamic array");
+        assert_eq!(dynamic_array.url, "https://en.wikipedia.org/wiki/Dynamic_array");
+        assert_eq!(dynamic_array.section, ["Linear data structures", "Arrays"]);
+        assert_eq!(dynamic_array.summary.as_deref(), Some("an array that grows as elements are added."));
+        assert_eq!(find(&data_structures, "Stack").summary.as_deref(), Some("last in, first out"));
+
+        assert_eq!(category("Dynamic array"), DataStructureCategory::Linear);
+        assert_eq!(category("Skip list"), DataStructureCategory::Linear);
+        assert_eq!(category("Red–black tree"), DataStructureCategory::Tree);
+        assert_eq!(category("Trie"), DataStructureCategory::Tree);
+        assert_eq!(category("Binary heap"), DataStructureCategory::Heap);
+        assert_eq!(category("Bloom filter"), DataStructureCategory::HashBased);
+        assert_eq!(category("Adjacency list"), DataStructureCategory::Graph);
+        // Abstract data types are judged by their title
+        assert_eq!(category("Associative array"), DataStructureCategory::HashBased);
+        assert_eq!(category("Priority queue"), DataStructureCategory::Queue);
+        assert_eq!(category("Stack"), DataStructureCategory::Stack);
+        assert_eq!(category("Set"), DataStructureCategory::Set);
+        assert_eq!(category("Symbol table"), DataStructureCategory::Other);
+    }
+
+    #[test]
+    fn test_category_for_section() {
+        let section = |headings: &[&str]| headings.iter().map(|heading| heading.to_string()).collect::<Vec<_>>();
+        assert_eq!(category_for_section(&section(&["Combinatorial algorithms", "Graph algorithms", "Graph search"])), AlgorithmCategory::Graph);
+        assert_eq!(category_for_section(&section(&["Computer science", "Compilers"])), AlgorithmCategory::Other);
+        assert_eq!(category_for_section(&section(&["Computer science", "Cryptography"])), AlgorithmCategory::Cryptographic);
+        assert_eq!(category_for_section(&section(&["Computational mathematics", "Geometry"])), AlgorithmCategory::Mathematical);
+        assert_eq!(category_for_section(&[]), AlgorithmCategory::Other);
+    }
+}
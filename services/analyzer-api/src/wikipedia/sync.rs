@@ -0,0 +1,98 @@
+//! Planning what a fetch changes in `wikipedia_algorithms`
+//!
+//! Rows are keyed by article URL. A fetch adds articles that are new or
+//! listed again after removal, updates those whose fields changed, touches
+//! the rest, and soft-deletes stored articles the list no longer has.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::models::wikipedia_algorithm::{StoredWikipediaAlgorithm, WikipediaAlgorithm, WikipediaSyncPlan};
+
+pub fn plan_sync(stored: &[StoredWikipediaAlgorithm], fetched: Vec<WikipediaAlgorithm>) -> WikipediaSyncPlan {
+    let by_url: HashMap<&str, &StoredWikipediaAlgorithm> = stored.iter().map(|row| (row.algorithm.url.as_str(), row)).collect();
+    let mut plan = WikipediaSyncPlan::default();
+    let mut listed = HashSet::new();
+
+    for algorithm in fetched {
+        if !listed.insert(algorithm.url.clone()) {
+            continue;
+        }
+        match by_url.get(algorithm.url.as_str()) {
+            None => plan.added.push(algorithm),
+            Some(row) if row.removed_at.is_some() => plan.added.push(algorithm),
+            Some(row) if algorithm.differs_from(&row.algorithm) => plan.changed.push(algorithm),
+            Some(_) => plan.unchanged.push(algorithm),
+        }
+    }
+
+    // An empty fetch means the page or the parser broke, not that every
+    // article went away
+    if !listed.is_empty() {
+        plan.removed = stored
+            .iter()
+            .filter(|row| row.removed_at.is_none() && !listed.contains(&row.algorithm.url))
+            .map(|row| row.algorithm.clone())
+            .collect();
+    }
+
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn algorithm(title: &str, description: &str, revision_id: u64) -> WikipediaAlgorithm {
+        WikipediaAlgorithm {
+            title: title.to_string(),
+            url: format!("https://en.wikipedia.org/wiki/{}", title.replace(' ', "_")),
+            category: "Sorting".to_string(),
+            description: Some(description.to_string()),
+            complexity: None,
+            revision_id: Some(revision_id),
+        }
+    }
+
+    fn stored(algorithm: WikipediaAlgorithm, removed: bool) -> StoredWikipediaAlgorithm {
+        StoredWikipediaAlgorithm {
+            algorithm,
+            last_seen_at: Some(Utc::now()),
+            removed_at: removed.then(Utc::now),
+        }
+    }
+
+    #[test]
+    fn test_plan_sync() {
+        let rows = vec![
+            stored(algorithm("Quicksort", "Divide and conquer.", 1), false),
+            stored(algorithm("Heapsort", "Uses a heap.", 1), false),
+            stored(algorithm("Bogosort", "Shuffles.", 1), false),
+            stored(algorithm("Stooge sort", "Recursive.", 1), true),
+        ];
+        let fetched = vec![
+            // A new revision with the same fields is not a change
+            algorithm("Quicksort", "Divide and conquer.", 2),
+            algorithm("Heapsort", "Uses a binary heap.", 2),
+            algorithm("Stooge sort", "Recursive.", 1),
+            algorithm("Smoothsort", "A heapsort variant.", 1),
+            algorithm("Smoothsort", "A heapsort variant.", 1),
+        ];
+
+        let plan = plan_sync(&rows, fetched);
+        let report = plan.report();
+        assert_eq!(report.added, ["Stooge sort", "Smoothsort"]);
+        assert_eq!(report.changed, ["Heapsort"]);
+        assert_eq!(report.removed, ["Bogosort"]);
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(plan.unchanged[0].revision_id, Some(2));
+        assert_eq!(report.to_string(), "2 added, 1 changed, 1 removed, 1 unchanged");
+
+        // Running the same fetch against its own result changes nothing
+        let rows: Vec<_> = plan.added.iter().chain(&plan.changed).chain(&plan.unchanged).cloned().map(|a| stored(a, false)).collect();
+        let again = plan_sync(&rows, rows.iter().map(|row| row.algorithm.clone()).collect()).report();
+        assert_eq!((again.added.len(), again.changed.len(), again.removed.len(), again.unchanged), (0, 0, 0, 4));
+
+        assert!(plan_sync(&rows, Vec::new()).removed.is_empty());
+    }
+}
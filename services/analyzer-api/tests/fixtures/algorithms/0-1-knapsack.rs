@@ -0,0 +1,9 @@
+fn knapsack(weights: &[usize], values: &[u64], capacity: usize) -> u64 {
+    let mut best = vec![0u64; capacity + 1];
+    for i in 0..weights.len() {
+        for c in (weights[i]..=capacity).rev() {
+            best[c] = best[c].max(best[c - weights[i]] + values[i]);
+        }
+    }
+    best[capacity]
+}
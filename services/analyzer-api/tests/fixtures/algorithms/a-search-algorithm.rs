@@ -0,0 +1,32 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+type Pos = (i32, i32);
+
+fn heuristic(a: Pos, b: Pos) -> u32 {
+    ((a.0 - b.0).abs() + (a.1 - b.1).abs()) as u32
+}
+
+fn find_path(walls: &[Pos], start: Pos, goal: Pos) -> Option<u32> {
+    let mut open = BinaryHeap::new();
+    let mut g_score: HashMap<Pos, u32> = HashMap::new();
+    g_score.insert(start, 0);
+    open.push(Reverse((heuristic(start, goal), start)));
+    while let Some(Reverse((_, current))) = open.pop() {
+        if current == goal {
+            return g_score.get(&goal).copied();
+        }
+        for (dx, dy) in [(0, 1), (1, 0), (0, -1), (-1, 0)] {
+            let next = (current.0 + dx, current.1 + dy);
+            if walls.contains(&next) {
+                continue;
+            }
+            let tentative = g_score[&current] + 1;
+            if tentative < *g_score.get(&next).unwrap_or(&u32::MAX) {
+                g_score.insert(next, tentative);
+                open.push(Reverse((tentative + heuristic(next, goal), next)));
+            }
+        }
+    }
+    None
+}
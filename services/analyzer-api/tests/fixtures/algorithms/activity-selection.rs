@@ -0,0 +1,12 @@
+fn max_meetings(mut activities: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    activities.sort_by_key(|&(_, finish)| finish);
+    let mut chosen = Vec::new();
+    let mut last_finish = 0;
+    for (start, finish) in activities {
+        if start >= last_finish {
+            chosen.push((start, finish));
+            last_finish = finish;
+        }
+    }
+    chosen
+}
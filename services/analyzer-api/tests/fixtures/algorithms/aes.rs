@@ -0,0 +1,21 @@
+fn sub_bytes(state: &mut [u8; 16], sbox: &[u8; 256]) {
+    for byte in state.iter_mut() {
+        *byte = sbox[*byte as usize];
+    }
+}
+
+fn shift_rows(state: &mut [u8; 16]) {
+    for row in 1..4 {
+        let mut r = [state[row], state[row + 4], state[row + 8], state[row + 12]];
+        r.rotate_left(row);
+        for col in 0..4 {
+            state[row + 4 * col] = r[col];
+        }
+    }
+}
+
+fn add_round_key(state: &mut [u8; 16], key: &[u8; 16]) {
+    for (s, k) in state.iter_mut().zip(key) {
+        *s ^= k;
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Default)]
+struct State {
+    next: HashMap<u8, usize>,
+    fail: usize,
+    output: Vec<usize>,
+}
+
+fn build(patterns: &[&[u8]]) -> Vec<State> {
+    let mut states = vec![State::default()];
+    for (id, pattern) in patterns.iter().enumerate() {
+        let mut s = 0;
+        for &c in pattern.iter() {
+            s = match states[s].next.get(&c) {
+                Some(&t) => t,
+                None => {
+                    states.push(State::default());
+                    let t = states.len() - 1;
+                    states[s].next.insert(c, t);
+                    t
+                }
+            };
+        }
+        states[s].output.push(id);
+    }
+    let mut queue: VecDeque<usize> = states[0].next.values().copied().collect();
+    while let Some(s) = queue.pop_front() {
+        let edges: Vec<(u8, usize)> = states[s].next.iter().map(|(&c, &t)| (c, t)).collect();
+        for (c, t) in edges {
+            let mut f = states[s].fail;
+            while f != 0 && !states[f].next.contains_key(&c) {
+                f = states[f].fail;
+            }
+            let target = states[f].next.get(&c).copied().filter(|&x| x != t).unwrap_or(0);
+            states[t].fail = target;
+            let inherited = states[target].output.clone();
+            states[t].output.extend(inherited);
+            queue.push_back(t);
+        }
+    }
+    states
+}
@@ -0,0 +1,27 @@
+struct Node {
+    key: i32,
+    height: i32,
+    left: Option<Box<Node>>,
+    right: Option<Box<Node>>,
+}
+
+fn height(node: &Option<Box<Node>>) -> i32 {
+    node.as_ref().map_or(0, |n| n.height)
+}
+
+fn rotate_right(mut node: Box<Node>) -> Box<Node> {
+    let mut pivot = node.left.take().unwrap();
+    node.left = pivot.right.take();
+    node.height = 1 + height(&node.left).max(height(&node.right));
+    pivot.right = Some(node);
+    pivot.height = 1 + height(&pivot.left).max(height(&pivot.right));
+    pivot
+}
+
+fn rebalance(node: Box<Node>) -> Box<Node> {
+    let balance = height(&node.left) - height(&node.right);
+    if balance > 1 {
+        return rotate_right(node);
+    }
+    node
+}
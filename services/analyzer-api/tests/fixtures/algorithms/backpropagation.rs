@@ -0,0 +1,13 @@
+fn train_step(x: &[f64], target: f64, w1: &mut Vec<Vec<f64>>, w2: &mut Vec<f64>, rate: f64) {
+    let act = |z: f64| 1.0 / (1.0 + (-z).exp());
+    let hidden: Vec<f64> = w1.iter().map(|weights| act(weights.iter().zip(x).map(|(w, xi)| w * xi).sum())).collect();
+    let output = act(hidden.iter().zip(w2.iter()).map(|(h, w)| h * w).sum());
+    let output_delta = (output - target) * output * (1.0 - output);
+    for h in 0..hidden.len() {
+        let delta = output_delta * w2[h] * hidden[h] * (1.0 - hidden[h]);
+        w2[h] -= rate * output_delta * hidden[h];
+        for i in 0..x.len() {
+            w1[h][i] -= rate * delta * x[i];
+        }
+    }
+}
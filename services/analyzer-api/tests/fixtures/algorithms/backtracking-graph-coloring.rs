@@ -0,0 +1,19 @@
+fn is_safe(adj: &[Vec<usize>], colors: &[usize], v: usize, c: usize) -> bool {
+    adj[v].iter().all(|&u| colors[u] != c)
+}
+
+fn assign(adj: &[Vec<usize>], m: usize, colors: &mut Vec<usize>, v: usize) -> bool {
+    if v == adj.len() {
+        return true;
+    }
+    for c in 1..=m {
+        if is_safe(adj, colors, v, c) {
+            colors[v] = c;
+            if assign(adj, m, colors, v + 1) {
+                return true;
+            }
+            colors[v] = 0;
+        }
+    }
+    false
+}
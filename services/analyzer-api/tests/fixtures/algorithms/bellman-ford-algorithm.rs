@@ -0,0 +1,13 @@
+fn shortest_with_negative_edges(n: usize, edges: &[(usize, usize, i64)], src: usize) -> Option<Vec<i64>> {
+    let mut dist = vec![i64::MAX; n];
+    dist[src] = 0;
+    for _ in 1..n {
+        for &(u, v, w) in edges {
+            if dist[u] != i64::MAX && dist[u] + w < dist[v] {
+                dist[v] = dist[u] + w;
+            }
+        }
+    }
+    let negative = edges.iter().any(|&(u, v, w)| dist[u] != i64::MAX && dist[u] + w < dist[v]);
+    if negative { None } else { Some(dist) }
+}
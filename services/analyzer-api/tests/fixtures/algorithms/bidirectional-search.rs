@@ -0,0 +1,33 @@
+use std::collections::{HashMap, VecDeque};
+
+fn meet_in_middle(adj: &[Vec<usize>], start: usize, goal: usize) -> Option<usize> {
+    let mut forward = VecDeque::from([start]);
+    let mut backward = VecDeque::from([goal]);
+    let mut visited_from_start = HashMap::from([(start, 0)]);
+    let mut visited_from_goal = HashMap::from([(goal, 0)]);
+    while !forward.is_empty() && !backward.is_empty() {
+        if let Some(u) = forward.pop_front() {
+            for &v in &adj[u] {
+                if let Some(d) = visited_from_goal.get(&v) {
+                    return Some(visited_from_start[&u] + 1 + d);
+                }
+                if !visited_from_start.contains_key(&v) {
+                    visited_from_start.insert(v, visited_from_start[&u] + 1);
+                    forward.push_back(v);
+                }
+            }
+        }
+        if let Some(u) = backward.pop_front() {
+            for &v in &adj[u] {
+                if let Some(d) = visited_from_start.get(&v) {
+                    return Some(visited_from_goal[&u] + 1 + d);
+                }
+                if !visited_from_goal.contains_key(&v) {
+                    visited_from_goal.insert(v, visited_from_goal[&u] + 1);
+                    backward.push_back(v);
+                }
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,23 @@
+struct Node {
+    key: i32,
+    left: Option<Box<Node>>,
+    right: Option<Box<Node>>,
+}
+
+impl Node {
+    fn insert(&mut self, key: i32) {
+        let child = if key < self.key { &mut self.left } else { &mut self.right };
+        match child {
+            Some(node) => node.insert(key),
+            None => *child = Some(Box::new(Node { key, left: None, right: None })),
+        }
+    }
+
+    fn contains(&self, key: i32) -> bool {
+        if key == self.key {
+            return true;
+        }
+        let child = if key < self.key { &self.left } else { &self.right };
+        child.as_ref().is_some_and(|node| node.contains(key))
+    }
+}
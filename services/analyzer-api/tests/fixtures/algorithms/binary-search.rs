@@ -0,0 +1,14 @@
+fn binary_search(sorted: &[i32], target: i32) -> Option<usize> {
+    let (mut left, mut right) = (0, sorted.len());
+    while left < right {
+        let mid = left + (right - left) / 2;
+        if sorted[mid] == target {
+            return Some(mid);
+        } else if sorted[mid] < target {
+            left = mid + 1;
+        } else {
+            right = mid;
+        }
+    }
+    None
+}
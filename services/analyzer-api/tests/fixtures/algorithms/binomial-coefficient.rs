@@ -0,0 +1,11 @@
+fn pascal_triangle(rows: usize) -> Vec<Vec<u64>> {
+    let mut triangle: Vec<Vec<u64>> = Vec::new();
+    for n in 0..rows {
+        let mut row = vec![1u64; n + 1];
+        for k in 1..n {
+            row[k] = triangle[n - 1][k - 1] + triangle[n - 1][k];
+        }
+        triangle.push(row);
+    }
+    triangle
+}
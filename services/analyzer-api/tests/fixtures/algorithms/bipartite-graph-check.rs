@@ -0,0 +1,26 @@
+use std::collections::VecDeque;
+
+fn two_colorable(adj: &[Vec<usize>]) -> bool {
+    let mut side = vec![None; adj.len()];
+    for start in 0..adj.len() {
+        if side[start].is_some() {
+            continue;
+        }
+        side[start] = Some(0);
+        let mut queue = VecDeque::from([start]);
+        while let Some(u) = queue.pop_front() {
+            let s = side[u].unwrap();
+            for &v in &adj[u] {
+                match side[v] {
+                    None => {
+                        side[v] = Some(1 - side[u].unwrap());
+                        queue.push_back(v);
+                    }
+                    Some(t) if t == s => return false,
+                    _ => {}
+                }
+            }
+        }
+    }
+    true
+}
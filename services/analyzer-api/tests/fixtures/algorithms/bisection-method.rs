@@ -0,0 +1,11 @@
+fn bisection(f: impl Fn(f64) -> f64, mut a: f64, mut b: f64, eps: f64) -> f64 {
+    while b - a > eps {
+        let mid = (a + b) / 2.0;
+        if f(a) * f(mid) <= 0.0 {
+            b = mid;
+        } else {
+            a = mid;
+        }
+    }
+    (a + b) / 2.0
+}
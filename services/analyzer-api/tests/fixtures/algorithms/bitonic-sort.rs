@@ -0,0 +1,23 @@
+fn sort(v: &mut [i32], ascending: bool) {
+    if v.len() <= 1 {
+        return;
+    }
+    let half = v.len() / 2;
+    sort(&mut v[..half], true);
+    sort(&mut v[half..], false);
+    merge_halves(v, ascending);
+}
+
+fn merge_halves(v: &mut [i32], ascending: bool) {
+    if v.len() <= 1 {
+        return;
+    }
+    let half = v.len() / 2;
+    for i in 0..half {
+        if (v[i] > v[i + half]) == ascending {
+            v.swap(i, i + half);
+        }
+    }
+    merge_halves(&mut v[..half], ascending);
+    merge_halves(&mut v[half..], ascending);
+}
@@ -0,0 +1,21 @@
+struct Filter {
+    bits: Vec<bool>,
+    hashes: u64,
+}
+
+impl Filter {
+    fn index(&self, item: u64, i: u64) -> usize {
+        (item.wrapping_mul(0x9E3779B97F4A7C15).wrapping_add(i.wrapping_mul(0xBF58476D1CE4E5B9)) % self.bits.len() as u64) as usize
+    }
+
+    fn insert(&mut self, item: u64) {
+        for i in 0..self.hashes {
+            let at = self.index(item, i);
+            self.bits[at] = true;
+        }
+    }
+
+    fn might_contain(&self, item: u64) -> bool {
+        (0..self.hashes).all(|i| self.bits[self.index(item, i)])
+    }
+}
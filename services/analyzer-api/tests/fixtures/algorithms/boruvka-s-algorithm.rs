@@ -0,0 +1,44 @@
+fn find(components: &mut Vec<usize>, x: usize) -> usize {
+    let mut root = x;
+    while components[root] != root {
+        root = components[root];
+    }
+    root
+}
+
+fn forest_weight(n: usize, edges: &[(usize, usize, u64)]) -> u64 {
+    let mut components: Vec<usize> = (0..n).collect();
+    let mut total = 0;
+    let mut count = n;
+    while count > 1 {
+        let mut cheapest: Vec<Option<usize>> = vec![None; n];
+        for (i, &(u, v, w)) in edges.iter().enumerate() {
+            let (a, b) = (find(&mut components, u), find(&mut components, v));
+            if a == b {
+                continue;
+            }
+            for c in [a, b] {
+                if cheapest[c].is_none_or(|j| edges[j].2 > w) {
+                    cheapest[c] = Some(i);
+                }
+            }
+        }
+        let mut merged = false;
+        for c in 0..n {
+            if let Some(i) = cheapest[c] {
+                let (u, v, w) = edges[i];
+                let (a, b) = (find(&mut components, u), find(&mut components, v));
+                if a != b {
+                    components[a] = b;
+                    total += w;
+                    count -= 1;
+                    merged = true;
+                }
+            }
+        }
+        if !merged {
+            break;
+        }
+    }
+    total
+}
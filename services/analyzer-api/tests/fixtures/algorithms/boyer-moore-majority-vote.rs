@@ -0,0 +1,11 @@
+fn majority_element(nums: &[i32]) -> Option<i32> {
+    let mut candidate = 0;
+    let mut count = 0;
+    for &x in nums {
+        if count == 0 {
+            candidate = x;
+        }
+        count += if x == candidate { 1 } else { -1 };
+    }
+    (nums.iter().filter(|&&x| x == candidate).count() * 2 > nums.len()).then_some(candidate)
+}
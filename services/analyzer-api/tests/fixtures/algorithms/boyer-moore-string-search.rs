@@ -0,0 +1,20 @@
+fn search(text: &[u8], pattern: &[u8]) -> Option<usize> {
+    let m = pattern.len();
+    let mut bad_char = [-1isize; 256];
+    for (i, &c) in pattern.iter().enumerate() {
+        bad_char[c as usize] = i as isize;
+    }
+    let mut s = 0;
+    while s + m <= text.len() {
+        let mut j = m as isize - 1;
+        while j >= 0 && pattern[j as usize] == text[s + j as usize] {
+            j -= 1;
+        }
+        if j < 0 {
+            return Some(s);
+        }
+        let shift = j - bad_char[text[s + j as usize] as usize];
+        s += shift.max(1) as usize;
+    }
+    None
+}
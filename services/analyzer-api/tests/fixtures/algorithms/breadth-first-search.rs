@@ -0,0 +1,20 @@
+use std::collections::VecDeque;
+
+fn distances(adj: &[Vec<usize>], start: usize) -> Vec<Option<usize>> {
+    let mut dist = vec![None; adj.len()];
+    let mut visited = vec![false; adj.len()];
+    let mut queue = VecDeque::new();
+    visited[start] = true;
+    dist[start] = Some(0);
+    queue.push_back(start);
+    while let Some(u) = queue.pop_front() {
+        for &v in &adj[u] {
+            if !visited[v] {
+                visited[v] = true;
+                dist[v] = dist[u].map(|d| d + 1);
+                queue.push_back(v);
+            }
+        }
+    }
+    dist
+}
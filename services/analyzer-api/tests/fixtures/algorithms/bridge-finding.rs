@@ -0,0 +1,19 @@
+fn find_bridges(adj: &[Vec<usize>], u: usize, parent: Option<usize>, timer: &mut usize, disc: &mut [usize], low: &mut [usize], bridges: &mut Vec<(usize, usize)>) {
+    *timer += 1;
+    disc[u] = *timer;
+    low[u] = *timer;
+    for &v in &adj[u] {
+        if Some(v) == parent {
+            continue;
+        }
+        if disc[v] == 0 {
+            find_bridges(adj, v, Some(u), timer, disc, low, bridges);
+            low[u] = low[u].min(low[v]);
+            if low[v] > disc[u] {
+                bridges.push((u, v));
+            }
+        } else {
+            low[u] = low[u].min(disc[v]);
+        }
+    }
+}
@@ -0,0 +1,12 @@
+fn bucket_sort(values: &[f64]) -> Vec<f64> {
+    let n = values.len().max(1);
+    let mut buckets: Vec<Vec<f64>> = vec![Vec::new(); n];
+    for &v in values {
+        let index = ((v * n as f64) as usize).min(n - 1);
+        buckets[index].push(v);
+    }
+    for bucket in buckets.iter_mut() {
+        bucket.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    }
+    buckets.concat()
+}
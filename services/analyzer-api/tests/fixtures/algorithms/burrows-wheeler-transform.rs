@@ -0,0 +1,12 @@
+fn transform(input: &str) -> (String, usize) {
+    let n = input.len();
+    let doubled = format!("{input}{input}");
+    let mut rotations: Vec<&str> = (0..n).map(|i| &doubled[i..i + n]).collect();
+    rotations.sort();
+    let index = rotations.iter().position(|r| *r == input).unwrap_or(0);
+    let mut last_column = String::with_capacity(n);
+    for r in &rotations {
+        last_column.push(r.chars().last().unwrap());
+    }
+    (last_column, index)
+}
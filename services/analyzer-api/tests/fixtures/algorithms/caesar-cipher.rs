@@ -0,0 +1,12 @@
+fn encrypt(text: &str, shift: u8) -> String {
+    text.chars()
+        .map(|c| {
+            if c.is_ascii_alphabetic() {
+                let base = if c.is_ascii_lowercase() { b'a' } else { b'A' };
+                (((c as u8 - base + shift) % 26) + base) as char
+            } else {
+                c
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,13 @@
+fn solve_congruences(remainders: &[i64], moduli: &[i64]) -> Option<i64> {
+    let product: i64 = moduli.iter().product();
+    let mut sum = 0;
+    for (&r, &m) in remainders.iter().zip(moduli) {
+        let p = product / m;
+        sum += r * inverse(p, m)? * p;
+    }
+    Some(sum.rem_euclid(product))
+}
+
+fn inverse(a: i64, m: i64) -> Option<i64> {
+    (1..m).find(|x| (a * x).rem_euclid(m) == 1)
+}
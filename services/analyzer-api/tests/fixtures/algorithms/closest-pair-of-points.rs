@@ -0,0 +1,32 @@
+type Point = (f64, f64);
+
+fn dist(a: Point, b: Point) -> f64 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+fn closest(points: &[Point]) -> f64 {
+    if points.len() <= 3 {
+        let mut best = f64::INFINITY;
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                best = best.min(dist(points[i], points[j]));
+            }
+        }
+        return best;
+    }
+    let mid = points.len() / 2;
+    let mid_x = points[mid].0;
+    let d = closest(&points[..mid]).min(closest(&points[mid..]));
+    let mut strip: Vec<Point> = points.iter().copied().filter(|p| (p.0 - mid_x).abs() < d).collect();
+    strip.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let mut best = d;
+    for i in 0..strip.len() {
+        for j in i + 1..strip.len() {
+            if strip[j].1 - strip[i].1 >= best {
+                break;
+            }
+            best = best.min(dist(strip[i], strip[j]));
+        }
+    }
+    best
+}
@@ -0,0 +1,22 @@
+fn cocktail_sort(a: &mut [i32]) {
+    let mut swapped = true;
+    while swapped {
+        swapped = false;
+        for i in 0..a.len() - 1 {
+            if a[i] > a[i + 1] {
+                a.swap(i, i + 1);
+                swapped = true;
+            }
+        }
+        if !swapped {
+            break;
+        }
+        swapped = false;
+        for i in (0..a.len() - 1).rev() {
+            if a[i] > a[i + 1] {
+                a.swap(i, i + 1);
+                swapped = true;
+            }
+        }
+    }
+}
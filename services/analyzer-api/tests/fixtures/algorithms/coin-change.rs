@@ -0,0 +1,12 @@
+fn min_coins(coins: &[usize], amount: usize) -> Option<usize> {
+    let mut dp = vec![usize::MAX; amount + 1];
+    dp[0] = 0;
+    for a in 1..=amount {
+        for &coin in coins {
+            if coin <= a && dp[a - coin] != usize::MAX {
+                dp[a] = dp[a].min(dp[a - coin] + 1);
+            }
+        }
+    }
+    (dp[amount] != usize::MAX).then_some(dp[amount])
+}
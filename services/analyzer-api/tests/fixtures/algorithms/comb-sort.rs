@@ -0,0 +1,17 @@
+fn comb_sort(a: &mut [i32]) {
+    let mut gap = a.len();
+    let mut sorted = false;
+    while !sorted {
+        gap = gap * 10 / 13;
+        if gap <= 1 {
+            gap = 1;
+            sorted = true;
+        }
+        for i in 0..a.len() - gap {
+            if a[i] > a[i + gap] {
+                a.swap(i, i + gap);
+                sorted = false;
+            }
+        }
+    }
+}
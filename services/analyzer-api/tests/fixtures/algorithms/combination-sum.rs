@@ -0,0 +1,14 @@
+fn combinations(candidates: &[u32], start: usize, remaining: u32, chosen: &mut Vec<u32>, out: &mut Vec<Vec<u32>>) {
+    if remaining == 0 {
+        out.push(chosen.clone());
+        return;
+    }
+    for i in start..candidates.len() {
+        if candidates[i] > remaining {
+            continue;
+        }
+        chosen.push(candidates[i]);
+        combinations(candidates, i, remaining - candidates[i], chosen, out);
+        chosen.pop();
+    }
+}
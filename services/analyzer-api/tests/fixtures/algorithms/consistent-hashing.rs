@@ -0,0 +1,15 @@
+use std::collections::BTreeMap;
+
+struct Ring {
+    ring: BTreeMap<u64, String>,
+}
+
+impl Ring {
+    fn node_for(&self, key_hash: u64) -> Option<&String> {
+        self.ring
+            .range(key_hash..)
+            .next()
+            .or_else(|| self.ring.iter().next())
+            .map(|(_, node)| node)
+    }
+}
@@ -0,0 +1,23 @@
+fn sort_and_count(v: &mut Vec<i32>) -> u64 {
+    if v.len() <= 1 {
+        return 0;
+    }
+    let mut right = v.split_off(v.len() / 2);
+    let mut inversions = sort_and_count(v) + sort_and_count(&mut right);
+    let mut merged = Vec::with_capacity(v.len() + right.len());
+    let (mut i, mut j) = (0, 0);
+    while i < v.len() && j < right.len() {
+        if v[i] <= right[j] {
+            merged.push(v[i]);
+            i += 1;
+        } else {
+            merged.push(right[j]);
+            inversions += (v.len() - i) as u64;
+            j += 1;
+        }
+    }
+    merged.extend_from_slice(&v[i..]);
+    merged.extend_from_slice(&right[j..]);
+    *v = merged;
+    inversions
+}
@@ -0,0 +1,15 @@
+fn counting_sort(input: &[usize], max: usize) -> Vec<usize> {
+    let mut count = vec![0; max + 1];
+    for &x in input {
+        count[x] += 1;
+    }
+    for i in 1..=max {
+        count[i] += count[i - 1];
+    }
+    let mut output = vec![0; input.len()];
+    for &x in input.iter().rev() {
+        count[x] -= 1;
+        output[count[x]] = x;
+    }
+    output
+}
@@ -0,0 +1,21 @@
+struct Table {
+    tables: [Vec<Option<u64>>; 2],
+}
+
+impl Table {
+    fn slot(&self, t: usize, key: u64) -> usize {
+        (key.wrapping_mul(if t == 0 { 0x9E37 } else { 0x85EB }) as usize) % self.tables[t].len()
+    }
+
+    fn insert(&mut self, mut key: u64) -> bool {
+        for attempt in 0..32 {
+            let t = attempt % 2;
+            let slot = self.slot(t, key);
+            match self.tables[t][slot].replace(key) {
+                None => return true,
+                Some(evicted) => key = evicted,
+            }
+        }
+        false
+    }
+}
@@ -0,0 +1,24 @@
+fn cycle_sort(arr: &mut [i32]) -> usize {
+    let mut writes = 0;
+    for cycle_start in 0..arr.len().saturating_sub(1) {
+        let mut item = arr[cycle_start];
+        let mut pos = cycle_start + arr[cycle_start + 1..].iter().filter(|&&x| x < item).count();
+        if pos == cycle_start {
+            continue;
+        }
+        while item == arr[pos] {
+            pos += 1;
+        }
+        std::mem::swap(&mut arr[pos], &mut item);
+        writes += 1;
+        while pos != cycle_start {
+            pos = cycle_start + arr[cycle_start + 1..].iter().filter(|&&x| x < item).count();
+            while item == arr[pos] {
+                pos += 1;
+            }
+            std::mem::swap(&mut arr[pos], &mut item);
+            writes += 1;
+        }
+    }
+    writes
+}
@@ -0,0 +1,43 @@
+#[derive(Clone, Copy, PartialEq)]
+enum Label {
+    Unvisited,
+    Noise,
+    Cluster(usize),
+}
+
+fn neighbors(points: &[(f64, f64)], p: usize, eps: f64) -> Vec<usize> {
+    (0..points.len())
+        .filter(|&q| ((points[p].0 - points[q].0).powi(2) + (points[p].1 - points[q].1).powi(2)).sqrt() <= eps)
+        .collect()
+}
+
+fn cluster(points: &[(f64, f64)], eps: f64, min_points: usize) -> Vec<Label> {
+    let mut labels = vec![Label::Unvisited; points.len()];
+    let mut next = 0;
+    for p in 0..points.len() {
+        if labels[p] != Label::Unvisited {
+            continue;
+        }
+        let mut seeds = neighbors(points, p, eps);
+        if seeds.len() < min_points {
+            labels[p] = Label::Noise;
+            continue;
+        }
+        labels[p] = Label::Cluster(next);
+        while let Some(q) = seeds.pop() {
+            if labels[q] == Label::Noise {
+                labels[q] = Label::Cluster(next);
+            }
+            if labels[q] != Label::Unvisited {
+                continue;
+            }
+            labels[q] = Label::Cluster(next);
+            let more = neighbors(points, q, eps);
+            if more.len() >= min_points {
+                seeds.extend(more);
+            }
+        }
+        next += 1;
+    }
+    labels
+}
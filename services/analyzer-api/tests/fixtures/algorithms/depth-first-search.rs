@@ -0,0 +1,9 @@
+fn dfs(graph: &Vec<Vec<usize>>, node: usize, visited: &mut Vec<bool>, order: &mut Vec<usize>) {
+    visited[node] = true;
+    order.push(node);
+    for &next in &graph[node] {
+        if !visited[next] {
+            dfs(graph, next, visited, order);
+        }
+    }
+}
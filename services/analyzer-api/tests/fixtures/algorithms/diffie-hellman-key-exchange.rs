@@ -0,0 +1,24 @@
+struct Party {
+    secret: u64,
+    public: u64,
+}
+
+fn join(g: u64, p: u64, secret: u64) -> Party {
+    Party { secret, public: mod_pow(g, secret, p) }
+}
+
+fn shared_key(me: &Party, their_public: u64, p: u64) -> u64 {
+    mod_pow(their_public, me.secret, p)
+}
+
+fn mod_pow(mut b: u64, mut e: u64, m: u64) -> u64 {
+    let mut r = 1;
+    while e > 0 {
+        if e & 1 == 1 {
+            r = r * b % m;
+        }
+        b = b * b % m;
+        e >>= 1;
+    }
+    r
+}
@@ -0,0 +1,21 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+fn shortest_paths(adj: &[Vec<(usize, u64)>], source: usize) -> Vec<u64> {
+    let mut dist = vec![u64::MAX; adj.len()];
+    let mut heap = BinaryHeap::new();
+    dist[source] = 0;
+    heap.push(Reverse((0, source)));
+    while let Some(Reverse((d, u))) = heap.pop() {
+        if d > dist[u] {
+            continue;
+        }
+        for &(v, w) in &adj[u] {
+            if d + w < dist[v] {
+                dist[v] = d + w;
+                heap.push(Reverse((dist[v], v)));
+            }
+        }
+    }
+    dist
+}
@@ -0,0 +1,40 @@
+use std::collections::VecDeque;
+
+struct Network {
+    capacity: Vec<Vec<i64>>,
+    level: Vec<i32>,
+}
+
+impl Network {
+    fn bfs(&mut self, source: usize, sink: usize) -> bool {
+        self.level = vec![-1; self.capacity.len()];
+        self.level[source] = 0;
+        let mut queue = VecDeque::from([source]);
+        while let Some(u) = queue.pop_front() {
+            for v in 0..self.capacity.len() {
+                if self.level[v] < 0 && self.capacity[u][v] > 0 {
+                    self.level[v] = self.level[u] + 1;
+                    queue.push_back(v);
+                }
+            }
+        }
+        self.level[sink] >= 0
+    }
+
+    fn send(&mut self, u: usize, sink: usize, pushed: i64) -> i64 {
+        if u == sink {
+            return pushed;
+        }
+        for v in 0..self.capacity.len() {
+            if self.level[v] == self.level[u] + 1 && self.capacity[u][v] > 0 {
+                let sent = self.send(v, sink, pushed.min(self.capacity[u][v]));
+                if sent > 0 {
+                    self.capacity[u][v] -= sent;
+                    self.capacity[v][u] += sent;
+                    return sent;
+                }
+            }
+        }
+        0
+    }
+}
@@ -0,0 +1,7 @@
+fn string_hash(s: &str) -> u64 {
+    let mut hash: u64 = 5381;
+    for c in s.bytes() {
+        hash = (hash << 5).wrapping_add(hash).wrapping_add(c as u64);
+    }
+    hash
+}
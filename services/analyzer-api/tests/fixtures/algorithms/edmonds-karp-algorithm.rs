@@ -0,0 +1,35 @@
+use std::collections::VecDeque;
+
+fn max_flow(mut residual: Vec<Vec<i64>>, source: usize, sink: usize) -> i64 {
+    let n = residual.len();
+    let mut flow = 0;
+    loop {
+        let mut parent = vec![usize::MAX; n];
+        parent[source] = source;
+        let mut queue = VecDeque::from([source]);
+        while let Some(u) = queue.pop_front() {
+            for v in 0..n {
+                if parent[v] == usize::MAX && residual[u][v] > 0 {
+                    parent[v] = u;
+                    queue.push_back(v);
+                }
+            }
+        }
+        if parent[sink] == usize::MAX {
+            return flow;
+        }
+        let mut bottleneck = i64::MAX;
+        let mut v = sink;
+        while v != source {
+            bottleneck = bottleneck.min(residual[parent[v]][v]);
+            v = parent[v];
+        }
+        let mut v = sink;
+        while v != source {
+            residual[parent[v]][v] -= bottleneck;
+            residual[v][parent[v]] += bottleneck;
+            v = parent[v];
+        }
+        flow += bottleneck;
+    }
+}
@@ -0,0 +1,10 @@
+fn unit_fractions(mut num: u64, mut den: u64) -> Vec<u64> {
+    let mut denominators = Vec::new();
+    while num > 0 {
+        let denominator = den.div_ceil(num);
+        denominators.push(denominator);
+        num = num * denominator - den;
+        den *= denominator;
+    }
+    denominators
+}
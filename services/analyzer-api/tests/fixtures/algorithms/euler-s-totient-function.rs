@@ -0,0 +1,17 @@
+fn phi(mut n: u64) -> u64 {
+    let mut result = n;
+    let mut p = 2;
+    while p * p <= n {
+        if n % p == 0 {
+            while n % p == 0 {
+                n /= p;
+            }
+            result -= result / p;
+        }
+        p += 1;
+    }
+    if n > 1 {
+        result -= result / n;
+    }
+    result
+}
@@ -0,0 +1,12 @@
+fn exponential_search(v: &[u32], target: u32) -> Option<usize> {
+    if v.is_empty() {
+        return None;
+    }
+    let mut bound = 1;
+    while bound < v.len() && v[bound] < target {
+        bound *= 2;
+    }
+    let start = bound / 2;
+    let end = (bound + 1).min(v.len());
+    v[start..end].binary_search(&target).ok().map(|i| start + i)
+}
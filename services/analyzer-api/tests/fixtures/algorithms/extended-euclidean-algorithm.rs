@@ -0,0 +1,12 @@
+fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+    let (g, x1, y1) = extended_gcd(b, a % b);
+    (g, y1, x1 - (a / b) * y1)
+}
+
+fn inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a, m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
@@ -0,0 +1,29 @@
+use std::f64::consts::PI;
+
+#[derive(Clone, Copy)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+fn transform(input: &[Complex]) -> Vec<Complex> {
+    let n = input.len();
+    if n == 1 {
+        return input.to_vec();
+    }
+    let even: Vec<Complex> = input.iter().step_by(2).copied().collect();
+    let odd: Vec<Complex> = input.iter().skip(1).step_by(2).copied().collect();
+    let (even, odd) = (transform(&even), transform(&odd));
+    let mut out = vec![Complex { re: 0.0, im: 0.0 }; n];
+    for k in 0..n / 2 {
+        let angle = -2.0 * PI * k as f64 / n as f64;
+        let twiddle = Complex { re: angle.cos(), im: angle.sin() };
+        let t = Complex {
+            re: twiddle.re * odd[k].re - twiddle.im * odd[k].im,
+            im: twiddle.re * odd[k].im + twiddle.im * odd[k].re,
+        };
+        out[k] = Complex { re: even[k].re + t.re, im: even[k].im + t.im };
+        out[k + n / 2] = Complex { re: even[k].re - t.re, im: even[k].im - t.im };
+    }
+    out
+}
@@ -0,0 +1,13 @@
+fn fwht(a: &mut [i64]) {
+    let mut len = 1;
+    while len < a.len() {
+        for i in (0..a.len()).step_by(len * 2) {
+            for j in i..i + len {
+                let (u, v) = (a[j], a[j + len]);
+                a[j] = u + v;
+                a[j + len] = u - v;
+            }
+        }
+        len <<= 1;
+    }
+}
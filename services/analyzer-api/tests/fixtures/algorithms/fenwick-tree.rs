@@ -0,0 +1,21 @@
+struct PrefixSums {
+    tree: Vec<i64>,
+}
+
+impl PrefixSums {
+    fn add(&mut self, mut i: usize, delta: i64) {
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    fn sum(&self, mut i: usize) -> i64 {
+        let mut total = 0;
+        while i > 0 {
+            total += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        total
+    }
+}
@@ -0,0 +1,30 @@
+fn fibonacci_search(a: &[i32], x: i32) -> Option<usize> {
+    let n = a.len();
+    let (mut fib2, mut fib1) = (0usize, 1usize);
+    let mut fib = fib2 + fib1;
+    while fib < n {
+        fib2 = fib1;
+        fib1 = fib;
+        fib = fib2 + fib1;
+    }
+    let mut offset: isize = -1;
+    while fib > 1 {
+        let i = ((offset + fib2 as isize) as usize).min(n - 1);
+        if a[i] < x {
+            fib = fib1;
+            fib1 = fib2;
+            fib2 = fib - fib1;
+            offset = i as isize;
+        } else if a[i] > x {
+            fib = fib2;
+            fib1 -= fib2;
+            fib2 = fib - fib1;
+        } else {
+            return Some(i);
+        }
+    }
+    if fib1 == 1 && ((offset + 1) as usize) < n && a[(offset + 1) as usize] == x {
+        return Some((offset + 1) as usize);
+    }
+    None
+}
@@ -0,0 +1,18 @@
+fn fill(image: &mut Vec<Vec<u8>>, r: usize, c: usize, target: u8, replacement: u8) {
+    if image[r][c] != target || target == replacement {
+        return;
+    }
+    image[r][c] = replacement;
+    if r > 0 {
+        fill(image, r - 1, c, target, replacement);
+    }
+    if r + 1 < image.len() {
+        fill(image, r + 1, c, target, replacement);
+    }
+    if c > 0 {
+        fill(image, r, c - 1, target, replacement);
+    }
+    if c + 1 < image[r].len() {
+        fill(image, r, c + 1, target, replacement);
+    }
+}
@@ -0,0 +1,12 @@
+fn has_cycle(next: &[Option<usize>], head: usize) -> bool {
+    let (mut slow, mut fast) = (Some(head), Some(head));
+    loop {
+        slow = slow.and_then(|s| next[s]);
+        fast = fast.and_then(|f| next[f]).and_then(|f| next[f]);
+        match (slow, fast) {
+            (_, None) => return false,
+            (Some(s), Some(f)) if s == f => return true,
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,29 @@
+fn augment(residual: &mut Vec<Vec<i32>>, u: usize, sink: usize, flow: i32, seen: &mut Vec<bool>) -> i32 {
+    if u == sink {
+        return flow;
+    }
+    seen[u] = true;
+    for v in 0..residual.len() {
+        if !seen[v] && residual[u][v] > 0 {
+            let pushed = augment(residual, v, sink, flow.min(residual[u][v]), seen);
+            if pushed > 0 {
+                residual[u][v] -= pushed;
+                residual[v][u] += pushed;
+                return pushed;
+            }
+        }
+    }
+    0
+}
+
+fn max_flow(mut residual: Vec<Vec<i32>>, source: usize, sink: usize) -> i32 {
+    let mut total = 0;
+    loop {
+        let mut seen = vec![false; residual.len()];
+        let pushed = augment(&mut residual, source, sink, i32::MAX, &mut seen);
+        if pushed == 0 {
+            return total;
+        }
+        total += pushed;
+    }
+}
@@ -0,0 +1,14 @@
+fn max_value(mut items: Vec<(f64, f64)>, mut capacity: f64) -> f64 {
+    items.sort_by(|a, b| (b.0 / b.1).total_cmp(&(a.0 / a.1)));
+    let mut total = 0.0;
+    for (value, weight) in items {
+        if capacity <= 0.0 {
+            break;
+        }
+        let ratio = value / weight;
+        let take = weight.min(capacity);
+        total += take * ratio;
+        capacity -= take;
+    }
+    total
+}
@@ -0,0 +1,19 @@
+fn stable_matching(preferences: &[Vec<usize>], rank: &[Vec<usize>]) -> Vec<Option<usize>> {
+    let n = preferences.len();
+    let mut engaged: Vec<Option<usize>> = vec![None; n];
+    let mut next_choice = vec![0; n];
+    let mut free: Vec<usize> = (0..n).collect();
+    while let Some(m) = free.pop() {
+        let w = preferences[m][next_choice[m]];
+        next_choice[m] += 1;
+        match engaged[w] {
+            None => engaged[w] = Some(m),
+            Some(current) if rank[w][m] < rank[w][current] => {
+                engaged[w] = Some(m);
+                free.push(current);
+            }
+            Some(_) => free.push(m),
+        }
+    }
+    engaged
+}
@@ -0,0 +1,11 @@
+fn gnome_sort<T: PartialOrd>(v: &mut [T]) {
+    let mut pos = 0;
+    while pos < v.len() {
+        if pos == 0 || v[pos] >= v[pos - 1] {
+            pos += 1;
+        } else {
+            v.swap(pos, pos - 1);
+            pos -= 1;
+        }
+    }
+}
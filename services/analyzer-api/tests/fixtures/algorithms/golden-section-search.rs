@@ -0,0 +1,15 @@
+fn minimize(f: impl Fn(f64) -> f64, mut a: f64, mut b: f64, tol: f64) -> f64 {
+    let inv_phi = (5f64.sqrt() - 1.0) / 2.0;
+    let mut c = b - (b - a) * inv_phi;
+    let mut d = a + (b - a) * inv_phi;
+    while (b - a).abs() > tol {
+        if f(c) < f(d) {
+            b = d;
+        } else {
+            a = c;
+        }
+        c = b - (b - a) * inv_phi;
+        d = a + (b - a) * inv_phi;
+    }
+    (a + b) / 2.0
+}
@@ -0,0 +1,9 @@
+fn minimize(gradient: impl Fn(&[f64]) -> Vec<f64>, mut params: Vec<f64>, learning_rate: f64, steps: usize) -> Vec<f64> {
+    for _ in 0..steps {
+        let g = gradient(&params);
+        for (p, gi) in params.iter_mut().zip(g) {
+            *p -= learning_rate * gi;
+        }
+    }
+    params
+}
@@ -0,0 +1,25 @@
+type Point = (i64, i64);
+
+fn cross(o: Point, a: Point, b: Point) -> i64 {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
+
+fn convex_hull(mut points: Vec<Point>) -> Vec<Point> {
+    points.sort();
+    points.dedup();
+    let mut hull: Vec<Point> = Vec::new();
+    for pass in 0..2 {
+        let start = hull.len();
+        for &p in &points {
+            while hull.len() >= start + 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0 {
+                hull.pop();
+            }
+            hull.push(p);
+        }
+        hull.pop();
+        if pass == 0 {
+            points.reverse();
+        }
+    }
+    hull
+}
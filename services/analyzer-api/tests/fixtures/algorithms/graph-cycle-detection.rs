@@ -0,0 +1,11 @@
+fn has_cycle(adj: &[Vec<usize>], v: usize, visited: &mut [bool], rec_stack: &mut [bool]) -> bool {
+    visited[v] = true;
+    rec_stack[v] = true;
+    for &next in &adj[v] {
+        if rec_stack[next] || (!visited[next] && has_cycle(adj, next, visited, rec_stack)) {
+            return true;
+        }
+    }
+    rec_stack[v] = false;
+    false
+}
@@ -0,0 +1,11 @@
+fn make_change(coins: &[u32], mut amount: u32) -> Vec<(u32, u32)> {
+    let mut change = Vec::new();
+    for &coin in coins {
+        let count = amount / coin;
+        if count > 0 {
+            change.push((coin, count));
+            amount -= count * coin;
+        }
+    }
+    change
+}
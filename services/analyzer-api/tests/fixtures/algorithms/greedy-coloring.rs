@@ -0,0 +1,13 @@
+fn color_vertices(adj: &[Vec<usize>]) -> Vec<usize> {
+    let mut color = vec![usize::MAX; adj.len()];
+    for u in 0..adj.len() {
+        let mut available = vec![true; adj.len()];
+        for &v in &adj[u] {
+            if color[v] != usize::MAX {
+                available[color[v]] = false;
+            }
+        }
+        color[u] = available.iter().position(|&free| free).unwrap_or(0);
+    }
+    color
+}
@@ -0,0 +1,19 @@
+fn extend(adj: &[Vec<bool>], path: &mut Vec<usize>, visited: &mut Vec<bool>) -> bool {
+    let n = adj.len();
+    if path.len() == n {
+        return true;
+    }
+    let last = *path.last().unwrap();
+    for v in 0..n {
+        if adj[last][v] && !visited[v] {
+            visited[v] = true;
+            path.push(v);
+            if extend(adj, path, visited) {
+                return true;
+            }
+            path.pop();
+            visited[v] = false;
+        }
+    }
+    false
+}
@@ -0,0 +1,25 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+struct Map<K, V> {
+    buckets: Vec<Vec<(K, V)>>,
+}
+
+impl<K: Hash + Eq, V> Map<K, V> {
+    fn bucket(&self, key: &K) -> usize {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        (hasher.finish() % self.buckets.len() as u64) as usize
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        let b = self.bucket(&key);
+        for entry in self.buckets[b].iter_mut() {
+            if entry.0 == key {
+                entry.1 = value;
+                return;
+            }
+        }
+        self.buckets[b].push((key, value));
+    }
+}
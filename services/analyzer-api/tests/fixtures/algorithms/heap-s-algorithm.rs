@@ -0,0 +1,15 @@
+fn generate(k: usize, a: &mut Vec<u32>, out: &mut Vec<Vec<u32>>) {
+    if k == 1 {
+        out.push(a.clone());
+        return;
+    }
+    generate(k - 1, a, out);
+    for i in 0..k - 1 {
+        if k % 2 == 0 {
+            a.swap(i, k - 1);
+        } else {
+            a.swap(0, k - 1);
+        }
+        generate(k - 1, a, out);
+    }
+}
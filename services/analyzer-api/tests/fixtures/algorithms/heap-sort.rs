@@ -0,0 +1,27 @@
+fn heapify(v: &mut [u32], len: usize, mut root: usize) {
+    loop {
+        let mut largest = root;
+        let (l, r) = (2 * root + 1, 2 * root + 2);
+        if l < len && v[l] > v[largest] {
+            largest = l;
+        }
+        if r < len && v[r] > v[largest] {
+            largest = r;
+        }
+        if largest == root {
+            break;
+        }
+        v.swap(root, largest);
+        root = largest;
+    }
+}
+
+pub fn sort_desc_heap(v: &mut [u32]) {
+    for i in (0..v.len() / 2).rev() {
+        heapify(v, v.len(), i);
+    }
+    for end in (1..v.len()).rev() {
+        v.swap(0, end);
+        heapify(v, end, 0);
+    }
+}
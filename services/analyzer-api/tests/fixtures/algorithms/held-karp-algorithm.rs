@@ -0,0 +1,21 @@
+fn shortest_tour(dist: &[Vec<u32>]) -> u32 {
+    let n = dist.len();
+    let full = 1 << n;
+    let mut dp = vec![vec![u32::MAX; n]; full];
+    dp[1][0] = 0;
+    for mask in 1..full {
+        for last in 0..n {
+            if dp[mask][last] == u32::MAX || mask & (1 << last) == 0 {
+                continue;
+            }
+            for next in 0..n {
+                if mask & (1 << next) != 0 {
+                    continue;
+                }
+                let m = mask | (1 << next);
+                dp[m][next] = dp[m][next].min(dp[mask][last] + dist[last][next]);
+            }
+        }
+    }
+    (1..n).map(|last| dp[full - 1][last].saturating_add(dist[last][0])).min().unwrap_or(0)
+}
@@ -0,0 +1,13 @@
+fn eulerian_circuit(mut adj: Vec<Vec<usize>>, start: usize) -> Vec<usize> {
+    let mut stack = vec![start];
+    let mut circuit = Vec::new();
+    while let Some(&v) = stack.last() {
+        if let Some(u) = adj[v].pop() {
+            stack.push(u);
+        } else {
+            circuit.push(stack.pop().unwrap());
+        }
+    }
+    circuit.reverse();
+    circuit
+}
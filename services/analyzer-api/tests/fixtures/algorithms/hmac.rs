@@ -0,0 +1,6 @@
+fn authenticate(key: &[u8; 64], message: &[u8], hash: impl Fn(&[u8]) -> Vec<u8>) -> Vec<u8> {
+    let ipad: Vec<u8> = key.iter().map(|b| b ^ 0x36).collect();
+    let opad: Vec<u8> = key.iter().map(|b| b ^ 0x5c).collect();
+    let inner = hash(&[ipad.as_slice(), message].concat());
+    hash(&[opad.as_slice(), &inner].concat())
+}
@@ -0,0 +1,22 @@
+fn try_match(u: usize, adj: &[Vec<usize>], pair_u: &mut [Option<usize>], pair_v: &mut [Option<usize>], seen: &mut [bool]) -> bool {
+    for &v in &adj[u] {
+        if seen[v] {
+            continue;
+        }
+        seen[v] = true;
+        if pair_v[v].is_none_or(|w| try_match(w, adj, pair_u, pair_v, seen)) {
+            pair_u[u] = Some(v);
+            pair_v[v] = Some(u);
+            return true;
+        }
+    }
+    false
+}
+
+fn maximum_matching(adj: &[Vec<usize>], right: usize) -> usize {
+    let mut pair_u = vec![None; adj.len()];
+    let mut pair_v = vec![None; right];
+    (0..adj.len())
+        .filter(|&u| try_match(u, adj, &mut pair_u, &mut pair_v, &mut vec![false; right]))
+        .count()
+}
@@ -0,0 +1,29 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+enum Node {
+    Leaf(char),
+    Branch(Box<Node>, Box<Node>),
+}
+
+fn build_code_tree(text: &str) -> Option<Node> {
+    let mut freq: HashMap<char, usize> = HashMap::new();
+    for c in text.chars() {
+        *freq.entry(c).or_default() += 1;
+    }
+    let mut nodes: Vec<Option<Node>> = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for (c, f) in freq {
+        heap.push(Reverse((f, nodes.len())));
+        nodes.push(Some(Node::Leaf(c)));
+    }
+    while heap.len() > 1 {
+        let Reverse((f1, left)) = heap.pop()?;
+        let Reverse((f2, right)) = heap.pop()?;
+        let branch = Node::Branch(Box::new(nodes[left].take()?), Box::new(nodes[right].take()?));
+        heap.push(Reverse((f1 + f2, nodes.len())));
+        nodes.push(Some(branch));
+    }
+    let Reverse((_, root)) = heap.pop()?;
+    nodes[root].take()
+}
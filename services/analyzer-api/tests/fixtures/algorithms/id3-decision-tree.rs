@@ -0,0 +1,16 @@
+use std::collections::HashMap;
+
+fn entropy(labels: &[bool]) -> f64 {
+    let p = labels.iter().filter(|&&l| l).count() as f64 / labels.len() as f64;
+    [p, 1.0 - p].iter().filter(|&&q| q > 0.0).map(|q| -q * q.log2()).sum()
+}
+
+fn information_gain(rows: &[(Vec<u8>, bool)], attribute: usize) -> f64 {
+    let labels: Vec<bool> = rows.iter().map(|r| r.1).collect();
+    let mut groups: HashMap<u8, Vec<bool>> = HashMap::new();
+    for (features, label) in rows {
+        groups.entry(features[attribute]).or_default().push(*label);
+    }
+    let remainder: f64 = groups.values().map(|g| g.len() as f64 / rows.len() as f64 * entropy(g)).sum();
+    entropy(&labels) - remainder
+}
@@ -0,0 +1,13 @@
+struct TreeNode {
+    val: i32,
+    left: Option<Box<TreeNode>>,
+    right: Option<Box<TreeNode>>,
+}
+
+fn inorder(node: &Option<Box<TreeNode>>, out: &mut Vec<i32>) {
+    if let Some(n) = node {
+        inorder(&n.left, out);
+        out.push(n.val);
+        inorder(&n.right, out);
+    }
+}
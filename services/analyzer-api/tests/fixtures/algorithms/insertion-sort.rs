@@ -0,0 +1,11 @@
+fn insertion_sort(a: &mut Vec<i32>) {
+    for i in 1..a.len() {
+        let key = a[i];
+        let mut j = i;
+        while j > 0 && a[j - 1] > key {
+            a[j] = a[j - 1];
+            j -= 1;
+        }
+        a[j] = key;
+    }
+}
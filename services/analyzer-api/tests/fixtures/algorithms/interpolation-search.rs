@@ -0,0 +1,18 @@
+fn interpolation_search(a: &[i64], key: i64) -> Option<usize> {
+    let (mut lo, mut hi) = (0usize, a.len().checked_sub(1)?);
+    while lo <= hi && key >= a[lo] && key <= a[hi] {
+        if a[hi] == a[lo] {
+            return (a[lo] == key).then_some(lo);
+        }
+        let pos = lo + ((key - a[lo]) as usize * (hi - lo)) / (a[hi] - a[lo]) as usize;
+        if a[pos] == key {
+            return Some(pos);
+        }
+        if a[pos] < key {
+            lo = pos + 1;
+        } else {
+            hi = pos - 1;
+        }
+    }
+    None
+}
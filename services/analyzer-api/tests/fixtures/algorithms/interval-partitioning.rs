@@ -0,0 +1,16 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+fn min_rooms(mut lectures: Vec<(u32, u32)>) -> usize {
+    lectures.sort();
+    let mut rooms = BinaryHeap::new();
+    for (start, end) in lectures {
+        if let Some(&Reverse(earliest_end)) = rooms.peek() {
+            if earliest_end <= start {
+                rooms.pop();
+            }
+        }
+        rooms.push(Reverse(end));
+    }
+    rooms.len()
+}
@@ -0,0 +1,16 @@
+fn schedule(mut jobs: Vec<(usize, u32)>) -> u32 {
+    jobs.sort_by(|a, b| b.1.cmp(&a.1));
+    let max_deadline = jobs.iter().map(|&(deadline, _)| deadline).max().unwrap_or(0);
+    let mut slots = vec![false; max_deadline];
+    let mut earned = 0;
+    for (deadline, profit) in jobs {
+        for slot in (0..deadline).rev() {
+            if !slots[slot] {
+                slots[slot] = true;
+                earned += profit;
+                break;
+            }
+        }
+    }
+    earned
+}
@@ -0,0 +1,33 @@
+fn reweight(n: usize, edges: &[(usize, usize, i64)]) -> Option<Vec<Vec<i64>>> {
+    let mut potential = vec![0i64; n];
+    for _ in 0..n {
+        for &(u, v, w) in edges {
+            if potential[u] + w < potential[v] {
+                potential[v] = potential[u] + w;
+            }
+        }
+    }
+    if edges.iter().any(|&(u, v, w)| potential[u] + w < potential[v]) {
+        return None;
+    }
+    let mut adj = vec![Vec::new(); n];
+    for &(u, v, w) in edges {
+        adj[u].push((v, (w + potential[u] - potential[v]) as u64));
+    }
+    let mut all = Vec::new();
+    for s in 0..n {
+        let dist = dijkstra(&adj, s);
+        all.push(
+            (0..n)
+                .map(|t| dist[t].map_or(i64::MAX, |d| d as i64 - potential[s] + potential[t]))
+                .collect(),
+        );
+    }
+    Some(all)
+}
+
+fn dijkstra(adj: &[Vec<(usize, u64)>], s: usize) -> Vec<Option<u64>> {
+    let mut dist = vec![None; adj.len()];
+    dist[s] = Some(0);
+    dist
+}
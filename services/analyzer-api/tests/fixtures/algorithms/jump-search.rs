@@ -0,0 +1,16 @@
+fn jump_search(a: &[i64], x: i64) -> Option<usize> {
+    let n = a.len();
+    let step = (n as f64).sqrt() as usize;
+    let mut prev = 0;
+    let mut next = step;
+    while next < n && a[next - 1] < x {
+        prev = next;
+        next += step;
+    }
+    for i in prev..next.min(n) {
+        if a[i] == x {
+            return Some(i);
+        }
+    }
+    None
+}
@@ -0,0 +1,24 @@
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum()
+}
+
+fn cluster(points: &[Vec<f64>], mut centroids: Vec<Vec<f64>>, iterations: usize) -> Vec<usize> {
+    let mut assignments = vec![0; points.len()];
+    for _ in 0..iterations {
+        for (i, p) in points.iter().enumerate() {
+            assignments[i] = (0..centroids.len())
+                .min_by(|&a, &b| distance(p, &centroids[a]).total_cmp(&distance(p, &centroids[b])))
+                .unwrap();
+        }
+        for (c, centroid) in centroids.iter_mut().enumerate() {
+            let members: Vec<&Vec<f64>> = points.iter().zip(&assignments).filter(|(_, &a)| a == c).map(|(p, _)| p).collect();
+            if members.is_empty() {
+                continue;
+            }
+            for d in 0..centroid.len() {
+                centroid[d] = members.iter().map(|m| m[d]).sum::<f64>() / members.len() as f64;
+            }
+        }
+    }
+    assignments
+}
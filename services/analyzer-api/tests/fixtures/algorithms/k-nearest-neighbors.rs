@@ -0,0 +1,14 @@
+use std::collections::HashMap;
+
+fn classify(training: &[(Vec<f64>, u32)], query: &[f64], k: usize) -> Option<u32> {
+    let mut distances: Vec<(f64, u32)> = training
+        .iter()
+        .map(|(point, label)| (point.iter().zip(query).map(|(a, b)| (a - b).powi(2)).sum(), *label))
+        .collect();
+    distances.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut votes: HashMap<u32, usize> = HashMap::new();
+    for (_, label) in distances.iter().take(k) {
+        *votes.entry(*label).or_default() += 1;
+    }
+    votes.into_iter().max_by_key(|&(_, count)| count).map(|(label, _)| label)
+}
@@ -0,0 +1,9 @@
+fn max_subarray(values: &[i64]) -> i64 {
+    let mut max_ending_here = values[0];
+    let mut best = values[0];
+    for &v in &values[1..] {
+        max_ending_here = v.max(max_ending_here + v);
+        best = best.max(max_ending_here);
+    }
+    best
+}
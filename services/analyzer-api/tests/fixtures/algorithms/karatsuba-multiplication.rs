@@ -0,0 +1,13 @@
+fn multiply(x: u128, y: u128) -> u128 {
+    if x < 10 || y < 10 {
+        return x * y;
+    }
+    let digits = x.max(y).ilog10() + 1;
+    let half = 10u128.pow(digits / 2);
+    let (high1, low1) = (x / half, x % half);
+    let (high2, low2) = (y / half, y % half);
+    let z0 = multiply(low1, low2);
+    let z1 = multiply(low1 + high1, low2 + high2);
+    let z2 = multiply(high1, high2);
+    z2 * half * half + (z1 - z2 - z0) * half + z0
+}
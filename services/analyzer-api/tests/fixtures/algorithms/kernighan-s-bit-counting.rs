@@ -0,0 +1,8 @@
+fn set_bits(mut n: u64) -> u32 {
+    let mut count = 0;
+    while n != 0 {
+        n &= n - 1;
+        count += 1;
+    }
+    count
+}
@@ -0,0 +1,33 @@
+fn prefix_table(pattern: &[u8]) -> Vec<usize> {
+    let mut lps = vec![0; pattern.len()];
+    let mut len = 0;
+    for i in 1..pattern.len() {
+        while len > 0 && pattern[i] != pattern[len] {
+            len = lps[len - 1];
+        }
+        if pattern[i] == pattern[len] {
+            len += 1;
+        }
+        lps[i] = len;
+    }
+    lps
+}
+
+fn find_all(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    let lps = prefix_table(pattern);
+    let mut matches = Vec::new();
+    let mut j = 0;
+    for (i, &c) in text.iter().enumerate() {
+        while j > 0 && c != pattern[j] {
+            j = lps[j - 1];
+        }
+        if c == pattern[j] {
+            j += 1;
+        }
+        if j == pattern.len() {
+            matches.push(i + 1 - j);
+            j = lps[j - 1];
+        }
+    }
+    matches
+}
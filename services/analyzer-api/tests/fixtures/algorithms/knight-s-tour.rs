@@ -0,0 +1,19 @@
+const MOVES: [(i32, i32); 8] = [(2, 1), (1, 2), (-1, 2), (-2, 1), (-2, -1), (-1, -2), (1, -2), (2, -1)];
+
+fn tour(board: &mut Vec<Vec<usize>>, r: i32, c: i32, step: usize) -> bool {
+    let n = board.len() as i32;
+    if step == board.len() * board.len() {
+        return true;
+    }
+    for (dr, dc) in MOVES {
+        let (nr, nc) = (r + dr, c + dc);
+        if nr >= 0 && nc >= 0 && nr < n && nc < n && board[nr as usize][nc as usize] == 0 {
+            board[nr as usize][nc as usize] = step + 1;
+            if tour(board, nr, nc, step + 1) {
+                return true;
+            }
+            board[nr as usize][nc as usize] = 0;
+        }
+    }
+    false
+}
@@ -0,0 +1,46 @@
+fn fill_order(g: &[Vec<usize>], v: usize, visited: &mut [bool], order: &mut Vec<usize>) {
+    visited[v] = true;
+    for &u in &g[v] {
+        if !visited[u] {
+            fill_order(g, u, visited, order);
+        }
+    }
+    order.push(v);
+}
+
+fn collect(g: &[Vec<usize>], v: usize, visited: &mut [bool], out: &mut Vec<usize>) {
+    visited[v] = true;
+    out.push(v);
+    for &u in &g[v] {
+        if !visited[u] {
+            collect(g, u, visited, out);
+        }
+    }
+}
+
+fn components(g: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let n = g.len();
+    let mut visited = vec![false; n];
+    let mut order = Vec::new();
+    for v in 0..n {
+        if !visited[v] {
+            fill_order(g, v, &mut visited, &mut order);
+        }
+    }
+    let mut transpose = vec![Vec::new(); n];
+    for (v, edges) in g.iter().enumerate() {
+        for &u in edges {
+            transpose[u].push(v);
+        }
+    }
+    let mut visited = vec![false; n];
+    let mut result = Vec::new();
+    for &v in order.iter().rev() {
+        if !visited[v] {
+            let mut out = Vec::new();
+            collect(&transpose, v, &mut visited, &mut out);
+            result.push(out);
+        }
+    }
+    result
+}
@@ -0,0 +1,25 @@
+fn find(parent: &mut Vec<usize>, x: usize) -> usize {
+    if parent[x] != x {
+        let root = find(parent, parent[x]);
+        parent[x] = root;
+    }
+    parent[x]
+}
+
+fn minimum_spanning_tree(n: usize, mut edges: Vec<(u32, usize, usize)>) -> u32 {
+    edges.sort();
+    let mut parent: Vec<usize> = (0..n).collect();
+    let mut total = 0;
+    for (w, u, v) in edges {
+        let (a, b) = (find(&mut parent, u), find(&mut parent, v));
+        if a != b {
+            union(&mut parent, a, b);
+            total += w;
+        }
+    }
+    total
+}
+
+fn union(parent: &mut [usize], a: usize, b: usize) {
+    parent[a] = b;
+}
@@ -0,0 +1,27 @@
+use std::collections::VecDeque;
+
+struct TreeNode {
+    val: i32,
+    left: Option<Box<TreeNode>>,
+    right: Option<Box<TreeNode>>,
+}
+
+fn levels(root: &TreeNode) -> Vec<Vec<i32>> {
+    let mut result = Vec::new();
+    let mut queue = VecDeque::from([root]);
+    while !queue.is_empty() {
+        let mut level = Vec::new();
+        for _ in 0..queue.len() {
+            let node = queue.pop_front().unwrap();
+            level.push(node.val);
+            if let Some(left) = &node.left {
+                queue.push_back(left);
+            }
+            if let Some(right) = &node.right {
+                queue.push_back(right);
+            }
+        }
+        result.push(level);
+    }
+    result
+}
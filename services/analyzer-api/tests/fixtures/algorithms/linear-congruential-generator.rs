@@ -0,0 +1,10 @@
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        self.state
+    }
+}
@@ -0,0 +1,16 @@
+struct OpenTable {
+    slots: Vec<Option<(u64, String)>>,
+}
+
+impl OpenTable {
+    fn insert(&mut self, key: u64, value: String) {
+        let mut index = key as usize % self.slots.len();
+        while let Some((k, _)) = &self.slots[index] {
+            if *k == key {
+                break;
+            }
+            index = (index + 1) % self.slots.len();
+        }
+        self.slots[index] = Some((key, value));
+    }
+}
@@ -0,0 +1,8 @@
+fn linear_search<T: PartialEq>(items: &[T], target: &T) -> Option<usize> {
+    for (i, item) in items.iter().enumerate() {
+        if item == target {
+            return Some(i);
+        }
+    }
+    None
+}
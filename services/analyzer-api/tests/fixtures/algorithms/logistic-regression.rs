@@ -0,0 +1,19 @@
+fn sigmoid(z: f64) -> f64 {
+    1.0 / (1.0 + (-z).exp())
+}
+
+fn train(samples: &[(Vec<f64>, f64)], epochs: usize, learning_rate: f64) -> (Vec<f64>, f64) {
+    let mut weights = vec![0.0; samples[0].0.len()];
+    let mut bias = 0.0;
+    for _ in 0..epochs {
+        for (x, y) in samples {
+            let z: f64 = weights.iter().zip(x).map(|(w, xi)| w * xi).sum::<f64>() + bias;
+            let error = sigmoid(z) - y;
+            for (w, xi) in weights.iter_mut().zip(x) {
+                *w -= learning_rate * error * xi;
+            }
+            bias -= learning_rate * error;
+        }
+    }
+    (weights, bias)
+}
@@ -0,0 +1,13 @@
+fn longest_shared_substring(a: &[u8], b: &[u8]) -> usize {
+    let mut dp = vec![vec![0; b.len() + 1]; a.len() + 1];
+    let mut longest = 0;
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            if a[i - 1] == b[j - 1] {
+                dp[i][j] = dp[i - 1][j - 1] + 1;
+                longest = longest.max(dp[i][j]);
+            }
+        }
+    }
+    longest
+}
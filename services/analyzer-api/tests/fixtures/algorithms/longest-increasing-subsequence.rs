@@ -0,0 +1,12 @@
+fn longest_increasing(nums: &[i32]) -> usize {
+    let mut tails: Vec<i32> = Vec::new();
+    for &x in nums {
+        let i = tails.partition_point(|&t| t < x);
+        if i == tails.len() {
+            tails.push(x);
+        } else {
+            tails[i] = x;
+        }
+    }
+    tails.len()
+}
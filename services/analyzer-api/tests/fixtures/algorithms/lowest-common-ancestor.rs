@@ -0,0 +1,28 @@
+struct Lifting {
+    up: Vec<Vec<usize>>,
+    depth: Vec<usize>,
+}
+
+impl Lifting {
+    fn lca(&self, mut a: usize, mut b: usize) -> usize {
+        if self.depth[a] < self.depth[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        let diff = self.depth[a] - self.depth[b];
+        for k in 0..self.up.len() {
+            if diff >> k & 1 == 1 {
+                a = self.up[k][a];
+            }
+        }
+        if a == b {
+            return a;
+        }
+        for k in (0..self.up.len()).rev() {
+            if self.up[k][a] != self.up[k][b] {
+                a = self.up[k][a];
+                b = self.up[k][b];
+            }
+        }
+        self.up[0][a]
+    }
+}
@@ -0,0 +1,28 @@
+use std::collections::{HashMap, VecDeque};
+
+struct LruCache {
+    capacity: usize,
+    map: HashMap<u64, String>,
+    order: VecDeque<u64>,
+}
+
+impl LruCache {
+    fn get(&mut self, key: u64) -> Option<&String> {
+        if self.map.contains_key(&key) {
+            self.order.retain(|&k| k != key);
+            self.order.push_back(key);
+        }
+        self.map.get(&key)
+    }
+
+    fn put(&mut self, key: u64, value: String) {
+        self.order.retain(|&k| k != key);
+        self.order.push_back(key);
+        self.map.insert(key, value);
+        if self.map.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.map.remove(&oldest);
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+struct Token {
+    offset: usize,
+    length: usize,
+    next: Option<u8>,
+}
+
+fn compress(input: &[u8], window: usize) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < input.len() {
+        let (mut best_offset, mut best_length) = (0, 0);
+        for start in i.saturating_sub(window)..i {
+            let mut len = 0;
+            while i + len < input.len() && input[start + len] == input[i + len] && len < window {
+                len += 1;
+            }
+            if len > best_length {
+                best_offset = i - start;
+                best_length = len;
+            }
+        }
+        tokens.push(Token { offset: best_offset, length: best_length, next: input.get(i + best_length).copied() });
+        i += best_length + 1;
+    }
+    tokens
+}
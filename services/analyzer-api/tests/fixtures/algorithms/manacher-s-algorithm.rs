@@ -0,0 +1,19 @@
+fn longest_palindrome(s: &str) -> usize {
+    let t: Vec<char> = std::iter::once('^').chain(s.chars().flat_map(|c| ['#', c])).chain(['#', '$']).collect();
+    let mut p = vec![0usize; t.len()];
+    let (mut center, mut right) = (0, 0);
+    for i in 1..t.len() - 1 {
+        let mirror = 2 * center as isize - i as isize;
+        if i < right && mirror >= 0 {
+            p[i] = (right - i).min(p[mirror as usize]);
+        }
+        while t[i + 1 + p[i]] == t[i - 1 - p[i]] {
+            p[i] += 1;
+        }
+        if i + p[i] > right {
+            center = i;
+            right = i + p[i];
+        }
+    }
+    p.into_iter().max().unwrap_or(0)
+}
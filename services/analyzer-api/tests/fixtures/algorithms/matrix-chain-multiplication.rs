@@ -0,0 +1,15 @@
+fn min_multiplications(dims: &[u64]) -> u64 {
+    let n = dims.len() - 1;
+    let mut cost = vec![vec![0u64; n]; n];
+    for len in 2..=n {
+        for i in 0..=n - len {
+            let j = i + len - 1;
+            cost[i][j] = u64::MAX;
+            for k in i..j {
+                let c = cost[i][k] + cost[k + 1][j] + dims[i] * dims[k + 1] * dims[j + 1];
+                cost[i][j] = cost[i][j].min(c);
+            }
+        }
+    }
+    cost[0][n - 1]
+}
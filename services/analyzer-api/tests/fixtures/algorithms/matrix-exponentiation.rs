@@ -0,0 +1,24 @@
+type Matrix = [[u64; 2]; 2];
+
+fn mat_mul(a: &Matrix, b: &Matrix, m: u64) -> Matrix {
+    let mut c = [[0; 2]; 2];
+    for i in 0..2 {
+        for j in 0..2 {
+            c[i][j] = (0..2).map(|k| a[i][k] * b[k][j] % m).sum::<u64>() % m;
+        }
+    }
+    c
+}
+
+fn nth_fib(mut n: u64, m: u64) -> u64 {
+    let mut result = [[1, 0], [0, 1]];
+    let mut base = [[1, 1], [1, 0]];
+    while n > 0 {
+        if n & 1 == 1 {
+            result = mat_mul(&result, &base, m);
+        }
+        base = mat_mul(&base, &base, m);
+        n >>= 1;
+    }
+    result[0][1]
+}
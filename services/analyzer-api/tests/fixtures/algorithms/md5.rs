@@ -0,0 +1,10 @@
+const S: [u32; 4] = [7, 12, 17, 22];
+const T0: u32 = 0xd76aa478;
+
+fn round_one(mut a: u32, b: u32, c: u32, d: u32, words: &[u32; 16]) -> u32 {
+    for i in 0..16 {
+        let f = (b & c) | (!b & d);
+        a = b.wrapping_add(a.wrapping_add(f).wrapping_add(T0).wrapping_add(words[i]).rotate_left(S[i % 4]));
+    }
+    a
+}
@@ -0,0 +1,26 @@
+fn select(v: &[i32], k: usize) -> i32 {
+    if v.len() <= 5 {
+        let mut sorted = v.to_vec();
+        sorted.sort();
+        return sorted[k];
+    }
+    let medians: Vec<i32> = v
+        .chunks(5)
+        .map(|chunk| {
+            let mut c = chunk.to_vec();
+            c.sort();
+            c[c.len() / 2]
+        })
+        .collect();
+    let pivot = select(&medians, medians.len() / 2);
+    let lows: Vec<i32> = v.iter().copied().filter(|&x| x < pivot).collect();
+    let pivots = v.iter().filter(|&&x| x == pivot).count();
+    if k < lows.len() {
+        select(&lows, k)
+    } else if k < lows.len() + pivots {
+        pivot
+    } else {
+        let highs: Vec<i32> = v.iter().copied().filter(|&x| x > pivot).collect();
+        select(&highs, k - lows.len() - pivots)
+    }
+}
@@ -0,0 +1,26 @@
+fn merge_sort(v: &[i64]) -> Vec<i64> {
+    if v.len() <= 1 {
+        return v.to_vec();
+    }
+    let mid = v.len() / 2;
+    let left = merge_sort(&v[..mid]);
+    let right = merge_sort(&v[mid..]);
+    merge(&left, &right)
+}
+
+fn merge(a: &[i64], b: &[i64]) -> Vec<i64> {
+    let mut out = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] <= b[j] {
+            out.push(a[i]);
+            i += 1;
+        } else {
+            out.push(b[j]);
+            j += 1;
+        }
+    }
+    out.extend_from_slice(&a[i..]);
+    out.extend_from_slice(&b[j..]);
+    out
+}
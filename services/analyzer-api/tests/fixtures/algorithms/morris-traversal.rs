@@ -0,0 +1,33 @@
+struct Arena {
+    val: Vec<i32>,
+    left: Vec<Option<usize>>,
+    right: Vec<Option<usize>>,
+}
+
+fn threaded_inorder(t: &mut Arena, root: Option<usize>) -> Vec<i32> {
+    let mut out = Vec::new();
+    let mut current = root;
+    while let Some(c) = current {
+        match t.left[c] {
+            None => {
+                out.push(t.val[c]);
+                current = t.right[c];
+            }
+            Some(l) => {
+                let mut predecessor = l;
+                while let Some(r) = t.right[predecessor].filter(|&r| r != c) {
+                    predecessor = r;
+                }
+                if t.right[predecessor].is_none() {
+                    t.right[predecessor] = Some(c);
+                    current = t.left[c];
+                } else {
+                    t.right[predecessor] = None;
+                    out.push(t.val[c]);
+                    current = t.right[c];
+                }
+            }
+        }
+    }
+    out
+}
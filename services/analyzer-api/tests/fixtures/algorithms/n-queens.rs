@@ -0,0 +1,20 @@
+fn place(row: usize, n: usize, cols: &mut Vec<bool>, diag1: &mut Vec<bool>, diag2: &mut Vec<bool>) -> usize {
+    if row == n {
+        return 1;
+    }
+    let mut count = 0;
+    for col in 0..n {
+        let (d1, d2) = (row + col, row + n - col - 1);
+        if cols[col] || diag1[d1] || diag2[d2] {
+            continue;
+        }
+        cols[col] = true;
+        diag1[d1] = true;
+        diag2[d2] = true;
+        count += place(row + 1, n, cols, diag1, diag2);
+        cols[col] = false;
+        diag1[d1] = false;
+        diag2[d2] = false;
+    }
+    count
+}
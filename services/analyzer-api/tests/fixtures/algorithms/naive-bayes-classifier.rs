@@ -0,0 +1,22 @@
+use std::collections::HashMap;
+
+struct Model {
+    prior: HashMap<String, f64>,
+    likelihood: HashMap<(String, String), f64>,
+}
+
+impl Model {
+    fn predict(&self, words: &[String]) -> Option<&String> {
+        let mut best: Option<(&String, f64)> = None;
+        for (class, p) in &self.prior {
+            let mut score = p.ln();
+            for w in words {
+                score += self.likelihood.get(&(class.clone(), w.clone())).copied().unwrap_or(1e-9).ln();
+            }
+            if best.is_none_or(|(_, s)| score > s) {
+                best = Some((class, score));
+            }
+        }
+        best.map(|(class, _)| class)
+    }
+}
@@ -0,0 +1,7 @@
+fn occurrences(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    text.windows(pattern.len())
+        .enumerate()
+        .filter(|(_, window)| *window == pattern)
+        .map(|(i, _)| i)
+        .collect()
+}
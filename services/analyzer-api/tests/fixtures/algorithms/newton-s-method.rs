@@ -0,0 +1,14 @@
+fn find_root(f: impl Fn(f64) -> f64, derivative: impl Fn(f64) -> f64, mut x: f64, tolerance: f64) -> Option<f64> {
+    for _ in 0..100 {
+        let fx = f(x);
+        if fx.abs() < tolerance {
+            return Some(x);
+        }
+        let slope = derivative(x);
+        if slope == 0.0 {
+            return None;
+        }
+        x -= fx / slope;
+    }
+    None
+}
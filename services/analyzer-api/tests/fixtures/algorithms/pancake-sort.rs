@@ -0,0 +1,9 @@
+fn pancake_sort(stack: &mut [u8]) {
+    for size in (2..=stack.len()).rev() {
+        let max_at = (0..size).max_by_key(|&i| stack[i]).unwrap();
+        if max_at != size - 1 {
+            stack[..=max_at].reverse();
+            stack[..size].reverse();
+        }
+    }
+}
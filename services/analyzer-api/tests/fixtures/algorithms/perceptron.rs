@@ -0,0 +1,17 @@
+fn train(samples: &[(Vec<f64>, i32)], epochs: usize) -> (Vec<f64>, f64) {
+    let mut weights = vec![0.0; samples[0].0.len()];
+    let mut bias = 0.0;
+    for _ in 0..epochs {
+        for (x, label) in samples {
+            let activation: f64 = weights.iter().zip(x).map(|(w, xi)| w * xi).sum::<f64>() + bias;
+            let predicted = if activation >= 0.0 { 1 } else { -1 };
+            if predicted != *label {
+                for (w, xi) in weights.iter_mut().zip(x) {
+                    *w += *label as f64 * xi;
+                }
+                bias += *label as f64;
+            }
+        }
+    }
+    (weights, bias)
+}
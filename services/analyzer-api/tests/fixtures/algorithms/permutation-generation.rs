@@ -0,0 +1,16 @@
+fn permute(items: &[char], used: &mut Vec<bool>, current: &mut String, out: &mut Vec<String>) {
+    if current.len() == items.len() {
+        out.push(current.clone());
+        return;
+    }
+    for i in 0..items.len() {
+        if used[i] {
+            continue;
+        }
+        used[i] = true;
+        current.push(items[i]);
+        permute(items, used, current, out);
+        current.pop();
+        used[i] = false;
+    }
+}
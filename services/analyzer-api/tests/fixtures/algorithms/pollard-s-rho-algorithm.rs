@@ -0,0 +1,14 @@
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn rho(n: u64, c: u64) -> Option<u64> {
+    let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+    let (mut x, mut y, mut d) = (2, 2, 1);
+    while d == 1 {
+        x = f(x);
+        y = f(f(y));
+        d = gcd(x.abs_diff(y), n);
+    }
+    (d != n).then_some(d)
+}
@@ -0,0 +1,23 @@
+const BASE: u64 = 131;
+const MODULUS: u64 = 1_000_000_007;
+
+struct Hashes {
+    prefix: Vec<u64>,
+    pow: Vec<u64>,
+}
+
+impl Hashes {
+    fn new(s: &[u8]) -> Self {
+        let mut prefix = vec![0; s.len() + 1];
+        let mut pow = vec![1; s.len() + 1];
+        for i in 0..s.len() {
+            prefix[i + 1] = (prefix[i] * BASE + s[i] as u64) % MODULUS;
+            pow[i + 1] = pow[i] * BASE % MODULUS;
+        }
+        Hashes { prefix, pow }
+    }
+
+    fn substring(&self, l: usize, r: usize) -> u64 {
+        (self.prefix[r] + MODULUS * MODULUS - self.prefix[l] * self.pow[r - l]) % MODULUS
+    }
+}
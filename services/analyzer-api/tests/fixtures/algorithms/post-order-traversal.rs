@@ -0,0 +1,11 @@
+struct TreeNode {
+    val: i32,
+    children: Vec<TreeNode>,
+}
+
+fn postorder(node: &TreeNode, out: &mut Vec<i32>) {
+    for child in &node.children {
+        postorder(child, out);
+    }
+    out.push(node.val);
+}
@@ -0,0 +1,11 @@
+struct TreeNode {
+    val: i32,
+    children: Vec<TreeNode>,
+}
+
+fn preorder(node: &TreeNode, out: &mut Vec<i32>) {
+    out.push(node.val);
+    for child in &node.children {
+        preorder(child, out);
+    }
+}
@@ -0,0 +1,21 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+fn spanning_weight(adj: &[Vec<(usize, u32)>]) -> u32 {
+    let mut in_mst = vec![false; adj.len()];
+    let mut heap = BinaryHeap::from([Reverse((0, 0))]);
+    let mut total = 0;
+    while let Some(Reverse((w, u))) = heap.pop() {
+        if in_mst[u] {
+            continue;
+        }
+        in_mst[u] = true;
+        total += w;
+        for &(v, weight) in &adj[u] {
+            if !in_mst[v] {
+                heap.push(Reverse((weight, v)));
+            }
+        }
+    }
+    total
+}
@@ -0,0 +1,18 @@
+fn first_component(data: &[Vec<f64>], iterations: usize) -> Vec<f64> {
+    let d = data[0].len();
+    let mut covariance = vec![vec![0.0; d]; d];
+    for row in data {
+        for i in 0..d {
+            for j in 0..d {
+                covariance[i][j] += row[i] * row[j] / data.len() as f64;
+            }
+        }
+    }
+    let mut eigenvector = vec![1.0; d];
+    for _ in 0..iterations {
+        let next: Vec<f64> = covariance.iter().map(|r| r.iter().zip(&eigenvector).map(|(a, b)| a * b).sum()).collect();
+        let norm = next.iter().map(|x| x * x).sum::<f64>().sqrt();
+        eigenvector = next.iter().map(|x| x / norm).collect();
+    }
+    eigenvector
+}
@@ -0,0 +1,22 @@
+pub fn quick_sort<T: Ord>(v: &mut [T]) {
+    if v.len() <= 1 {
+        return;
+    }
+    let p = partition(v);
+    let (left, right) = v.split_at_mut(p);
+    quick_sort(left);
+    quick_sort(&mut right[1..]);
+}
+
+fn partition<T: Ord>(v: &mut [T]) -> usize {
+    let pivot = v.len() - 1;
+    let mut store = 0;
+    for i in 0..pivot {
+        if v[i] <= v[pivot] {
+            v.swap(i, store);
+            store += 1;
+        }
+    }
+    v.swap(store, pivot);
+    store
+}
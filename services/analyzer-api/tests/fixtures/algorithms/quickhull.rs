@@ -0,0 +1,15 @@
+type P = (f64, f64);
+
+fn side(a: P, b: P, p: P) -> f64 {
+    (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0)
+}
+
+fn extend_hull(points: &[P], a: P, b: P, hull: &mut Vec<P>) {
+    let outside: Vec<P> = points.iter().copied().filter(|&p| side(a, b, p) > 0.0).collect();
+    let Some(&farthest) = outside.iter().max_by(|&&p, &&q| side(a, b, p).total_cmp(&side(a, b, q))) else {
+        return;
+    };
+    hull.push(farthest);
+    extend_hull(&outside, a, farthest, hull);
+    extend_hull(&outside, farthest, b, hull);
+}
@@ -0,0 +1,25 @@
+fn kth_smallest(v: &mut [i32], kth: usize) -> i32 {
+    let (mut lo, mut hi) = (0, v.len() - 1);
+    loop {
+        let pivot = partition(v, lo, hi);
+        if pivot == kth {
+            return v[pivot];
+        } else if pivot < kth {
+            lo = pivot + 1;
+        } else {
+            hi = pivot - 1;
+        }
+    }
+}
+
+fn partition(v: &mut [i32], lo: usize, hi: usize) -> usize {
+    let mut store = lo;
+    for i in lo..hi {
+        if v[i] < v[hi] {
+            v.swap(i, store);
+            store += 1;
+        }
+    }
+    v.swap(store, hi);
+    store
+}
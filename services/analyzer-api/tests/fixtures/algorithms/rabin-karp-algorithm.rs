@@ -0,0 +1,24 @@
+const BASE: u64 = 256;
+const MODULUS: u64 = 1_000_000_007;
+
+fn find(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    let m = pattern.len();
+    if m == 0 || m > text.len() {
+        return Vec::new();
+    }
+    let high = (1..m).fold(1, |h, _| h * BASE % MODULUS);
+    let hash = |s: &[u8]| s.iter().fold(0, |h, &c| (h * BASE + c as u64) % MODULUS);
+    let pattern_hash = hash(pattern);
+    let mut window_hash = hash(&text[..m]);
+    let mut found = Vec::new();
+    for i in 0..=text.len() - m {
+        if window_hash == pattern_hash && &text[i..i + m] == pattern {
+            found.push(i);
+        }
+        if i + m < text.len() {
+            window_hash = (window_hash + MODULUS - text[i] as u64 * high % MODULUS) % MODULUS;
+            window_hash = (window_hash * BASE + text[i + m] as u64) % MODULUS;
+        }
+    }
+    found
+}
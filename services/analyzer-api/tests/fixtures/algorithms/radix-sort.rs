@@ -0,0 +1,12 @@
+fn radix_sort(nums: &mut Vec<u32>) {
+    let max = *nums.iter().max().unwrap_or(&0);
+    let mut exp = 1;
+    while max / exp > 0 {
+        let mut buckets: Vec<Vec<u32>> = vec![Vec::new(); 10];
+        for &n in nums.iter() {
+            buckets[(n / exp % 10) as usize].push(n);
+        }
+        *nums = buckets.concat();
+        exp *= 10;
+    }
+}
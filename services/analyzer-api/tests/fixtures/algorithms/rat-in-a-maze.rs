@@ -0,0 +1,16 @@
+fn solve_maze(maze: &[Vec<u8>], r: usize, c: usize, path: &mut Vec<Vec<bool>>) -> bool {
+    let n = maze.len();
+    if r == n - 1 && c == n - 1 && maze[r][c] == 1 {
+        path[r][c] = true;
+        return true;
+    }
+    if r >= n || c >= n || maze[r][c] == 0 || path[r][c] {
+        return false;
+    }
+    path[r][c] = true;
+    if (c + 1 < n && solve_maze(maze, r, c + 1, path)) || (r + 1 < n && solve_maze(maze, r + 1, c, path)) {
+        return true;
+    }
+    path[r][c] = false;
+    false
+}
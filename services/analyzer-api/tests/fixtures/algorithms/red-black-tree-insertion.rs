@@ -0,0 +1,34 @@
+#[derive(Clone, Copy, PartialEq)]
+enum Color {
+    Red,
+    Black,
+}
+
+struct Tree {
+    color: Vec<Color>,
+    parent: Vec<Option<usize>>,
+    left: Vec<Option<usize>>,
+    right: Vec<Option<usize>>,
+}
+
+impl Tree {
+    fn fix_insert(&mut self, mut z: usize) {
+        while let Some(p) = self.parent[z].filter(|&p| self.color[p] == Color::Red) {
+            let Some(g) = self.parent[p] else { break };
+            let uncle = if self.left[g] == Some(p) { self.right[g] } else { self.left[g] };
+            match uncle {
+                Some(u) if self.color[u] == Color::Red => {
+                    self.color[p] = Color::Black;
+                    self.color[u] = Color::Black;
+                    self.color[g] = Color::Red;
+                    z = g;
+                }
+                _ => {
+                    self.color[p] = Color::Black;
+                    self.color[g] = Color::Red;
+                    break;
+                }
+            }
+        }
+    }
+}
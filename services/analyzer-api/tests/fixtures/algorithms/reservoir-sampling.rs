@@ -0,0 +1,14 @@
+fn sample<T: Clone>(stream: impl Iterator<Item = T>, k: usize, mut random: impl FnMut(usize) -> usize) -> Vec<T> {
+    let mut reservoir = Vec::with_capacity(k);
+    for (i, item) in stream.enumerate() {
+        if i < k {
+            reservoir.push(item);
+        } else {
+            let j = random(i + 1);
+            if j < k {
+                reservoir[j] = item;
+            }
+        }
+    }
+    reservoir
+}
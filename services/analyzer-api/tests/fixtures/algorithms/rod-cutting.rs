@@ -0,0 +1,9 @@
+fn best_revenue(prices: &[u32], length: usize) -> u32 {
+    let mut revenue = vec![0; length + 1];
+    for len in 1..=length {
+        for cut in 1..=len.min(prices.len()) {
+            revenue[len] = revenue[len].max(prices[cut - 1] + revenue[len - cut]);
+        }
+    }
+    revenue[length]
+}
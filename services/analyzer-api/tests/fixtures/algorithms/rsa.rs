@@ -0,0 +1,36 @@
+struct KeyPair {
+    n: u64,
+    e: u64,
+    d: u64,
+}
+
+fn generate(p: u64, q: u64, e: u64) -> Option<KeyPair> {
+    let n = p * q;
+    let phi = (p - 1) * (q - 1);
+    let d = mod_inverse(e, phi)?;
+    Some(KeyPair { n, e, d })
+}
+
+fn encrypt(key: &KeyPair, message: u64) -> u64 {
+    mod_pow(message, key.e, key.n)
+}
+
+fn decrypt(key: &KeyPair, cipher: u64) -> u64 {
+    mod_pow(cipher, key.d, key.n)
+}
+
+fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    (1..m).find(|x| a * x % m == 1)
+}
+
+fn mod_pow(mut b: u64, mut e: u64, m: u64) -> u64 {
+    let mut r = 1;
+    while e > 0 {
+        if e & 1 == 1 {
+            r = r * b % m;
+        }
+        b = b * b % m;
+        e >>= 1;
+    }
+    r
+}
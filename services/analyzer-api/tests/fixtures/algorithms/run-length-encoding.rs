@@ -0,0 +1,14 @@
+fn encode(input: &str) -> String {
+    let mut out = String::new();
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        let mut run_length = 1;
+        while chars.peek() == Some(&c) {
+            chars.next();
+            run_length += 1;
+        }
+        out.push_str(&run_length.to_string());
+        out.push(c);
+    }
+    out
+}
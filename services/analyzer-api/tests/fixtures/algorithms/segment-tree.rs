@@ -0,0 +1,20 @@
+struct SumTree {
+    n: usize,
+    tree: Vec<i64>,
+}
+
+impl SumTree {
+    fn update(&mut self, node: usize, lo: usize, hi: usize, index: usize, value: i64) {
+        if lo == hi {
+            self.tree[node] = value;
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        if index <= mid {
+            self.update(2 * node, lo, mid, index, value);
+        } else {
+            self.update(2 * node + 1, mid + 1, hi, index, value);
+        }
+        self.tree[node] = self.tree[2 * node] + self.tree[2 * node + 1];
+    }
+}
@@ -0,0 +1,11 @@
+fn selection_sort(items: &mut [f64]) {
+    for i in 0..items.len() {
+        let mut min_idx = i;
+        for j in i + 1..items.len() {
+            if items[j] < items[min_idx] {
+                min_idx = j;
+            }
+        }
+        items.swap(i, min_idx);
+    }
+}
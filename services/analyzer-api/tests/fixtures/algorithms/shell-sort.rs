@@ -0,0 +1,15 @@
+fn shell_sort(a: &mut [i32]) {
+    let mut gap = a.len() / 2;
+    while gap > 0 {
+        for i in gap..a.len() {
+            let temp = a[i];
+            let mut j = i;
+            while j >= gap && a[j - gap] > temp {
+                a[j] = a[j - gap];
+                j -= gap;
+            }
+            a[j] = temp;
+        }
+        gap /= 2;
+    }
+}
@@ -0,0 +1,17 @@
+fn primes_up_to(n: usize) -> Vec<usize> {
+    let mut is_prime = vec![true; n + 1];
+    is_prime[0] = false;
+    if n >= 1 {
+        is_prime[1] = false;
+    }
+    let mut i = 2;
+    while i * i <= n {
+        if is_prime[i] {
+            for j in (i * i..=n).step_by(i) {
+                is_prime[j] = false;
+            }
+        }
+        i += 1;
+    }
+    (0..=n).filter(|&k| is_prime[k]).collect()
+}
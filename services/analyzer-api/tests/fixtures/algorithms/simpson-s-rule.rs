@@ -0,0 +1,9 @@
+fn integrate(f: impl Fn(f64) -> f64, a: f64, b: f64, intervals: usize) -> f64 {
+    let h = (b - a) / intervals as f64;
+    let mut sum = f(a) + f(b);
+    for i in 1..intervals {
+        let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+        sum += weight * f(a + i as f64 * h);
+    }
+    sum * h / 3.0
+}
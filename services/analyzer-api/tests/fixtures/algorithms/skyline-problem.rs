@@ -0,0 +1,31 @@
+fn outline(buildings: &[(u32, u32, u32)]) -> Vec<(u32, u32)> {
+    match buildings {
+        [] => Vec::new(),
+        [(left, right, height)] => vec![(*left, *height), (*right, 0)],
+        _ => {
+            let mid = buildings.len() / 2;
+            merge(outline(&buildings[..mid]), outline(&buildings[mid..]))
+        }
+    }
+}
+
+fn merge(a: Vec<(u32, u32)>, b: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    let (mut i, mut j, mut ha, mut hb) = (0, 0, 0, 0);
+    let mut out: Vec<(u32, u32)> = Vec::new();
+    while i < a.len() || j < b.len() {
+        let x = if j == b.len() || (i < a.len() && a[i].0 < b[j].0) {
+            ha = a[i].1;
+            i += 1;
+            a[i - 1].0
+        } else {
+            hb = b[j].1;
+            j += 1;
+            b[j - 1].0
+        };
+        let height = ha.max(hb);
+        if out.last().is_none_or(|&(_, h)| h != height) {
+            out.push((x, height));
+        }
+    }
+    out
+}
@@ -0,0 +1,19 @@
+use std::collections::VecDeque;
+
+fn window_maxima(nums: &[i32], k: usize) -> Vec<i32> {
+    let mut window: VecDeque<usize> = VecDeque::new();
+    let mut out = Vec::new();
+    for i in 0..nums.len() {
+        if window.front().is_some_and(|&j| j + k <= i) {
+            window.pop_front();
+        }
+        while window.back().is_some_and(|&j| nums[j] <= nums[i]) {
+            window.pop_back();
+        }
+        window.push_back(i);
+        if i + 1 >= k {
+            out.push(nums[window[0]]);
+        }
+    }
+    out
+}
@@ -0,0 +1,14 @@
+type M2 = [[i64; 2]; 2];
+
+fn multiply(a: &M2, b: &M2) -> M2 {
+    let (a11, a12, a21, a22) = (a[0][0], a[0][1], a[1][0], a[1][1]);
+    let (b11, b12, b21, b22) = (b[0][0], b[0][1], b[1][0], b[1][1]);
+    let m1 = (a11 + a22) * (b11 + b22);
+    let m2 = (a21 + a22) * b11;
+    let m3 = a11 * (b12 - b22);
+    let m4 = a22 * (b21 - b11);
+    let m5 = (a11 + a12) * b22;
+    let m6 = (a21 - a11) * (b11 + b12);
+    let m7 = (a12 - a22) * (b21 + b22);
+    [[m1 + m4 - m5 + m7, m3 + m5], [m2 + m4, m1 - m2 + m3 + m6]]
+}
@@ -0,0 +1,8 @@
+fn subsets(items: &[i32], start: usize, current: &mut Vec<i32>, out: &mut Vec<Vec<i32>>) {
+    out.push(current.clone());
+    for i in start..items.len() {
+        current.push(items[i]);
+        subsets(items, i + 1, current, out);
+        current.pop();
+    }
+}
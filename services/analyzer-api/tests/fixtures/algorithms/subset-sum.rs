@@ -0,0 +1,10 @@
+fn can_reach(nums: &[usize], target: usize) -> bool {
+    let mut reachable = vec![false; target + 1];
+    reachable[0] = true;
+    for &x in nums {
+        for s in (x..=target).rev() {
+            reachable[s] |= reachable[s - x];
+        }
+    }
+    reachable[target]
+}
@@ -0,0 +1,25 @@
+fn solve(board: &mut [[u8; 9]; 9]) -> bool {
+    for r in 0..9 {
+        for c in 0..9 {
+            if board[r][c] != 0 {
+                continue;
+            }
+            for digit in 1..=9 {
+                if allowed(board, r, c, digit) {
+                    board[r][c] = digit;
+                    if solve(board) {
+                        return true;
+                    }
+                    board[r][c] = 0;
+                }
+            }
+            return false;
+        }
+    }
+    true
+}
+
+fn allowed(board: &[[u8; 9]; 9], r: usize, c: usize, digit: u8) -> bool {
+    let (br, bc) = (r / 3 * 3, c / 3 * 3);
+    (0..9).all(|i| board[r][i] != digit && board[i][c] != digit && board[br + i / 3][bc + i % 3] != digit)
+}
@@ -0,0 +1,17 @@
+fn build_suffix_array(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut sa: Vec<usize> = (0..n).collect();
+    let mut rank: Vec<i64> = s.iter().map(|&c| c as i64).collect();
+    let mut k = 1;
+    while k < n {
+        let key = |i: usize, rank: &[i64]| (rank[i], rank.get(i + k).copied().unwrap_or(-1));
+        sa.sort_by_key(|&i| key(i, &rank));
+        let mut next = vec![0; n];
+        for w in 1..n {
+            next[sa[w]] = next[sa[w - 1]] + i64::from(key(sa[w - 1], &rank) < key(sa[w], &rank));
+        }
+        rank = next;
+        k *= 2;
+    }
+    sa
+}
@@ -0,0 +1,12 @@
+fn ternary_search(f: impl Fn(f64) -> f64, mut lo: f64, mut hi: f64) -> f64 {
+    for _ in 0..100 {
+        let mid1 = lo + (hi - lo) / 3.0;
+        let mid2 = hi - (hi - lo) / 3.0;
+        if f(mid1) < f(mid2) {
+            lo = mid1;
+        } else {
+            hi = mid2;
+        }
+    }
+    (lo + hi) / 2.0
+}
@@ -0,0 +1,39 @@
+const MIN_RUN: usize = 32;
+
+fn sort_runs(v: &mut Vec<i32>) {
+    let n = v.len();
+    let mut start = 0;
+    while start < n {
+        let end = (start + MIN_RUN).min(n);
+        insertion(&mut v[start..end]);
+        start = end;
+    }
+    let mut size = MIN_RUN;
+    while size < n {
+        let mut left = 0;
+        while left < n {
+            let mid = (left + size).min(n);
+            let right = (left + 2 * size).min(n);
+            let merged = merge(&v[left..mid], &v[mid..right]);
+            v[left..right].copy_from_slice(&merged);
+            left += 2 * size;
+        }
+        size *= 2;
+    }
+}
+
+fn insertion(run: &mut [i32]) {
+    for i in 1..run.len() {
+        let mut j = i;
+        while j > 0 && run[j - 1] > run[j] {
+            run.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+fn merge(a: &[i32], b: &[i32]) -> Vec<i32> {
+    let mut out: Vec<i32> = a.iter().chain(b).copied().collect();
+    out.sort();
+    out
+}
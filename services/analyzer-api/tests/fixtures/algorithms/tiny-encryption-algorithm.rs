@@ -0,0 +1,12 @@
+const DELTA: u32 = 0x9E3779B9;
+
+fn encipher(v: &mut [u32; 2], k: &[u32; 4]) {
+    let (mut v0, mut v1) = (v[0], v[1]);
+    let mut sum: u32 = 0;
+    for _ in 0..32 {
+        sum = sum.wrapping_add(DELTA);
+        v0 = v0.wrapping_add((v1 << 4).wrapping_add(k[0]) ^ v1.wrapping_add(sum) ^ (v1 >> 5).wrapping_add(k[1]));
+        v1 = v1.wrapping_add((v0 << 4).wrapping_add(k[2]) ^ v0.wrapping_add(sum) ^ (v0 >> 5).wrapping_add(k[3]));
+    }
+    *v = [v0, v1];
+}
@@ -0,0 +1,22 @@
+use std::collections::VecDeque;
+
+fn build_order(n: usize, deps: &[(usize, usize)]) -> Option<Vec<usize>> {
+    let mut adj = vec![Vec::new(); n];
+    let mut in_degree = vec![0; n];
+    for &(a, b) in deps {
+        adj[a].push(b);
+        in_degree[b] += 1;
+    }
+    let mut queue: VecDeque<usize> = (0..n).filter(|&v| in_degree[v] == 0).collect();
+    let mut order = Vec::new();
+    while let Some(u) = queue.pop_front() {
+        order.push(u);
+        for &v in &adj[u] {
+            in_degree[v] -= 1;
+            if in_degree[v] == 0 {
+                queue.push_back(v);
+            }
+        }
+    }
+    (order.len() == n).then_some(order)
+}
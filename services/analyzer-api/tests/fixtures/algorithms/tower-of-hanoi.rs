@@ -0,0 +1,8 @@
+fn hanoi(disks: u32, from: char, to: char, via: char, moves: &mut Vec<(char, char)>) {
+    if disks == 0 {
+        return;
+    }
+    hanoi(disks - 1, from, via, to, moves);
+    moves.push((from, to));
+    hanoi(disks - 1, via, to, from, moves);
+}
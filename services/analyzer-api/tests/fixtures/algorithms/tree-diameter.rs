@@ -0,0 +1,12 @@
+struct TreeNode {
+    left: Option<Box<TreeNode>>,
+    right: Option<Box<TreeNode>>,
+}
+
+fn depth(node: &Option<Box<TreeNode>>, diameter: &mut usize) -> usize {
+    let Some(n) = node else { return 0 };
+    let left = depth(&n.left, diameter);
+    let right = depth(&n.right, diameter);
+    *diameter = (*diameter).max(left + right);
+    1 + left.max(right)
+}
@@ -0,0 +1,15 @@
+fn factorize(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    let mut i = 2;
+    while i * i <= n {
+        while n % i == 0 {
+            factors.push(i);
+            n /= i;
+        }
+        i += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
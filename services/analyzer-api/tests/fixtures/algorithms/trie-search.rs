@@ -0,0 +1,28 @@
+use std::collections::HashMap;
+
+#[derive(Default)]
+struct Node {
+    children: HashMap<char, Node>,
+    is_end: bool,
+}
+
+impl Node {
+    fn insert(&mut self, word: &str) {
+        let mut node = self;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.is_end = true;
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        let mut node = self;
+        for c in word.chars() {
+            match node.children.get(&c) {
+                Some(next) => node = next,
+                None => return false,
+            }
+        }
+        node.is_end
+    }
+}
@@ -0,0 +1,11 @@
+fn unbounded_knapsack(items: &[(usize, u32)], capacity: usize) -> u32 {
+    let mut dp = vec![0; capacity + 1];
+    for c in 1..=capacity {
+        for &(weight, value) in items {
+            if weight <= c {
+                dp[c] = dp[c].max(dp[c - weight] + value);
+            }
+        }
+    }
+    dp[capacity]
+}
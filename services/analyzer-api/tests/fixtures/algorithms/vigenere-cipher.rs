@@ -0,0 +1,8 @@
+fn encode(plain: &str, key: &str) -> String {
+    plain
+        .bytes()
+        .filter(u8::is_ascii_uppercase)
+        .zip(key.bytes().cycle())
+        .map(|(p, k)| ((p - b'A' + (k - b'A')) % 26 + b'A') as char)
+        .collect()
+}
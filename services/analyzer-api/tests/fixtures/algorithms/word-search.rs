@@ -0,0 +1,16 @@
+fn exists(board: &mut Vec<Vec<char>>, word: &[char], r: usize, c: usize, i: usize) -> bool {
+    if i == word.len() {
+        return true;
+    }
+    if r >= board.len() || c >= board[0].len() || board[r][c] != word[i] {
+        return false;
+    }
+    let saved = board[r][c];
+    board[r][c] = '#';
+    let found = exists(board, word, r + 1, c, i + 1)
+        || exists(board, word, r, c + 1, i + 1)
+        || (r > 0 && exists(board, word, r - 1, c, i + 1))
+        || (c > 0 && exists(board, word, r, c - 1, i + 1));
+    board[r][c] = saved;
+    found
+}
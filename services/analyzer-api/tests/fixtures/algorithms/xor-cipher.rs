@@ -0,0 +1,3 @@
+fn apply_key(data: &[u8], key: &[u8]) -> Vec<u8> {
+    data.iter().zip(key.iter().cycle()).map(|(b, k)| b ^ k).collect()
+}
@@ -0,0 +1,10 @@
+struct Keys {
+    table: [[u64; 64]; 12],
+}
+
+impl Keys {
+    fn apply_move(&self, hash: &mut u64, piece: usize, from_square: usize, to_square: usize) {
+        *hash ^= self.table[piece][from_square];
+        *hash ^= self.table[piece][to_square];
+    }
+}
@@ -0,0 +1,28 @@
+//! Request and response types of the analyzer API
+//!
+//! Shared by the server and its clients so both sides agree on the wire
+//! format. Each API version has its own module; the `v1` types are frozen
+//! and must not change shape, new fields go into `v2`.
+
+use serde::{Deserialize, Serialize};
+
+pub mod v1;
+pub mod v2;
+pub mod sse;
+
+/// JSON body returned with every non-2xx response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ErrorResponse {
+    pub error: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn new(error: &str, message: impl Into<String>) -> Self {
+        Self {
+            error: error.to_string(),
+            message: message.into(),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Server-Sent Events framing for streamed analyses
+//!
+//! The server writes events with [`encode`]; clients that cannot use
+//! `EventSource` (it only supports `GET`) feed the raw response body to an
+//! [`EventParser`]. Only the `event` and `data` fields are used, and
+//! events are separated by a blank line.
+
+/// A finding from one detector, with a [`crate::v2::StreamedFinding`] as data
+pub const FINDING: &str = "finding";
+
+/// Last event of a successful stream, with a [`crate::v2::AnalysisResponse`] as data
+pub const SUMMARY: &str = "summary";
+
+/// Last event of a failed stream, with an [`crate::ErrorResponse`] as data
+pub const ERROR: &str = "error";
+
+/// One decoded event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    /// Event name; `message` when the event did not set one
+    pub event: String,
+    pub data: String,
+}
+
+/// Frame one event; multi-line data is split over several `data` fields
+pub fn encode(event: &str, data: &str) -> String {
+    let mut frame = format!("event: {}\n", event);
+    for line in data.split('\n') {
+        frame.push_str("data: ");
+        frame.push_str(line);
+        frame.push('\n');
+    }
+    frame.push('\n');
+    frame
+}
+
+/// Incremental decoder for a stream of events arriving in arbitrary chunks
+#[derive(Debug, Default)]
+pub struct EventParser {
+    buffer: Vec<u8>,
+}
+
+impl EventParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a chunk of the body and return the events it completes
+    ///
+    /// Chunks may split an event, or a UTF-8 character, anywhere; the
+    /// incomplete tail is kept until the next chunk.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<Event> {
+        self.buffer.extend_from_slice(chunk);
+        let mut events = Vec::new();
+
+        while let Some(end) = self.buffer.windows(2).position(|pair| pair == b"\n\n") {
+            let frame: Vec<u8> = self.buffer.drain(..end + 2).collect();
+            if let Some(event) = parse_frame(&String::from_utf8_lossy(&frame)) {
+                events.push(event);
+            }
+        }
+        events
+    }
+}
+
+fn parse_frame(frame: &str) -> Option<Event> {
+    let mut event = None;
+    let mut data: Option<String> = None;
+
+    for line in frame.lines() {
+        // Lines starting with a colon are comments, e.g. keep-alives
+        if line.is_empty() || line.starts_with(':') {
+            continue;
+        }
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "event" => event = Some(value.to_string()),
+            "data" => match &mut data {
+                Some(data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => data = Some(value.to_string()),
+            },
+            _ => {}
+        }
+    }
+
+    // Events without data are not dispatched
+    data.map(|data| Event {
+        event: event.unwrap_or_else(|| "message".to_string()),
+        data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_across_chunks() {
+        let stream = [encode(FINDING, "{\"a\":1}"), encode(SUMMARY, "line one\nline two")].concat();
+        let (head, tail) = stream.as_bytes().split_at(11);
+
+        let mut parser = EventParser::new();
+        assert!(parser.push(head).is_empty());
+        let events = parser.push(tail);
+
+        assert_eq!(
+            events,
+            vec![
+                Event { event: FINDING.to_string(), data: "{\"a\":1}".to_string() },
+                Event { event: SUMMARY.to_string(), data: "line one\nline two".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_comments_and_unnamed_events() {
+        let mut parser = EventParser::new();
+        let events = parser.push(b": keep-alive\n\ndata: hello\n\n");
+        assert_eq!(events, vec![Event { event: "message".to_string(), data: "hello".to_string() }]);
+    }
+}
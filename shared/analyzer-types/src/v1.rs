@@ -0,0 +1,104 @@
+//! Version 1 of the API: the original `/analyze` contract, frozen
+//!
+//! Category fields carry the enum variant name (`DynamicProgramming`), as
+//! the unversioned endpoint always did.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v1::AnalyzeRequest))]
+pub struct AnalyzeRequest {
+    pub code: String,
+    /// Optional time budget in milliseconds, capped by the server limit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_budget_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v1::DetectedAlgorithm))]
+pub struct DetectedAlgorithm {
+    pub name: String,
+    pub category: String,
+    pub complexity: String,
+    pub description: String,
+    pub wikipedia_link: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v1::DetectedDataStructure))]
+pub struct DetectedDataStructure {
+    pub name: String,
+    pub category: String,
+    pub complexity: String,
+    pub description: String,
+    pub wikipedia_link: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v1::AnalysisResult))]
+pub struct AnalysisResult {
+    pub patterns: Vec<String>,
+    /// Names of `detailed_algorithms`
+    pub algorithms: Vec<String>,
+    pub detailed_algorithms: Vec<DetectedAlgorithm>,
+    /// Names of `detailed_data_structures`
+    pub data_structures: Vec<String>,
+    pub detailed_data_structures: Vec<DetectedDataStructure>,
+    pub complexity: String,
+    pub recommendations: Vec<String>,
+    /// Set when the time budget ran out and the results are partial
+    #[serde(default)]
+    pub timed_out: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The field names below are the v1 contract; changing them breaks clients
+    #[test]
+    fn test_analysis_result_fields_are_frozen() {
+        let result = AnalysisResult {
+            patterns: vec![],
+            algorithms: vec!["Bubble Sort".to_string()],
+            detailed_algorithms: vec![DetectedAlgorithm {
+                name: "Bubble Sort".to_string(),
+                category: "Sorting".to_string(),
+                complexity: "O(n^2)".to_string(),
+                description: String::new(),
+                wikipedia_link: String::new(),
+            }],
+            data_structures: vec![],
+            detailed_data_structures: vec![],
+            complexity: "Low".to_string(),
+            recommendations: vec![],
+            timed_out: false,
+        };
+
+        let value = serde_json::to_value(&result).unwrap();
+        let mut keys: Vec<&str> = value.as_object().unwrap().keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(
+            keys,
+            [
+                "algorithms",
+                "complexity",
+                "data_structures",
+                "detailed_algorithms",
+                "detailed_data_structures",
+                "patterns",
+                "recommendations",
+                "timed_out",
+            ]
+        );
+
+        let algorithm = &value["detailed_algorithms"][0];
+        let mut keys: Vec<&str> = algorithm.as_object().unwrap().keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["category", "complexity", "description", "name", "wikipedia_link"]);
+    }
+}
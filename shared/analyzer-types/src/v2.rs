@@ -0,0 +1,525 @@
+//! Version 2 of the API: one list of findings per kind, no name duplicates
+
+use serde::{Deserialize, Serialize};
+
+use crate::ErrorResponse;
+
+/// Language assumed when a request does not name one
+pub const DEFAULT_LANGUAGE: &str = "rust";
+
+fn default_language() -> String {
+    DEFAULT_LANGUAGE.to_string()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::AnalyzeRequest))]
+pub struct AnalyzeRequest {
+    pub code: String,
+    /// Source language; only `rust` is supported for now
+    #[serde(default = "default_language")]
+    pub language: String,
+    /// Optional time budget in milliseconds, capped by the server limit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_budget_ms: Option<u64>,
+}
+
+/// A detected algorithm or data structure
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::Finding))]
+pub struct Finding {
+    pub name: String,
+    /// Human-readable category, e.g. `Dynamic Programming`
+    pub category: String,
+    pub complexity: String,
+    pub description: String,
+    pub wikipedia_link: String,
+    /// How the entry relates to other catalog entries
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relations: Vec<Relation>,
+    /// Types in the analyzed code whose shape matched the entry
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub definitions: Vec<TypeDefinition>,
+}
+
+/// A range of the analyzed code; lines and columns are 1-based, and the
+/// end column is that of the last character
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::SourceSpan))]
+pub struct SourceSpan {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+/// A struct, enum or type alias of the analyzed code that implements a
+/// catalog entry
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::TypeDefinition))]
+pub struct TypeDefinition {
+    pub name: String,
+    pub span: SourceSpan,
+    /// The fields that gave the shape away, e.g. `left: Option<Box<Node>>`
+    pub evidence: String,
+}
+
+/// Which catalog an entry belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::CatalogKind))]
+#[serde(rename_all = "snake_case")]
+pub enum CatalogKind {
+    Algorithm,
+    DataStructure,
+}
+
+impl CatalogKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CatalogKind::Algorithm => "algorithm",
+            CatalogKind::DataStructure => "data_structure",
+        }
+    }
+}
+
+impl std::str::FromStr for CatalogKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "algorithm" => Ok(CatalogKind::Algorithm),
+            "data_structure" => Ok(CatalogKind::DataStructure),
+            other => Err(format!("unknown catalog kind '{}'", other)),
+        }
+    }
+}
+
+/// How one catalog entry relates to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::RelationKind))]
+#[serde(rename_all = "snake_case")]
+pub enum RelationKind {
+    /// Built on the target, e.g. Heap Sort uses a Binary Heap
+    Uses,
+    /// A modified form of the target, e.g. an AVL Tree is a Binary Search Tree
+    VariantOf,
+    /// Solves the same problem as the target; holds in both directions
+    AlternativeTo,
+    /// Worth understanding before this entry, e.g. Breadth-First Search before Dijkstra's Algorithm
+    Prerequisite,
+}
+
+impl RelationKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RelationKind::Uses => "uses",
+            RelationKind::VariantOf => "variant_of",
+            RelationKind::AlternativeTo => "alternative_to",
+            RelationKind::Prerequisite => "prerequisite",
+        }
+    }
+
+    /// Whether the relation holds in both directions
+    pub fn is_symmetric(&self) -> bool {
+        matches!(self, RelationKind::AlternativeTo)
+    }
+}
+
+/// A typed edge from a catalog entry to another one
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::Relation))]
+pub struct Relation {
+    pub kind: RelationKind,
+    /// Name of the related entry
+    pub target: String,
+    /// Catalog the related entry is in
+    pub target_kind: CatalogKind,
+}
+
+impl Relation {
+    /// A relation to an entry of the algorithm catalog
+    pub fn algorithm(kind: RelationKind, target: &str) -> Self {
+        Relation {
+            kind,
+            target: target.to_string(),
+            target_kind: CatalogKind::Algorithm,
+        }
+    }
+
+    /// A relation to an entry of the data structure catalog
+    pub fn data_structure(kind: RelationKind, target: &str) -> Self {
+        Relation {
+            kind,
+            target: target.to_string(),
+            target_kind: CatalogKind::DataStructure,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::AnalysisResponse))]
+pub struct AnalysisResponse {
+    pub language: String,
+    /// Overall size-based complexity rating: `Low`, `Medium` or `High`
+    pub complexity: String,
+    /// Set when the time budget ran out and the findings are partial
+    pub timed_out: bool,
+    pub algorithms: Vec<Finding>,
+    pub data_structures: Vec<Finding>,
+    /// Standard-library collections the code uses, and what it calls on them
+    #[serde(default)]
+    pub collections: Vec<CollectionUsage>,
+    /// Collections that fit the way the code uses them better
+    #[serde(default)]
+    pub suggestions: Vec<Suggestion>,
+    /// Constructs that are likely to make the code slower than it needs to be
+    #[serde(default)]
+    pub anti_patterns: Vec<AntiPattern>,
+    pub patterns: Vec<String>,
+    pub recommendations: Vec<String>,
+}
+
+/// A `std::collections` type the analyzed code creates, takes as a
+/// parameter or declares as a field
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::CollectionUsage))]
+pub struct CollectionUsage {
+    /// Type name, e.g. `VecDeque`
+    pub collection: String,
+    /// Variable or field holding it, e.g. `queue` or `self.items`; absent
+    /// for temporaries
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binding: Option<String>,
+    /// Catalog entry it implements, e.g. `Queue` for a `VecDeque`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_structure: Option<String>,
+    /// Where it is created, received or declared
+    pub span: SourceSpan,
+    /// Calls on it, in source order
+    pub operations: Vec<CollectionOperation>,
+}
+
+/// A method called on a collection, with its documented cost
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::CollectionOperation))]
+pub struct CollectionOperation {
+    /// Method name; `remove(0)` for removing the first element
+    pub operation: String,
+    /// Cost in Big-O notation, e.g. `O(n)`
+    pub cost: String,
+    /// `amortized` or `expected` when the cost only holds that way
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Set when the call runs once per iteration of a loop or of an
+    /// iterator closure
+    #[serde(default)]
+    pub in_loop: bool,
+    pub span: SourceSpan,
+}
+
+/// A collection that would make a call the code repeats cheaper
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::Suggestion))]
+pub struct Suggestion {
+    /// Collection the code uses, e.g. `Vec`
+    pub collection: String,
+    /// Variable or field holding it; absent for temporaries
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binding: Option<String>,
+    /// Collection to use instead, e.g. `VecDeque`
+    pub suggested: String,
+    /// The call that prompted the suggestion
+    pub operation: String,
+    pub span: SourceSpan,
+    /// Cost of the call as written, e.g. `O(n)`
+    pub before: String,
+    /// Cost of the equivalent call on the suggested collection, e.g. `O(1)`
+    pub after: String,
+    /// The suggestion as a sentence, as listed in `recommendations`
+    pub message: String,
+}
+
+/// A performance smell in the analyzed code
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::AntiPattern))]
+pub struct AntiPattern {
+    pub kind: AntiPatternKind,
+    pub severity: Severity,
+    pub span: SourceSpan,
+    /// What is wrong and what to do instead
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::AntiPatternKind))]
+#[serde(rename_all = "snake_case")]
+pub enum AntiPatternKind {
+    /// Two nested loops over the same collection comparing its elements
+    NestedLoopComparison,
+    /// Strings joined with `+` inside a loop
+    StringConcatInLoop,
+    /// A collection cloned on every iteration of a loop
+    CloneInLoop,
+    /// A `collect()` whose result is only iterated once, right away
+    CollectThenIterate,
+    /// The same value lower- or upper-cased on every iteration of a loop
+    CaseConversionInLoop,
+}
+
+impl AntiPatternKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AntiPatternKind::NestedLoopComparison => "nested_loop_comparison",
+            AntiPatternKind::StringConcatInLoop => "string_concat_in_loop",
+            AntiPatternKind::CloneInLoop => "clone_in_loop",
+            AntiPatternKind::CollectThenIterate => "collect_then_iterate",
+            AntiPatternKind::CaseConversionInLoop => "case_conversion_in_loop",
+        }
+    }
+}
+
+/// How much an anti-pattern is likely to cost, from `low` to `high`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::Severity))]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// A constant factor, e.g. an extra allocation
+    Low,
+    /// Work repeated per iteration that could be done once
+    Medium,
+    /// A higher complexity class than needed, e.g. `O(n²)` for `O(n)`
+    High,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Low => "low",
+            Severity::Medium => "medium",
+            Severity::High => "high",
+        }
+    }
+}
+
+/// One named source file in a batch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::BatchFile))]
+pub struct BatchFile {
+    /// Path of the file within the project, used to label its results
+    pub path: String,
+    pub code: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::BatchRequest))]
+pub struct BatchRequest {
+    /// Optional label for the stored submission, e.g. a project name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub files: Vec<BatchFile>,
+    #[serde(default = "default_language")]
+    pub language: String,
+    /// Time budget per file in milliseconds, capped by the server limit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_budget_ms: Option<u64>,
+}
+
+/// Outcome for one file: either a result or the reason it was not analyzed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::BatchFileResult))]
+pub struct BatchFileResult {
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<AnalysisResponse>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorResponse>,
+}
+
+/// An algorithm or data structure and the files it was found in
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::InventoryEntry))]
+pub struct InventoryEntry {
+    pub name: String,
+    pub category: String,
+    pub files: Vec<String>,
+}
+
+/// What a batch contains, across all of its files
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::Inventory))]
+pub struct Inventory {
+    /// Files that were analyzed successfully
+    pub analyzed: usize,
+    /// Files rejected or not analyzed because of an error
+    pub failed: usize,
+    /// Archive entries left out because they are not source files of the language
+    #[serde(default)]
+    pub skipped: Vec<String>,
+    /// Most widespread first
+    pub algorithms: Vec<InventoryEntry>,
+    /// Most widespread first
+    pub data_structures: Vec<InventoryEntry>,
+    /// Set when any file ran out of time budget
+    pub timed_out: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::BatchResponse))]
+pub struct BatchResponse {
+    /// Id of the stored submission; absent when it could not be saved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_id: Option<String>,
+    pub files: Vec<BatchFileResult>,
+    pub inventory: Inventory,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::JobStatus))]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Done,
+    Failed,
+}
+
+impl JobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobStatus::Queued => "queued",
+            JobStatus::Running => "running",
+            JobStatus::Done => "done",
+            JobStatus::Failed => "failed",
+        }
+    }
+
+    /// Whether the job will not change any more
+    pub fn is_finished(&self) -> bool {
+        matches!(self, JobStatus::Done | JobStatus::Failed)
+    }
+}
+
+impl std::str::FromStr for JobStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "queued" => Ok(JobStatus::Queued),
+            "running" => Ok(JobStatus::Running),
+            "done" => Ok(JobStatus::Done),
+            "failed" => Ok(JobStatus::Failed),
+            other => Err(format!("unknown job status '{}'", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::JobProgress))]
+pub struct JobProgress {
+    /// Files analyzed so far
+    pub completed: usize,
+    pub total: usize,
+}
+
+/// An asynchronous analysis job; its result is a [`BatchResponse`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::Job))]
+pub struct Job {
+    pub id: String,
+    pub status: JobStatus,
+    pub progress: JobProgress,
+    /// RFC 3339 timestamp
+    pub created_at: String,
+    /// RFC 3339 timestamp of the last status or progress change
+    pub updated_at: String,
+    /// Why the job failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorResponse>,
+}
+
+/// Detector that produced a streamed finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::Detector))]
+#[serde(rename_all = "snake_case")]
+pub enum Detector {
+    Algorithms,
+    DataStructures,
+}
+
+/// Data of a `finding` event on `/v2/analyze/stream`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "openapi", schema(as = v2::StreamedFinding))]
+pub struct StreamedFinding {
+    pub detector: Detector,
+    /// Function the finding was detected in; absent when only the file as a
+    /// whole matched, e.g. for code outside any function
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub function: Option<String>,
+    /// 1-based line on which that function starts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    pub finding: Finding,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_language_defaults_to_rust() {
+        let request: AnalyzeRequest = serde_json::from_str(r#"{"code": "fn main() {}"}"#).unwrap();
+        assert_eq!(request.language, DEFAULT_LANGUAGE);
+        assert_eq!(request.time_budget_ms, None);
+    }
+
+    #[test]
+    fn test_job_status_round_trips_through_str() {
+        for status in [JobStatus::Queued, JobStatus::Running, JobStatus::Done, JobStatus::Failed] {
+            assert_eq!(status.as_str().parse::<JobStatus>(), Ok(status));
+            assert_eq!(serde_json::to_value(status).unwrap(), status.as_str());
+        }
+        assert!(!JobStatus::Running.is_finished());
+        assert!(JobStatus::Failed.is_finished());
+    }
+
+    #[test]
+    fn test_relations_serialize_in_snake_case() {
+        let relation = Relation::data_structure(RelationKind::Uses, "Binary Heap");
+        assert_eq!(
+            serde_json::to_value(&relation).unwrap(),
+            serde_json::json!({"kind": "uses", "target": "Binary Heap", "target_kind": "data_structure"})
+        );
+        assert_eq!(serde_json::to_value(RelationKind::VariantOf).unwrap(), RelationKind::VariantOf.as_str());
+        assert_eq!("data_structure".parse::<CatalogKind>(), Ok(CatalogKind::DataStructure));
+
+        let finding: Finding = serde_json::from_str(
+            r#"{"name": "X", "category": "Sorting", "complexity": "O(n)", "description": "", "wikipedia_link": ""}"#,
+        )
+        .unwrap();
+        assert!(finding.relations.is_empty());
+    }
+}
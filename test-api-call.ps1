@@ -6,7 +6,7 @@ $json = @{
 } | ConvertTo-Json
 
 try {
-    $response = Invoke-WebRequest -Uri "http://localhost:8081/analyze" -Method POST -ContentType "application/json" -Body $json
+    $response = Invoke-WebRequest -Uri "http://localhost:8081/v1/analyze" -Method POST -ContentType "application/json" -Body $json
     Write-Host "Response Status: $($response.StatusCode)"
     Write-Host "Response Content:"
     Write-Host $response.Content